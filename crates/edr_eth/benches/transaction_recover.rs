@@ -0,0 +1,55 @@
+use std::hint::black_box;
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use edr_eth::{
+    signature::secret_key_from_str,
+    transaction::{LegacySignedTransaction, LegacyTransactionRequest, TransactionKind},
+    Address, Bytes, U256,
+};
+
+const NUM_RECOVERIES: usize = 1000;
+
+fn signed_transaction() -> LegacySignedTransaction {
+    let secret_key =
+        secret_key_from_str("e331b6d69882b4cb4ea581d88e0b604039a3de5967688d3dcffdd2270c0fd109")
+            .expect("valid secret key");
+
+    LegacyTransactionRequest {
+        nonce: 1,
+        gas_price: U256::from(2),
+        gas_limit: 21_000,
+        kind: TransactionKind::Call(Address::ZERO),
+        value: U256::from(4),
+        input: Bytes::default(),
+    }
+    .sign(&secret_key)
+    .expect("signing succeeds")
+}
+
+fn bench_recover(c: &mut Criterion) {
+    let mut group = c.benchmark_group("LegacySignedTransaction::recover");
+
+    let mut transaction = signed_transaction();
+    group.bench_function("uncached", |b| {
+        b.iter(|| {
+            for _ in 0..NUM_RECOVERIES {
+                transaction.caller.take();
+                black_box(transaction.recover().expect("recovery succeeds"));
+            }
+        });
+    });
+
+    let transaction = signed_transaction();
+    group.bench_function("cached", |b| {
+        b.iter(|| {
+            for _ in 0..NUM_RECOVERIES {
+                black_box(transaction.recover().expect("recovery succeeds"));
+            }
+        });
+    });
+
+    group.finish();
+}
+
+criterion_group!(transaction_recover_benches, bench_recover);
+criterion_main!(transaction_recover_benches);
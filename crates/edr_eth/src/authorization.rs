@@ -0,0 +1,168 @@
+use alloy_primitives::keccak256;
+use alloy_rlp::{RlpDecodable, RlpEncodable};
+use k256::SecretKey;
+
+use crate::{
+    signature::{Signature, SignatureError},
+    utils::envelop_bytes,
+    Address, B256, U256,
+};
+
+/// The magic byte that is prepended to the RLP-encoded authorization when
+/// computing its signature hash, as defined by EIP-7702.
+pub const AUTHORIZATION_MAGIC: u8 = 0x05;
+
+/// An authorization for an externally owned account to delegate its code to a
+/// contract (EIP-7702).
+#[derive(Clone, Debug, PartialEq, Eq, RlpDecodable, RlpEncodable)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub struct Authorization {
+    // The order of these fields determines de-/encoding order.
+    /// The chain id for which the authorization is valid, or zero for all
+    /// chains
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde::u64"))]
+    pub chain_id: u64,
+    /// The address of the contract that code is delegated to
+    pub address: Address,
+    /// The nonce of the authority at the time of authorization
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde::u64"))]
+    pub nonce: u64,
+}
+
+impl Authorization {
+    /// Computes the hash that is signed by the authority.
+    pub fn signature_hash(&self) -> B256 {
+        let encoded = alloy_rlp::encode(self);
+
+        keccak256(envelop_bytes(AUTHORIZATION_MAGIC, &encoded))
+    }
+
+    /// Signs the authorization using the provided secret key.
+    pub fn sign(self, secret_key: &SecretKey) -> Result<SignedAuthorization, SignatureError> {
+        let signature = Signature::new(self.signature_hash(), secret_key)?;
+
+        Ok(SignedAuthorization {
+            chain_id: self.chain_id,
+            address: self.address,
+            nonce: self.nonce,
+            y_parity: u8::from(signature.odd_y_parity()),
+            r: signature.r,
+            s: signature.s,
+        })
+    }
+}
+
+/// A signed [`Authorization`], as included in the authorization list of an
+/// EIP-7702 transaction.
+#[derive(Clone, Debug, PartialEq, Eq, RlpDecodable, RlpEncodable)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub struct SignedAuthorization {
+    // The order of these fields determines de-/encoding order.
+    /// The chain id for which the authorization is valid, or zero for all
+    /// chains
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde::u64"))]
+    pub chain_id: u64,
+    /// The address of the contract that code is delegated to
+    pub address: Address,
+    /// The nonce of the authority at the time of authorization
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde::u64"))]
+    pub nonce: u64,
+    /// The parity of the Y coordinate of the signature
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde::u8"))]
+    pub y_parity: u8,
+    /// R value of the signature
+    pub r: U256,
+    /// S value of the signature
+    pub s: U256,
+}
+
+impl SignedAuthorization {
+    /// Returns the unsigned authorization.
+    pub fn authorization(&self) -> Authorization {
+        Authorization {
+            chain_id: self.chain_id,
+            address: self.address,
+            nonce: self.nonce,
+        }
+    }
+
+    /// Recovers the address of the authority that signed the authorization.
+    ///
+    /// EIP-7702 requires the Y parity to be zero or one and disallows
+    /// malleable signatures.
+    pub fn recover(&self) -> Result<Address, SignatureError> {
+        if self.y_parity > 1 {
+            return Err(SignatureError::InvalidRecoveryId {
+                v: u64::from(self.y_parity),
+            });
+        }
+
+        let signature = Signature {
+            r: self.r,
+            s: self.s,
+            v: u64::from(self.y_parity),
+        };
+
+        signature.recover_strict(self.authorization().signature_hash())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_rlp::Decodable;
+
+    use super::*;
+    use crate::signature::{secret_key_from_str, secret_key_to_address};
+
+    const DUMMY_SECRET_KEY: &str =
+        "e331b6d69882b4cb4ea581d88e0b604039a3de5967688d3dcffdd2270c0fd109";
+
+    fn dummy_authorization() -> Authorization {
+        Authorization {
+            chain_id: 1,
+            address: Address::repeat_byte(0x42),
+            nonce: 7,
+        }
+    }
+
+    #[test]
+    fn signed_authorization_recover() -> anyhow::Result<()> {
+        let secret_key = secret_key_from_str(DUMMY_SECRET_KEY)?;
+        let signed = dummy_authorization().sign(&secret_key)?;
+
+        assert_eq!(signed.authorization(), dummy_authorization());
+        assert_eq!(signed.recover()?, secret_key_to_address(DUMMY_SECRET_KEY)?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn signed_authorization_rlp() -> anyhow::Result<()> {
+        let secret_key = secret_key_from_str(DUMMY_SECRET_KEY)?;
+        let signed = dummy_authorization().sign(&secret_key)?;
+
+        let encoded = alloy_rlp::encode(&signed);
+        assert_eq!(
+            signed,
+            SignedAuthorization::decode(&mut encoded.as_slice())?
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn signed_authorization_invalid_y_parity() -> anyhow::Result<()> {
+        let secret_key = secret_key_from_str(DUMMY_SECRET_KEY)?;
+        let mut signed = dummy_authorization().sign(&secret_key)?;
+        signed.y_parity = 2;
+
+        assert!(matches!(
+            signed.recover(),
+            Err(SignatureError::InvalidRecoveryId { v: 2 })
+        ));
+
+        Ok(())
+    }
+}
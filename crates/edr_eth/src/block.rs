@@ -9,6 +9,8 @@ mod options;
 mod reorg;
 mod reward;
 
+use std::num::NonZeroU64;
+
 use alloy_rlp::{BufMut, Decodable, RlpDecodable, RlpEncodable};
 use revm_primitives::{calc_excess_blob_gas, keccak256};
 
@@ -17,7 +19,7 @@ pub use self::{
     options::BlockOptions,
     reorg::{
         block_time, is_safe_block_number, largest_safe_block_number, safe_block_depth,
-        IsSafeBlockNumberArgs, LargestSafeBlockNumberArgs,
+        IsSafeBlockNumberArgs, LargestSafeBlockNumberArgs, ReorgConfig,
     },
     reward::miner_reward,
 };
@@ -242,7 +244,10 @@ impl PartialHeader {
             base_fee: options.base_fee.or_else(|| {
                 if spec_id >= SpecId::LONDON {
                     Some(if let Some(parent) = &parent {
-                        calculate_next_base_fee(parent)
+                        calculate_next_base_fee_with_params(
+                            parent,
+                            &options.base_fee_params.unwrap_or_default(),
+                        )
                     } else {
                         // Initial base fee from https://eips.ethereum.org/EIPS/eip-1559
                         U256::from(1_000_000_000)
@@ -331,17 +336,48 @@ impl From<Header> for PartialHeader {
     }
 }
 
+/// Parameters of the EIP-1559 base fee adjustment.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub struct BaseFeeParams {
+    /// The ratio between a block's gas limit and its gas target
+    pub elasticity_multiplier: NonZeroU64,
+    /// The inverse of the maximum relative change of the base fee between
+    /// consecutive blocks
+    pub max_change_denominator: NonZeroU64,
+}
+
+impl Default for BaseFeeParams {
+    /// Returns the parameters of Ethereum mainnet, as defined in EIP-1559.
+    fn default() -> Self {
+        Self {
+            elasticity_multiplier: NonZeroU64::new(2).expect("constant is non-zero"),
+            max_change_denominator: NonZeroU64::new(8).expect("constant is non-zero"),
+        }
+    }
+}
+
 /// Calculates the next base fee for a post-London block, given the parent's
-/// header.
+/// header, using Ethereum mainnet's [`BaseFeeParams`].
 ///
 /// # Panics
 ///
 /// Panics if the parent header does not contain a base fee.
 pub fn calculate_next_base_fee(parent: &Header) -> U256 {
-    let elasticity = 2;
-    let base_fee_max_change_denominator = U256::from(8);
+    calculate_next_base_fee_with_params(parent, &BaseFeeParams::default())
+}
 
-    let parent_gas_target = parent.gas_limit / elasticity;
+/// Calculates the next base fee for a post-London block, given the parent's
+/// header and the [`BaseFeeParams`].
+///
+/// # Panics
+///
+/// Panics if the parent header does not contain a base fee.
+pub fn calculate_next_base_fee_with_params(parent: &Header, params: &BaseFeeParams) -> U256 {
+    let base_fee_max_change_denominator = U256::from(params.max_change_denominator.get());
+
+    let parent_gas_target = parent.gas_limit / params.elasticity_multiplier;
     let parent_base_fee = parent
         .base_fee_per_gas
         .expect("Post-London headers must contain a baseFee");
@@ -414,6 +450,71 @@ mod tests {
         }
     }
 
+    #[test]
+    fn next_base_fee_changes_by_an_eighth_for_full_and_empty_blocks() {
+        const GAS_LIMIT: u64 = 30_000_000;
+
+        let mut base_fee = U256::from(1_000_000_000u64);
+        for gas_used in [GAS_LIMIT, GAS_LIMIT, 0, 0, 0] {
+            let parent_header = Header {
+                base_fee_per_gas: Some(base_fee),
+                gas_used,
+                gas_limit: GAS_LIMIT,
+                ..Default::default()
+            };
+
+            let next_base_fee = calculate_next_base_fee(&parent_header);
+            let expected = if gas_used == GAS_LIMIT {
+                base_fee + base_fee / U256::from(8)
+            } else {
+                base_fee - base_fee / U256::from(8)
+            };
+            assert_eq!(next_base_fee, expected);
+
+            base_fee = next_base_fee;
+        }
+    }
+
+    #[test]
+    fn next_base_fee_with_custom_params() {
+        let params = BaseFeeParams {
+            elasticity_multiplier: NonZeroU64::new(4).expect("constant is non-zero"),
+            max_change_denominator: NonZeroU64::new(50).expect("constant is non-zero"),
+        };
+
+        let base_fee = U256::from(1_000_000_000u64);
+        let full_parent = Header {
+            base_fee_per_gas: Some(base_fee),
+            gas_used: 40_000_000,
+            gas_limit: 40_000_000,
+            ..Default::default()
+        };
+
+        // The gas used exceeds the target by three times the target
+        assert_eq!(
+            calculate_next_base_fee_with_params(&full_parent, &params),
+            base_fee + base_fee * U256::from(3) / U256::from(50)
+        );
+
+        let target_parent = Header {
+            gas_used: 10_000_000,
+            ..full_parent
+        };
+        assert_eq!(
+            calculate_next_base_fee_with_params(&target_parent, &params),
+            base_fee
+        );
+
+        let empty_parent = Header {
+            gas_used: 0,
+            ..target_parent
+        };
+        assert_eq!(
+            calculate_next_base_fee_with_params(&empty_parent, &params),
+            base_fee - base_fee / U256::from(50)
+        );
+    }
+
     #[test]
     fn header_rlp_roundtrip() {
         let mut header = Header {
@@ -603,4 +704,51 @@ mod tests {
         assert_eq!(encoded, expected_encoding);
         assert_eq!(header.hash(), expected_hash);
     }
+
+    #[test]
+    fn partial_header_rolling_excess_blob_gas() {
+        use revm_primitives::{MAX_BLOB_GAS_PER_BLOCK, TARGET_BLOB_GAS_PER_BLOCK};
+
+        fn next_header(parent: Option<&Header>, blob_gas_used: u64) -> Header {
+            let mut partial_header =
+                PartialHeader::new(SpecId::CANCUN, BlockOptions::default(), parent);
+
+            partial_header
+                .blob_gas
+                .as_mut()
+                .expect("Cancun headers have blob gas")
+                .gas_used = blob_gas_used;
+
+            Header::new(
+                partial_header,
+                KECCAK_RLP_EMPTY_ARRAY,
+                KECCAK_NULL_RLP,
+                Some(KECCAK_NULL_RLP),
+            )
+        }
+
+        // (blob gas used, expected excess blob gas)
+        let blocks = [
+            (MAX_BLOB_GAS_PER_BLOCK, 0),
+            (MAX_BLOB_GAS_PER_BLOCK, TARGET_BLOB_GAS_PER_BLOCK),
+            (TARGET_BLOB_GAS_PER_BLOCK, 2 * TARGET_BLOB_GAS_PER_BLOCK),
+            (0, 2 * TARGET_BLOB_GAS_PER_BLOCK),
+            (0, TARGET_BLOB_GAS_PER_BLOCK),
+            (0, 0),
+        ];
+
+        let mut parent: Option<Header> = None;
+        for (blob_gas_used, expected_excess_gas) in blocks {
+            let header = next_header(parent.as_ref(), blob_gas_used);
+
+            let blob_gas = header
+                .blob_gas
+                .as_ref()
+                .expect("Cancun headers have blob gas");
+            assert_eq!(blob_gas.excess_gas, expected_excess_gas);
+            assert_eq!(blob_gas.gas_used, blob_gas_used);
+
+            parent = Some(header);
+        }
+    }
 }
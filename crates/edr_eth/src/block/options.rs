@@ -1,4 +1,4 @@
-use super::BlobGas;
+use super::{BaseFeeParams, BlobGas};
 use crate::{withdrawal::Withdrawal, Address, Bytes, B256, B64, U256};
 
 /// Data of a block header
@@ -26,6 +26,9 @@ pub struct BlockOptions {
     pub nonce: Option<B64>,
     /// The block's base gas fee
     pub base_fee: Option<U256>,
+    /// The parameters used to calculate the block's base fee from its parent,
+    /// if no base fee is provided. Defaults to Ethereum mainnet's.
+    pub base_fee_params: Option<BaseFeeParams>,
     /// The block's withdrawals
     pub withdrawals: Option<Vec<Withdrawal>>,
     /// Blob gas was added by EIP-4844 and is ignored in older headers.
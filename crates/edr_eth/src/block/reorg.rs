@@ -1,5 +1,7 @@
 use std::time::Duration;
 
+use crate::HashMap;
+
 /// The default depth of blocks to consider safe from a reorg and thus
 /// cacheable.
 const DEFAULT_SAFE_BLOCK_DEPTH: u64 = 128;
@@ -8,29 +10,36 @@ const DEFAULT_SAFE_BLOCK_DEPTH: u64 = 128;
 /// the safe side.
 const DEFAULT_SAFE_BLOCK_TIME: Duration = Duration::from_secs(1);
 
+/// Overrides of the safe block depth, keyed by chain id. Chains without an
+/// override use the built-in defaults of [`safe_block_depth`].
+pub type ReorgConfig = HashMap<u64, u64>;
+
 /// Test whether a block number is safe from a reorg for a specific chain based
 /// on the latest block number.
-pub fn is_safe_block_number(args: IsSafeBlockNumberArgs) -> bool {
+pub fn is_safe_block_number(args: IsSafeBlockNumberArgs<'_>) -> bool {
     largest_safe_block_number((&args).into())
         .is_some_and(|safe_block_number| args.block_number <= safe_block_number)
 }
 
 /// Arguments for the `is_safe_block_number` function.
 /// The purpose of this struct is to prevent mixing up the U256 arguments.
-pub struct IsSafeBlockNumberArgs {
+pub struct IsSafeBlockNumberArgs<'a> {
     /// The chain id
     pub chain_id: u64,
     /// The latest known block number
     pub latest_block_number: u64,
     /// The block number to test
     pub block_number: u64,
+    /// Optional overrides of the safe block depth per chain id
+    pub reorg_config: Option<&'a ReorgConfig>,
 }
 
-impl<'a> From<&'a IsSafeBlockNumberArgs> for LargestSafeBlockNumberArgs {
-    fn from(value: &'a IsSafeBlockNumberArgs) -> LargestSafeBlockNumberArgs {
+impl<'a> From<&IsSafeBlockNumberArgs<'a>> for LargestSafeBlockNumberArgs<'a> {
+    fn from(value: &IsSafeBlockNumberArgs<'a>) -> LargestSafeBlockNumberArgs<'a> {
         LargestSafeBlockNumberArgs {
             chain_id: value.chain_id,
             latest_block_number: value.latest_block_number,
+            reorg_config: value.reorg_config,
         }
     }
 }
@@ -39,18 +48,24 @@ impl<'a> From<&'a IsSafeBlockNumberArgs> for LargestSafeBlockNumberArgs {
 /// based on the latest block number.
 ///
 /// Returns `None` if the genesis block falls within the safe block depth.
-pub fn largest_safe_block_number(args: LargestSafeBlockNumberArgs) -> Option<u64> {
-    args.latest_block_number
-        .checked_sub(safe_block_depth(args.chain_id))
+pub fn largest_safe_block_number(args: LargestSafeBlockNumberArgs<'_>) -> Option<u64> {
+    let safe_block_depth = args
+        .reorg_config
+        .and_then(|reorg_config| reorg_config.get(&args.chain_id).copied())
+        .unwrap_or_else(|| safe_block_depth(args.chain_id));
+
+    args.latest_block_number.checked_sub(safe_block_depth)
 }
 
 /// Arguments for the `largest_safe_block_number` function.
 /// The purpose of this struct is to prevent mixing up the U256 arguments.
-pub struct LargestSafeBlockNumberArgs {
+pub struct LargestSafeBlockNumberArgs<'a> {
     /// The chain id
     pub chain_id: u64,
     /// The latest known block number
     pub latest_block_number: u64,
+    /// Optional overrides of the safe block depth per chain id
+    pub reorg_config: Option<&'a ReorgConfig>,
 }
 
 /// The safe block depth for a specific chain.
@@ -64,6 +79,9 @@ pub fn safe_block_depth(chain_id: u64) -> u64 {
         1 | 4 | 5 | 42 => 32,
         // Ropsten
         3 => 100,
+        // Sepolia and Holesky testnets
+        // Proof-of-stake networks finalize after two epochs of 32 blocks
+        11_155_111 | 17_000 => 64,
         // Gnosis/xDai
         100 => 38,
         _ => {
@@ -79,9 +97,9 @@ pub fn safe_block_depth(chain_id: u64) -> u64 {
 /// The interval between blocks for a specific chain.
 pub fn block_time(chain_id: u64) -> Duration {
     match chain_id {
-        // Ethereum mainnet, Ropsten, Rinkeby, Goerli and Kovan testnets
+        // Ethereum mainnet, Ropsten, Rinkeby, Goerli, Kovan, Holesky and Sepolia testnets
         // 32 blocks is one epoch on Ethereum mainnet
-        1 | 3 | 4 | 5 | 42 => Duration::from_secs(12),
+        1 | 3 | 4 | 5 | 42 | 17_000 | 11_155_111 => Duration::from_secs(12),
         // Gnosis/xDai
         // https://gnosisscan.io/chart/blocktime
         100 => Duration::from_secs(5),
@@ -98,9 +116,23 @@ pub fn block_time(chain_id: u64) -> Duration {
 #[cfg(test)]
 mod tests {
     const ROPSTEN_CHAIN_ID: u64 = 3;
+    const HOLESKY_CHAIN_ID: u64 = 17_000;
+    const SEPOLIA_CHAIN_ID: u64 = 11_155_111;
 
     use super::*;
 
+    #[test]
+    fn safe_block_depth_holesky() {
+        assert_eq!(safe_block_depth(HOLESKY_CHAIN_ID), 64);
+        assert_eq!(block_time(HOLESKY_CHAIN_ID), Duration::from_secs(12));
+    }
+
+    #[test]
+    fn safe_block_depth_sepolia() {
+        assert_eq!(safe_block_depth(SEPOLIA_CHAIN_ID), 64);
+        assert_eq!(block_time(SEPOLIA_CHAIN_ID), Duration::from_secs(12));
+    }
+
     #[test]
     fn largest_safe_block_number_with_safe_blocks() {
         const LATEST_BLOCK_NUMBER: u64 = 1_000;
@@ -109,6 +141,7 @@ mod tests {
         let args = LargestSafeBlockNumberArgs {
             chain_id: ROPSTEN_CHAIN_ID,
             latest_block_number: LATEST_BLOCK_NUMBER,
+            reorg_config: None,
         };
         assert_eq!(
             largest_safe_block_number(args),
@@ -123,6 +156,7 @@ mod tests {
         let args = LargestSafeBlockNumberArgs {
             chain_id: ROPSTEN_CHAIN_ID,
             latest_block_number: LATEST_BLOCK_NUMBER,
+            reorg_config: None,
         };
         assert_eq!(largest_safe_block_number(args), None);
     }
@@ -138,6 +172,7 @@ mod tests {
             chain_id: ROPSTEN_CHAIN_ID,
             latest_block_number: LATEST_BLOCK_NUMBER,
             block_number: safe_block_number,
+            reorg_config: None,
         };
         assert!(is_safe_block_number(args));
 
@@ -145,6 +180,7 @@ mod tests {
             chain_id: ROPSTEN_CHAIN_ID,
             latest_block_number: LATEST_BLOCK_NUMBER,
             block_number: safe_block_number + 1,
+            reorg_config: None,
         };
         assert!(!is_safe_block_number(args));
     }
@@ -157,6 +193,7 @@ mod tests {
             chain_id: ROPSTEN_CHAIN_ID,
             latest_block_number: LATEST_BLOCK_NUMBER,
             block_number: 0,
+            reorg_config: None,
         };
         assert!(!is_safe_block_number(args));
 
@@ -164,7 +201,89 @@ mod tests {
             chain_id: ROPSTEN_CHAIN_ID,
             latest_block_number: LATEST_BLOCK_NUMBER,
             block_number: LATEST_BLOCK_NUMBER,
+            reorg_config: None,
+        };
+        assert!(!is_safe_block_number(args));
+    }
+
+    #[test]
+    fn largest_safe_block_number_with_reorg_config_override() {
+        const CUSTOM_CHAIN_ID: u64 = 424_242;
+        const CUSTOM_SAFE_BLOCK_DEPTH: u64 = 1_000;
+        const LATEST_BLOCK_NUMBER: u64 = 5_000;
+
+        let reorg_config: ReorgConfig = [
+            (CUSTOM_CHAIN_ID, CUSTOM_SAFE_BLOCK_DEPTH),
+            (ROPSTEN_CHAIN_ID, 10),
+        ]
+        .into_iter()
+        .collect();
+
+        let args = LargestSafeBlockNumberArgs {
+            chain_id: CUSTOM_CHAIN_ID,
+            latest_block_number: LATEST_BLOCK_NUMBER,
+            reorg_config: Some(&reorg_config),
+        };
+        assert_eq!(
+            largest_safe_block_number(args),
+            Some(LATEST_BLOCK_NUMBER - CUSTOM_SAFE_BLOCK_DEPTH)
+        );
+
+        // Overrides take precedence over the built-in defaults
+        let args = LargestSafeBlockNumberArgs {
+            chain_id: ROPSTEN_CHAIN_ID,
+            latest_block_number: LATEST_BLOCK_NUMBER,
+            reorg_config: Some(&reorg_config),
+        };
+        assert_eq!(
+            largest_safe_block_number(args),
+            Some(LATEST_BLOCK_NUMBER - 10)
+        );
+    }
+
+    #[test]
+    fn largest_safe_block_number_with_reorg_config_unknown_chain() {
+        const UNKNOWN_CHAIN_ID: u64 = 424_242;
+        const LATEST_BLOCK_NUMBER: u64 = 5_000;
+
+        let reorg_config: ReorgConfig = [(ROPSTEN_CHAIN_ID, 10)].into_iter().collect();
+
+        let args = LargestSafeBlockNumberArgs {
+            chain_id: UNKNOWN_CHAIN_ID,
+            latest_block_number: LATEST_BLOCK_NUMBER,
+            reorg_config: Some(&reorg_config),
+        };
+        assert_eq!(
+            largest_safe_block_number(args),
+            Some(LATEST_BLOCK_NUMBER - DEFAULT_SAFE_BLOCK_DEPTH)
+        );
+    }
+
+    #[test]
+    fn is_safe_block_number_with_reorg_config_override() {
+        const LATEST_BLOCK_NUMBER: u64 = 1_000;
+        const CUSTOM_SAFE_BLOCK_DEPTH: u64 = 500;
+
+        let reorg_config: ReorgConfig = [(ROPSTEN_CHAIN_ID, CUSTOM_SAFE_BLOCK_DEPTH)]
+            .into_iter()
+            .collect();
+
+        // Safe according to the built-in depth, but not the override
+        let block_number = LATEST_BLOCK_NUMBER - safe_block_depth(ROPSTEN_CHAIN_ID);
+        let args = IsSafeBlockNumberArgs {
+            chain_id: ROPSTEN_CHAIN_ID,
+            latest_block_number: LATEST_BLOCK_NUMBER,
+            block_number,
+            reorg_config: Some(&reorg_config),
         };
         assert!(!is_safe_block_number(args));
+
+        let args = IsSafeBlockNumberArgs {
+            chain_id: ROPSTEN_CHAIN_ID,
+            latest_block_number: LATEST_BLOCK_NUMBER,
+            block_number: LATEST_BLOCK_NUMBER - CUSTOM_SAFE_BLOCK_DEPTH,
+            reorg_config: Some(&reorg_config),
+        };
+        assert!(is_safe_block_number(args));
     }
 }
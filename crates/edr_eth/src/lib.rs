@@ -9,6 +9,8 @@
 pub mod access_list;
 /// Ethereum account types
 pub mod account;
+/// EIP-7702 authorization types
+pub mod authorization;
 /// Parent beacon types and constants
 pub mod beacon;
 /// Ethereum block types
@@ -1,4 +1,5 @@
 mod block;
+mod call;
 mod filter;
 mod receipt;
 
@@ -6,6 +7,7 @@ pub use revm_primitives::Log;
 
 pub use self::{
     block::{BlockLog, FullBlockLog},
+    call::CallLog,
     filter::FilterLog,
     receipt::ReceiptLog,
 };
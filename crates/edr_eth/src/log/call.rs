@@ -0,0 +1,81 @@
+use std::ops::Deref;
+
+use revm_primitives::ExecutionResult;
+
+use crate::log::Log;
+
+/// A log that was emitted during a call. As nothing is mined, it lacks any
+/// block or transaction context.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub struct CallLog {
+    /// Execution log
+    #[cfg_attr(feature = "serde", serde(flatten))]
+    pub inner: Log,
+    /// Index of the log within the call
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde::u64"))]
+    pub log_index: u64,
+}
+
+impl CallLog {
+    /// Converts the logs of the provided execution result into call logs.
+    /// Returns an empty list if the execution reverted or halted.
+    pub fn from_execution_result(result: &ExecutionResult) -> Vec<Self> {
+        result
+            .logs()
+            .iter()
+            .zip(0u64..)
+            .map(|(log, log_index)| Self {
+                inner: log.clone(),
+                log_index,
+            })
+            .collect()
+    }
+}
+
+impl Deref for CallLog {
+    type Target = Log;
+
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+#[cfg(all(test, feature = "serde"))]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+    use crate::{Address, Bytes, B256};
+
+    #[test]
+    fn test_call_log_serde() -> anyhow::Result<()> {
+        let log = CallLog {
+            inner: Log::new_unchecked(
+                Address::from_str("0000000000000000000000000000000000000011")?,
+                vec![B256::from_str(
+                    "000000000000000000000000000000000000000000000000000000000000dead",
+                )?],
+                Bytes::from(hex::decode("0100ff")?),
+            ),
+            log_index: 1,
+        };
+
+        let serialized = serde_json::to_value(&log).unwrap();
+        assert_eq!(
+            serialized,
+            serde_json::json!({
+                "address": "0x0000000000000000000000000000000000000011",
+                "topics": ["0x000000000000000000000000000000000000000000000000000000000000dead"],
+                "data": "0x0100ff",
+                "logIndex": "0x1",
+            })
+        );
+
+        let deserialized: CallLog = serde_json::from_value(serialized).unwrap();
+        assert_eq!(log, deserialized);
+
+        Ok(())
+    }
+}
@@ -2,7 +2,7 @@ use sha3::{digest::FixedOutput, Digest, Sha3_256};
 
 use super::filter::{LogFilterOptions, OneOrMore};
 use crate::{
-    block::{is_safe_block_number, IsSafeBlockNumberArgs},
+    block::{is_safe_block_number, IsSafeBlockNumberArgs, ReorgConfig},
     remote::{
         request_methods::RequestMethod, BlockSpec, BlockTag, Eip1898BlockSpec, PreEip1898BlockSpec,
     },
@@ -456,11 +456,17 @@ pub(super) struct CacheKeyForUncheckedBlockNumber {
 impl CacheKeyForUncheckedBlockNumber {
     /// Check whether the block number is safe to cache before returning a cache
     /// key.
-    pub fn validate_block_number(self, chain_id: u64, latest_block_number: u64) -> Option<String> {
+    pub fn validate_block_number(
+        self,
+        chain_id: u64,
+        latest_block_number: u64,
+        reorg_config: Option<&ReorgConfig>,
+    ) -> Option<String> {
         let is_safe = is_safe_block_number(IsSafeBlockNumberArgs {
             chain_id,
             latest_block_number,
             block_number: self.block_number,
+            reorg_config,
         });
         if is_safe {
             Some(self.hasher.finalize())
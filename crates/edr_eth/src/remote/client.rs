@@ -33,7 +33,7 @@ use super::{
 };
 pub use crate::remote::client::reqwest_error::{MiddlewareError, ReqwestError};
 use crate::{
-    block::{block_time, is_safe_block_number, IsSafeBlockNumberArgs},
+    block::{block_time, is_safe_block_number, IsSafeBlockNumberArgs, ReorgConfig},
     log::FilterLog,
     receipt::BlockReceipt,
     remote::{
@@ -170,6 +170,7 @@ pub struct RpcClient {
     cached_block_number: RwLock<Option<CachedBlockNumber>>,
     client: ClientWithMiddleware,
     next_id: AtomicU64,
+    reorg_config: Option<ReorgConfig>,
     rpc_cache_dir: PathBuf,
     tmp_dir: PathBuf,
 }
@@ -226,11 +227,24 @@ impl RpcClient {
             cached_block_number: RwLock::new(None),
             client,
             next_id: AtomicU64::new(0),
+            reorg_config: None,
             rpc_cache_dir: cache_dir.join(RPC_CACHE_DIR),
             tmp_dir,
         })
     }
 
+    /// Sets the overrides of the safe block depth per chain id, which
+    /// determine whether responses for a block are cached.
+    pub fn with_reorg_config(mut self, reorg_config: Option<ReorgConfig>) -> Self {
+        self.reorg_config = reorg_config;
+        self
+    }
+
+    /// Retrieves the overrides of the safe block depth per chain id, if any.
+    pub fn reorg_config(&self) -> Option<&ReorgConfig> {
+        self.reorg_config.as_ref()
+    }
+
     fn parse_response_str<T: DeserializeOwned>(response: &str) -> Result<T, RpcClientError> {
         serde_json::from_str(response).map_err(|error| RpcClientError::InvalidResponse {
             response: response.to_string(),
@@ -351,7 +365,11 @@ impl RpcClient {
     ) -> Result<Option<String>, RpcClientError> {
         let chain_id = self.chain_id().await?;
         let latest_block_number = self.cached_block_number().await?;
-        Ok(safety_checker.validate_block_number(chain_id, latest_block_number))
+        Ok(safety_checker.validate_block_number(
+            chain_id,
+            latest_block_number,
+            self.reorg_config.as_ref(),
+        ))
     }
 
     async fn resolve_block_tag<T>(
@@ -715,6 +733,7 @@ impl RpcClient {
             chain_id,
             latest_block_number,
             block_number,
+            reorg_config: self.reorg_config.as_ref(),
         }))
     }
 
@@ -1216,6 +1235,72 @@ mod tests {
         mock.assert_async().await;
     }
 
+    #[tokio::test]
+    async fn cached_state_survives_restart() {
+        use mockito::{Matcher, Mock, Server};
+        use serde_json::json;
+
+        const BLOCK_NUMBER: u64 = 100;
+
+        fn mock_method(server: &mut Server, method: &str, result: &str) -> Mock {
+            server
+                .mock("POST", "/")
+                .match_body(Matcher::PartialJson(json!({ "method": method })))
+                .with_header("content-type", "application/json")
+                .with_body(json!({ "jsonrpc": "2.0", "id": 1, "result": result }).to_string())
+        }
+
+        let mut server = Server::new_async().await;
+
+        // Every client instance needs to resolve the chain ID once.
+        let chain_id_mock = mock_method(&mut server, "eth_chainId", "0x1")
+            .expect(2)
+            .create_async()
+            .await;
+        // Only needed to verify that the block number is safe to cache.
+        let block_number_mock = mock_method(&mut server, "eth_blockNumber", "0xf4240")
+            .expect(1)
+            .create_async()
+            .await;
+        let storage_mock = mock_method(&mut server, "eth_getStorageAt", "0x2a")
+            .expect(1)
+            .create_async()
+            .await;
+
+        let cache_dir = TempDir::new().unwrap();
+        let address = Address::repeat_byte(0x11);
+
+        let cold_client =
+            RpcClient::new(&server.url(), cache_dir.path().into(), None).expect("url ok");
+        let cold_value = cold_client
+            .get_storage_at(
+                &address,
+                U256::from(1),
+                Some(BlockSpec::Number(BLOCK_NUMBER)),
+            )
+            .await
+            .expect("should have succeeded");
+
+        // A new client with the same cache directory emulates a process restart.
+        let warm_client =
+            RpcClient::new(&server.url(), cache_dir.path().into(), None).expect("url ok");
+        let warm_value = warm_client
+            .get_storage_at(
+                &address,
+                U256::from(1),
+                Some(BlockSpec::Number(BLOCK_NUMBER)),
+            )
+            .await
+            .expect("should have succeeded");
+
+        assert_eq!(cold_value, Some(U256::from(0x2a)));
+        assert_eq!(warm_value, cold_value);
+
+        chain_id_mock.assert_async().await;
+        block_number_mock.assert_async().await;
+        storage_mock.assert_async().await;
+    }
+
     #[cfg(feature = "test-remote")]
     mod alchemy {
         use std::fs::File;
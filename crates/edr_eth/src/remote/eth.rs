@@ -13,7 +13,8 @@ use std::fmt::Debug;
 
 pub use self::call_request::CallRequest;
 use crate::{
-    access_list::AccessListItem, withdrawal::Withdrawal, Address, Bloom, Bytes, B256, B64, U256,
+    access_list::AccessListItem, authorization::SignedAuthorization, withdrawal::Withdrawal,
+    Address, Bloom, Bytes, B256, B64, U256,
 };
 
 /// transaction
@@ -95,6 +96,9 @@ pub struct Transaction {
     /// data blobs.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub blob_versioned_hashes: Option<Vec<B256>>,
+    /// List of signed authorizations to set the code of authorities (EIP-7702)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub authorization_list: Option<Vec<SignedAuthorization>>,
 }
 
 impl Transaction {
@@ -200,6 +204,53 @@ pub struct Block<TX> {
     pub parent_beacon_block_root: Option<B256>,
 }
 
+/// The result of `eth_createAccessList`.
+#[derive(Clone, Debug, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccessListResult {
+    /// The accounts and storage slots accessed by the transaction
+    pub access_list: Vec<AccessListItem>,
+    /// The gas used by the transaction when executed with the access list
+    #[serde(with = "crate::serde::u64")]
+    pub gas_used: u64,
+    /// The gas used by the transaction when executed without the access list
+    #[serde(with = "crate::serde::u64")]
+    pub gas_used_without_access_list: u64,
+}
+
+/// The result of `eth_getProof`.
+#[derive(Clone, Debug, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountProof {
+    /// The address of the account
+    pub address: Address,
+    /// The RLP-encoded state trie nodes from the state root to the account.
+    /// For non-existent accounts this proves the account's absence.
+    pub account_proof: Vec<Bytes>,
+    /// The balance of the account
+    pub balance: U256,
+    /// The code hash of the account
+    pub code_hash: B256,
+    /// The nonce of the account
+    #[serde(with = "crate::serde::u64")]
+    pub nonce: u64,
+    /// The storage root of the account
+    pub storage_hash: B256,
+    /// The proofs of the requested storage slots
+    pub storage_proof: Vec<StorageProof>,
+}
+
+/// A Merkle proof of a storage slot, as returned by `eth_getProof`.
+#[derive(Clone, Debug, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+pub struct StorageProof {
+    /// The index of the storage slot
+    pub key: U256,
+    /// The value of the storage slot
+    pub value: U256,
+    /// The RLP-encoded storage trie nodes from the storage root to the slot
+    pub proof: Vec<Bytes>,
+}
+
 /// Fee history for the returned block range. This can be a subsection of the
 /// requested range if not all blocks are available.
 #[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
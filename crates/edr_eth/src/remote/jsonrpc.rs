@@ -102,6 +102,8 @@ pub enum Id {
     Num(u64),
     /// String id
     Str(String),
+    /// Null id, used when the id of an invalid request cannot be determined
+    Null,
 }
 /// Represents JSON-RPC protocol version.
 #[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
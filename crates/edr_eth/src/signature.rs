@@ -21,6 +21,17 @@ use sha3::{Digest, Keccak256};
 
 use crate::{utils::hash_message, Address, Bytes, B256, U256};
 
+/// Half of the order of the secp256k1 curve, i.e. `secp256k1n / 2`.
+///
+/// EIP-2 requires the S value of transaction signatures to be less than or
+/// equal to this value.
+pub const SECP256K1N_HALF: U256 = U256::from_limbs([
+    0xdfe92f46681b20a0,
+    0x5d576e7357a4501d,
+    0xffffffffffffffff,
+    0x7fffffffffffffff,
+]);
+
 /// Converts a [`PublicKey`] to an [`Address`].
 pub fn public_key_to_address(public_key: PublicKey) -> Address {
     let public_key = public_key.to_encoded_point(/* compress = */ false);
@@ -74,9 +85,40 @@ pub enum SignatureError {
         error("invalid signature length, got {0}, expected 65")
     )]
     InvalidLength(usize),
+    /// The V value of the signature doesn't correspond to a valid recovery id
+    #[cfg_attr(
+        feature = "std",
+        error("Invalid signature: invalid recovery id, v = {v}")
+    )]
+    InvalidRecoveryId {
+        /// The V value of the signature
+        v: u64,
+    },
+    /// The R or S value of the signature is zero or not less than `secp256k1n`
+    #[cfg_attr(
+        feature = "std",
+        error("Invalid signature: r or s value is out of range, r = {r}, s = {s}")
+    )]
+    InvalidRS {
+        /// The R value of the signature
+        r: U256,
+        /// The S value of the signature
+        s: U256,
+    },
     /// Invalid secret key.
     #[cfg_attr(feature = "std", error("Invalid secret key: {0}"))]
     InvalidSecretKey(String),
+    /// The chain id of a signed transaction doesn't match the expected chain id
+    #[cfg_attr(
+        feature = "std",
+        error("Invalid chain id. Expected {expected}, got {actual}")
+    )]
+    InvalidChainId {
+        /// The expected chain id
+        expected: u64,
+        /// The chain id of the transaction
+        actual: u64,
+    },
     /// When parsing a signature from string to hex
     #[cfg_attr(feature = "std", error(transparent))]
     DecodingError(#[cfg_attr(feature = "std", from)] hex::FromHexError),
@@ -93,9 +135,23 @@ pub enum SignatureError {
     /// Elliptic curve error
     #[cfg_attr(feature = "std", error(transparent))]
     EllipticCurveError(#[cfg_attr(feature = "std", from)] k256::elliptic_curve::Error),
-    /// Error in recovering public key from signature
-    #[cfg_attr(feature = "std", error("Public key recovery error"))]
-    RecoveryError,
+    /// No public key could be recovered from the signature for the message,
+    /// despite the signature being well-formed
+    #[cfg_attr(
+        feature = "std",
+        error("Failed to recover public key from signature of message hash {message_hash}")
+    )]
+    RecoveryFailed {
+        /// The hash of the signed message
+        message_hash: B256,
+    },
+    /// The S value of the signature is greater than `secp256k1n / 2`, which is
+    /// disallowed by EIP-2
+    #[cfg_attr(
+        feature = "std",
+        error("Invalid signature: s value is greater than secp256k1n/2")
+    )]
+    HighS,
 }
 
 /// Recovery message data.
@@ -194,31 +250,63 @@ impl Signature {
 
         let verifying_key =
             VerifyingKey::recover_from_prehash(message_hash.as_slice(), &signature, recovery_id)
-                .map_err(SignatureError::ECDSAError)?;
+                .map_err(|_error| SignatureError::RecoveryFailed { message_hash })?;
 
         Ok(public_key_to_address(verifying_key.into()))
     }
 
+    /// Recovers the Ethereum address which was used to sign the given message,
+    /// rejecting malleable signatures of which the S value is greater than
+    /// `secp256k1n / 2` (EIP-2).
+    pub fn recover_strict<M>(&self, message: M) -> Result<Address, SignatureError>
+    where
+        M: Into<RecoveryMessage>,
+    {
+        if self.is_high_s() {
+            return Err(SignatureError::HighS);
+        }
+
+        self.recover(message)
+    }
+
+    /// Returns whether the S value is greater than `secp256k1n / 2`, making
+    /// the signature malleable.
+    pub fn is_high_s(&self) -> bool {
+        self.s > SECP256K1N_HALF
+    }
+
     /// Retrieves the recovery signature.
+    ///
+    /// Signatures with a high S value are normalized to their low S
+    /// equivalent, which recovers to the same public key.
     fn as_signature(&self) -> Result<(ECDSASignature, RecoveryId), SignatureError> {
-        let recovery_id = self.recovery_id()?;
-        let signature = {
+        let mut recovery_id = self.recovery_id()?;
+        let mut signature = {
             let r_bytes = self.r.to_be_bytes::<32>();
             let s_bytes = self.s.to_be_bytes::<32>();
 
             let mut bytes = [0u8; 64];
             bytes[..32].copy_from_slice(&r_bytes);
             bytes[32..64].copy_from_slice(&s_bytes);
-            ECDSASignature::from_slice(&bytes).map_err(SignatureError::ECDSAError)?
+            ECDSASignature::from_slice(&bytes).map_err(|_error| SignatureError::InvalidRS {
+                r: self.r,
+                s: self.s,
+            })?
         };
 
+        if let Some(normalized) = signature.normalize_s() {
+            signature = normalized;
+            recovery_id = RecoveryId::new(!recovery_id.is_y_odd(), recovery_id.is_x_reduced());
+        }
+
         Ok((signature, recovery_id))
     }
 
     /// Retrieve the recovery ID.
     pub fn recovery_id(&self) -> Result<RecoveryId, SignatureError> {
         let standard_v = normalize_recovery_id(self.v);
-        RecoveryId::try_from(standard_v).map_err(SignatureError::ECDSAError)
+        RecoveryId::try_from(standard_v)
+            .map_err(|_error| SignatureError::InvalidRecoveryId { v: self.v })
     }
 
     /// Copies and serializes `self` into a new `Vec` with the recovery id
@@ -464,4 +552,129 @@ mod tests {
         let secret_key_str_result = secret_key_to_str(&secret_key);
         assert_eq!(secret_key_str, secret_key_str_result);
     }
+
+    #[test]
+    fn recover_strict_canonical_signature() {
+        let secret_key_str = "ac0974bec39a17e36ba4a6b4d238ff944bacb478cbed5efcae784d7bf4f2ff80";
+        let secret_key = secret_key_from_str(secret_key_str).unwrap();
+        let expected_address = secret_key_to_address(secret_key_str).unwrap();
+
+        let message_hash = hash_message("whatever");
+        let signature = Signature::new(message_hash, &secret_key).unwrap();
+        assert!(!signature.is_high_s());
+
+        assert_eq!(signature.recover(message_hash).unwrap(), expected_address);
+        assert_eq!(
+            signature.recover_strict(message_hash).unwrap(),
+            expected_address
+        );
+    }
+
+    #[test]
+    fn recover_malleable_signature() {
+        let secret_key_str = "ac0974bec39a17e36ba4a6b4d238ff944bacb478cbed5efcae784d7bf4f2ff80";
+        let secret_key = secret_key_from_str(secret_key_str).unwrap();
+        let expected_address = secret_key_to_address(secret_key_str).unwrap();
+
+        let message_hash = hash_message("whatever");
+        let canonical = Signature::new(message_hash, &secret_key).unwrap();
+
+        // The complementary signature (r, n - s) with the opposite Y parity is
+        // equally valid, but disallowed by EIP-2.
+        let secp256k1n = SECP256K1N_HALF * U256::from(2) + U256::from(1);
+        let malleable = Signature {
+            r: canonical.r,
+            s: secp256k1n - canonical.s,
+            v: if canonical.v == 27 { 28 } else { 27 },
+        };
+        assert!(malleable.is_high_s());
+
+        assert_eq!(malleable.recover(message_hash).unwrap(), expected_address);
+        assert!(matches!(
+            malleable.recover_strict(message_hash),
+            Err(SignatureError::HighS)
+        ));
+    }
+
+    #[test]
+    fn recover_invalid_recovery_id() {
+        let secret_key_str = "ac0974bec39a17e36ba4a6b4d238ff944bacb478cbed5efcae784d7bf4f2ff80";
+        let secret_key = secret_key_from_str(secret_key_str).unwrap();
+
+        let message_hash = hash_message("whatever");
+        let signature = Signature {
+            v: 29,
+            ..Signature::new(message_hash, &secret_key).unwrap()
+        };
+
+        assert!(matches!(
+            signature.recovery_id(),
+            Err(SignatureError::InvalidRecoveryId { v: 29 })
+        ));
+        assert!(matches!(
+            signature.recover(message_hash),
+            Err(SignatureError::InvalidRecoveryId { v: 29 })
+        ));
+        assert!(matches!(
+            signature.recover_strict(message_hash),
+            Err(SignatureError::InvalidRecoveryId { v: 29 })
+        ));
+    }
+
+    #[test]
+    fn recover_invalid_r_or_s() {
+        let secret_key_str = "ac0974bec39a17e36ba4a6b4d238ff944bacb478cbed5efcae784d7bf4f2ff80";
+        let secret_key = secret_key_from_str(secret_key_str).unwrap();
+
+        let message_hash = hash_message("whatever");
+        let canonical = Signature::new(message_hash, &secret_key).unwrap();
+
+        let secp256k1n = SECP256K1N_HALF * U256::from(2) + U256::from(1);
+        let invalid_signatures = [
+            Signature {
+                r: U256::ZERO,
+                ..canonical
+            },
+            Signature {
+                s: U256::ZERO,
+                ..canonical
+            },
+            Signature {
+                r: secp256k1n,
+                ..canonical
+            },
+        ];
+
+        for signature in invalid_signatures {
+            let error = signature
+                .recover(message_hash)
+                .expect_err("signature is invalid");
+
+            assert!(matches!(
+                error,
+                SignatureError::InvalidRS { r, s } if r == signature.r && s == signature.s
+            ));
+        }
+    }
+
+    #[test]
+    fn recover_failed() {
+        // There is no curve point with an x-coordinate of 5, so no public key
+        // can be recovered, even though the signature is well-formed.
+        let message_hash = hash_message("whatever");
+        let signature = Signature {
+            r: U256::from(5),
+            s: U256::from(1),
+            v: 27,
+        };
+
+        assert!(matches!(
+            signature.recover(message_hash),
+            Err(SignatureError::RecoveryFailed { message_hash: hash }) if hash == message_hash
+        ));
+        assert!(matches!(
+            signature.recover_strict(message_hash),
+            Err(SignatureError::RecoveryFailed { .. })
+        ));
+    }
 }
@@ -38,6 +38,15 @@ impl HardforkActivations {
             .map(|entry| entry.1)
     }
 
+    /// Retrieves the block number of the first hardfork activation after the
+    /// provided block number, if any.
+    pub fn next_activation_after(&self, block_number: u64) -> Option<u64> {
+        self.hardforks
+            .iter()
+            .map(|(hardfork_number, _)| *hardfork_number)
+            .find(|hardfork_number| *hardfork_number > block_number)
+    }
+
     /// Retrieves the block number at which the provided hardfork was activated.
     pub fn hardfork_activation(&self, spec_id: SpecId) -> Option<u64> {
         self.hardforks
@@ -60,6 +69,8 @@ struct ChainConfig {
     pub name: String,
     /// Hardfork activations for the chain
     pub hardfork_activations: HardforkActivations,
+    /// Default block gas limit of the chain
+    pub block_gas_limit: u64,
 }
 
 const MAINNET_HARDFORKS: &[(u64, SpecId)] = &[
@@ -92,6 +103,7 @@ fn mainnet_config() -> &'static ChainConfig {
         ChainConfig {
             name: "mainnet".to_string(),
             hardfork_activations,
+            block_gas_limit: 30_000_000,
         }
     })
 }
@@ -115,6 +127,7 @@ fn ropsten_config() -> &'static ChainConfig {
         ChainConfig {
             name: "ropsten".to_string(),
             hardfork_activations,
+            block_gas_limit: 30_000_000,
         }
     })
 }
@@ -137,6 +150,7 @@ fn rinkeby_config() -> &'static ChainConfig {
         ChainConfig {
             name: "rinkeby".to_string(),
             hardfork_activations,
+            block_gas_limit: 30_000_000,
         }
     })
 }
@@ -160,6 +174,7 @@ fn goerli_config() -> &'static ChainConfig {
         ChainConfig {
             name: "goerli".to_string(),
             hardfork_activations,
+            block_gas_limit: 30_000_000,
         }
     })
 }
@@ -182,6 +197,7 @@ fn kovan_config() -> &'static ChainConfig {
         ChainConfig {
             name: "kovan".to_string(),
             hardfork_activations,
+            block_gas_limit: 12_500_000,
         }
     })
 }
@@ -201,6 +217,7 @@ fn holesky_config() -> &'static ChainConfig {
         ChainConfig {
             name: "holesky".to_string(),
             hardfork_activations,
+            block_gas_limit: 30_000_000,
         }
     })
 }
@@ -221,6 +238,7 @@ fn sepolia_config() -> &'static ChainConfig {
         ChainConfig {
             name: "sepolia".to_string(),
             hardfork_activations,
+            block_gas_limit: 30_000_000,
         }
     })
 }
@@ -256,3 +274,11 @@ pub fn chain_hardfork_activations(chain_id: u64) -> Option<&'static HardforkActi
         .get(&chain_id)
         .map(|config| &config.hardfork_activations)
 }
+
+/// Returns the default block gas limit corresponding to the provided chain ID,
+/// if it is supported.
+pub fn chain_block_gas_limit(chain_id: u64) -> Option<u64> {
+    chain_configs()
+        .get(&chain_id)
+        .map(|config| config.block_gas_limit)
+}
@@ -17,6 +17,12 @@ impl TransactionKind {
             TransactionKind::Create => None,
         }
     }
+
+    /// Returns the address of the callee if this transaction is a call, or
+    /// `None` if it is a contract creation.
+    pub fn to_address(&self) -> Option<Address> {
+        self.as_call().copied()
+    }
 }
 
 impl From<Option<Address>> for TransactionKind {
@@ -56,3 +62,36 @@ impl alloy_rlp::Encodable for TransactionKind {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_optional_address() {
+        let address = Address::random();
+
+        assert_eq!(
+            TransactionKind::from(Some(address)),
+            TransactionKind::Call(address)
+        );
+        assert_eq!(TransactionKind::from(None), TransactionKind::Create);
+    }
+
+    #[test]
+    fn to_address() {
+        let address = Address::random();
+
+        assert_eq!(TransactionKind::Call(address).to_address(), Some(address));
+        assert_eq!(TransactionKind::Create.to_address(), None);
+    }
+
+    #[test]
+    fn address_round_trip() {
+        let address = Address::random();
+
+        for kind in [TransactionKind::Call(address), TransactionKind::Create] {
+            assert_eq!(TransactionKind::from(kind.to_address()), kind);
+        }
+    }
+}
@@ -2,6 +2,7 @@ mod eip155;
 mod eip1559;
 mod eip2930;
 mod eip4844;
+mod eip7702;
 mod legacy;
 
 use k256::SecretKey;
@@ -9,9 +10,9 @@ use k256::SecretKey;
 pub use self::{
     eip155::Eip155TransactionRequest, eip1559::Eip1559TransactionRequest,
     eip2930::Eip2930TransactionRequest, eip4844::Eip4844TransactionRequest,
-    legacy::LegacyTransactionRequest,
+    eip7702::Eip7702TransactionRequest, legacy::LegacyTransactionRequest,
 };
-use crate::{signature::SignatureError, transaction::SignedTransaction, Address, U256};
+use crate::{signature::SignatureError, transaction::SignedTransaction, Address, B256, U256};
 
 /// Container type for various Ethereum transaction requests
 ///
@@ -89,6 +90,21 @@ impl TransactionRequest {
         }
     }
 
+    /// Computes the hash that is signed by the sender of the transaction.
+    ///
+    /// For typed transactions this includes the EIP-2718 type prefix, so the
+    /// returned hash is the message that signatures of the corresponding
+    /// [`SignedTransaction`] are recovered from.
+    pub fn signing_hash(&self) -> B256 {
+        match self {
+            TransactionRequest::Legacy(transaction) => transaction.hash(),
+            TransactionRequest::Eip155(transaction) => transaction.hash(),
+            TransactionRequest::Eip2930(transaction) => transaction.hash(),
+            TransactionRequest::Eip1559(transaction) => transaction.hash(),
+            TransactionRequest::Eip4844(transaction) => transaction.hash(),
+        }
+    }
+
     pub fn sign(self, secret_key: &SecretKey) -> Result<SignedTransaction, SignatureError> {
         Ok(match self {
             TransactionRequest::Legacy(transaction) => transaction.sign(secret_key)?.into(),
@@ -118,3 +134,115 @@ pub struct TransactionRequestAndSender {
     /// The sender's address.
     pub sender: Address,
 }
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+    use crate::{
+        access_list::AccessListItem,
+        signature::{secret_key_from_str, secret_key_to_address},
+        transaction::TransactionKind,
+        Bytes,
+    };
+
+    const DUMMY_SECRET_KEY: &str =
+        "e331b6d69882b4cb4ea581d88e0b604039a3de5967688d3dcffdd2270c0fd109";
+
+    fn dummy_to() -> Address {
+        Address::from_str("0xc014ba5ec014ba5ec014ba5ec014ba5ec014ba5e").unwrap()
+    }
+
+    fn dummy_access_list() -> Vec<AccessListItem> {
+        vec![AccessListItem {
+            address: Address::ZERO,
+            storage_keys: vec![B256::ZERO, B256::from(U256::from(1))],
+        }]
+    }
+
+    fn dummy_requests() -> Vec<TransactionRequest> {
+        vec![
+            TransactionRequest::Legacy(LegacyTransactionRequest {
+                nonce: 1,
+                gas_price: U256::from(2),
+                gas_limit: 3,
+                kind: TransactionKind::Call(dummy_to()),
+                value: U256::from(4),
+                input: Bytes::from(vec![1, 2]),
+            }),
+            TransactionRequest::Eip155(Eip155TransactionRequest {
+                nonce: 1,
+                gas_price: U256::from(2),
+                gas_limit: 3,
+                kind: TransactionKind::Create,
+                value: U256::from(4),
+                input: Bytes::from(vec![1, 2]),
+                chain_id: 123,
+            }),
+            TransactionRequest::Eip2930(Eip2930TransactionRequest {
+                chain_id: 123,
+                nonce: 1,
+                gas_price: U256::from(2),
+                gas_limit: 3,
+                kind: TransactionKind::Call(dummy_to()),
+                value: U256::from(4),
+                input: Bytes::from(vec![1, 2]),
+                access_list: dummy_access_list(),
+            }),
+            TransactionRequest::Eip1559(Eip1559TransactionRequest {
+                chain_id: 123,
+                nonce: 1,
+                max_priority_fee_per_gas: U256::from(2),
+                max_fee_per_gas: U256::from(5),
+                gas_limit: 3,
+                kind: TransactionKind::Call(dummy_to()),
+                value: U256::from(4),
+                input: Bytes::from(vec![1, 2]),
+                access_list: dummy_access_list(),
+            }),
+            TransactionRequest::Eip4844(Eip4844TransactionRequest {
+                chain_id: 123,
+                nonce: 1,
+                max_priority_fee_per_gas: U256::from(2),
+                max_fee_per_gas: U256::from(5),
+                max_fee_per_blob_gas: U256::from(7),
+                gas_limit: 3,
+                to: dummy_to(),
+                value: U256::from(4),
+                input: Bytes::from(vec![1, 2]),
+                access_list: dummy_access_list(),
+                blob_hashes: vec![B256::from(U256::from(1))],
+            }),
+        ]
+    }
+
+    /// Computes the hash that is used to recover the signer of the signed
+    /// transaction.
+    fn recovery_hash(transaction: &SignedTransaction) -> B256 {
+        match transaction {
+            SignedTransaction::PreEip155Legacy(tx) => LegacyTransactionRequest::from(tx).hash(),
+            SignedTransaction::PostEip155Legacy(tx) => Eip155TransactionRequest::from(tx).hash(),
+            SignedTransaction::Eip2930(tx) => Eip2930TransactionRequest::from(tx).hash(),
+            SignedTransaction::Eip1559(tx) => Eip1559TransactionRequest::from(tx).hash(),
+            SignedTransaction::Eip4844(tx) => Eip4844TransactionRequest::from(tx).hash(),
+            SignedTransaction::Eip7702(tx) => Eip7702TransactionRequest::from(tx).hash(),
+        }
+    }
+
+    #[test]
+    fn signing_hash_matches_recovery_hash() -> anyhow::Result<()> {
+        let secret_key = secret_key_from_str(DUMMY_SECRET_KEY)?;
+        let expected_address = secret_key_to_address(DUMMY_SECRET_KEY)?;
+
+        for request in dummy_requests() {
+            let signing_hash = request.signing_hash();
+            let signed = request.sign(&secret_key)?;
+
+            assert_eq!(signing_hash, recovery_hash(&signed));
+            assert_eq!(signed.recover()?, expected_address);
+        }
+
+        Ok(())
+    }
+}
@@ -46,6 +46,7 @@ impl Eip155TransactionRequest {
             input: self.input,
             signature,
             hash: OnceLock::new(),
+            caller: OnceLock::new(),
             is_fake: false,
         })
     }
@@ -64,6 +65,7 @@ impl Eip155TransactionRequest {
             input: self.input,
             signature,
             hash: OnceLock::new(),
+            caller: OnceLock::new(),
             is_fake: true,
         }
     }
@@ -174,6 +176,31 @@ mod tests {
         assert_eq!(expected, request.hash());
     }
 
+    #[test]
+    fn eip155_specification_signing_hash() -> anyhow::Result<()> {
+        // Example from https://eips.ethereum.org/EIPS/eip-155
+        let request = Eip155TransactionRequest {
+            nonce: 9,
+            gas_price: U256::from(20_000_000_000u64),
+            gas_limit: 21_000,
+            kind: TransactionKind::Call("0x3535353535353535353535353535353535353535".parse()?),
+            value: U256::from(1_000_000_000_000_000_000u64),
+            input: Bytes::default(),
+            chain_id: 1,
+        };
+
+        let expected_encoding = hex::decode(
+            "ec098504a817c800825208943535353535353535353535353535353535353535880de0b6b3a764000080018080",
+        )?;
+        assert_eq!(alloy_rlp::encode(&request), expected_encoding);
+
+        let expected_hash: B256 =
+            "daf5a779ae972f972197303d7b574746c7ef83eadac0f2791ad23db92e4c8e53".parse()?;
+        assert_eq!(request.hash(), expected_hash);
+
+        Ok(())
+    }
+
     test_fake_sign_properties!();
 
     #[test]
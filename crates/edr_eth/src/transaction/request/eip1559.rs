@@ -56,6 +56,7 @@ impl Eip1559TransactionRequest {
             r: signature.r,
             s: signature.s,
             hash: OnceLock::new(),
+            caller: OnceLock::new(),
             is_fake: false,
         })
     }
@@ -77,6 +78,7 @@ impl Eip1559TransactionRequest {
             r: signature.r,
             s: signature.s,
             hash: OnceLock::new(),
+            caller: OnceLock::new(),
             is_fake: true,
         }
     }
@@ -55,6 +55,7 @@ impl Eip2930TransactionRequest {
             r: signature.r,
             s: signature.s,
             hash: OnceLock::new(),
+            caller: OnceLock::new(),
             is_fake: false,
         })
     }
@@ -76,6 +77,7 @@ impl Eip2930TransactionRequest {
             r: signature.r,
             s: signature.s,
             hash: OnceLock::new(),
+            caller: OnceLock::new(),
             is_fake: true,
         }
     }
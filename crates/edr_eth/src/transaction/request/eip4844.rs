@@ -57,6 +57,7 @@ impl Eip4844TransactionRequest {
             r: signature.r,
             s: signature.s,
             hash: OnceLock::new(),
+            caller: OnceLock::new(),
             is_fake: false,
         })
     }
@@ -80,6 +81,7 @@ impl Eip4844TransactionRequest {
             r: signature.r,
             s: signature.s,
             hash: OnceLock::new(),
+            caller: OnceLock::new(),
             is_fake: true,
         }
     }
@@ -0,0 +1,103 @@
+use std::sync::OnceLock;
+
+use alloy_primitives::keccak256;
+use alloy_rlp::RlpEncodable;
+use k256::SecretKey;
+
+use crate::{
+    access_list::AccessListItem,
+    authorization::SignedAuthorization,
+    signature::{Signature, SignatureError},
+    transaction::{fake_signature::make_fake_signature, Eip7702SignedTransaction},
+    utils::envelop_bytes,
+    Address, Bytes, B256, U256,
+};
+
+#[derive(Clone, Debug, PartialEq, Eq, RlpEncodable)]
+pub struct Eip7702TransactionRequest {
+    // The order of these fields determines encoding order.
+    pub chain_id: u64,
+    pub nonce: u64,
+    pub max_priority_fee_per_gas: U256,
+    pub max_fee_per_gas: U256,
+    pub gas_limit: u64,
+    pub to: Address,
+    pub value: U256,
+    pub input: Bytes,
+    pub access_list: Vec<AccessListItem>,
+    pub authorization_list: Vec<SignedAuthorization>,
+}
+
+impl Eip7702TransactionRequest {
+    /// Computes the hash of the transaction.
+    pub fn hash(&self) -> B256 {
+        let encoded = alloy_rlp::encode(self);
+
+        keccak256(envelop_bytes(4, &encoded))
+    }
+
+    pub fn sign(self, secret_key: &SecretKey) -> Result<Eip7702SignedTransaction, SignatureError> {
+        let hash = self.hash();
+
+        let signature = Signature::new(hash, secret_key)?;
+
+        Ok(Eip7702SignedTransaction {
+            chain_id: self.chain_id,
+            nonce: self.nonce,
+            max_priority_fee_per_gas: self.max_priority_fee_per_gas,
+            max_fee_per_gas: self.max_fee_per_gas,
+            gas_limit: self.gas_limit,
+            to: self.to,
+            value: self.value,
+            input: self.input,
+            access_list: self.access_list.into(),
+            authorization_list: self.authorization_list,
+            odd_y_parity: signature.odd_y_parity(),
+            r: signature.r,
+            s: signature.s,
+            hash: OnceLock::new(),
+            caller: OnceLock::new(),
+            is_fake: false,
+        })
+    }
+
+    pub fn fake_sign(self, sender: &Address) -> Eip7702SignedTransaction {
+        let signature = make_fake_signature::<1>(sender);
+
+        Eip7702SignedTransaction {
+            chain_id: self.chain_id,
+            nonce: self.nonce,
+            max_priority_fee_per_gas: self.max_priority_fee_per_gas,
+            max_fee_per_gas: self.max_fee_per_gas,
+            gas_limit: self.gas_limit,
+            to: self.to,
+            value: self.value,
+            input: self.input,
+            access_list: self.access_list.into(),
+            authorization_list: self.authorization_list,
+            odd_y_parity: signature.odd_y_parity(),
+            r: signature.r,
+            s: signature.s,
+            hash: OnceLock::new(),
+            caller: OnceLock::new(),
+            is_fake: true,
+        }
+    }
+}
+
+impl From<&Eip7702SignedTransaction> for Eip7702TransactionRequest {
+    fn from(t: &Eip7702SignedTransaction) -> Self {
+        Self {
+            chain_id: t.chain_id,
+            nonce: t.nonce,
+            max_priority_fee_per_gas: t.max_priority_fee_per_gas,
+            max_fee_per_gas: t.max_fee_per_gas,
+            gas_limit: t.gas_limit,
+            to: t.to,
+            value: t.value,
+            input: t.input.clone(),
+            access_list: t.access_list.0.clone(),
+            authorization_list: t.authorization_list.clone(),
+        }
+    }
+}
@@ -44,6 +44,7 @@ impl LegacyTransactionRequest {
             input: self.input,
             signature,
             hash: OnceLock::new(),
+            caller: OnceLock::new(),
             is_fake: false,
         })
     }
@@ -61,6 +62,7 @@ impl LegacyTransactionRequest {
             input: self.input,
             signature,
             hash: OnceLock::new(),
+            caller: OnceLock::new(),
             is_fake: true,
         }
     }
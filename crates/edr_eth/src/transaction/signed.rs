@@ -2,18 +2,22 @@ mod eip155;
 mod eip1559;
 mod eip2930;
 mod eip4844;
+mod eip7702;
 mod legacy;
 
+use std::sync::OnceLock;
+
 use alloy_rlp::{Buf, BufMut, Decodable};
 
 pub use self::{
     eip155::Eip155SignedTransaction, eip1559::Eip1559SignedTransaction,
     eip2930::Eip2930SignedTransaction, eip4844::Eip4844SignedTransaction,
-    legacy::LegacySignedTransaction,
+    eip7702::Eip7702SignedTransaction, legacy::LegacySignedTransaction,
 };
 use super::kind::TransactionKind;
 use crate::{
     access_list::AccessList,
+    authorization::SignedAuthorization,
     signature::{Signature, SignatureError},
     utils::enveloped,
     Address, Bytes, B256, U256,
@@ -21,6 +25,52 @@ use crate::{
 
 const INVALID_TX_TYPE_ERROR_MESSAGE: &str = "invalid tx type";
 
+/// The address of a transaction's signer, cached together with the signing
+/// hash and signature that it was recovered from.
+///
+/// As the fields of signed transactions are public, they can be modified after
+/// the caller was recovered. The cached address is only returned if the
+/// transaction still has the same signing hash and signature.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RecoveredCaller {
+    signing_hash: B256,
+    signature: Signature,
+    caller: Address,
+}
+
+impl RecoveredCaller {
+    /// Returns the address of the signer.
+    pub fn caller(&self) -> Address {
+        self.caller
+    }
+
+    /// Recovers the signer of the provided signing hash and signature, reusing
+    /// the cached address if it was recovered from the same inputs.
+    fn recover(
+        cache: &OnceLock<Self>,
+        signature: &Signature,
+        signing_hash: B256,
+    ) -> Result<Address, SignatureError> {
+        if let Some(cached) = cache.get() {
+            if cached.signing_hash == signing_hash && cached.signature == *signature {
+                return Ok(cached.caller);
+            }
+        }
+
+        let caller = signature.recover(signing_hash)?;
+
+        // A stale cache can't be overwritten through a shared reference, so it's
+        // bypassed instead.
+        let _ = cache.set(Self {
+            signing_hash,
+            signature: *signature,
+            caller,
+        });
+
+        Ok(caller)
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum SignedTransaction {
@@ -34,6 +84,8 @@ pub enum SignedTransaction {
     Eip1559(Eip1559SignedTransaction),
     /// EIP-4844 transaction
     Eip4844(Eip4844SignedTransaction),
+    /// EIP-7702 transaction
+    Eip7702(Eip7702SignedTransaction),
 }
 
 impl SignedTransaction {
@@ -45,6 +97,7 @@ impl SignedTransaction {
             SignedTransaction::Eip2930(tx) => tx.gas_price,
             SignedTransaction::Eip1559(tx) => tx.max_fee_per_gas,
             SignedTransaction::Eip4844(tx) => tx.max_fee_per_gas,
+            SignedTransaction::Eip7702(tx) => tx.max_fee_per_gas,
         }
     }
 
@@ -56,6 +109,7 @@ impl SignedTransaction {
             SignedTransaction::Eip2930(tx) => tx.gas_limit,
             SignedTransaction::Eip1559(tx) => tx.gas_limit,
             SignedTransaction::Eip4844(tx) => tx.gas_limit,
+            SignedTransaction::Eip7702(tx) => tx.gas_limit,
         }
     }
 
@@ -67,6 +121,7 @@ impl SignedTransaction {
             SignedTransaction::Eip2930(tx) => tx.value,
             SignedTransaction::Eip1559(tx) => tx.value,
             SignedTransaction::Eip4844(tx) => tx.value,
+            SignedTransaction::Eip7702(tx) => tx.value,
         }
     }
 
@@ -78,6 +133,7 @@ impl SignedTransaction {
             SignedTransaction::Eip2930(tx) => &tx.input,
             SignedTransaction::Eip1559(tx) => &tx.input,
             SignedTransaction::Eip4844(tx) => &tx.input,
+            SignedTransaction::Eip7702(tx) => &tx.input,
         }
     }
 
@@ -88,6 +144,30 @@ impl SignedTransaction {
             SignedTransaction::Eip2930(tx) => Some(&tx.access_list),
             SignedTransaction::Eip1559(tx) => Some(&tx.access_list),
             SignedTransaction::Eip4844(tx) => Some(&tx.access_list),
+            SignedTransaction::Eip7702(tx) => Some(&tx.access_list),
+        }
+    }
+
+    /// Computes the effective gas price of the transaction, given the base fee
+    /// per gas of the block in which it is included.
+    ///
+    /// For legacy and EIP-2930 transactions this is the gas price. For
+    /// EIP-1559, EIP-4844 and EIP-7702 transactions this is the base fee plus
+    /// the priority fee, capped by the max fee per gas.
+    pub fn effective_gas_price(&self, block_base_fee: U256) -> U256 {
+        match self {
+            SignedTransaction::PreEip155Legacy(tx) => tx.gas_price,
+            SignedTransaction::PostEip155Legacy(tx) => tx.gas_price,
+            SignedTransaction::Eip2930(tx) => tx.gas_price,
+            SignedTransaction::Eip1559(tx) => tx
+                .max_fee_per_gas
+                .min(block_base_fee.saturating_add(tx.max_priority_fee_per_gas)),
+            SignedTransaction::Eip4844(tx) => tx
+                .max_fee_per_gas
+                .min(block_base_fee.saturating_add(tx.max_priority_fee_per_gas)),
+            SignedTransaction::Eip7702(tx) => tx
+                .max_fee_per_gas
+                .min(block_base_fee.saturating_add(tx.max_priority_fee_per_gas)),
         }
     }
 
@@ -104,6 +184,7 @@ impl SignedTransaction {
             | SignedTransaction::Eip2930(_) => None,
             SignedTransaction::Eip1559(tx) => Some(tx.max_fee_per_gas),
             SignedTransaction::Eip4844(tx) => Some(tx.max_fee_per_gas),
+            SignedTransaction::Eip7702(tx) => Some(tx.max_fee_per_gas),
         }
     }
 
@@ -115,6 +196,7 @@ impl SignedTransaction {
             | SignedTransaction::Eip2930(_) => None,
             SignedTransaction::Eip1559(tx) => Some(tx.max_priority_fee_per_gas),
             SignedTransaction::Eip4844(tx) => Some(tx.max_priority_fee_per_gas),
+            SignedTransaction::Eip7702(tx) => Some(tx.max_priority_fee_per_gas),
         }
     }
 
@@ -124,7 +206,8 @@ impl SignedTransaction {
             SignedTransaction::PreEip155Legacy(_)
             | SignedTransaction::PostEip155Legacy(_)
             | SignedTransaction::Eip2930(_)
-            | SignedTransaction::Eip1559(_) => None,
+            | SignedTransaction::Eip1559(_)
+            | SignedTransaction::Eip7702(_) => None,
             SignedTransaction::Eip4844(tx) => Some(tx.max_fee_per_blob_gas),
         }
     }
@@ -135,7 +218,8 @@ impl SignedTransaction {
             SignedTransaction::PreEip155Legacy(_)
             | SignedTransaction::PostEip155Legacy(_)
             | SignedTransaction::Eip2930(_)
-            | SignedTransaction::Eip1559(_) => None,
+            | SignedTransaction::Eip1559(_)
+            | SignedTransaction::Eip7702(_) => None,
             SignedTransaction::Eip4844(tx) => Some(tx.blob_hashes.clone()),
         }
     }
@@ -153,6 +237,7 @@ impl SignedTransaction {
             SignedTransaction::Eip2930(t) => t.nonce,
             SignedTransaction::Eip1559(t) => t.nonce,
             SignedTransaction::Eip4844(t) => t.nonce,
+            SignedTransaction::Eip7702(t) => t.nonce,
         }
     }
 
@@ -164,6 +249,7 @@ impl SignedTransaction {
             SignedTransaction::Eip2930(t) => Some(t.chain_id),
             SignedTransaction::Eip1559(t) => Some(t.chain_id),
             SignedTransaction::Eip4844(t) => Some(t.chain_id),
+            SignedTransaction::Eip7702(t) => Some(t.chain_id),
         }
     }
 
@@ -197,6 +283,7 @@ impl SignedTransaction {
             SignedTransaction::Eip2930(t) => t.hash(),
             SignedTransaction::Eip1559(t) => t.hash(),
             SignedTransaction::Eip4844(t) => t.hash(),
+            SignedTransaction::Eip7702(t) => t.hash(),
         }
     }
 
@@ -208,7 +295,41 @@ impl SignedTransaction {
             SignedTransaction::Eip2930(tx) => tx.recover(),
             SignedTransaction::Eip1559(tx) => tx.recover(),
             SignedTransaction::Eip4844(tx) => tx.recover(),
+            SignedTransaction::Eip7702(tx) => tx.recover(),
+        }
+    }
+
+    /// Recovers the Ethereum address which was used to sign the transaction,
+    /// rejecting malleable signatures of which the S value is greater than
+    /// `secp256k1n / 2` (EIP-2).
+    ///
+    /// Historical transactions - e.g. from a remote blockchain - may predate
+    /// this requirement, so they should be recovered using
+    /// [`SignedTransaction::recover`] instead.
+    pub fn recover_strict(&self) -> Result<Address, SignatureError> {
+        if self.signature().is_high_s() {
+            return Err(SignatureError::HighS);
+        }
+
+        self.recover()
+    }
+
+    /// Recovers the Ethereum address which was used to sign the transaction,
+    /// after verifying that the transaction was signed for the expected chain.
+    ///
+    /// Pre-EIP-155 transactions are not replay-protected, so they are accepted
+    /// for any chain id.
+    pub fn recover_with_chain_id(&self, expected_chain_id: u64) -> Result<Address, SignatureError> {
+        if let Some(chain_id) = self.chain_id() {
+            if chain_id != expected_chain_id {
+                return Err(SignatureError::InvalidChainId {
+                    expected: expected_chain_id,
+                    actual: chain_id,
+                });
+            }
         }
+
+        self.recover()
     }
 
     /// Returns what kind of transaction this is
@@ -219,12 +340,13 @@ impl SignedTransaction {
             SignedTransaction::Eip2930(tx) => tx.kind,
             SignedTransaction::Eip1559(tx) => tx.kind,
             SignedTransaction::Eip4844(tx) => TransactionKind::Call(tx.to),
+            SignedTransaction::Eip7702(tx) => TransactionKind::Call(tx.to),
         }
     }
 
     /// Returns the callee if this transaction is a call
     pub fn to(&self) -> Option<Address> {
-        self.kind().as_call().copied()
+        self.kind().to_address()
     }
 
     /// Returns the [`Signature`] of the transaction
@@ -247,6 +369,19 @@ impl SignedTransaction {
                 s: tx.s,
                 v: u64::from(tx.odd_y_parity),
             },
+            SignedTransaction::Eip7702(tx) => Signature {
+                r: tx.r,
+                s: tx.s,
+                v: u64::from(tx.odd_y_parity),
+            },
+        }
+    }
+
+    /// Returns the authorization list of the transaction, if any.
+    pub fn authorization_list(&self) -> Option<&[SignedAuthorization]> {
+        match self {
+            SignedTransaction::Eip7702(tx) => Some(&tx.authorization_list),
+            _ => None,
         }
     }
 
@@ -264,6 +399,7 @@ impl SignedTransaction {
             SignedTransaction::Eip2930(_) => 1,
             SignedTransaction::Eip1559(_) => 2,
             SignedTransaction::Eip4844(_) => 3,
+            SignedTransaction::Eip7702(_) => 4,
         }
     }
 
@@ -302,6 +438,13 @@ impl Decodable for SignedTransaction {
                     Eip4844SignedTransaction::decode(buf)?,
                 ))
             }
+            0x04 => {
+                buf.advance(1);
+
+                Ok(SignedTransaction::Eip7702(
+                    Eip7702SignedTransaction::decode(buf)?,
+                ))
+            }
             byte if is_list(byte) => {
                 let tx = LegacySignedTransaction::decode(buf)?;
                 if tx.signature.v >= 35 {
@@ -323,6 +466,7 @@ impl alloy_rlp::Encodable for SignedTransaction {
             SignedTransaction::Eip2930(tx) => enveloped(1, tx, out),
             SignedTransaction::Eip1559(tx) => enveloped(2, tx, out),
             SignedTransaction::Eip4844(tx) => enveloped(3, tx, out),
+            SignedTransaction::Eip7702(tx) => enveloped(4, tx, out),
         }
     }
 
@@ -333,6 +477,7 @@ impl alloy_rlp::Encodable for SignedTransaction {
             SignedTransaction::Eip2930(tx) => tx.length() + 1,
             SignedTransaction::Eip1559(tx) => tx.length() + 1,
             SignedTransaction::Eip4844(tx) => tx.length() + 1,
+            SignedTransaction::Eip7702(tx) => tx.length() + 1,
         }
     }
 }
@@ -367,12 +512,18 @@ impl From<Eip4844SignedTransaction> for SignedTransaction {
     }
 }
 
+impl From<Eip7702SignedTransaction> for SignedTransaction {
+    fn from(transaction: Eip7702SignedTransaction) -> Self {
+        Self::Eip7702(transaction)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::sync::OnceLock;
 
     use super::*;
-    use crate::Bytes;
+    use crate::{signature::SECP256K1N_HALF, transaction::Eip1559TransactionRequest, Bytes};
 
     #[test]
     fn can_recover_sender() {
@@ -446,6 +597,7 @@ mod tests {
                     v: 1,
                 },
                 hash: OnceLock::new(),
+                caller: OnceLock::new(),
                 is_fake: false
             }),
             post_eip155 => SignedTransaction::PostEip155Legacy(Eip155SignedTransaction {
@@ -461,6 +613,7 @@ mod tests {
                     v: 37,
                 },
                 hash: OnceLock::new(),
+                caller: OnceLock::new(),
                 is_fake: false
             }),
             eip2930 => SignedTransaction::Eip2930(Eip2930SignedTransaction {
@@ -476,6 +629,7 @@ mod tests {
                 s: U256::default(),
                 access_list: vec![].into(),
                 hash: OnceLock::new(),
+                caller: OnceLock::new(),
                 is_fake: false
             }),
             eip1559 => SignedTransaction::Eip1559(Eip1559SignedTransaction {
@@ -492,6 +646,7 @@ mod tests {
                 r: U256::default(),
                 s: U256::default(),
                 hash: OnceLock::new(),
+                caller: OnceLock::new(),
                 is_fake: false
             }),
             eip4844 => SignedTransaction::Eip4844(Eip4844SignedTransaction {
@@ -510,6 +665,32 @@ mod tests {
                 r: U256::default(),
                 s: U256::default(),
                 hash: OnceLock::new(),
+                caller: OnceLock::new(),
+                is_fake: false
+            }),
+            eip7702 => SignedTransaction::Eip7702(Eip7702SignedTransaction {
+                chain_id: 1,
+                nonce: 0,
+                max_priority_fee_per_gas: U256::from(1),
+                max_fee_per_gas: U256::from(2),
+                gas_limit: 3,
+                to: Address::random(),
+                value: U256::from(4),
+                input: Bytes::from(vec![1, 2]),
+                access_list: vec![].into(),
+                authorization_list: vec![SignedAuthorization {
+                    chain_id: 1,
+                    address: Address::random(),
+                    nonce: 5,
+                    y_parity: 1,
+                    r: U256::from(6),
+                    s: U256::from(7),
+                }],
+                odd_y_parity: true,
+                r: U256::default(),
+                s: U256::default(),
+                hash: OnceLock::new(),
+                caller: OnceLock::new(),
                 is_fake: false
             }),
     }
@@ -540,6 +721,7 @@ mod tests {
                 .unwrap(),
             },
             hash: OnceLock::new(),
+            caller: OnceLock::new(),
             is_fake: false,
         });
         assert_eq!(
@@ -569,6 +751,7 @@ mod tests {
                 .unwrap(),
             },
             hash: OnceLock::new(),
+            caller: OnceLock::new(),
             is_fake: false,
         });
         assert_eq!(
@@ -598,6 +781,7 @@ mod tests {
                 .unwrap(),
             },
             hash: OnceLock::new(),
+            caller: OnceLock::new(),
             is_fake: false,
         });
         assert_eq!(
@@ -624,6 +808,7 @@ mod tests {
             s: U256::from_str("0x016b83f4f980694ed2eee4d10667242b1f40dc406901b34125b008d334d47469")
                 .unwrap(),
             hash: OnceLock::new(),
+            caller: OnceLock::new(),
             is_fake: false,
         });
         assert_eq!(
@@ -653,6 +838,7 @@ mod tests {
                 .unwrap(),
             },
             hash: OnceLock::new(),
+            caller: OnceLock::new(),
             is_fake: false,
         });
         assert_eq!(
@@ -661,6 +847,27 @@ mod tests {
         );
     }
 
+    #[test]
+    fn decode_unknown_transaction_type() {
+        for type_byte in [0x00u8, 0x05, 0x7f] {
+            let encoded = [type_byte, 0xc0];
+
+            let error = SignedTransaction::decode(&mut encoded.as_slice())
+                .expect_err("unknown transaction type");
+            assert!(matches!(
+                error,
+                alloy_rlp::Error::Custom(message)
+                    if SignedTransaction::is_invalid_transaction_type_error(message)
+            ));
+        }
+    }
+
+    #[test]
+    fn decode_empty_transaction() {
+        let error = SignedTransaction::decode(&mut [].as_slice()).expect_err("empty input");
+        assert_eq!(error, alloy_rlp::Error::InputTooShort);
+    }
+
     // <https://github.com/gakonst/ethers-rs/issues/1732>
     #[test]
     fn test_recover_legacy_tx() {
@@ -675,6 +882,118 @@ mod tests {
         assert_eq!(expected, recovered);
     }
 
+    #[test]
+    fn recover_with_chain_id_pre_eip155() {
+        // Same transaction as in `can_recover_sender`, with v = 28
+        let bytes = hex::decode("f85f800182520894095e7baea6a6c7c4c2dfeb977efac326af552d870a801ca048b55bfa915ac795c431978d8a6a992b628d557da5ff759b307d495a36649353a010002cef538bc0c8e21c46080634a93e082408b0ad93f4a7207e63ec5463793d").unwrap();
+
+        let tx = SignedTransaction::decode(&mut bytes.as_slice()).unwrap();
+        assert!(matches!(tx, SignedTransaction::PreEip155Legacy(_)));
+
+        let expected: Address = "0x0f65fe9276bc9a24ae7083ae28e2660ef72df99e"
+            .parse()
+            .unwrap();
+
+        // Not replay-protected, so any chain id is accepted
+        for chain_id in [1, 31337] {
+            assert_eq!(tx.recover_with_chain_id(chain_id).unwrap(), expected);
+        }
+    }
+
+    #[test]
+    fn recover_with_chain_id_eip155() {
+        // Same transaction as in `test_recover_legacy_tx`, signed for chain 1
+        let raw_tx = "f9015482078b8505d21dba0083022ef1947a250d5630b4cf539739df2c5dacb4c659f2488d880c46549a521b13d8b8e47ff36ab50000000000000000000000000000000000000000000066ab5a608bd00a23f2fe000000000000000000000000000000000000000000000000000000000000008000000000000000000000000048c04ed5691981c42154c6167398f95e8f38a7ff00000000000000000000000000000000000000000000000000000000632ceac70000000000000000000000000000000000000000000000000000000000000002000000000000000000000000c02aaa39b223fe8d0a0e5c4f27ead9083c756cc20000000000000000000000006c6ee5e31d828de241282b9606c8e98ea48526e225a0c9077369501641a92ef7399ff81c21639ed4fd8fc69cb793cfa1dbfab342e10aa0615facb2f1bcf3274a354cfe384a38d0cc008a11c2dd23a69111bc6930ba27a8";
+
+        let tx = SignedTransaction::decode(&mut hex::decode(raw_tx).unwrap().as_slice()).unwrap();
+        assert!(matches!(tx, SignedTransaction::PostEip155Legacy(_)));
+
+        let expected: Address = "0xa12e1462d0ced572f396f58b6e2d03894cd7c8a4"
+            .parse()
+            .unwrap();
+        assert_eq!(tx.recover_with_chain_id(1).unwrap(), expected);
+
+        let error = tx
+            .recover_with_chain_id(31337)
+            .expect_err("chain ids don't match");
+        assert!(matches!(
+            error,
+            SignatureError::InvalidChainId {
+                expected: 31337,
+                actual: 1
+            }
+        ));
+    }
+
+    #[test]
+    fn recover_strict_rejects_high_s() {
+        // Same transaction as in `test_recover_legacy_tx`
+        let raw_tx = "f9015482078b8505d21dba0083022ef1947a250d5630b4cf539739df2c5dacb4c659f2488d880c46549a521b13d8b8e47ff36ab50000000000000000000000000000000000000000000066ab5a608bd00a23f2fe000000000000000000000000000000000000000000000000000000000000008000000000000000000000000048c04ed5691981c42154c6167398f95e8f38a7ff00000000000000000000000000000000000000000000000000000000632ceac70000000000000000000000000000000000000000000000000000000000000002000000000000000000000000c02aaa39b223fe8d0a0e5c4f27ead9083c756cc20000000000000000000000006c6ee5e31d828de241282b9606c8e98ea48526e225a0c9077369501641a92ef7399ff81c21639ed4fd8fc69cb793cfa1dbfab342e10aa0615facb2f1bcf3274a354cfe384a38d0cc008a11c2dd23a69111bc6930ba27a8";
+
+        let raw_tx = hex::decode(raw_tx).unwrap();
+        let tx = SignedTransaction::decode(&mut raw_tx.as_slice()).unwrap();
+        let expected: Address = "0xa12e1462d0ced572f396f58b6e2d03894cd7c8a4"
+            .parse()
+            .unwrap();
+        assert_eq!(tx.recover_strict().unwrap(), expected);
+
+        // Decode a fresh copy, so the caller hasn't been recovered yet
+        let SignedTransaction::PostEip155Legacy(mut malleable) =
+            SignedTransaction::decode(&mut raw_tx.as_slice()).unwrap()
+        else {
+            panic!("Expected an EIP-155 transaction");
+        };
+
+        // Flip the signature to its high S equivalent
+        let secp256k1n = SECP256K1N_HALF * U256::from(2) + U256::from(1);
+        malleable.signature.s = secp256k1n - malleable.signature.s;
+        malleable.signature.v = if malleable.signature.v % 2 == 0 {
+            malleable.signature.v - 1
+        } else {
+            malleable.signature.v + 1
+        };
+
+        let malleable = SignedTransaction::PostEip155Legacy(malleable);
+        assert_eq!(malleable.recover().unwrap(), expected);
+        assert!(matches!(
+            malleable.recover_strict(),
+            Err(SignatureError::HighS)
+        ));
+    }
+
+    #[test]
+    fn effective_gas_price_is_capped_by_max_fee() {
+        let transaction: SignedTransaction = Eip1559TransactionRequest {
+            chain_id: 1,
+            nonce: 0,
+            max_priority_fee_per_gas: U256::from(2),
+            max_fee_per_gas: U256::from(10),
+            gas_limit: 21_000,
+            kind: TransactionKind::Call(Address::ZERO),
+            value: U256::ZERO,
+            input: Bytes::new(),
+            access_list: Vec::new(),
+        }
+        .fake_sign(&Address::random())
+        .into();
+
+        // The priority fee fits within the max fee
+        assert_eq!(
+            transaction.effective_gas_price(U256::from(5)),
+            U256::from(7)
+        );
+        // The priority fee is partially paid
+        assert_eq!(
+            transaction.effective_gas_price(U256::from(9)),
+            U256::from(10)
+        );
+        // The base fee exceeds the max fee
+        assert_eq!(
+            transaction.effective_gas_price(U256::from(11)),
+            U256::from(10)
+        );
+    }
+
     #[test]
     fn from_is_implemented_for_all_variants() {
         fn _compile_test(transaction: SignedTransaction) -> SignedTransaction {
@@ -684,6 +1003,7 @@ mod tests {
                 SignedTransaction::Eip2930(transaction) => transaction.into(),
                 SignedTransaction::Eip1559(transaction) => transaction.into(),
                 SignedTransaction::Eip4844(transaction) => transaction.into(),
+                SignedTransaction::Eip7702(transaction) => transaction.into(),
             }
         }
     }
@@ -8,7 +8,7 @@ use crate::{
     signature::{Signature, SignatureError},
     transaction::{
         fake_signature::recover_fake_signature, kind::TransactionKind,
-        request::Eip155TransactionRequest,
+        request::Eip155TransactionRequest, RecoveredCaller,
     },
     Address, Bytes, B256, U256,
 };
@@ -31,6 +31,11 @@ pub struct Eip155SignedTransaction {
     #[rlp(skip)]
     #[cfg_attr(feature = "serde", serde(skip))]
     pub hash: OnceLock<B256>,
+    /// Cached address of the signer
+    #[rlp(default)]
+    #[rlp(skip)]
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub caller: OnceLock<RecoveredCaller>,
     /// Whether the signed transaction is from an impersonated account.
     #[rlp(default)]
     #[rlp(skip)]
@@ -44,12 +49,19 @@ impl Eip155SignedTransaction {
     }
 
     /// Recovers the Ethereum address which was used to sign the transaction.
+    ///
+    /// The result is cached, so subsequent calls don't repeat the ECDSA
+    /// recovery.
     pub fn recover(&self) -> Result<Address, SignatureError> {
         if self.is_fake {
             return Ok(recover_fake_signature(&self.signature));
         }
-        self.signature
-            .recover(Eip155TransactionRequest::from(self).hash())
+
+        RecoveredCaller::recover(
+            &self.caller,
+            &self.signature,
+            Eip155TransactionRequest::from(self).hash(),
+        )
     }
 
     pub fn chain_id(&self) -> u64 {
@@ -68,6 +80,8 @@ impl From<LegacySignedTransaction> for Eip155SignedTransaction {
             input: tx.input,
             signature: tx.signature,
             hash: tx.hash,
+            // The legacy signature hash differs, so the caller must be recovered anew.
+            caller: OnceLock::new(),
             is_fake: tx.is_fake,
         }
     }
@@ -153,4 +167,20 @@ mod tests {
             Eip155SignedTransaction::decode(&mut encoded.as_slice()).unwrap()
         );
     }
+
+    #[test]
+    fn recover_eip155_specification_transaction() -> anyhow::Result<()> {
+        // Example from https://eips.ethereum.org/EIPS/eip-155, signed with the
+        // private key 0x4646...46
+        let bytes = hex::decode("f86c098504a817c800825208943535353535353535353535353535353535353535880de0b6b3a76400008025a028ef61340bd939bc2195fe537567866003e1a15d3c71ff63e1590620aa636276a067cbe9d8997f761aecb703304b3800ccf555c9f3dc64214b297fb1966a3b6d83")?;
+
+        let legacy = LegacySignedTransaction::decode(&mut bytes.as_slice())?;
+        let transaction = Eip155SignedTransaction::from(legacy);
+        assert_eq!(transaction.chain_id(), 1);
+
+        let expected: Address = "0x9d8a62f656a8d1615c1294fd71e9cfb3e4855a4f".parse()?;
+        assert_eq!(transaction.recover()?, expected);
+
+        Ok(())
+    }
 }
@@ -8,7 +8,7 @@ use crate::{
     signature::{Signature, SignatureError},
     transaction::{
         fake_signature::recover_fake_signature, kind::TransactionKind,
-        request::Eip1559TransactionRequest,
+        request::Eip1559TransactionRequest, RecoveredCaller,
     },
     utils::envelop_bytes,
     Address, Bytes, B256, U256,
@@ -38,6 +38,11 @@ pub struct Eip1559SignedTransaction {
     #[rlp(skip)]
     #[cfg_attr(feature = "serde", serde(skip))]
     pub hash: OnceLock<B256>,
+    /// Cached address of the signer
+    #[rlp(default)]
+    #[rlp(skip)]
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub caller: OnceLock<RecoveredCaller>,
     /// Whether the signature is from an impersonated account.
     #[rlp(default)]
     #[rlp(skip)]
@@ -56,6 +61,9 @@ impl Eip1559SignedTransaction {
     }
 
     /// Recovers the Ethereum address which was used to sign the transaction.
+    ///
+    /// The result is cached, so subsequent calls don't repeat the ECDSA
+    /// recovery.
     pub fn recover(&self) -> Result<Address, SignatureError> {
         let signature = Signature {
             r: self.r,
@@ -67,7 +75,11 @@ impl Eip1559SignedTransaction {
             return Ok(recover_fake_signature(&signature));
         }
 
-        signature.recover(Eip1559TransactionRequest::from(self).hash())
+        RecoveredCaller::recover(
+            &self.caller,
+            &signature,
+            Eip1559TransactionRequest::from(self).hash(),
+        )
     }
 }
 
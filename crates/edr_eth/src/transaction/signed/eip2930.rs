@@ -8,7 +8,7 @@ use crate::{
     signature::{Signature, SignatureError},
     transaction::{
         fake_signature::recover_fake_signature, kind::TransactionKind,
-        request::Eip2930TransactionRequest,
+        request::Eip2930TransactionRequest, RecoveredCaller,
     },
     utils::envelop_bytes,
     Address, Bytes, B256, U256,
@@ -37,6 +37,11 @@ pub struct Eip2930SignedTransaction {
     #[rlp(skip)]
     #[cfg_attr(feature = "serde", serde(skip))]
     pub hash: OnceLock<B256>,
+    /// Cached address of the signer
+    #[rlp(default)]
+    #[rlp(skip)]
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub caller: OnceLock<RecoveredCaller>,
     /// Whether the signed transaction is from an impersonated account.
     #[rlp(default)]
     #[rlp(skip)]
@@ -55,6 +60,9 @@ impl Eip2930SignedTransaction {
     }
 
     /// Recovers the Ethereum address which was used to sign the transaction.
+    ///
+    /// The result is cached, so subsequent calls don't repeat the ECDSA
+    /// recovery.
     pub fn recover(&self) -> Result<Address, SignatureError> {
         let signature = Signature {
             r: self.r,
@@ -66,7 +74,11 @@ impl Eip2930SignedTransaction {
             return Ok(recover_fake_signature(&signature));
         }
 
-        signature.recover(Eip2930TransactionRequest::from(self).hash())
+        RecoveredCaller::recover(
+            &self.caller,
+            &signature,
+            Eip2930TransactionRequest::from(self).hash(),
+        )
     }
 }
 
@@ -7,7 +7,9 @@ use revm_primitives::GAS_PER_BLOB;
 use crate::{
     access_list::AccessList,
     signature::{Signature, SignatureError},
-    transaction::{fake_signature::recover_fake_signature, Eip4844TransactionRequest},
+    transaction::{
+        fake_signature::recover_fake_signature, Eip4844TransactionRequest, RecoveredCaller,
+    },
     utils::envelop_bytes,
     Address, Bytes, B256, U256,
 };
@@ -38,6 +40,11 @@ pub struct Eip4844SignedTransaction {
     #[rlp(skip)]
     #[cfg_attr(feature = "serde", serde(skip))]
     pub hash: OnceLock<B256>,
+    /// Cached address of the signer
+    #[rlp(default)]
+    #[rlp(skip)]
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub caller: OnceLock<RecoveredCaller>,
     /// Whether the signed transaction is from an impersonated account.
     #[rlp(default)]
     #[rlp(skip)]
@@ -60,6 +67,9 @@ impl Eip4844SignedTransaction {
     }
 
     /// Recovers the Ethereum address which was used to sign the transaction.
+    ///
+    /// The result is cached, so subsequent calls don't repeat the ECDSA
+    /// recovery.
     pub fn recover(&self) -> Result<Address, SignatureError> {
         let signature = Signature {
             r: self.r,
@@ -71,7 +81,11 @@ impl Eip4844SignedTransaction {
             return Ok(recover_fake_signature(&signature));
         }
 
-        signature.recover(Eip4844TransactionRequest::from(self).hash())
+        RecoveredCaller::recover(
+            &self.caller,
+            &signature,
+            Eip4844TransactionRequest::from(self).hash(),
+        )
     }
 
     /// Total blob gas used by the transaction.
@@ -128,6 +142,7 @@ mod tests {
                 .unwrap(),
             odd_y_parity: false,
             hash: OnceLock::new(),
+            caller: OnceLock::new(),
             is_fake: false,
         }
     }
@@ -0,0 +1,212 @@
+use std::sync::OnceLock;
+
+use alloy_primitives::keccak256;
+use alloy_rlp::{RlpDecodable, RlpEncodable};
+
+use crate::{
+    access_list::AccessList,
+    authorization::SignedAuthorization,
+    signature::{Signature, SignatureError},
+    transaction::{
+        fake_signature::recover_fake_signature, request::Eip7702TransactionRequest, RecoveredCaller,
+    },
+    utils::envelop_bytes,
+    Address, Bytes, B256, U256,
+};
+
+#[derive(Clone, Debug, Eq, RlpDecodable, RlpEncodable)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Eip7702SignedTransaction {
+    // The order of these fields determines de-/encoding order.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde::u64"))]
+    pub chain_id: u64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde::u64"))]
+    pub nonce: u64,
+    pub max_priority_fee_per_gas: U256,
+    pub max_fee_per_gas: U256,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde::u64"))]
+    pub gas_limit: u64,
+    pub to: Address,
+    pub value: U256,
+    pub input: Bytes,
+    pub access_list: AccessList,
+    pub authorization_list: Vec<SignedAuthorization>,
+    pub odd_y_parity: bool,
+    pub r: U256,
+    pub s: U256,
+    /// Cached transaction hash
+    #[rlp(default)]
+    #[rlp(skip)]
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub hash: OnceLock<B256>,
+    /// Cached address of the signer
+    #[rlp(default)]
+    #[rlp(skip)]
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub caller: OnceLock<RecoveredCaller>,
+    /// Whether the signature is from an impersonated account.
+    #[rlp(default)]
+    #[rlp(skip)]
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub is_fake: bool,
+}
+
+impl Eip7702SignedTransaction {
+    pub fn hash(&self) -> &B256 {
+        self.hash.get_or_init(|| {
+            let encoded = alloy_rlp::encode(self);
+            let enveloped = envelop_bytes(4, &encoded);
+
+            keccak256(enveloped)
+        })
+    }
+
+    /// Recovers the Ethereum address which was used to sign the transaction.
+    ///
+    /// The result is cached, so subsequent calls don't repeat the ECDSA
+    /// recovery.
+    pub fn recover(&self) -> Result<Address, SignatureError> {
+        let signature = Signature {
+            r: self.r,
+            s: self.s,
+            v: u64::from(self.odd_y_parity),
+        };
+
+        if self.is_fake {
+            return Ok(recover_fake_signature(&signature));
+        }
+
+        RecoveredCaller::recover(
+            &self.caller,
+            &signature,
+            Eip7702TransactionRequest::from(self).hash(),
+        )
+    }
+
+    /// Recovers the authorities that signed the authorization list, in order.
+    ///
+    /// Fails on the first authorization with an invalid signature.
+    pub fn recover_authorities(&self) -> Result<Vec<Address>, SignatureError> {
+        self.authorization_list
+            .iter()
+            .map(SignedAuthorization::recover)
+            .collect()
+    }
+}
+
+impl PartialEq for Eip7702SignedTransaction {
+    fn eq(&self, other: &Self) -> bool {
+        self.chain_id == other.chain_id
+            && self.nonce == other.nonce
+            && self.max_priority_fee_per_gas == other.max_priority_fee_per_gas
+            && self.max_fee_per_gas == other.max_fee_per_gas
+            && self.gas_limit == other.gas_limit
+            && self.to == other.to
+            && self.value == other.value
+            && self.input == other.input
+            && self.access_list == other.access_list
+            && self.authorization_list == other.authorization_list
+            && self.odd_y_parity == other.odd_y_parity
+            && self.r == other.r
+            && self.s == other.s
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use alloy_rlp::Decodable;
+    use k256::SecretKey;
+
+    use super::*;
+    use crate::{
+        access_list::AccessListItem,
+        authorization::Authorization,
+        signature::{secret_key_from_str, secret_key_to_address},
+    };
+
+    const DUMMY_SECRET_KEY: &str =
+        "e331b6d69882b4cb4ea581d88e0b604039a3de5967688d3dcffdd2270c0fd109";
+    const AUTHORITY_SECRET_KEY: &str =
+        "ac0974bec39a17e36ba4a6b4d238ff944bacb478cbed5efcae784d7bf4f2ff80";
+
+    fn dummy_secret_key() -> SecretKey {
+        secret_key_from_str(DUMMY_SECRET_KEY).unwrap()
+    }
+
+    fn dummy_request() -> Eip7702TransactionRequest {
+        let to = Address::from_str("0xc014ba5ec014ba5ec014ba5ec014ba5ec014ba5e").unwrap();
+        let input = hex::decode("1234").unwrap();
+
+        let authority_secret_key = secret_key_from_str(AUTHORITY_SECRET_KEY).unwrap();
+        let authorization = Authorization {
+            chain_id: 1,
+            address: Address::repeat_byte(0x42),
+            nonce: 0,
+        }
+        .sign(&authority_secret_key)
+        .unwrap();
+
+        Eip7702TransactionRequest {
+            chain_id: 1,
+            nonce: 1,
+            max_priority_fee_per_gas: U256::from(2),
+            max_fee_per_gas: U256::from(5),
+            gas_limit: 3,
+            to,
+            value: U256::from(4),
+            input: Bytes::from(input),
+            access_list: vec![AccessListItem {
+                address: Address::ZERO,
+                storage_keys: vec![B256::ZERO, B256::from(U256::from(1))],
+            }],
+            authorization_list: vec![authorization],
+        }
+    }
+
+    #[test]
+    fn test_eip7702_signed_transaction_recover() {
+        let request = dummy_request();
+
+        let signed = request.sign(&dummy_secret_key()).unwrap();
+
+        let expected = secret_key_to_address(DUMMY_SECRET_KEY)
+            .expect("Failed to retrieve address from secret key");
+        assert_eq!(expected, signed.recover().expect("should succeed"));
+
+        let expected_authority = secret_key_to_address(AUTHORITY_SECRET_KEY)
+            .expect("Failed to retrieve address from secret key");
+        assert_eq!(
+            vec![expected_authority],
+            signed.recover_authorities().expect("should succeed")
+        );
+    }
+
+    #[test]
+    fn test_eip7702_signed_transaction_rlp() {
+        let request = dummy_request();
+        let signed = request.sign(&dummy_secret_key()).unwrap();
+
+        let encoded = alloy_rlp::encode(&signed);
+        assert_eq!(
+            signed,
+            Eip7702SignedTransaction::decode(&mut encoded.as_slice()).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_eip7702_invalid_authorization_signature() {
+        let mut request = dummy_request();
+        request.authorization_list[0].r = U256::ZERO;
+
+        let signed = request.sign(&dummy_secret_key()).unwrap();
+
+        // The transaction itself is still validly signed
+        let expected = secret_key_to_address(DUMMY_SECRET_KEY)
+            .expect("Failed to retrieve address from secret key");
+        assert_eq!(expected, signed.recover().expect("should succeed"));
+
+        assert!(signed.recover_authorities().is_err());
+    }
+}
@@ -7,7 +7,7 @@ use crate::{
     signature::{Signature, SignatureError},
     transaction::{
         fake_signature::recover_fake_signature, kind::TransactionKind,
-        request::LegacyTransactionRequest,
+        request::LegacyTransactionRequest, RecoveredCaller,
     },
     Address, Bytes, B256, U256,
 };
@@ -30,6 +30,11 @@ pub struct LegacySignedTransaction {
     #[rlp(skip)]
     #[cfg_attr(feature = "serde", serde(skip))]
     pub hash: OnceLock<B256>,
+    /// Cached address of the signer
+    #[rlp(default)]
+    #[rlp(skip)]
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub caller: OnceLock<RecoveredCaller>,
     /// Whether the signature is from an impersonated account.
     #[rlp(default)]
     #[rlp(skip)]
@@ -43,12 +48,19 @@ impl LegacySignedTransaction {
     }
 
     /// Recovers the Ethereum address which was used to sign the transaction.
+    ///
+    /// The result is cached, so subsequent calls don't repeat the ECDSA
+    /// recovery.
     pub fn recover(&self) -> Result<Address, SignatureError> {
         if self.is_fake {
             return Ok(recover_fake_signature(&self.signature));
         }
-        self.signature
-            .recover(LegacyTransactionRequest::from(self).hash())
+
+        RecoveredCaller::recover(
+            &self.caller,
+            &self.signature,
+            LegacyTransactionRequest::from(self).hash(),
+        )
     }
 }
 
@@ -130,4 +142,52 @@ mod tests {
             LegacySignedTransaction::decode(&mut encoded.as_slice()).unwrap()
         );
     }
+
+    #[test]
+    fn test_legacy_signed_transaction_recover_is_cached() {
+        let request = dummy_request();
+        let signed = request.sign(&dummy_secret_key()).unwrap();
+        assert!(signed.caller.get().is_none());
+
+        let caller = signed.recover().unwrap();
+        assert_eq!(
+            signed.caller.get().map(RecoveredCaller::caller),
+            Some(caller)
+        );
+
+        // Clones receive their own copy of the cache
+        let mut cloned = signed.clone();
+        assert_eq!(
+            cloned.caller.get().map(RecoveredCaller::caller),
+            Some(caller)
+        );
+
+        cloned.caller.take();
+        assert_eq!(
+            signed.caller.get().map(RecoveredCaller::caller),
+            Some(caller)
+        );
+        assert_eq!(cloned.recover().unwrap(), caller);
+
+        // The cache is not part of the encoding
+        let encoded = alloy_rlp::encode(&signed);
+        let decoded = LegacySignedTransaction::decode(&mut encoded.as_slice()).unwrap();
+        assert!(decoded.caller.get().is_none());
+    }
+
+    #[test]
+    fn test_legacy_signed_transaction_recover_ignores_stale_cache() {
+        let request = dummy_request();
+        let mut signed = request.sign(&dummy_secret_key()).unwrap();
+        let caller = signed.recover().unwrap();
+
+        // Modifying the transaction after recovery changes its signer
+        signed.nonce += 1;
+        let modified_caller = signed.recover().unwrap();
+        assert_ne!(modified_caller, caller);
+
+        let mut uncached = signed.clone();
+        uncached.caller.take();
+        assert_eq!(modified_caller, uncached.recover().unwrap());
+    }
 }
@@ -2,7 +2,7 @@
 
 use alloy_rlp::{RlpDecodable, RlpEncodable};
 
-use crate::{Address, U256};
+use crate::{trie, Address, B256, U256};
 
 /// Ethereum withdrawal
 #[derive(Clone, Debug, PartialEq, Eq, RlpDecodable, RlpEncodable)]
@@ -20,3 +20,51 @@ pub struct Withdrawal {
     /// The value contained in withdrawal
     pub amount: U256,
 }
+
+/// Computes the withdrawals root of a block, i.e. the root of the Merkle
+/// Patricia trie of the RLP-encoded withdrawals, keyed by their index.
+pub fn withdrawals_root(withdrawals: &[Withdrawal]) -> B256 {
+    trie::ordered_trie_root(withdrawals.iter().map(alloy_rlp::encode))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+
+    #[test]
+    fn withdrawals_root_empty() {
+        assert_eq!(withdrawals_root(&[]), trie::KECCAK_NULL_RLP);
+    }
+
+    #[test]
+    fn withdrawals_root_known_value() -> anyhow::Result<()> {
+        let address = Address::from_str("0x388c818ca8b9251b393131c08a736a67ccb19297")?;
+        let withdrawals = [
+            Withdrawal {
+                index: 0,
+                validator_index: 65_535,
+                address,
+                amount: U256::from(1_000_000),
+            },
+            Withdrawal {
+                index: 1,
+                validator_index: 65_536,
+                address,
+                amount: U256::from(2_500_000),
+            },
+        ];
+
+        assert_eq!(
+            withdrawals_root(&withdrawals[..1]),
+            B256::from_str("0x1397c86f5ddd84b7ebca6ae045a77bfff97a79fc6f21e7afb1ce753aa6c4c26b")?
+        );
+        assert_eq!(
+            withdrawals_root(&withdrawals),
+            B256::from_str("0x93c831cda43be7b5f7d498a366601d3cdf335374934568985327a16f5fa14370")?
+        );
+
+        Ok(())
+    }
+}
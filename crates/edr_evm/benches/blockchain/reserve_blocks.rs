@@ -0,0 +1,62 @@
+use criterion::{criterion_group, criterion_main, BatchSize, Criterion};
+use edr_eth::B256;
+use edr_evm::{
+    blockchain::{Blockchain, BlockchainMut, GenesisBlockOptions, LocalBlockchain},
+    state::StateDiff,
+    SpecId,
+};
+
+const NUM_RESERVED_BLOCKS: u64 = 1_000_000;
+
+fn bench_reserve_blocks(c: &mut Criterion) {
+    let create_blockchain = || {
+        LocalBlockchain::new(
+            StateDiff::default(),
+            1,
+            SpecId::LATEST,
+            GenesisBlockOptions {
+                mix_hash: Some(B256::ZERO),
+                ..GenesisBlockOptions::default()
+            },
+        )
+        .expect("Failed to create blockchain")
+    };
+
+    let mut group = c.benchmark_group("reserve_blocks");
+
+    group.bench_function("reserve", |b| {
+        b.iter_batched(
+            create_blockchain,
+            |mut blockchain| {
+                let result = blockchain.reserve_blocks(NUM_RESERVED_BLOCKS, 1);
+                debug_assert!(result.is_ok());
+            },
+            BatchSize::SmallInput,
+        );
+    });
+
+    group.bench_function("reserve_and_query_hash", |b| {
+        b.iter_batched(
+            create_blockchain,
+            |mut blockchain| {
+                blockchain
+                    .reserve_blocks(NUM_RESERVED_BLOCKS, 1)
+                    .expect("Failed to reserve blocks");
+
+                let block = blockchain
+                    .block_by_number(NUM_RESERVED_BLOCKS / 2)
+                    .expect("Failed to retrieve block")
+                    .expect("Reserved block should exist");
+
+                let result = blockchain.block_by_hash(block.hash());
+                debug_assert!(matches!(result, Ok(Some(_))));
+            },
+            BatchSize::SmallInput,
+        );
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_reserve_blocks);
+criterion_main!(benches);
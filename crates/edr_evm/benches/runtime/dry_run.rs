@@ -0,0 +1,108 @@
+use std::collections::BTreeMap;
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use edr_eth::{Address, Bytes, B256};
+use edr_evm::{
+    blockchain::{Blockchain, GenesisBlockOptions, LocalBlockchain},
+    dry_run,
+    state::{AccountOverride, StateDiff, StateOverrides},
+    trace::{register_trace_collector_handles, TraceCollector},
+    BlockEnv, Bytecode, CfgEnv, CfgEnvWithHandlerCfg, DebugContext, SpecId, TransactTo, TxEnv,
+};
+
+/// Decrements a counter from 10,000 to zero in a loop.
+const LOOP_CODE: [u8; 13] = [
+    0x61, 0x27, 0x10, // PUSH2 10000
+    0x5b, // JUMPDEST
+    0x60, 0x01, // PUSH1 1
+    0x90, // SWAP1
+    0x03, // SUB
+    0x80, // DUP1
+    0x60, 0x03, // PUSH1 3
+    0x57, // JUMPI
+    0x00, // STOP
+];
+
+fn bench_dry_run(c: &mut Criterion) {
+    let blockchain = LocalBlockchain::new(
+        StateDiff::default(),
+        1,
+        SpecId::LATEST,
+        GenesisBlockOptions {
+            mix_hash: Some(B256::ZERO),
+            ..GenesisBlockOptions::default()
+        },
+    )
+    .expect("Failed to create blockchain");
+
+    let state = blockchain
+        .state_at_block_number(0, &BTreeMap::new())
+        .expect("Failed to retrieve genesis state");
+
+    let contract_address = Address::random();
+    let state_overrides = StateOverrides::new(
+        [(
+            contract_address,
+            AccountOverride {
+                balance: None,
+                nonce: None,
+                code: Some(Bytecode::new_raw(Bytes::from_static(&LOOP_CODE))),
+                storage: None,
+            },
+        )]
+        .into_iter()
+        .collect(),
+    );
+
+    let cfg = CfgEnvWithHandlerCfg::new_with_spec_id(CfgEnv::default(), SpecId::LATEST);
+    let transaction = TxEnv {
+        transact_to: TransactTo::Call(contract_address),
+        gas_limit: 1_000_000,
+        ..TxEnv::default()
+    };
+    let block = BlockEnv {
+        prevrandao: Some(B256::ZERO),
+        ..BlockEnv::default()
+    };
+
+    let mut group = c.benchmark_group("dry_run");
+
+    group.bench_function("without_tracing", |b| {
+        b.iter(|| {
+            let result = dry_run::<(), _, _>(
+                &blockchain,
+                &*state,
+                &state_overrides,
+                cfg.clone(),
+                transaction.clone(),
+                block.clone(),
+                None,
+            );
+            debug_assert!(result.is_ok());
+        });
+    });
+
+    group.bench_function("with_tracing", |b| {
+        b.iter(|| {
+            let mut tracer = TraceCollector::default();
+            let result = dry_run(
+                &blockchain,
+                &*state,
+                &state_overrides,
+                cfg.clone(),
+                transaction.clone(),
+                block.clone(),
+                Some(DebugContext {
+                    data: &mut tracer,
+                    register_handles_fn: register_trace_collector_handles,
+                }),
+            );
+            debug_assert!(result.is_ok());
+        });
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_dry_run);
+criterion_main!(benches);
@@ -0,0 +1,73 @@
+use std::num::NonZeroUsize;
+
+use criterion::{criterion_group, criterion_main, BatchSize, BenchmarkId, Criterion};
+use edr_eth::{Address, U256};
+use edr_evm::{
+    alloy_primitives::U160, state::AccountTrie, Account, AccountInfo, AccountStatus, HashMap,
+    StorageSlot,
+};
+
+/// Number of accounts in the small and large change sets, respectively.
+const NUMBER_OF_ACCOUNTS: [u64; 2] = [4, 500];
+const STORAGE_SLOTS_PER_ACCOUNT: u64 = 16;
+
+fn change_set(number_of_accounts: u64) -> HashMap<Address, Account> {
+    (1..=number_of_accounts)
+        .map(|account_number| {
+            let address = Address::from(U160::from(account_number));
+            let storage = (0..STORAGE_SLOTS_PER_ACCOUNT)
+                .map(|slot| {
+                    (
+                        U256::from(slot),
+                        StorageSlot::new_changed(U256::ZERO, U256::from(account_number)),
+                    )
+                })
+                .collect();
+
+            let account = Account {
+                info: AccountInfo {
+                    balance: U256::from(account_number),
+                    nonce: account_number,
+                    ..AccountInfo::default()
+                },
+                storage,
+                status: AccountStatus::Touched,
+            };
+
+            (address, account)
+        })
+        .collect()
+}
+
+fn bench_account_trie_commit(c: &mut Criterion) {
+    let available_parallelism =
+        std::thread::available_parallelism().unwrap_or(NonZeroUsize::new(1).unwrap());
+
+    let mut group = c.benchmark_group("AccountTrie::commit");
+    for number_of_accounts in NUMBER_OF_ACCOUNTS {
+        let changes = change_set(number_of_accounts);
+
+        for (label, parallelism) in [
+            ("serial", NonZeroUsize::new(1).unwrap()),
+            ("batched", available_parallelism),
+        ] {
+            group.bench_with_input(
+                BenchmarkId::new(label, number_of_accounts),
+                &parallelism,
+                |b, parallelism| {
+                    b.iter_batched(
+                        AccountTrie::default,
+                        |mut trie| {
+                            trie.commit_with_parallelism(&changes, *parallelism);
+                            trie
+                        },
+                        BatchSize::SmallInput,
+                    );
+                },
+            );
+        }
+    }
+}
+
+criterion_group!(benches, bench_account_trie_commit);
+criterion_main!(benches);
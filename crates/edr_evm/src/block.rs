@@ -1,18 +1,28 @@
 mod builder;
 mod local;
+mod pruned;
 mod remote;
 
 use std::{fmt::Debug, sync::Arc};
 
 use auto_impl::auto_impl;
-use edr_eth::{block, receipt::BlockReceipt, remote::eth, withdrawal::Withdrawal, B256, U256};
+use edr_eth::{
+    block::{self, BlobGas},
+    receipt::BlockReceipt,
+    remote::eth,
+    withdrawal::Withdrawal,
+    B256, U256,
+};
+use revm::primitives::{BlobExcessGasAndPrice, BlockEnv, SpecId};
 
+pub(crate) use self::remote::header_from_rpc_block;
 pub use self::{
     builder::{
-        BlockBuilder, BlockBuilderCreationError, BlockTransactionError, BuildBlockResult,
-        ExecutionResultWithContext,
+        BlockBuilder, BlockBuilderCreationError, BlockOmmerError, BlockTransactionError,
+        BuildBlockResult, ExecutionResultWithContext,
     },
     local::LocalBlock,
+    pruned::PrunedBlock,
     remote::{CreationError as RemoteBlockCreationError, RemoteBlock},
 };
 use crate::ExecutableTransaction;
@@ -111,3 +121,99 @@ impl<BlockchainErrorT> From<BlockAndTotalDifficulty<BlockchainErrorT>> for eth::
         }
     }
 }
+
+/// Constructs the block environment of the provided header, for executing
+/// transactions in the context of the block.
+///
+/// Before the Merge, the block's difficulty is used; afterwards, its mix hash
+/// is used as prevrandao.
+pub fn block_env_from_header(header: &block::Header, spec_id: SpecId) -> BlockEnv {
+    BlockEnv {
+        number: U256::from(header.number),
+        coinbase: header.beneficiary,
+        timestamp: U256::from(header.timestamp),
+        gas_limit: U256::from(header.gas_limit),
+        basefee: header.base_fee_per_gas.unwrap_or_default(),
+        difficulty: header.difficulty,
+        prevrandao: if spec_id >= SpecId::MERGE {
+            Some(header.mix_hash)
+        } else {
+            None
+        },
+        blob_excess_gas_and_price: header
+            .blob_gas
+            .as_ref()
+            .map(|BlobGas { excess_gas, .. }| BlobExcessGasAndPrice::new(*excess_gas)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use edr_eth::Address;
+
+    use super::*;
+
+    fn test_header() -> block::Header {
+        block::Header {
+            beneficiary: Address::random(),
+            difficulty: U256::from(131_072),
+            number: 42,
+            gas_limit: 30_000_000,
+            timestamp: 1_700_000_000,
+            mix_hash: B256::random(),
+            ..block::Header::default()
+        }
+    }
+
+    #[test]
+    fn block_env_from_pre_merge_header() {
+        let header = test_header();
+
+        let block_env = block_env_from_header(&header, SpecId::LONDON);
+        assert_eq!(block_env.number, U256::from(header.number));
+        assert_eq!(block_env.coinbase, header.beneficiary);
+        assert_eq!(block_env.timestamp, U256::from(header.timestamp));
+        assert_eq!(block_env.gas_limit, U256::from(header.gas_limit));
+        assert_eq!(block_env.basefee, U256::ZERO);
+        assert_eq!(block_env.difficulty, header.difficulty);
+        assert_eq!(block_env.prevrandao, None);
+        assert_eq!(block_env.blob_excess_gas_and_price, None);
+    }
+
+    #[test]
+    fn block_env_from_post_merge_header() {
+        let header = block::Header {
+            difficulty: U256::ZERO,
+            base_fee_per_gas: Some(U256::from(7)),
+            ..test_header()
+        };
+
+        let block_env = block_env_from_header(&header, SpecId::MERGE);
+        assert_eq!(block_env.basefee, U256::from(7));
+        assert_eq!(block_env.difficulty, U256::ZERO);
+        assert_eq!(block_env.prevrandao, Some(header.mix_hash));
+        assert_eq!(block_env.blob_excess_gas_and_price, None);
+    }
+
+    #[test]
+    fn block_env_from_post_cancun_header() {
+        const EXCESS_BLOB_GAS: u64 = 0x2_0000;
+
+        let header = block::Header {
+            difficulty: U256::ZERO,
+            base_fee_per_gas: Some(U256::from(7)),
+            blob_gas: Some(BlobGas {
+                gas_used: 0x2_0000,
+                excess_gas: EXCESS_BLOB_GAS,
+            }),
+            ..test_header()
+        };
+
+        let block_env = block_env_from_header(&header, SpecId::CANCUN);
+        assert_eq!(block_env.prevrandao, Some(header.mix_hash));
+        assert_eq!(
+            block_env.blob_excess_gas_and_price,
+            Some(BlobExcessGasAndPrice::new(EXCESS_BLOB_GAS))
+        );
+    }
+}
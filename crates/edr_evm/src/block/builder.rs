@@ -1,10 +1,7 @@
-use std::{
-    fmt::Debug,
-    time::{SystemTime, UNIX_EPOCH},
-};
+use std::{fmt::Debug, sync::Arc};
 
 use edr_eth::{
-    block::{BlobGas, BlockOptions, Header, PartialHeader},
+    block::{miner_reward, BlobGas, BlockOptions, Header, PartialHeader},
     log::{add_log_to_bloom, Log},
     receipt::{TransactionReceipt, TypedReceipt, TypedReceiptData},
     transaction::SignedTransaction,
@@ -27,11 +24,19 @@ use crate::{
     blockchain::SyncBlockchain,
     debug::{DebugContext, EvmContext},
     state::{AccountModifierFn, StateDebug, StateDiff, SyncState},
+    time::{SystemTimeSource, TimeSource},
     ExecutableTransaction,
 };
 
 const DAO_EXTRA_DATA: &[u8] = b"dao-hard-fork";
 
+/// The maximum number of generations that an ommer can be older than the
+/// block that includes it.
+const MAX_OMMER_DEPTH: u64 = 6;
+
+/// The number of Wei in a Gwei, used to convert withdrawal amounts.
+const GWEI_TO_WEI: u64 = 1_000_000_000;
+
 /// An error caused during construction of a block builder.
 #[derive(Debug, thiserror::Error)]
 pub enum BlockBuilderCreationError {
@@ -43,6 +48,22 @@ pub enum BlockBuilderCreationError {
     UnsupportedHardfork(SpecId),
 }
 
+/// An error caused when adding ommers to a block builder.
+#[derive(Debug, thiserror::Error)]
+pub enum BlockOmmerError {
+    /// Post-merge blocks cannot contain ommers.
+    #[error("Post-merge blocks cannot contain ommers")]
+    PostMerge,
+    /// The ommer's block number is not within the allowed range of the block.
+    #[error("Ommer with block number {ommer_number} is invalid for block {block_number}. Ommers must be at most {MAX_OMMER_DEPTH} generations older than the block.")]
+    InvalidNumber {
+        /// The ommer's block number
+        ommer_number: u64,
+        /// The block's number
+        block_number: u64,
+    },
+}
+
 /// An error caused during execution of a transaction while building a block.
 #[derive(Debug, thiserror::Error)]
 pub enum BlockTransactionError<BE, SE> {
@@ -58,6 +79,10 @@ pub enum BlockTransactionError<BE, SE> {
     /// Transaction has higher blob gas usage than is remaining in block
     #[error("Transaction has higher blob gas usage than is remaining in block")]
     ExceedsBlockBlobGasLimit,
+    /// EIP-7702 transactions cannot be executed, as their authorization list
+    /// would not be applied
+    #[error("Cannot run transaction: EIP-7702 transactions are not supported.")]
+    Eip7702Unsupported,
     /// Sender does not have enough funds to send transaction.
     #[error("Sender doesn't have enough funds to send tx. The max upfront cost is: {max_upfront_cost} and the sender's balance is: {sender_balance}.")]
     InsufficientFunds {
@@ -132,6 +157,9 @@ pub struct BlockBuilder {
     receipts: Vec<TransactionReceipt<Log>>,
     parent_gas_limit: Option<u64>,
     withdrawals: Option<Vec<Withdrawal>>,
+    ommers: Vec<Header>,
+    allow_exceeding_block_gas_limit: bool,
+    time_source: Arc<dyn TimeSource>,
 }
 
 impl BlockBuilder {
@@ -155,13 +183,14 @@ impl BlockBuilder {
             None
         };
 
-        let withdrawals = std::mem::take(&mut options.withdrawals).or_else(|| {
-            if cfg.handler_cfg.spec_id >= SpecId::SHANGHAI {
-                Some(Vec::new())
-            } else {
-                None
-            }
-        });
+        // Withdrawals were introduced in Shanghai (EIP-4895) and are ignored for older
+        // hardforks.
+        let withdrawals = std::mem::take(&mut options.withdrawals);
+        let withdrawals = if cfg.handler_cfg.spec_id >= SpecId::SHANGHAI {
+            Some(withdrawals.unwrap_or_default())
+        } else {
+            None
+        };
 
         let header = PartialHeader::new(cfg.handler_cfg.spec_id, options, Some(parent));
 
@@ -185,9 +214,66 @@ impl BlockBuilder {
             receipts: Vec::new(),
             parent_gas_limit,
             withdrawals,
+            ommers: Vec::new(),
+            allow_exceeding_block_gas_limit: false,
+            time_source: Arc::new(SystemTimeSource),
         })
     }
 
+    /// Sets whether transactions with a gas limit that exceeds the block's
+    /// remaining gas are allowed, e.g. for `eth_call` semantics. Defaults to
+    /// `false`.
+    ///
+    /// When allowed, such transactions are executed with their gas limit
+    /// capped at the block gas limit, instead of failing with
+    /// [`BlockTransactionError::ExceedsBlockGasLimit`]. The block's gas used
+    /// never exceeds its gas limit, so the built block can be inconsistent
+    /// with its transactions and must not be committed to a blockchain.
+    pub fn set_allow_exceeding_block_gas_limit(&mut self, allow: bool) {
+        self.allow_exceeding_block_gas_limit = allow;
+    }
+
+    /// Sets the source of the block's timestamp, which is used if no timestamp
+    /// was specified during construction. Defaults to the system's clock.
+    pub fn set_time_source(&mut self, time_source: Arc<dyn TimeSource>) {
+        self.time_source = time_source;
+    }
+
+    /// Sets the ommers of the block. Their rewards and the beneficiary's
+    /// inclusion rewards are paid when finalizing the block, so they should
+    /// not be included in [`BlockBuilder::reward_beneficiary`]'s number of
+    /// ommers.
+    ///
+    /// Post-merge blocks must not have ommers.
+    pub fn set_ommers(&mut self, ommers: Vec<Header>) -> Result<(), BlockOmmerError> {
+        if ommers.is_empty() {
+            self.ommers = ommers;
+            return Ok(());
+        }
+
+        if self.cfg.handler_cfg.spec_id >= SpecId::MERGE {
+            return Err(BlockOmmerError::PostMerge);
+        }
+
+        let block_number = self.header.number;
+        if let Some(ommer) = ommers.iter().find(|ommer| {
+            ommer.number >= block_number || ommer.number + MAX_OMMER_DEPTH < block_number
+        }) {
+            return Err(BlockOmmerError::InvalidNumber {
+                ommer_number: ommer.number,
+                block_number,
+            });
+        }
+
+        self.ommers = ommers;
+        Ok(())
+    }
+
+    /// Retrieves the ommers of the block.
+    pub fn ommers(&self) -> &[Header] {
+        &self.ommers
+    }
+
     /// Retrieves the config of the block builder.
     pub fn config(&self) -> &CfgEnvWithHandlerCfg {
         &self.cfg
@@ -208,6 +294,20 @@ impl BlockBuilder {
         &self.header
     }
 
+    /// Computes the reward of the block's beneficiary for the builder's
+    /// hardfork, including the inclusion reward for the provided number of
+    /// ommers. The result can be passed to [`BlockBuilder::finalize`].
+    ///
+    /// Post-merge blocks don't have a block reward, so no rewards are
+    /// returned.
+    pub fn reward_beneficiary(&self, num_ommers: u64) -> Vec<(Address, U256)> {
+        miner_reward(self.cfg.handler_cfg.spec_id).map_or_else(Vec::new, |reward| {
+            let ommer_inclusion_reward = reward / U256::from(32) * U256::from(num_ommers);
+
+            vec![(self.header.beneficiary, reward + ommer_inclusion_reward)]
+        })
+    }
+
     /// Adds a pending transaction to
     #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
     pub fn add_transaction<'blockchain, 'evm, BlockchainErrorT, DebugDataT, StateT, StateErrorT>(
@@ -223,9 +323,19 @@ impl BlockBuilder {
         StateT: StateRef<Error = StateErrorT> + DatabaseCommit + StateDebug<Error = StateErrorT>,
         StateErrorT: Debug + Send,
     {
+        if !transaction.is_executable() {
+            return ExecutionResultWithContext {
+                result: Err(BlockTransactionError::Eip7702Unsupported),
+                evm_context: EvmContext {
+                    debug: debug_context,
+                    state,
+                },
+            };
+        }
+
         //  transaction's gas limit cannot be greater than the remaining gas in the
         // block
-        if transaction.gas_limit() > self.gas_remaining() {
+        if transaction.gas_limit() > self.gas_remaining() && !self.allow_exceeding_block_gas_limit {
             return ExecutionResultWithContext {
                 result: Err(BlockTransactionError::ExceedsBlockGasLimit),
                 evm_context: EvmContext {
@@ -269,12 +379,16 @@ impl BlockBuilder {
                 .map(|BlobGas { excess_gas, .. }| BlobExcessGasAndPrice::new(*excess_gas)),
         };
 
-        let env = EnvWithHandlerCfg::new_with_cfg_env(
+        let mut env = EnvWithHandlerCfg::new_with_cfg_env(
             self.cfg.clone(),
             block.clone(),
             transaction.clone().into(),
         );
 
+        if self.allow_exceeding_block_gas_limit {
+            env.tx.gas_limit = env.tx.gas_limit.min(self.header.gas_limit);
+        }
+
         let db = DatabaseComponents {
             state,
             block_hash: blockchain,
@@ -361,7 +475,10 @@ impl BlockBuilder {
 
         state.commit(state_diff);
 
-        self.header.gas_used += result.gas_used();
+        // The gas used can only exceed the block gas limit if that's explicitly
+        // allowed, in which case it's capped.
+        self.header.gas_used =
+            (self.header.gas_used + result.gas_used()).min(self.header.gas_limit);
 
         if let Some(BlobGas { gas_used, .. }) = self.header.blob_gas.as_mut() {
             *gas_used += blob_gas;
@@ -387,16 +504,10 @@ impl BlockBuilder {
             None
         };
 
-        let gas_price = transaction.gas_price();
         let effective_gas_price = if spec_id >= SpecId::LONDON {
-            if let SignedTransaction::Eip1559(transaction) = &*transaction {
-                block.basefee
-                    + (gas_price - block.basefee).min(transaction.max_priority_fee_per_gas)
-            } else {
-                gas_price
-            }
+            transaction.effective_gas_price(block.basefee)
         } else {
-            gas_price
+            transaction.gas_price()
         };
 
         let receipt = TransactionReceipt {
@@ -420,6 +531,7 @@ impl BlockBuilder {
                     SignedTransaction::Eip2930(_) => TypedReceiptData::Eip2930 { status },
                     SignedTransaction::Eip1559(_) => TypedReceiptData::Eip1559 { status },
                     SignedTransaction::Eip4844(_) => TypedReceiptData::Eip4844 { status },
+                    SignedTransaction::Eip7702(_) => TypedReceiptData::Eip7702 { status },
                 },
                 spec_id,
             },
@@ -447,12 +559,23 @@ impl BlockBuilder {
     pub fn finalize<StateT, StateErrorT>(
         mut self,
         state: &mut StateT,
-        rewards: Vec<(Address, U256)>,
+        mut rewards: Vec<(Address, U256)>,
     ) -> Result<BuildBlockResult, StateErrorT>
     where
         StateT: SyncState<StateErrorT> + ?Sized,
         StateErrorT: Debug + Send,
     {
+        if let Some(reward) = miner_reward(self.cfg.handler_cfg.spec_id) {
+            for ommer in &self.ommers {
+                // The ommer's beneficiary receives (8 + ommer number - block number) / 8 of
+                // the block reward and the block's beneficiary receives 1/32 of the block
+                // reward, for including the ommer.
+                let generations = U256::from(8 + ommer.number - self.header.number);
+                rewards.push((ommer.beneficiary, reward * generations / U256::from(8)));
+                rewards.push((self.header.beneficiary, reward / U256::from(32)));
+            }
+        }
+
         for (address, reward) in rewards {
             if reward > U256::ZERO {
                 let account_info = state.modify_account(
@@ -466,6 +589,22 @@ impl BlockBuilder {
             }
         }
 
+        for withdrawal in self.withdrawals.iter().flatten() {
+            // Withdrawal amounts are denominated in Gwei
+            let amount = withdrawal.amount * U256::from(GWEI_TO_WEI);
+            if amount > U256::ZERO {
+                let account_info = state.modify_account(
+                    withdrawal.address,
+                    AccountModifierFn::new(Box::new(move |balance, _nonce, _code| {
+                        *balance += amount;
+                    })),
+                )?;
+
+                self.state_diff
+                    .apply_account_change(withdrawal.address, account_info);
+            }
+        }
+
         if let Some(gas_limit) = self.parent_gas_limit {
             self.header.gas_limit = gas_limit;
         }
@@ -493,18 +632,14 @@ impl BlockBuilder {
 
         // Only set the timestamp if it wasn't specified during construction
         if self.header.timestamp == 0 {
-            self.header.timestamp = SystemTime::now()
-                .duration_since(UNIX_EPOCH)
-                .expect("Current time must be after unix epoch")
-                .as_secs();
+            self.header.timestamp = self.time_source.unix_timestamp();
         }
 
-        // TODO: handle ommers
         let block = LocalBlock::new(
             self.header,
             self.transactions,
             self.receipts,
-            Vec::new(),
+            self.ommers,
             self.withdrawals,
         );
 
@@ -582,4 +717,631 @@ mod tests {
             Err(BlockBuilderCreationError::DaoHardforkInvalidData)
         ));
     }
+
+    fn reward_beneficiary_test(spec_id: SpecId, num_ommers: u64) -> Vec<(Address, U256)> {
+        use edr_eth::block::BlockOptions;
+
+        use super::*;
+
+        let beneficiary = Address::random();
+        let cfg = CfgEnvWithHandlerCfg::new_with_spec_id(CfgEnv::default(), spec_id);
+        let block_builder = BlockBuilder::new(
+            cfg,
+            &Header::default(),
+            BlockOptions {
+                beneficiary: Some(beneficiary),
+                ..BlockOptions::default()
+            },
+            None,
+        )
+        .expect("Should construct block builder");
+
+        let rewards = block_builder.reward_beneficiary(num_ommers);
+        assert!(rewards.iter().all(|(address, _)| *address == beneficiary));
+
+        rewards
+    }
+
+    #[test]
+    fn reward_beneficiary_byzantium() {
+        use super::*;
+
+        let rewards = reward_beneficiary_test(SpecId::BYZANTIUM, 0);
+        assert_eq!(rewards.len(), 1);
+        assert_eq!(rewards[0].1, U256::from(3_000_000_000_000_000_000u128));
+    }
+
+    #[test]
+    fn reward_beneficiary_constantinople_with_ommers() {
+        use super::*;
+
+        let rewards = reward_beneficiary_test(SpecId::CONSTANTINOPLE, 2);
+        assert_eq!(rewards.len(), 1);
+        // 2 ETH + 2 * (2 ETH / 32)
+        assert_eq!(rewards[0].1, U256::from(2_125_000_000_000_000_000u128));
+    }
+
+    #[test]
+    fn reward_beneficiary_post_merge() {
+        use super::*;
+
+        let rewards = reward_beneficiary_test(SpecId::MERGE, 0);
+        assert!(rewards.is_empty());
+    }
+
+    #[test]
+    fn add_transaction_exceeding_block_gas_limit() -> anyhow::Result<()> {
+        use edr_eth::block::BlockOptions;
+
+        use super::*;
+        use crate::{
+            blockchain::{Blockchain, BlockchainError, GenesisBlockOptions, LocalBlockchain},
+            state::{StateDiff, StateError, TrieState},
+            test_utils::dummy_eip155_transaction_with_limit,
+            Block,
+        };
+
+        const BLOCK_GAS_LIMIT: u64 = 30_000;
+        const TRANSACTION_GAS_LIMIT: u64 = 25_000;
+        // Matches the chain ID of dummy transactions
+        const CHAIN_ID: u64 = 123;
+
+        let blockchain = LocalBlockchain::new(
+            StateDiff::default(),
+            CHAIN_ID,
+            SpecId::BERLIN,
+            GenesisBlockOptions::default(),
+        )?;
+        let mut state = TrieState::default();
+
+        let mut cfg = CfgEnv::default();
+        cfg.chain_id = CHAIN_ID;
+        let cfg = CfgEnvWithHandlerCfg::new_with_spec_id(cfg, SpecId::BERLIN);
+
+        let mut builder = BlockBuilder::new(
+            cfg,
+            blockchain.last_block()?.header(),
+            BlockOptions {
+                gas_limit: Some(BLOCK_GAS_LIMIT),
+                ..BlockOptions::default()
+            },
+            None,
+        )?;
+
+        let caller = Address::random();
+        let ExecutionResultWithContext { result, .. } = builder
+            .add_transaction::<BlockchainError, (), &mut TrieState, StateError>(
+                &blockchain,
+                &mut state,
+                dummy_eip155_transaction_with_limit(caller, 0, TRANSACTION_GAS_LIMIT)?,
+                None,
+            );
+        assert!(matches!(result, Ok(ExecutionResult::Success { .. })));
+        assert_eq!(builder.gas_used(), 21_000);
+
+        // The transaction's gas limit exceeds the remaining gas
+        let transaction = dummy_eip155_transaction_with_limit(caller, 1, TRANSACTION_GAS_LIMIT)?;
+        let ExecutionResultWithContext { result, .. } = builder
+            .add_transaction::<BlockchainError, (), &mut TrieState, StateError>(
+                &blockchain,
+                &mut state,
+                transaction.clone(),
+                None,
+            );
+        assert!(matches!(
+            result,
+            Err(BlockTransactionError::ExceedsBlockGasLimit)
+        ));
+
+        builder.set_allow_exceeding_block_gas_limit(true);
+
+        let ExecutionResultWithContext { result, .. } = builder
+            .add_transaction::<BlockchainError, (), &mut TrieState, StateError>(
+                &blockchain,
+                &mut state,
+                transaction,
+                None,
+            );
+        assert!(matches!(result, Ok(ExecutionResult::Success { .. })));
+
+        // The gas used is capped at the block gas limit
+        assert_eq!(builder.gas_used(), BLOCK_GAS_LIMIT);
+
+        Ok(())
+    }
+
+    #[test]
+    fn add_eip7702_transaction_is_unsupported() -> anyhow::Result<()> {
+        use edr_eth::{block::BlockOptions, transaction::Eip7702TransactionRequest, B256};
+
+        use super::*;
+        use crate::{
+            blockchain::{Blockchain, BlockchainError, GenesisBlockOptions, LocalBlockchain},
+            state::{StateDiff, StateError, TrieState},
+        };
+
+        let blockchain = LocalBlockchain::new(
+            StateDiff::default(),
+            1,
+            SpecId::CANCUN,
+            GenesisBlockOptions {
+                mix_hash: Some(B256::ZERO),
+                ..GenesisBlockOptions::default()
+            },
+        )?;
+        let mut state = TrieState::default();
+
+        let cfg = CfgEnvWithHandlerCfg::new_with_spec_id(CfgEnv::default(), SpecId::CANCUN);
+        let mut builder = BlockBuilder::new(
+            cfg,
+            blockchain.last_block()?.header(),
+            BlockOptions::default(),
+            None,
+        )?;
+
+        let caller = Address::random();
+        let request = Eip7702TransactionRequest {
+            chain_id: 1,
+            nonce: 0,
+            max_priority_fee_per_gas: U256::ZERO,
+            max_fee_per_gas: U256::from(1_000_000_000u64),
+            gas_limit: 30_000,
+            to: Address::random(),
+            value: U256::ZERO,
+            input: Bytes::new(),
+            access_list: Vec::new(),
+            authorization_list: Vec::new(),
+        };
+        let transaction = ExecutableTransaction::with_caller(
+            SpecId::CANCUN,
+            request.fake_sign(&caller).into(),
+            caller,
+        )?;
+
+        let ExecutionResultWithContext { result, .. } = builder
+            .add_transaction::<BlockchainError, (), &mut TrieState, StateError>(
+                &blockchain,
+                &mut state,
+                transaction,
+                None,
+            );
+        assert!(matches!(
+            result,
+            Err(BlockTransactionError::Eip7702Unsupported)
+        ));
+        assert_eq!(builder.gas_used(), 0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn add_eip1559_transaction_tips_miner_priority_fee() -> anyhow::Result<()> {
+        use edr_eth::{block::BlockOptions, AccountInfo, HashMap};
+
+        use super::*;
+        use crate::{
+            blockchain::{Blockchain, BlockchainError, GenesisBlockOptions, LocalBlockchain},
+            state::{AccountTrie, StateDiff, StateError, TrieState},
+            test_utils::dummy_eip1559_transaction,
+            Block,
+        };
+
+        // Matches the chain ID of dummy transactions
+        const CHAIN_ID: u64 = 123;
+
+        let base_fee = U256::from(10_000_000_000u64);
+        let max_fee_per_gas = U256::from(100_000_000_000u64);
+        let max_priority_fee_per_gas = U256::from(1_000_000_000u64);
+
+        let blockchain = LocalBlockchain::new(
+            StateDiff::default(),
+            CHAIN_ID,
+            SpecId::LONDON,
+            GenesisBlockOptions::default(),
+        )?;
+
+        let caller = Address::random();
+        let accounts = [(
+            caller,
+            AccountInfo {
+                balance: U256::from(1_000_000_000_000_000_000u128),
+                ..AccountInfo::default()
+            },
+        )]
+        .into_iter()
+        .collect::<HashMap<_, _>>();
+        let mut state = TrieState::with_accounts(AccountTrie::with_accounts(&accounts));
+
+        let mut cfg = CfgEnv::default();
+        cfg.chain_id = CHAIN_ID;
+        let cfg = CfgEnvWithHandlerCfg::new_with_spec_id(cfg, SpecId::LONDON);
+
+        let beneficiary = Address::random();
+        let mut builder = BlockBuilder::new(
+            cfg,
+            blockchain.last_block()?.header(),
+            BlockOptions {
+                beneficiary: Some(beneficiary),
+                base_fee: Some(base_fee),
+                ..BlockOptions::default()
+            },
+            None,
+        )?;
+
+        let transaction =
+            dummy_eip1559_transaction(caller, 0, max_fee_per_gas, max_priority_fee_per_gas)?;
+        let ExecutionResultWithContext { result, .. } = builder
+            .add_transaction::<BlockchainError, (), &mut TrieState, StateError>(
+                &blockchain,
+                &mut state,
+                transaction,
+                None,
+            );
+        let gas_used = U256::from(result?.gas_used());
+
+        // The beneficiary only receives the priority fee; the base fee is burnt
+        let beneficiary_balance = state
+            .basic(beneficiary)?
+            .map_or(U256::ZERO, |account| account.balance);
+        assert_eq!(beneficiary_balance, gas_used * max_priority_fee_per_gas);
+
+        let BuildBlockResult { block, .. } = builder.finalize(&mut state, Vec::new())?;
+        let receipt = &block.transaction_receipts()[0];
+        assert_eq!(
+            receipt.effective_gas_price,
+            Some(base_fee + max_priority_fee_per_gas)
+        );
+        assert_eq!(receipt.transaction_type(), 2);
+
+        Ok(())
+    }
+
+    #[test]
+    fn add_legacy_transaction_receipt_uses_gas_price() -> anyhow::Result<()> {
+        use edr_eth::{block::BlockOptions, AccountInfo, HashMap};
+
+        use super::*;
+        use crate::{
+            blockchain::{Blockchain, BlockchainError, GenesisBlockOptions, LocalBlockchain},
+            state::{AccountTrie, StateDiff, StateError, TrieState},
+            test_utils::dummy_eip155_transaction_with_price,
+            Block,
+        };
+
+        // Matches the chain ID of dummy transactions
+        const CHAIN_ID: u64 = 123;
+
+        let base_fee = U256::from(10_000_000_000u64);
+        let gas_price = U256::from(25_000_000_000u64);
+
+        let blockchain = LocalBlockchain::new(
+            StateDiff::default(),
+            CHAIN_ID,
+            SpecId::LONDON,
+            GenesisBlockOptions::default(),
+        )?;
+
+        let caller = Address::random();
+        let accounts = [(
+            caller,
+            AccountInfo {
+                balance: U256::from(1_000_000_000_000_000_000u128),
+                ..AccountInfo::default()
+            },
+        )]
+        .into_iter()
+        .collect::<HashMap<_, _>>();
+        let mut state = TrieState::with_accounts(AccountTrie::with_accounts(&accounts));
+
+        let mut cfg = CfgEnv::default();
+        cfg.chain_id = CHAIN_ID;
+        let cfg = CfgEnvWithHandlerCfg::new_with_spec_id(cfg, SpecId::LONDON);
+
+        let mut builder = BlockBuilder::new(
+            cfg,
+            blockchain.last_block()?.header(),
+            BlockOptions {
+                base_fee: Some(base_fee),
+                ..BlockOptions::default()
+            },
+            None,
+        )?;
+
+        let transaction = dummy_eip155_transaction_with_price(caller, 0, gas_price)?;
+        let ExecutionResultWithContext { result, .. } = builder
+            .add_transaction::<BlockchainError, (), &mut TrieState, StateError>(
+                &blockchain,
+                &mut state,
+                transaction,
+                None,
+            );
+        assert!(result?.is_success());
+
+        let BuildBlockResult { block, .. } = builder.finalize(&mut state, Vec::new())?;
+        let receipt = &block.transaction_receipts()[0];
+
+        // Legacy transactions pay their gas price, irrespective of the base fee
+        assert_eq!(receipt.effective_gas_price, Some(gas_price));
+        assert_eq!(receipt.transaction_type(), 0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn finalize_uses_time_source() -> anyhow::Result<()> {
+        use edr_eth::block::BlockOptions;
+
+        use super::*;
+        use crate::{
+            state::{StateError, TrieState},
+            time::ManualTimeSource,
+            Block,
+        };
+
+        const TIMESTAMP: u64 = 1_700_000_000;
+
+        let cfg = CfgEnvWithHandlerCfg::new_with_spec_id(CfgEnv::default(), SpecId::BERLIN);
+        let mut builder =
+            BlockBuilder::new(cfg, &Header::default(), BlockOptions::default(), None)?;
+
+        let time_source = Arc::new(ManualTimeSource::new(TIMESTAMP));
+        builder.set_time_source(time_source.clone());
+        time_source.advance(3600);
+
+        let mut state = TrieState::default();
+        let BuildBlockResult { block, .. } =
+            builder.finalize::<_, StateError>(&mut state, Vec::new())?;
+        assert_eq!(block.header().timestamp, TIMESTAMP + 3600);
+
+        Ok(())
+    }
+
+    fn ommer_test_builder(spec_id: SpecId, beneficiary: Address) -> super::BlockBuilder {
+        use edr_eth::block::{BlockOptions, Header};
+
+        use super::*;
+
+        let cfg = CfgEnvWithHandlerCfg::new_with_spec_id(CfgEnv::default(), spec_id);
+        let parent = Header {
+            number: 9,
+            base_fee_per_gas: Some(U256::from(1_000_000_000u64)),
+            ..Header::default()
+        };
+
+        BlockBuilder::new(
+            cfg,
+            &parent,
+            BlockOptions {
+                beneficiary: Some(beneficiary),
+                ..BlockOptions::default()
+            },
+            None,
+        )
+        .expect("Should construct block builder")
+    }
+
+    #[test]
+    fn finalize_with_ommers_pays_rewards() -> anyhow::Result<()> {
+        use edr_eth::block::Header;
+        use revm::primitives::keccak256;
+
+        use super::*;
+        use crate::{
+            state::{StateError, TrieState},
+            Block,
+        };
+
+        let beneficiary = Address::random();
+        let mut builder = ommer_test_builder(SpecId::BYZANTIUM, beneficiary);
+
+        let ommers = vec![
+            Header {
+                number: 9,
+                beneficiary: Address::random(),
+                ..Header::default()
+            },
+            Header {
+                number: 4,
+                beneficiary: Address::random(),
+                ..Header::default()
+            },
+        ];
+        builder.set_ommers(ommers.clone())?;
+
+        let rewards = builder.reward_beneficiary(0);
+
+        let mut state = TrieState::default();
+        let BuildBlockResult { block, .. } =
+            builder.finalize::<_, StateError>(&mut state, rewards)?;
+
+        assert_eq!(
+            block.header().ommers_hash,
+            keccak256(alloy_rlp::encode(&ommers))
+        );
+        assert_eq!(
+            block.ommer_hashes(),
+            ommers.iter().map(Header::hash).collect::<Vec<_>>()
+        );
+
+        let balance = |address| {
+            state
+                .basic(address)
+                .map(|account| account.map_or(U256::ZERO, |account| account.balance))
+        };
+
+        // 3 ETH + 2 * (3 ETH / 32)
+        assert_eq!(
+            balance(beneficiary)?,
+            U256::from(3_187_500_000_000_000_000u128)
+        );
+        // (8 + 9 - 10) / 8 * 3 ETH
+        assert_eq!(
+            balance(ommers[0].beneficiary)?,
+            U256::from(2_625_000_000_000_000_000u128)
+        );
+        // (8 + 4 - 10) / 8 * 3 ETH
+        assert_eq!(
+            balance(ommers[1].beneficiary)?,
+            U256::from(750_000_000_000_000_000u128)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn set_ommers_rejects_invalid_numbers() {
+        use edr_eth::block::Header;
+
+        use super::*;
+
+        let mut builder = ommer_test_builder(SpecId::BYZANTIUM, Address::random());
+
+        for ommer_number in [3, 10] {
+            let result = builder.set_ommers(vec![Header {
+                number: ommer_number,
+                ..Header::default()
+            }]);
+
+            assert!(matches!(
+                result,
+                Err(BlockOmmerError::InvalidNumber {
+                    ommer_number: number,
+                    block_number: 10,
+                }) if number == ommer_number
+            ));
+        }
+    }
+
+    #[test]
+    fn post_merge_block_has_no_ommers() -> anyhow::Result<()> {
+        use edr_eth::{block::Header, trie::KECCAK_RLP_EMPTY_ARRAY};
+
+        use super::*;
+        use crate::{
+            state::{StateError, TrieState},
+            Block,
+        };
+
+        let mut builder = ommer_test_builder(SpecId::MERGE, Address::random());
+
+        let result = builder.set_ommers(vec![Header {
+            number: 9,
+            ..Header::default()
+        }]);
+        assert!(matches!(result, Err(BlockOmmerError::PostMerge)));
+
+        let mut state = TrieState::default();
+        let BuildBlockResult { block, .. } =
+            builder.finalize::<_, StateError>(&mut state, Vec::new())?;
+
+        assert!(block.ommer_hashes().is_empty());
+        assert_eq!(block.header().ommers_hash, KECCAK_RLP_EMPTY_ARRAY);
+
+        Ok(())
+    }
+
+    fn withdrawals_test_builder(spec_id: SpecId, withdrawals: Vec<Withdrawal>) -> BlockBuilder {
+        use edr_eth::block::BlockOptions;
+
+        use super::*;
+
+        let cfg = CfgEnvWithHandlerCfg::new_with_spec_id(CfgEnv::default(), spec_id);
+        let parent = Header {
+            base_fee_per_gas: Some(U256::from(1_000_000_000u64)),
+            ..Header::default()
+        };
+
+        BlockBuilder::new(
+            cfg,
+            &parent,
+            BlockOptions {
+                withdrawals: Some(withdrawals),
+                ..BlockOptions::default()
+            },
+            None,
+        )
+        .expect("Should construct block builder")
+    }
+
+    #[test]
+    fn finalize_credits_withdrawals() -> anyhow::Result<()> {
+        use edr_eth::withdrawal::withdrawals_root;
+
+        use super::*;
+        use crate::{
+            state::{StateError, TrieState},
+            Block,
+        };
+
+        let withdrawals = vec![
+            Withdrawal {
+                index: 0,
+                validator_index: 1,
+                address: Address::random(),
+                amount: U256::from(32_000_000_000u64),
+            },
+            Withdrawal {
+                index: 1,
+                validator_index: 2,
+                address: Address::random(),
+                amount: U256::from(1_500_000u64),
+            },
+        ];
+
+        let builder = withdrawals_test_builder(SpecId::SHANGHAI, withdrawals.clone());
+
+        let mut state = TrieState::default();
+        let BuildBlockResult { block, state_diff } =
+            builder.finalize::<_, StateError>(&mut state, Vec::new())?;
+
+        // Amounts are converted from Gwei to Wei
+        let expected_balances = [
+            U256::from(32_000_000_000_000_000_000u128),
+            U256::from(1_500_000_000_000_000u128),
+        ];
+        for (withdrawal, expected_balance) in withdrawals.iter().zip(expected_balances) {
+            let balance = state
+                .basic(withdrawal.address)?
+                .map_or(U256::ZERO, |account| account.balance);
+            assert_eq!(balance, expected_balance);
+
+            assert!(state_diff.as_inner().contains_key(&withdrawal.address));
+        }
+
+        assert_eq!(block.withdrawals(), Some(withdrawals.as_slice()));
+        assert_eq!(
+            block.header().withdrawals_root,
+            Some(withdrawals_root(&withdrawals))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn pre_shanghai_block_has_no_withdrawals() -> anyhow::Result<()> {
+        use super::*;
+        use crate::{
+            state::{StateError, TrieState},
+            Block,
+        };
+
+        let withdrawal = Withdrawal {
+            index: 0,
+            validator_index: 1,
+            address: Address::random(),
+            amount: U256::from(1_000_000_000u64),
+        };
+
+        let builder = withdrawals_test_builder(SpecId::MERGE, vec![withdrawal.clone()]);
+
+        let mut state = TrieState::default();
+        let BuildBlockResult { block, .. } =
+            builder.finalize::<_, StateError>(&mut state, Vec::new())?;
+
+        assert!(block.withdrawals().is_none());
+        assert!(block.header().withdrawals_root.is_none());
+        assert!(state.basic(withdrawal.address)?.is_none());
+
+        Ok(())
+    }
 }
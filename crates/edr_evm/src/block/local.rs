@@ -6,7 +6,7 @@ use edr_eth::{
     log::{FilterLog, FullBlockLog, Log, ReceiptLog},
     receipt::{BlockReceipt, TransactionReceipt, TypedReceipt},
     trie,
-    withdrawal::Withdrawal,
+    withdrawal::{withdrawals_root, Withdrawal},
     B256,
 };
 use itertools::izip;
@@ -63,9 +63,7 @@ impl LocalBlock {
         let ommers_hash = keccak256(alloy_rlp::encode(&ommers));
         let transactions_root = trie::ordered_trie_root(transactions.iter().map(alloy_rlp::encode));
 
-        let withdrawals_root = withdrawals
-            .as_ref()
-            .map(|w| trie::ordered_trie_root(w.iter().map(alloy_rlp::encode)));
+        let withdrawals_root = withdrawals.as_deref().map(withdrawals_root);
 
         let header = Header::new(
             partial_header,
@@ -204,3 +202,81 @@ impl From<LocalBlock> for Arc<dyn SyncBlock<Error = BlockchainError>> {
         Arc::new(value)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use edr_eth::{receipt::TypedReceiptData, Address, Bloom, Bytes};
+
+    use super::*;
+
+    fn dummy_receipt(
+        cumulative_gas_used: u64,
+        gas_used: u64,
+        num_logs: usize,
+    ) -> TransactionReceipt<Log> {
+        let logs = (0..num_logs)
+            .map(|index| {
+                Log::new_unchecked(
+                    Address::random(),
+                    vec![B256::with_last_byte(index as u8)],
+                    Bytes::new(),
+                )
+            })
+            .collect();
+
+        TransactionReceipt {
+            inner: TypedReceipt {
+                cumulative_gas_used,
+                logs_bloom: Bloom::ZERO,
+                logs,
+                data: TypedReceiptData::Eip1559 { status: 1 },
+                spec_id: SpecId::LATEST,
+            },
+            transaction_hash: B256::random(),
+            transaction_index: 0,
+            from: Address::random(),
+            to: Some(Address::random()),
+            contract_address: None,
+            gas_used,
+            effective_gas_price: None,
+        }
+    }
+
+    #[test]
+    fn block_receipts_offset_log_indices() {
+        const BLOCK_NUMBER: u64 = 10;
+
+        let block_hash = B256::random();
+        let receipts = vec![
+            dummy_receipt(30_000, 30_000, 2),
+            dummy_receipt(51_000, 21_000, 0),
+            dummy_receipt(80_000, 29_000, 1),
+        ];
+
+        let block_receipts = transaction_to_block_receipts(&block_hash, BLOCK_NUMBER, receipts);
+        assert_eq!(block_receipts.len(), 3);
+
+        let cumulative_gas_used: Vec<u64> = block_receipts
+            .iter()
+            .map(|receipt| receipt.cumulative_gas_used)
+            .collect();
+        assert_eq!(cumulative_gas_used, vec![30_000, 51_000, 80_000]);
+
+        let log_indices: Vec<(u64, u64)> = block_receipts
+            .iter()
+            .flat_map(|receipt| {
+                receipt
+                    .logs
+                    .iter()
+                    .map(|log| (log.transaction_index, log.log_index))
+            })
+            .collect();
+        assert_eq!(log_indices, vec![(0, 0), (0, 1), (2, 2)]);
+
+        for (transaction_index, receipt) in block_receipts.iter().enumerate() {
+            assert_eq!(receipt.transaction_index, transaction_index as u64);
+            assert_eq!(receipt.block_hash, block_hash);
+            assert_eq!(receipt.block_number, BLOCK_NUMBER);
+        }
+    }
+}
@@ -0,0 +1,71 @@
+use std::sync::Arc;
+
+use edr_eth::{block, receipt::BlockReceipt, withdrawal::Withdrawal, B256};
+
+use crate::{blockchain::BlockchainError, Block, ExecutableTransaction, SyncBlock};
+
+/// A locally mined block, of which the transaction receipts have been pruned.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PrunedBlock {
+    header: block::Header,
+    transactions: Vec<ExecutableTransaction>,
+    ommer_hashes: Vec<B256>,
+    withdrawals: Option<Vec<Withdrawal>>,
+    hash: B256,
+    rlp_size: u64,
+}
+
+impl PrunedBlock {
+    /// Constructs a new instance from the provided block, discarding its
+    /// transaction receipts.
+    pub fn new<BlockT: Block + ?Sized>(block: &BlockT) -> Self {
+        Self {
+            header: block.header().clone(),
+            transactions: block.transactions().to_vec(),
+            ommer_hashes: block.ommer_hashes().to_vec(),
+            withdrawals: block.withdrawals().map(<[Withdrawal]>::to_vec),
+            hash: *block.hash(),
+            rlp_size: block.rlp_size(),
+        }
+    }
+}
+
+impl Block for PrunedBlock {
+    type Error = BlockchainError;
+
+    fn hash(&self) -> &B256 {
+        &self.hash
+    }
+
+    fn header(&self) -> &block::Header {
+        &self.header
+    }
+
+    fn rlp_size(&self) -> u64 {
+        self.rlp_size
+    }
+
+    fn transactions(&self) -> &[ExecutableTransaction] {
+        &self.transactions
+    }
+
+    fn transaction_receipts(&self) -> Result<Vec<Arc<BlockReceipt>>, Self::Error> {
+        Err(BlockchainError::ReceiptsPruned {
+            block_number: self.header.number,
+        })
+    }
+
+    fn ommer_hashes(&self) -> &[B256] {
+        self.ommer_hashes.as_slice()
+    }
+
+    fn withdrawals(&self) -> Option<&[Withdrawal]> {
+        self.withdrawals.as_deref()
+    }
+}
+
+impl From<PrunedBlock> for Arc<dyn SyncBlock<Error = BlockchainError>> {
+    fn from(value: PrunedBlock) -> Self {
+        Arc::new(value)
+    }
+}
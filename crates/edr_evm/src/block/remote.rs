@@ -64,32 +64,7 @@ impl RemoteBlock {
         rpc_client: Arc<RpcClient>,
         runtime: runtime::Handle,
     ) -> Result<Self, CreationError> {
-        let header = Header {
-            parent_hash: block.parent_hash,
-            ommers_hash: block.sha3_uncles,
-            beneficiary: block.miner.ok_or(CreationError::MissingMiner)?,
-            state_root: block.state_root,
-            transactions_root: block.transactions_root,
-            receipts_root: block.receipts_root,
-            logs_bloom: block.logs_bloom,
-            difficulty: block.difficulty,
-            number: block.number.ok_or(CreationError::MissingNumber)?,
-            gas_limit: block.gas_limit,
-            gas_used: block.gas_used,
-            timestamp: block.timestamp,
-            extra_data: block.extra_data,
-            mix_hash: block.mix_hash.ok_or(CreationError::MissingMixHash)?,
-            nonce: block.nonce.ok_or(CreationError::MissingNonce)?,
-            base_fee_per_gas: block.base_fee_per_gas,
-            withdrawals_root: block.withdrawals_root,
-            blob_gas: block.blob_gas_used.and_then(|gas_used| {
-                block.excess_blob_gas.map(|excess_gas| BlobGas {
-                    gas_used,
-                    excess_gas,
-                })
-            }),
-            parent_beacon_block_root: block.parent_beacon_block_root,
-        };
+        let header = header_from_rpc_block(&block)?;
 
         let transactions = block
             .transactions
@@ -113,6 +88,38 @@ impl RemoteBlock {
     }
 }
 
+/// Constructs the header of the provided JSON-RPC block.
+pub(crate) fn header_from_rpc_block<TransactionT>(
+    block: &eth::Block<TransactionT>,
+) -> Result<Header, CreationError> {
+    Ok(Header {
+        parent_hash: block.parent_hash,
+        ommers_hash: block.sha3_uncles,
+        beneficiary: block.miner.ok_or(CreationError::MissingMiner)?,
+        state_root: block.state_root,
+        transactions_root: block.transactions_root,
+        receipts_root: block.receipts_root,
+        logs_bloom: block.logs_bloom,
+        difficulty: block.difficulty,
+        number: block.number.ok_or(CreationError::MissingNumber)?,
+        gas_limit: block.gas_limit,
+        gas_used: block.gas_used,
+        timestamp: block.timestamp,
+        extra_data: block.extra_data.clone(),
+        mix_hash: block.mix_hash.ok_or(CreationError::MissingMixHash)?,
+        nonce: block.nonce.ok_or(CreationError::MissingNonce)?,
+        base_fee_per_gas: block.base_fee_per_gas,
+        withdrawals_root: block.withdrawals_root,
+        blob_gas: block.blob_gas_used.and_then(|gas_used| {
+            block.excess_blob_gas.map(|excess_gas| BlobGas {
+                gas_used,
+                excess_gas,
+            })
+        }),
+        parent_beacon_block_root: block.parent_beacon_block_root,
+    })
+}
+
 impl Block for RemoteBlock {
     type Error = BlockchainError;
 
@@ -20,6 +20,7 @@ use self::storage::ReservableSparseBlockchainStorage;
 pub use self::{
     forked::{CreationError as ForkedCreationError, ForkedBlockchain, ForkedBlockchainError},
     local::{CreationError as LocalCreationError, GenesisBlockOptions, LocalBlockchain},
+    remote::HeaderCacheMetrics,
 };
 use crate::{
     state::{StateDiff, StateOverride, SyncState},
@@ -65,6 +66,12 @@ pub enum BlockchainError {
     /// Missing withdrawals for post-Shanghai blockchain
     #[error("Missing withdrawals for post-Shanghai blockchain")]
     MissingWithdrawals,
+    /// The receipts of the block have been pruned
+    #[error("Historical data pruned: receipts of block {block_number} are no longer available")]
+    ReceiptsPruned {
+        /// Block number
+        block_number: u64,
+    },
     /// Block number does not exist in blockchain
     #[error("Unknown block number")]
     UnknownBlockNumber,
@@ -182,6 +189,10 @@ pub trait BlockchainMut {
     /// Reverts to the block with the provided number, deleting all later
     /// blocks.
     fn revert_to_block(&mut self, block_number: u64) -> Result<(), Self::Error>;
+
+    /// Prunes the transaction receipts of all locally stored blocks up to and
+    /// including the provided block number.
+    fn prune_receipts(&mut self, block_number: u64) -> Result<(), Self::Error>;
 }
 
 /// Trait that meets all requirements for a synchronous blockchain.
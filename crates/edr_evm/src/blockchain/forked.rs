@@ -1,8 +1,13 @@
-use std::{collections::BTreeMap, num::NonZeroU64, str::FromStr, sync::Arc};
+use std::{
+    collections::BTreeMap,
+    num::{NonZeroU64, NonZeroUsize},
+    str::FromStr,
+    sync::Arc,
+};
 
 use edr_eth::{
     beacon::{BEACON_ROOTS_ADDRESS, BEACON_ROOTS_BYTECODE},
-    block::{largest_safe_block_number, safe_block_depth, LargestSafeBlockNumberArgs},
+    block::{largest_safe_block_number, safe_block_depth, LargestSafeBlockNumberArgs, ReorgConfig},
     log::FilterLog,
     receipt::BlockReceipt,
     remote::{client::ForkMetadata, BlockSpec, RpcClient, RpcClientError},
@@ -19,9 +24,11 @@ use revm::{
 use tokio::runtime;
 
 use super::{
-    compute_state_at_block, remote::RemoteBlockchain, storage,
-    storage::ReservableSparseBlockchainStorage, validate_next_block, Blockchain, BlockchainError,
-    BlockchainMut,
+    compute_state_at_block,
+    remote::{HeaderCacheMetrics, RemoteBlockchain},
+    storage,
+    storage::ReservableSparseBlockchainStorage,
+    validate_next_block, Blockchain, BlockchainError, BlockchainMut,
 };
 use crate::{
     state::{ForkState, IrregularState, StateDiff, StateError, StateOverride, SyncState},
@@ -118,6 +125,7 @@ impl ForkedBlockchain {
             recommended_fork_block_number(RecommendedForkBlockNumberArgs {
                 chain_id: remote_chain_id,
                 latest_block_number,
+                reorg_config: rpc_client.reorg_config(),
             });
 
         let fork_block_number = if let Some(fork_block_number) = fork_block_number {
@@ -227,6 +235,16 @@ impl ForkedBlockchain {
         })
     }
 
+    /// Retrieves the number of hits and misses of the remote header cache.
+    pub fn header_cache_metrics(&self) -> HeaderCacheMetrics {
+        self.remote.header_cache_metrics()
+    }
+
+    /// Sets the maximum number of cached remote headers.
+    pub fn set_header_cache_capacity(&mut self, capacity: NonZeroUsize) {
+        self.remote.set_header_cache_capacity(capacity);
+    }
+
     fn runtime(&self) -> &runtime::Handle {
         self.remote.runtime()
     }
@@ -390,6 +408,15 @@ impl Blockchain for ForkedBlockchain {
             .receipt_by_transaction_hash(transaction_hash)
         {
             Ok(Some(receipt))
+        } else if let Some(block) = self
+            .local_storage
+            .block_by_transaction_hash(transaction_hash)
+        {
+            // Receipts are inserted alongside their block, so they can only be missing if
+            // they were pruned.
+            Err(BlockchainError::ReceiptsPruned {
+                block_number: block.header().number,
+            })
         } else {
             Ok(tokio::task::block_in_place(move || {
                 self.runtime()
@@ -407,13 +434,15 @@ impl Blockchain for ForkedBlockchain {
         if block_number <= self.fork_block_number {
             tokio::task::block_in_place(move || {
                 self.runtime()
-                    .block_on(self.remote.block_by_number(block_number))
+                    .block_on(self.remote.header_by_number(block_number))
             })
             .map_err(BlockchainError::Forked)
-            .and_then(|block| {
+            .and_then(|header| {
+                let header = header.ok_or(BlockchainError::UnknownBlockNumber)?;
+
                 if let Some(hardfork_activations) = &self.hardfork_activations {
                     hardfork_activations
-                        .hardfork_at_block_number(block.header().number)
+                        .hardfork_at_block_number(header.number)
                         .ok_or(BlockchainError::UnknownBlockSpec {
                             block_number,
                             hardfork_activations: hardfork_activations.clone(),
@@ -575,22 +604,32 @@ impl BlockchainMut for ForkedBlockchain {
             }
         }
     }
+
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+    fn prune_receipts(&mut self, block_number: u64) -> Result<(), Self::Error> {
+        self.local_storage.prune_receipts(block_number);
+
+        Ok(())
+    }
 }
 
 /// Arguments for the `recommended_fork_block_number` function.
 /// The purpose of this struct is to prevent mixing up the `u64` arguments.
-struct RecommendedForkBlockNumberArgs {
+struct RecommendedForkBlockNumberArgs<'a> {
     /// The chain id
     pub chain_id: u64,
     /// The latest known block number
     pub latest_block_number: u64,
+    /// Optional overrides of the safe block depth per chain id
+    pub reorg_config: Option<&'a ReorgConfig>,
 }
 
-impl<'a> From<&'a RecommendedForkBlockNumberArgs> for LargestSafeBlockNumberArgs {
-    fn from(value: &'a RecommendedForkBlockNumberArgs) -> Self {
+impl<'a> From<&RecommendedForkBlockNumberArgs<'a>> for LargestSafeBlockNumberArgs<'a> {
+    fn from(value: &RecommendedForkBlockNumberArgs<'a>) -> Self {
         Self {
             chain_id: value.chain_id,
             latest_block_number: value.latest_block_number,
+            reorg_config: value.reorg_config,
         }
     }
 }
@@ -604,7 +643,7 @@ impl<'a> From<&'a RecommendedForkBlockNumberArgs> for LargestSafeBlockNumberArgs
 /// This decision is based on the assumption that a forked blockchain with a
 /// `safe_block_depth` larger than the `latest_block_number` has a high
 /// probability of being a devnet.
-fn recommended_fork_block_number(args: RecommendedForkBlockNumberArgs) -> u64 {
+fn recommended_fork_block_number(args: RecommendedForkBlockNumberArgs<'_>) -> u64 {
     largest_safe_block_number(LargestSafeBlockNumberArgs::from(&args))
         .unwrap_or(args.latest_block_number)
 }
@@ -623,6 +662,7 @@ mod tests {
         let args = RecommendedForkBlockNumberArgs {
             chain_id: ROPSTEN_CHAIN_ID,
             latest_block_number: LATEST_BLOCK_NUMBER,
+            reorg_config: None,
         };
         assert_eq!(
             recommended_fork_block_number(args),
@@ -637,7 +677,28 @@ mod tests {
         let args = RecommendedForkBlockNumberArgs {
             chain_id: ROPSTEN_CHAIN_ID,
             latest_block_number: LATEST_BLOCK_NUMBER,
+            reorg_config: None,
         };
         assert_eq!(recommended_fork_block_number(args), LATEST_BLOCK_NUMBER);
     }
+
+    #[test]
+    fn recommended_fork_block_number_with_reorg_config_override() {
+        const LATEST_BLOCK_NUMBER: u64 = 1_000;
+        const CUSTOM_SAFE_BLOCK_DEPTH: u64 = 500;
+
+        let reorg_config: ReorgConfig = [(ROPSTEN_CHAIN_ID, CUSTOM_SAFE_BLOCK_DEPTH)]
+            .into_iter()
+            .collect();
+
+        let args = RecommendedForkBlockNumberArgs {
+            chain_id: ROPSTEN_CHAIN_ID,
+            latest_block_number: LATEST_BLOCK_NUMBER,
+            reorg_config: Some(&reorg_config),
+        };
+        assert_eq!(
+            recommended_fork_block_number(args),
+            LATEST_BLOCK_NUMBER - CUSTOM_SAFE_BLOCK_DEPTH
+        );
+    }
 }
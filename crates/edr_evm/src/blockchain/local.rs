@@ -11,6 +11,7 @@ use edr_eth::{
     beacon::{BEACON_ROOTS_ADDRESS, BEACON_ROOTS_BYTECODE},
     block::{BlobGas, BlockOptions, PartialHeader},
     log::FilterLog,
+    spec::HardforkActivations,
     AccountInfo, Address, Bytes, B256, U256,
 };
 use revm::{
@@ -79,6 +80,7 @@ pub struct LocalBlockchain {
     storage: ReservableSparseBlockchainStorage<Arc<dyn SyncBlock<Error = BlockchainError>>>,
     chain_id: u64,
     spec_id: SpecId,
+    hardfork_activations: Option<HardforkActivations>,
 }
 
 impl LocalBlockchain {
@@ -200,8 +202,25 @@ impl LocalBlockchain {
             storage,
             chain_id,
             spec_id,
+            hardfork_activations: None,
         }
     }
+
+    /// Sets the schedule of hardfork activations. Blocks that precede the
+    /// first activation use the blockchain's [`SpecId`].
+    pub fn with_hardfork_activations(mut self, hardfork_activations: HardforkActivations) -> Self {
+        self.hardfork_activations = Some(hardfork_activations);
+        self
+    }
+
+    /// Retrieves the hardfork specification that is active at the provided
+    /// block number, without checking whether the block exists.
+    fn spec_for_block_number(&self, block_number: u64) -> SpecId {
+        self.hardfork_activations
+            .as_ref()
+            .and_then(|activations| activations.hardfork_at_block_number(block_number))
+            .unwrap_or(self.spec_id)
+    }
 }
 
 impl Blockchain for LocalBlockchain {
@@ -277,7 +296,17 @@ impl Blockchain for LocalBlockchain {
         &self,
         transaction_hash: &B256,
     ) -> Result<Option<Arc<edr_eth::receipt::BlockReceipt>>, Self::BlockchainError> {
-        Ok(self.storage.receipt_by_transaction_hash(transaction_hash))
+        if let Some(receipt) = self.storage.receipt_by_transaction_hash(transaction_hash) {
+            Ok(Some(receipt))
+        } else if let Some(block) = self.storage.block_by_transaction_hash(transaction_hash) {
+            // Receipts are inserted alongside their block, so they can only be missing if
+            // they were pruned.
+            Err(BlockchainError::ReceiptsPruned {
+                block_number: block.header().number,
+            })
+        } else {
+            Ok(None)
+        }
     }
 
     #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
@@ -286,11 +315,11 @@ impl Blockchain for LocalBlockchain {
             return Err(BlockchainError::UnknownBlockNumber);
         }
 
-        Ok(self.spec_id)
+        Ok(self.spec_for_block_number(block_number))
     }
 
     fn spec_id(&self) -> SpecId {
-        self.spec_id
+        self.spec_for_block_number(self.last_block_number() + 1)
     }
 
     #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
@@ -326,7 +355,8 @@ impl BlockchainMut for LocalBlockchain {
     ) -> Result<BlockAndTotalDifficulty<Self::Error>, Self::Error> {
         let last_block = self.last_block()?;
 
-        validate_next_block(self.spec_id, &last_block, &block)?;
+        let spec_id = self.spec_for_block_number(block.header().number);
+        validate_next_block(spec_id, &last_block, &block)?;
 
         let previous_total_difficulty = self
             .total_difficulty_by_hash(last_block.hash())
@@ -347,27 +377,38 @@ impl BlockchainMut for LocalBlockchain {
 
     #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
     fn reserve_blocks(&mut self, additional: u64, interval: u64) -> Result<(), Self::Error> {
-        let additional = if let Some(additional) = NonZeroU64::new(additional) {
-            additional
-        } else {
-            return Ok(()); // nothing to do
-        };
-
-        let last_block = self.last_block()?;
-        let previous_total_difficulty = self
-            .total_difficulty_by_hash(last_block.hash())?
-            .expect("Must exist as its block is stored");
-
-        let last_header = last_block.header();
+        let mut remaining = additional;
+
+        // Reservations share a single spec, so they are split at hardfork activations
+        while let Some(additional) = NonZeroU64::new(remaining) {
+            let last_block = self.last_block()?;
+            let previous_total_difficulty = self
+                .total_difficulty_by_hash(last_block.hash())?
+                .expect("Must exist as its block is stored");
+
+            let last_header = last_block.header();
+            let first_number = last_header.number + 1;
+
+            let additional = self
+                .hardfork_activations
+                .as_ref()
+                .and_then(|activations| activations.next_activation_after(first_number))
+                .and_then(|activation| NonZeroU64::new(activation - first_number))
+                .map_or(additional, |until_activation| {
+                    additional.min(until_activation)
+                });
+
+            self.storage.reserve_blocks(
+                additional,
+                interval,
+                last_header.base_fee_per_gas,
+                last_header.state_root,
+                previous_total_difficulty,
+                self.spec_for_block_number(first_number),
+            );
 
-        self.storage.reserve_blocks(
-            additional,
-            interval,
-            last_header.base_fee_per_gas,
-            last_header.state_root,
-            previous_total_difficulty,
-            self.spec_id,
-        );
+            remaining -= additional.get();
+        }
 
         Ok(())
     }
@@ -380,6 +421,13 @@ impl BlockchainMut for LocalBlockchain {
             Err(BlockchainError::UnknownBlockNumber)
         }
     }
+
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+    fn prune_receipts(&mut self, block_number: u64) -> Result<(), Self::Error> {
+        self.storage.prune_receipts(block_number);
+
+        Ok(())
+    }
 }
 
 impl BlockHashRef for LocalBlockchain {
@@ -460,4 +508,52 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn reserve_blocks_across_hardfork_activation() -> anyhow::Result<()> {
+        const ACTIVATION_BLOCK_NUMBER: u64 = 5;
+
+        let mut blockchain = LocalBlockchain::new(
+            StateDiff::default(),
+            123,
+            SpecId::MERGE,
+            GenesisBlockOptions {
+                mix_hash: Some(B256::random()),
+                ..GenesisBlockOptions::default()
+            },
+        )?
+        .with_hardfork_activations(HardforkActivations::new(vec![(
+            ACTIVATION_BLOCK_NUMBER,
+            SpecId::SHANGHAI,
+        )]));
+
+        assert_eq!(blockchain.spec_id(), SpecId::MERGE);
+
+        let genesis_timestamp = blockchain.last_block()?.header().timestamp;
+        blockchain.reserve_blocks(10, 1)?;
+        assert_eq!(blockchain.last_block_number(), 10);
+        assert_eq!(blockchain.spec_id(), SpecId::SHANGHAI);
+
+        for block_number in 1..=10 {
+            let expected = if block_number < ACTIVATION_BLOCK_NUMBER {
+                SpecId::MERGE
+            } else {
+                SpecId::SHANGHAI
+            };
+
+            assert_eq!(blockchain.spec_at_block_number(block_number)?, expected);
+
+            let block = blockchain
+                .block_by_number(block_number)?
+                .expect("Block must exist");
+
+            assert_eq!(block.header().timestamp, genesis_timestamp + block_number);
+            assert_eq!(
+                block.header().withdrawals_root.is_some(),
+                expected >= SpecId::SHANGHAI
+            );
+        }
+
+        Ok(())
+    }
 }
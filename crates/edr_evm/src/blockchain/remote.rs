@@ -1,22 +1,72 @@
-use std::sync::Arc;
+use std::{num::NonZeroUsize, sync::Arc};
 
 use async_rwlock::{RwLock, RwLockUpgradableReadGuard};
 use edr_eth::{
+    block::Header,
     log::FilterLog,
     receipt::BlockReceipt,
     remote::{self, filter::OneOrMore, BlockSpec, PreEip1898BlockSpec, RpcClient},
     Address, B256, U256,
 };
+use lru::LruCache;
+use parking_lot::Mutex;
 use revm::primitives::HashSet;
 use tokio::runtime;
 
 use super::storage::SparseBlockchainStorage;
-use crate::{blockchain::ForkedBlockchainError, Block, RemoteBlock};
+use crate::{block::header_from_rpc_block, blockchain::ForkedBlockchainError, Block, RemoteBlock};
+
+/// The default number of remote block headers that are cached.
+const DEFAULT_HEADER_CACHE_CAPACITY: usize = 1024;
+
+/// The number of hits and misses of a remote header cache.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct HeaderCacheMetrics {
+    /// The number of headers that were served from the cache
+    pub hits: u64,
+    /// The number of headers that had to be fetched from the remote
+    pub misses: u64,
+}
+
+/// A bounded cache of remote block headers, evicting the least recently used
+/// header.
+#[derive(Debug)]
+struct HeaderCache {
+    headers: LruCache<u64, Header>,
+    metrics: HeaderCacheMetrics,
+}
+
+impl HeaderCache {
+    fn new(capacity: NonZeroUsize) -> Self {
+        Self {
+            headers: LruCache::new(capacity),
+            metrics: HeaderCacheMetrics::default(),
+        }
+    }
+
+    fn header_by_number(&mut self, number: u64) -> Option<Header> {
+        let header = self.headers.get(&number).cloned();
+
+        if header.is_some() {
+            self.metrics.hits += 1;
+        } else {
+            self.metrics.misses += 1;
+        }
+
+        header
+    }
+
+    fn insert(&mut self, header: Header) {
+        self.headers.put(header.number, header);
+    }
+}
 
 #[derive(Debug)]
 pub struct RemoteBlockchain<BlockT: Block + Clone, const FORCE_CACHING: bool> {
     client: Arc<RpcClient>,
     cache: RwLock<SparseBlockchainStorage<BlockT>>,
+    // Historical headers are immutable, so they never need to be invalidated.
+    header_cache: Mutex<HeaderCache>,
     runtime: runtime::Handle,
 }
 
@@ -28,6 +78,10 @@ impl<BlockT: Block + Clone + From<RemoteBlock>, const FORCE_CACHING: bool>
         Self {
             client,
             cache: RwLock::new(SparseBlockchainStorage::default()),
+            header_cache: Mutex::new(HeaderCache::new(
+                NonZeroUsize::new(DEFAULT_HEADER_CACHE_CAPACITY)
+                    .expect("Header cache capacity is non-zero"),
+            )),
             runtime,
         }
     }
@@ -111,6 +165,57 @@ impl<BlockT: Block + Clone + From<RemoteBlock>, const FORCE_CACHING: bool>
         &self.client
     }
 
+    /// Retrieves the header of the block with the provided number, if it
+    /// exists. Unlike [`Self::block_by_number`], this doesn't fetch the
+    /// block's transactions.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+    pub async fn header_by_number(
+        &self,
+        number: u64,
+    ) -> Result<Option<Header>, ForkedBlockchainError> {
+        // This block ensures that the read lock is dropped
+        {
+            if let Some(block) = self.cache.read().await.block_by_number(number) {
+                return Ok(Some(block.header().clone()));
+            }
+        }
+
+        let cached_header = self.header_cache.lock().header_by_number(number);
+        if let Some(header) = cached_header {
+            return Ok(Some(header));
+        }
+
+        let Some(block) = self
+            .client
+            .get_block_by_number(PreEip1898BlockSpec::Number(number))
+            .await?
+        else {
+            return Ok(None);
+        };
+
+        let header = header_from_rpc_block(&block)?;
+
+        let is_cacheable =
+            FORCE_CACHING || self.client.is_cacheable_block_number(header.number).await?;
+
+        if is_cacheable {
+            self.header_cache.lock().insert(header.clone());
+        }
+
+        Ok(Some(header))
+    }
+
+    /// Retrieves the number of hits and misses of the header cache.
+    pub fn header_cache_metrics(&self) -> HeaderCacheMetrics {
+        self.header_cache.lock().metrics
+    }
+
+    /// Sets the maximum number of cached headers. If the cache contains more
+    /// headers, the least recently used ones are evicted.
+    pub fn set_header_cache_capacity(&mut self, capacity: NonZeroUsize) {
+        self.header_cache.get_mut().headers.resize(capacity);
+    }
+
     pub async fn logs(
         &self,
         from_block: BlockSpec,
@@ -272,4 +377,41 @@ mod tests {
             .block_by_number(block_number)
             .is_none());
     }
+
+    #[tokio::test]
+    async fn second_header_fetch_is_cached() {
+        const BLOCK_NUMBER: u64 = 16_220_843;
+
+        let tempdir = tempfile::tempdir().expect("can create tempdir");
+
+        let rpc_client =
+            RpcClient::new(&get_alchemy_url(), tempdir.path().to_path_buf(), None).expect("url ok");
+
+        let remote = RemoteBlockchain::<RemoteBlock, false>::new(
+            Arc::new(rpc_client),
+            runtime::Handle::current(),
+        );
+
+        let header = remote
+            .header_by_number(BLOCK_NUMBER)
+            .await
+            .unwrap()
+            .expect("block exists");
+        assert_eq!(header.number, BLOCK_NUMBER);
+        assert_eq!(
+            remote.header_cache_metrics(),
+            HeaderCacheMetrics { hits: 0, misses: 1 }
+        );
+
+        let cached_header = remote
+            .header_by_number(BLOCK_NUMBER)
+            .await
+            .unwrap()
+            .expect("block exists");
+        assert_eq!(cached_header, header);
+        assert_eq!(
+            remote.header_cache_metrics(),
+            HeaderCacheMetrics { hits: 1, misses: 1 }
+        );
+    }
 }
@@ -5,7 +5,7 @@ use parking_lot::{RwLock, RwLockUpgradableReadGuard, RwLockWriteGuard};
 use revm::primitives::{HashMap, HashSet};
 
 use super::{sparse, InsertError, SparseBlockchainStorage};
-use crate::{state::StateDiff, Block, LocalBlock};
+use crate::{state::StateDiff, Block, LocalBlock, PrunedBlock};
 
 /// A reservation for a sequence of blocks that have not yet been inserted into
 /// storage.
@@ -318,6 +318,15 @@ impl<BlockT: Block + Clone + From<LocalBlock>> ReservableSparseBlockchainStorage
     }
 }
 
+impl<BlockT: Block + Clone + From<PrunedBlock>> ReservableSparseBlockchainStorage<BlockT> {
+    /// Prunes the transaction receipts of all stored blocks up to and including
+    /// the provided block number.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+    pub fn prune_receipts(&mut self, block_number: u64) {
+        self.storage.get_mut().prune_receipts(block_number);
+    }
+}
+
 #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
 fn calculate_timestamp_for_reserved_block<BlockT: Block + Clone>(
     storage: &SparseBlockchainStorage<BlockT>,
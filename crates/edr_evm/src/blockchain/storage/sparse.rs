@@ -3,12 +3,12 @@ use std::sync::Arc;
 use edr_eth::{
     receipt::BlockReceipt,
     remote::filter::{matches_address_filter, matches_topics_filter},
-    Address, B256, U256,
+    Address, Bloom, BloomInput, B256, U256,
 };
 use revm::primitives::{HashMap, HashSet};
 
 use super::InsertError;
-use crate::{hash_map::OccupiedError, Block};
+use crate::{hash_map::OccupiedError, Block, PrunedBlock};
 
 /// A storage solution for storing a subset of a Blockchain's blocks in-memory.
 #[derive(Debug)]
@@ -18,6 +18,7 @@ pub struct SparseBlockchainStorage<BlockT: Block + Clone + ?Sized> {
     number_to_block: HashMap<u64, BlockT>,
     transaction_hash_to_block: HashMap<B256, BlockT>,
     transaction_hash_to_receipt: HashMap<B256, Arc<BlockReceipt>>,
+    first_unpruned_block_number: u64,
 }
 
 impl<BlockT: Block + Clone + ?Sized> SparseBlockchainStorage<BlockT> {
@@ -47,6 +48,7 @@ impl<BlockT: Block + Clone + ?Sized> SparseBlockchainStorage<BlockT> {
             number_to_block,
             transaction_hash_to_block,
             transaction_hash_to_receipt: HashMap::new(),
+            first_unpruned_block_number: 0,
         }
     }
 
@@ -89,6 +91,8 @@ impl<BlockT: Block + Clone + ?Sized> SparseBlockchainStorage<BlockT> {
     /// blocks.
     #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
     pub fn revert_to_block(&mut self, block_number: u64) {
+        self.first_unpruned_block_number = self.first_unpruned_block_number.min(block_number + 1);
+
         let removed_blocks = self
             .number_to_block
             .extract_if(|number, _| *number > block_number);
@@ -207,6 +211,56 @@ impl<BlockT: Block + Clone + ?Sized> SparseBlockchainStorage<BlockT> {
     }
 }
 
+impl<BlockT: Block + Clone + From<PrunedBlock>> SparseBlockchainStorage<BlockT> {
+    /// Prunes the transaction receipts of all blocks up to and including the
+    /// provided block number.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+    pub fn prune_receipts(&mut self, block_number: u64) {
+        if block_number < self.first_unpruned_block_number {
+            return;
+        }
+
+        // Reserved blocks can cause large gaps in the block numbers, so iterate over
+        // whichever is smaller: the range of block numbers or the stored blocks.
+        let range = self.first_unpruned_block_number..=block_number;
+        let range_length = block_number - self.first_unpruned_block_number + 1;
+        let block_numbers: Vec<u64> = if range_length <= self.number_to_block.len() as u64 {
+            range
+                .filter(|number| self.number_to_block.contains_key(number))
+                .collect()
+        } else {
+            self.number_to_block
+                .keys()
+                .filter(|number| range.contains(number))
+                .copied()
+                .collect()
+        };
+
+        for number in block_numbers {
+            let block = self
+                .number_to_block
+                .get_mut(&number)
+                .expect("Block number was retrieved from storage");
+
+            let pruned_block = BlockT::from(PrunedBlock::new(&*block));
+            *block = pruned_block.clone();
+
+            self.hash_to_block
+                .insert(*pruned_block.hash(), pruned_block.clone());
+
+            for transaction in pruned_block.transactions() {
+                let transaction_hash = transaction.hash();
+
+                self.transaction_hash_to_block
+                    .insert(*transaction_hash, pruned_block.clone());
+                self.transaction_hash_to_receipt.remove(transaction_hash);
+            }
+        }
+
+        self.first_unpruned_block_number = block_number + 1;
+    }
+}
+
 impl<BlockT: Block + Clone> Default for SparseBlockchainStorage<BlockT> {
     fn default() -> Self {
         Self {
@@ -215,10 +269,32 @@ impl<BlockT: Block + Clone> Default for SparseBlockchainStorage<BlockT> {
             number_to_block: HashMap::default(),
             transaction_hash_to_block: HashMap::default(),
             transaction_hash_to_receipt: HashMap::default(),
+            first_unpruned_block_number: 0,
         }
     }
 }
 
+/// Whether the logs bloom may contain logs that match the provided filter.
+fn bloom_matches_filter(
+    bloom: &Bloom,
+    addresses: &HashSet<Address>,
+    topics_filter: &[Option<Vec<B256>>],
+) -> bool {
+    let matches_address = addresses.is_empty()
+        || addresses
+            .iter()
+            .any(|address| bloom.contains_input(BloomInput::Raw(address.as_slice())));
+
+    matches_address
+        && topics_filter.iter().all(|topics| {
+            topics.as_ref().map_or(true, |topics| {
+                topics
+                    .iter()
+                    .any(|topic| bloom.contains_input(BloomInput::Raw(topic.as_slice())))
+            })
+        })
+}
+
 /// Retrieves the logs that match the provided filter.
 pub fn logs<BlockT: Block + Clone>(
     storage: &SparseBlockchainStorage<BlockT>,
@@ -232,6 +308,11 @@ pub fn logs<BlockT: Block + Clone>(
 
     for block_number in from_block..=to_block {
         if let Some(block) = storage.block_by_number(block_number) {
+            // Skip blocks that cannot contain matching logs
+            if !bloom_matches_filter(&block.header().logs_bloom, &addresses, topics_filter) {
+                continue;
+            }
+
             let receipts = block.transaction_receipts()?;
             for receipt in receipts {
                 let filtered_logs = receipt.logs.iter().filter(|log| {
@@ -48,6 +48,10 @@ where
     }
 
     for transaction in transactions {
+        if !transaction.is_executable() {
+            return Err(TransactionError::Eip7702Unsupported.into());
+        }
+
         if transaction.hash() == transaction_hash {
             let mut tracer = TracerEip3155::new(trace_config);
 
@@ -207,6 +211,21 @@ pub struct DebugTraceLogItem {
     /// Map of all stored values with keys and values encoded as hex strings.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub storage: Option<HashMap<String, String>>,
+    /// Whether the storage slot accessed by an `SLOAD` or `SSTORE` was cold or
+    /// warm (EIP-2929). Only present for post-Berlin storage accesses.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub storage_access: Option<StorageAccess>,
+}
+
+/// The access status of a storage slot, as defined by EIP-2929.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum StorageAccess {
+    /// The slot was accessed for the first time in the transaction.
+    Cold,
+    /// The slot was already accessed in the transaction or included in its
+    /// access list.
+    Warm,
 }
 
 /// Register EIP-3155 tracer handles.
@@ -273,7 +292,10 @@ fn instruction_handler<
             // the old Inspector behavior.
             interpreter.instruction_pointer = unsafe { interpreter.instruction_pointer.sub(1) };
 
-            host.context.external.get_context_data().step(interpreter);
+            host.context
+                .external
+                .get_context_data()
+                .step(interpreter, &host.context.evm);
             if interpreter.instruction_result != InstructionResult::Continue {
                 return;
             }
@@ -306,6 +328,7 @@ pub struct TracerEip3155 {
     stack: Vec<U256>,
     // Contract-specific storage
     storage: HashMap<Address, HashMap<String, String>>,
+    storage_access: Option<StorageAccess>,
 }
 
 impl TracerEip3155 {
@@ -322,10 +345,15 @@ impl TracerEip3155 {
             memory: Vec::default(),
             mem_size: 0,
             storage: HashMap::default(),
+            storage_access: None,
         }
     }
 
-    fn step(&mut self, interp: &mut Interpreter) {
+    fn step<DatabaseT: Database>(
+        &mut self,
+        interp: &mut Interpreter,
+        context: &EvmContext<DatabaseT>,
+    ) {
         self.contract_address = interp.contract.address;
         self.gas_remaining = interp.gas().remaining();
 
@@ -342,6 +370,26 @@ impl TracerEip3155 {
         self.opcode = interp.current_opcode();
 
         self.pc = interp.program_counter();
+
+        self.storage_access = if matches!(self.opcode, opcode::SLOAD | opcode::SSTORE)
+            && context.journaled_state.spec >= SpecId::BERLIN
+        {
+            interp.stack.data().last().map(|key| {
+                let is_warm = context
+                    .journaled_state
+                    .state
+                    .get(&interp.contract.address)
+                    .map_or(false, |account| account.storage.contains_key(key));
+
+                if is_warm {
+                    StorageAccess::Warm
+                } else {
+                    StorageAccess::Cold
+                }
+            })
+        } else {
+            None
+        };
     }
 
     fn step_end<DatabaseT: Database>(
@@ -416,6 +464,7 @@ impl TracerEip3155 {
             error,
             memory,
             storage,
+            storage_access: self.storage_access,
         };
         self.logs.push(log_item);
     }
@@ -0,0 +1,291 @@
+use std::collections::BTreeMap;
+
+use revm::{
+    handler::register::EvmHandler,
+    interpreter::{CallInputs, CallOutcome, CreateInputs, CreateOutcome},
+    primitives::{Address, FixedBytes},
+    Database,
+};
+
+use crate::{
+    debug::GetContextData,
+    inspector::{register_sync_inspector_handles, SyncInspector},
+};
+
+/// Identifies a contract function by its contract address and selector.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct FunctionId {
+    /// The address of the contract
+    pub address: Address,
+    /// The 4-byte function selector. None for creates and for calls whose
+    /// input is shorter than a selector.
+    pub selector: Option<FixedBytes<4>>,
+}
+
+/// The gas used by a contract function, aggregated across its invocations.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct FunctionGas {
+    /// The number of times that the function was invoked
+    pub calls: u64,
+    /// The gas used by the function itself, excluding the gas used by the
+    /// calls and creates that it made
+    pub self_gas: u64,
+    /// The gas used by the function, including the gas used by the calls and
+    /// creates that it made. Recursive invocations are only counted once.
+    pub total_gas: u64,
+}
+
+/// The gas usage of the contract functions that were invoked.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct GasProfile {
+    /// The gas usage per contract function
+    pub functions: BTreeMap<FunctionId, FunctionGas>,
+}
+
+impl GasProfile {
+    /// Retrieves the gas usage of the provided function, if it was invoked.
+    pub fn function(&self, function: &FunctionId) -> Option<&FunctionGas> {
+        self.functions.get(function)
+    }
+}
+
+/// A call or create frame that has not exited yet.
+#[derive(Debug)]
+struct Frame {
+    /// None for create frames, as the created address is only known on exit.
+    function: Option<FunctionId>,
+    /// The gas used by the frames that were entered from this frame
+    child_gas: u64,
+}
+
+/// An inspector that attributes the gas used by call and create frames to
+/// contract functions.
+///
+/// The gas used by the top-level frame excludes the transaction's intrinsic
+/// gas cost. Creates that fail are not attributed to any function, but their
+/// gas is still excluded from their parent's self gas.
+#[derive(Debug, Default)]
+pub struct GasProfiler {
+    frames: Vec<Frame>,
+    profile: GasProfile,
+}
+
+impl GasProfiler {
+    /// Returns the gas profile of the frames that have exited.
+    pub fn profile(&self) -> &GasProfile {
+        &self.profile
+    }
+
+    /// Converts the profiler into its gas profile.
+    pub fn into_profile(self) -> GasProfile {
+        self.profile
+    }
+
+    fn enter(&mut self, function: Option<FunctionId>) {
+        self.frames.push(Frame {
+            function,
+            child_gas: 0,
+        });
+    }
+
+    fn exit(&mut self, created_address: Option<Address>, gas_used: u64) {
+        let Some(frame) = self.frames.pop() else {
+            return;
+        };
+
+        if let Some(parent) = self.frames.last_mut() {
+            parent.child_gas += gas_used;
+        }
+
+        let function = frame.function.or_else(|| {
+            created_address.map(|address| FunctionId {
+                address,
+                selector: None,
+            })
+        });
+
+        if let Some(function) = function {
+            let is_recursive = self
+                .frames
+                .iter()
+                .any(|frame| frame.function == Some(function));
+
+            let gas = self.profile.functions.entry(function).or_default();
+            gas.calls += 1;
+            gas.self_gas += gas_used.saturating_sub(frame.child_gas);
+            if !is_recursive {
+                gas.total_gas += gas_used;
+            }
+        }
+    }
+}
+
+impl SyncInspector for GasProfiler {
+    fn call(&mut self, inputs: &CallInputs) -> Option<CallOutcome> {
+        let selector = inputs.input.get(..4).map(FixedBytes::<4>::from_slice);
+
+        self.enter(Some(FunctionId {
+            address: inputs.contract,
+            selector,
+        }));
+
+        None
+    }
+
+    fn call_end(&mut self, _inputs: &CallInputs, outcome: &CallOutcome) {
+        self.exit(None, outcome.gas().spend());
+    }
+
+    fn create(&mut self, _inputs: &CreateInputs) -> Option<CreateOutcome> {
+        self.enter(None);
+
+        None
+    }
+
+    fn create_end(&mut self, _inputs: &CreateInputs, outcome: &CreateOutcome) {
+        self.exit(outcome.address, outcome.gas().spend());
+    }
+}
+
+impl GetContextData<GasProfiler> for GasProfiler {
+    fn get_context_data(&mut self) -> &mut GasProfiler {
+        self
+    }
+}
+
+/// Registers the [`GasProfiler`]'s handles to the EVM handler.
+pub fn register_gas_profiler_handles<DatabaseT: Database, ContextT: GetContextData<GasProfiler>>(
+    handler: &mut EvmHandler<'_, ContextT, DatabaseT>,
+) {
+    register_sync_inspector_handles::<DatabaseT, ContextT, GasProfiler>(handler);
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use edr_eth::{Bytes, SpecId, B256};
+    use revm::primitives::{BlockEnv, Bytecode, CfgEnv, CfgEnvWithHandlerCfg, TransactTo, TxEnv};
+
+    use super::*;
+    use crate::{
+        blockchain::{Blockchain, GenesisBlockOptions, LocalBlockchain},
+        dry_run,
+        state::{AccountOverride, StateDiff, StateOverrides},
+        DebugContext,
+    };
+
+    #[test]
+    fn attributes_self_and_total_gas_per_function() -> anyhow::Result<()> {
+        const CALLER_SELECTOR: [u8; 4] = [0xaa, 0xbb, 0xcc, 0xdd];
+        const CALLEE_SELECTOR: [u8; 4] = [0x12, 0x34, 0x56, 0x78];
+
+        let blockchain = LocalBlockchain::new(
+            StateDiff::default(),
+            1,
+            SpecId::LATEST,
+            GenesisBlockOptions {
+                mix_hash: Some(B256::ZERO),
+                ..GenesisBlockOptions::default()
+            },
+        )?;
+
+        let state = blockchain.state_at_block_number(0, &BTreeMap::new())?;
+
+        let caller_address = Address::random();
+        let callee_address = Address::random();
+
+        // PUSH4 <callee selector> PUSH1 0xe0 SHL PUSH1 0x00 MSTORE
+        // PUSH1 0x00 PUSH1 0x00 PUSH1 0x04 PUSH1 0x00 PUSH1 0x00
+        // PUSH20 <callee> GAS CALL STOP
+        let mut caller_code = vec![0x63];
+        caller_code.extend_from_slice(&CALLEE_SELECTOR);
+        caller_code.extend_from_slice(&[0x60, 0xe0, 0x1b, 0x60, 0x00, 0x52]);
+        caller_code
+            .extend_from_slice(&[0x60, 0x00, 0x60, 0x00, 0x60, 0x04, 0x60, 0x00, 0x60, 0x00]);
+        caller_code.push(0x73);
+        caller_code.extend_from_slice(callee_address.as_slice());
+        caller_code.extend_from_slice(&[0x5a, 0xf1, 0x00]);
+
+        // PUSH1 0x01 POP STOP
+        let callee_code = vec![0x60, 0x01, 0x50, 0x00];
+
+        let code_override = |code: Vec<u8>| AccountOverride {
+            balance: None,
+            nonce: None,
+            code: Some(Bytecode::new_raw(Bytes::from(code))),
+            storage: None,
+        };
+
+        let state_overrides = StateOverrides::new(
+            [
+                (caller_address, code_override(caller_code)),
+                (callee_address, code_override(callee_code)),
+            ]
+            .into_iter()
+            .collect(),
+        );
+
+        let cfg = CfgEnvWithHandlerCfg::new_with_spec_id(CfgEnv::default(), SpecId::LATEST);
+        let transaction = TxEnv {
+            transact_to: TransactTo::Call(caller_address),
+            data: Bytes::from_static(&CALLER_SELECTOR),
+            gas_limit: 100_000,
+            ..TxEnv::default()
+        };
+        let block = BlockEnv {
+            prevrandao: Some(B256::ZERO),
+            ..BlockEnv::default()
+        };
+
+        let mut profiler = GasProfiler::default();
+        let result = dry_run(
+            &blockchain,
+            &*state,
+            &state_overrides,
+            cfg,
+            transaction,
+            block,
+            Some(DebugContext {
+                data: &mut profiler,
+                register_handles_fn: register_gas_profiler_handles,
+            }),
+        )?;
+        assert!(result.result.is_success());
+
+        let profile = profiler.into_profile();
+        assert_eq!(profile.functions.len(), 2);
+
+        let callee = profile
+            .function(&FunctionId {
+                address: callee_address,
+                selector: Some(FixedBytes::from(CALLEE_SELECTOR)),
+            })
+            .expect("callee was invoked");
+
+        // PUSH1 0x01 POP STOP
+        assert_eq!(
+            *callee,
+            FunctionGas {
+                calls: 1,
+                self_gas: 5,
+                total_gas: 5,
+            }
+        );
+
+        let caller = profile
+            .function(&FunctionId {
+                address: caller_address,
+                selector: Some(FixedBytes::from(CALLER_SELECTOR)),
+            })
+            .expect("caller was invoked");
+
+        // The intrinsic gas cost consists of the base cost and four non-zero
+        // calldata bytes
+        assert_eq!(caller.calls, 1);
+        assert_eq!(caller.total_gas, result.result.gas_used() - 21_000 - 4 * 16);
+        assert_eq!(caller.self_gas, caller.total_gas - callee.total_gas);
+
+        Ok(())
+    }
+}
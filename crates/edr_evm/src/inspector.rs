@@ -0,0 +1,361 @@
+use std::{cell::RefCell, fmt::Debug, rc::Rc, sync::Arc};
+
+use revm::{
+    handler::register::EvmHandler,
+    interpreter::{CallInputs, CallOutcome, CreateInputs, CreateOutcome},
+    primitives::EVMError,
+    Database, FrameOrResult, FrameResult,
+};
+
+use crate::debug::GetContextData;
+
+/// An observer of call and create messages that can be composed with other
+/// inspectors in an [`InspectorStack`].
+pub trait SyncInspector: Debug + Send {
+    /// Called before a call is executed. Returning an outcome skips the
+    /// execution of the call.
+    fn call(&mut self, _inputs: &CallInputs) -> Option<CallOutcome> {
+        None
+    }
+
+    /// Called after a call has been executed.
+    fn call_end(&mut self, _inputs: &CallInputs, _outcome: &CallOutcome) {}
+
+    /// Called before a create is executed. Returning an outcome skips the
+    /// execution of the create.
+    fn create(&mut self, _inputs: &CreateInputs) -> Option<CreateOutcome> {
+        None
+    }
+
+    /// Called after a create has been executed.
+    fn create_end(&mut self, _inputs: &CreateInputs, _outcome: &CreateOutcome) {}
+}
+
+/// A stack of inspectors that forwards every hook to each inspector, in the
+/// order that they were added.
+///
+/// All inspectors observe every hook, but only the last inspector's return
+/// value for `call` and `create` is authoritative. Outcomes returned by
+/// earlier inspectors are ignored.
+#[derive(Debug, Default)]
+pub struct InspectorStack {
+    inspectors: Vec<Box<dyn SyncInspector>>,
+}
+
+impl InspectorStack {
+    /// Constructs a new instance with the provided inspectors.
+    pub fn new(inspectors: Vec<Box<dyn SyncInspector>>) -> Self {
+        Self { inspectors }
+    }
+
+    /// Adds an inspector to the top of the stack.
+    pub fn push(&mut self, inspector: Box<dyn SyncInspector>) {
+        self.inspectors.push(inspector);
+    }
+}
+
+impl SyncInspector for InspectorStack {
+    fn call(&mut self, inputs: &CallInputs) -> Option<CallOutcome> {
+        let mut outcome = None;
+        for inspector in &mut self.inspectors {
+            outcome = inspector.call(inputs);
+        }
+        outcome
+    }
+
+    fn call_end(&mut self, inputs: &CallInputs, outcome: &CallOutcome) {
+        for inspector in &mut self.inspectors {
+            inspector.call_end(inputs, outcome);
+        }
+    }
+
+    fn create(&mut self, inputs: &CreateInputs) -> Option<CreateOutcome> {
+        let mut outcome = None;
+        for inspector in &mut self.inspectors {
+            outcome = inspector.create(inputs);
+        }
+        outcome
+    }
+
+    fn create_end(&mut self, inputs: &CreateInputs, outcome: &CreateOutcome) {
+        for inspector in &mut self.inspectors {
+            inspector.create_end(inputs, outcome);
+        }
+    }
+}
+
+impl GetContextData<InspectorStack> for InspectorStack {
+    fn get_context_data(&mut self) -> &mut InspectorStack {
+        self
+    }
+}
+
+/// Registers the [`InspectorStack`]'s handles to the EVM handler.
+pub fn register_inspector_stack_handles<
+    DatabaseT: Database,
+    ContextT: GetContextData<InspectorStack>,
+>(
+    handler: &mut EvmHandler<'_, ContextT, DatabaseT>,
+) {
+    register_sync_inspector_handles::<DatabaseT, ContextT, InspectorStack>(handler);
+}
+
+/// Registers the handles of a [`SyncInspector`] to the EVM handler.
+pub(crate) fn register_sync_inspector_handles<DatabaseT, ContextT, InspectorT>(
+    handler: &mut EvmHandler<'_, ContextT, DatabaseT>,
+) where
+    DatabaseT: Database,
+    ContextT: GetContextData<InspectorT>,
+    InspectorT: SyncInspector,
+{
+    // call and create input stacks shared between handlers. They are used to
+    // share inputs with the *_end hooks.
+    let call_input_stack = Rc::<RefCell<Vec<_>>>::new(RefCell::new(Vec::new()));
+    let create_input_stack = Rc::<RefCell<Vec<_>>>::new(RefCell::new(Vec::new()));
+
+    // Create handler
+    let create_input_stack_inner = create_input_stack.clone();
+    let old_handle = handler.execution.create.clone();
+    handler.execution.create = Arc::new(
+        move |ctx, inputs| -> Result<FrameOrResult, EVMError<DatabaseT::Error>> {
+            let outcome = ctx.external.get_context_data().create(&inputs);
+
+            create_input_stack_inner.borrow_mut().push(inputs.clone());
+
+            if let Some(outcome) = outcome {
+                Ok(FrameOrResult::Result(FrameResult::Create(outcome)))
+            } else {
+                old_handle(ctx, inputs)
+            }
+        },
+    );
+
+    // Call handler
+    let call_input_stack_inner = call_input_stack.clone();
+    let old_handle = handler.execution.call.clone();
+    handler.execution.call = Arc::new(
+        move |ctx, inputs| -> Result<FrameOrResult, EVMError<DatabaseT::Error>> {
+            let outcome = ctx.external.get_context_data().call(&inputs);
+
+            call_input_stack_inner.borrow_mut().push(inputs.clone());
+
+            if let Some(outcome) = outcome {
+                Ok(FrameOrResult::Result(FrameResult::Call(outcome)))
+            } else {
+                old_handle(ctx, inputs)
+            }
+        },
+    );
+
+    // call outcome
+    let call_input_stack_inner = call_input_stack.clone();
+    let old_handle = handler.execution.insert_call_outcome.clone();
+    handler.execution.insert_call_outcome = Arc::new(
+        move |ctx: &mut revm::Context<ContextT, DatabaseT>, frame, shared_memory, outcome| {
+            let call_inputs = call_input_stack_inner.borrow_mut().pop().unwrap();
+
+            ctx.external
+                .get_context_data()
+                .call_end(&call_inputs, &outcome);
+
+            old_handle(ctx, frame, shared_memory, outcome)
+        },
+    );
+
+    // create outcome
+    let create_input_stack_inner = create_input_stack.clone();
+    let old_handle = handler.execution.insert_create_outcome.clone();
+    handler.execution.insert_create_outcome = Arc::new(move |ctx, frame, outcome| {
+        let create_inputs = create_input_stack_inner.borrow_mut().pop().unwrap();
+
+        ctx.external
+            .get_context_data()
+            .create_end(&create_inputs, &outcome);
+
+        old_handle(ctx, frame, outcome)
+    });
+
+    // last frame outcome
+    let old_handle = handler.execution.last_frame_return.clone();
+    handler.execution.last_frame_return = Arc::new(move |ctx, frame_result| {
+        let inspector = ctx.external.get_context_data();
+        match frame_result {
+            FrameResult::Call(outcome) => {
+                let call_inputs = call_input_stack.borrow_mut().pop().unwrap();
+                inspector.call_end(&call_inputs, outcome);
+            }
+            FrameResult::Create(outcome) => {
+                let create_inputs = create_input_stack.borrow_mut().pop().unwrap();
+                inspector.create_end(&create_inputs, outcome);
+            }
+        }
+        old_handle(ctx, frame_result)
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        collections::BTreeMap,
+        sync::{Arc, Mutex},
+    };
+
+    use edr_eth::{Address, Bytes, SpecId, B256};
+    use revm::{
+        interpreter::{Gas, InstructionResult, InterpreterResult},
+        primitives::{
+            BlockEnv, Bytecode, CfgEnv, CfgEnvWithHandlerCfg, ExecutionResult, TransactTo, TxEnv,
+        },
+    };
+
+    use super::*;
+    use crate::{
+        blockchain::{Blockchain, GenesisBlockOptions, LocalBlockchain},
+        dry_run,
+        state::{AccountOverride, StateDiff, StateOverrides},
+        DebugContext, DryRunResult,
+    };
+
+    type HookLog = Arc<Mutex<Vec<(usize, &'static str)>>>;
+
+    /// An inspector that records the hooks it receives.
+    #[derive(Debug)]
+    struct RecordingInspector {
+        id: usize,
+        log: HookLog,
+        revert_calls: bool,
+    }
+
+    impl SyncInspector for RecordingInspector {
+        fn call(&mut self, inputs: &CallInputs) -> Option<CallOutcome> {
+            self.log.lock().unwrap().push((self.id, "call"));
+
+            self.revert_calls.then(|| {
+                CallOutcome::new(
+                    InterpreterResult {
+                        result: InstructionResult::Revert,
+                        output: Bytes::new(),
+                        gas: Gas::new(inputs.gas_limit),
+                    },
+                    inputs.return_memory_offset.clone(),
+                )
+            })
+        }
+
+        fn call_end(&mut self, _inputs: &CallInputs, _outcome: &CallOutcome) {
+            self.log.lock().unwrap().push((self.id, "call_end"));
+        }
+    }
+
+    fn recording_stack(log: &HookLog, reverting_ids: &[usize]) -> InspectorStack {
+        InspectorStack::new(
+            (0..3)
+                .map(|id| {
+                    Box::new(RecordingInspector {
+                        id,
+                        log: log.clone(),
+                        revert_calls: reverting_ids.contains(&id),
+                    }) as Box<dyn SyncInspector>
+                })
+                .collect(),
+        )
+    }
+
+    fn dry_run_stop_contract(stack: &mut InspectorStack) -> anyhow::Result<ExecutionResult> {
+        // STOP
+        const STOP_CODE: [u8; 1] = [0x00];
+
+        let blockchain = LocalBlockchain::new(
+            StateDiff::default(),
+            1,
+            SpecId::LATEST,
+            GenesisBlockOptions {
+                mix_hash: Some(B256::ZERO),
+                ..GenesisBlockOptions::default()
+            },
+        )?;
+
+        let state = blockchain.state_at_block_number(0, &BTreeMap::new())?;
+
+        let contract_address = Address::random();
+        let state_overrides = StateOverrides::new(
+            [(
+                contract_address,
+                AccountOverride {
+                    balance: None,
+                    nonce: None,
+                    code: Some(Bytecode::new_raw(Bytes::from_static(&STOP_CODE))),
+                    storage: None,
+                },
+            )]
+            .into_iter()
+            .collect(),
+        );
+
+        let cfg = CfgEnvWithHandlerCfg::new_with_spec_id(CfgEnv::default(), SpecId::LATEST);
+        let transaction = TxEnv {
+            transact_to: TransactTo::Call(contract_address),
+            gas_limit: 100_000,
+            ..TxEnv::default()
+        };
+        let block = BlockEnv {
+            prevrandao: Some(B256::ZERO),
+            ..BlockEnv::default()
+        };
+
+        let DryRunResult { result, .. } = dry_run(
+            &blockchain,
+            &*state,
+            &state_overrides,
+            cfg,
+            transaction,
+            block,
+            Some(DebugContext {
+                data: stack,
+                register_handles_fn: register_inspector_stack_handles,
+            }),
+        )?;
+
+        Ok(result)
+    }
+
+    #[test]
+    fn hooks_are_forwarded_in_order() -> anyhow::Result<()> {
+        let log = HookLog::default();
+        let mut stack = recording_stack(&log, &[]);
+
+        let result = dry_run_stop_contract(&mut stack)?;
+        assert!(result.is_success());
+
+        assert_eq!(
+            *log.lock().unwrap(),
+            vec![
+                (0, "call"),
+                (1, "call"),
+                (2, "call"),
+                (0, "call_end"),
+                (1, "call_end"),
+                (2, "call_end"),
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn last_inspector_outcome_is_authoritative() -> anyhow::Result<()> {
+        let log = HookLog::default();
+
+        // Earlier inspectors' outcomes are ignored
+        let mut stack = recording_stack(&log, &[0, 1]);
+        let result = dry_run_stop_contract(&mut stack)?;
+        assert!(result.is_success());
+
+        // The last inspector's outcome skips execution
+        let mut stack = recording_stack(&log, &[2]);
+        let result = dry_run_stop_contract(&mut stack)?;
+        assert!(matches!(result, ExecutionResult::Revert { .. }));
+
+        Ok(())
+    }
+}
@@ -13,12 +13,20 @@ pub use crate::{
     debug_trace::{
         debug_trace_transaction, execution_result_to_debug_result,
         register_eip_3155_tracer_handles, DebugTraceConfig, DebugTraceError, DebugTraceLogItem,
-        DebugTraceResult, TracerEip3155,
+        DebugTraceResult, StorageAccess, TracerEip3155,
     },
+    gas_profiler::{
+        register_gas_profiler_handles, FunctionGas, FunctionId, GasProfile, GasProfiler,
+    },
+    inspector::{register_inspector_stack_handles, InspectorStack, SyncInspector},
     mempool::{MemPool, MemPoolAddTransactionError, OrderedTransaction},
     miner::*,
     random::RandomHashGenerator,
-    runtime::{dry_run, guaranteed_dry_run, run, SyncDatabase},
+    revert::{revert_reason, RevertReason, ERROR_SELECTOR, PANIC_SELECTOR},
+    runtime::{
+        dry_run, dry_run_with_block_overrides, dry_run_with_diff, guaranteed_dry_run, run,
+        BlockOverrides, DryRunResult, ResultAndStateDiff, RunResult, SyncDatabase,
+    },
     transaction::*,
 };
 
@@ -35,14 +43,19 @@ mod block;
 pub(crate) mod collections;
 mod debug;
 mod debug_trace;
+mod gas_profiler;
+mod inspector;
 /// Types for managing Ethereum mem pool
 pub mod mempool;
 mod miner;
 pub(crate) mod random;
+mod revert;
 mod runtime;
 /// Utilities for testing
 #[cfg(any(test, feature = "test-utils"))]
 pub mod test_utils;
+/// Sources of the current time
+pub mod time;
 mod transaction;
 
 /// Types for interfacing with the evm
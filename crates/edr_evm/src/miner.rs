@@ -452,4 +452,82 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn fifo_ordering_interleaved_senders() -> anyhow::Result<()> {
+        let sender1 = Address::random();
+        let sender2 = Address::random();
+
+        let account_with_balance = AccountInfo {
+            balance: U256::from(100_000_000u64),
+            ..AccountInfo::default()
+        };
+        let mut fixture = MemPoolTestFixture::with_accounts(&[
+            (sender1, account_with_balance.clone()),
+            (sender2, account_with_balance),
+        ]);
+
+        let transaction1 = dummy_eip155_transaction_with_price(sender1, 0, U256::from(100))?;
+        fixture.add_transaction(transaction1.clone())?;
+
+        let transaction2 = dummy_eip155_transaction_with_price(sender2, 0, U256::from(200))?;
+        fixture.add_transaction(transaction2.clone())?;
+
+        let transaction3 = dummy_eip155_transaction_with_price(sender2, 1, U256::from(50))?;
+        fixture.add_transaction(transaction3.clone())?;
+
+        let transaction4 = dummy_eip155_transaction_with_price(sender1, 1, U256::from(300))?;
+        fixture.add_transaction(transaction4.clone())?;
+
+        let mut ordered_transactions = fixture.mem_pool.iter(first_in_first_out_comparator);
+
+        // Gas prices are ignored; transactions are returned in insertion order
+        assert_eq!(ordered_transactions.next(), Some(transaction1));
+        assert_eq!(ordered_transactions.next(), Some(transaction2));
+        assert_eq!(ordered_transactions.next(), Some(transaction3));
+        assert_eq!(ordered_transactions.next(), Some(transaction4));
+        assert_eq!(ordered_transactions.next(), None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn priority_ordering_interleaved_senders_preserves_nonce_order() -> anyhow::Result<()> {
+        let sender1 = Address::random();
+        let sender2 = Address::random();
+
+        let account_with_balance = AccountInfo {
+            balance: U256::from(100_000_000u64),
+            ..AccountInfo::default()
+        };
+        let mut fixture = MemPoolTestFixture::with_accounts(&[
+            (sender1, account_with_balance.clone()),
+            (sender2, account_with_balance),
+        ]);
+
+        let transaction1 = dummy_eip155_transaction_with_price(sender1, 0, U256::from(100))?;
+        fixture.add_transaction(transaction1.clone())?;
+
+        // Pays the most, but can only be mined after `transaction1`
+        let transaction2 = dummy_eip155_transaction_with_price(sender1, 1, U256::from(300))?;
+        fixture.add_transaction(transaction2.clone())?;
+
+        let transaction3 = dummy_eip155_transaction_with_price(sender2, 0, U256::from(200))?;
+        fixture.add_transaction(transaction3.clone())?;
+
+        let transaction4 = dummy_eip155_transaction_with_price(sender2, 1, U256::from(50))?;
+        fixture.add_transaction(transaction4.clone())?;
+
+        let mut ordered_transactions = fixture
+            .mem_pool
+            .iter(|lhs, rhs| priority_comparator(lhs, rhs, None));
+
+        assert_eq!(ordered_transactions.next(), Some(transaction3));
+        assert_eq!(ordered_transactions.next(), Some(transaction1));
+        assert_eq!(ordered_transactions.next(), Some(transaction2));
+        assert_eq!(ordered_transactions.next(), Some(transaction4));
+        assert_eq!(ordered_transactions.next(), None);
+
+        Ok(())
+    }
 }
@@ -0,0 +1,134 @@
+use edr_eth::{Bytes, U256};
+use revm::primitives::ExecutionResult;
+
+/// The selector of Solidity's `Error(string)` revert, used by `require` and
+/// `revert` with a reason string.
+pub const ERROR_SELECTOR: [u8; 4] = [0x08, 0xc3, 0x79, 0xa0];
+
+/// The selector of Solidity's `Panic(uint256)` revert, used for assertion
+/// failures, arithmetic overflows, etc.
+pub const PANIC_SELECTOR: [u8; 4] = [0x4e, 0x48, 0x7b, 0x71];
+
+/// The decoded revert data of a reverted execution.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum RevertReason {
+    /// A reason string, e.g. from `require(condition, "reason")`
+    Error(String),
+    /// A panic code, e.g. `0x11` for an arithmetic overflow
+    Panic(U256),
+    /// Revert data that isn't a reason string or panic, e.g. a custom error
+    Raw(Bytes),
+}
+
+impl RevertReason {
+    /// Decodes the provided revert data. Revert data with an unknown selector
+    /// or that cannot be decoded is returned as [`RevertReason::Raw`].
+    pub fn decode(output: &Bytes) -> Self {
+        let decoded = output.get(..4).and_then(|selector| {
+            let data = &output[4..];
+            if selector == ERROR_SELECTOR {
+                decode_string(data).map(Self::Error)
+            } else if selector == PANIC_SELECTOR {
+                decode_word(data, 0).map(Self::Panic)
+            } else {
+                None
+            }
+        });
+
+        decoded.unwrap_or_else(|| Self::Raw(output.clone()))
+    }
+}
+
+/// Decodes the revert reason of the provided execution result, if it
+/// reverted.
+pub fn revert_reason(execution_result: &ExecutionResult) -> Option<RevertReason> {
+    match execution_result {
+        ExecutionResult::Revert { output, .. } => Some(RevertReason::decode(output)),
+        ExecutionResult::Success { .. } | ExecutionResult::Halt { .. } => None,
+    }
+}
+
+fn decode_word(data: &[u8], offset: usize) -> Option<U256> {
+    let word = data.get(offset..offset.checked_add(32)?)?;
+
+    Some(U256::from_be_slice(word))
+}
+
+fn decode_string(data: &[u8]) -> Option<String> {
+    let offset = usize::try_from(decode_word(data, 0)?).ok()?;
+    let length = usize::try_from(decode_word(data, offset)?).ok()?;
+
+    let start = offset.checked_add(32)?;
+    let bytes = data.get(start..start.checked_add(length)?)?;
+
+    String::from_utf8(bytes.to_vec()).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// ABI-encodes `Error(string)` revert data for the provided reason.
+    fn encode_error(reason: &str) -> Bytes {
+        let padded_length = reason.len().div_ceil(32) * 32;
+
+        let mut output = ERROR_SELECTOR.to_vec();
+        output.extend_from_slice(&U256::from(32).to_be_bytes::<32>());
+        output.extend_from_slice(&U256::from(reason.len()).to_be_bytes::<32>());
+        output.extend_from_slice(reason.as_bytes());
+        output.resize(output.len() + padded_length - reason.len(), 0);
+
+        Bytes::from(output)
+    }
+
+    #[test]
+    fn decode_error_string() {
+        let output = encode_error("msg");
+
+        assert_eq!(
+            RevertReason::decode(&output),
+            RevertReason::Error(String::from("msg"))
+        );
+    }
+
+    #[test]
+    fn decode_panic() {
+        let mut output = PANIC_SELECTOR.to_vec();
+        output.extend_from_slice(&U256::from(0x11).to_be_bytes::<32>());
+
+        assert_eq!(
+            RevertReason::decode(&Bytes::from(output)),
+            RevertReason::Panic(U256::from(0x11))
+        );
+    }
+
+    #[test]
+    fn decode_unknown_selector() {
+        let output = Bytes::from_static(&[0xde, 0xad, 0xbe, 0xef, 0x01]);
+
+        assert_eq!(
+            RevertReason::decode(&output),
+            RevertReason::Raw(output.clone())
+        );
+    }
+
+    #[test]
+    fn decode_malformed_error_string() {
+        let mut output = encode_error("msg").to_vec();
+        output.truncate(4 + 32 + 16);
+        let output = Bytes::from(output);
+
+        assert_eq!(
+            RevertReason::decode(&output),
+            RevertReason::Raw(output.clone())
+        );
+    }
+
+    #[test]
+    fn decode_empty_output() {
+        assert_eq!(
+            RevertReason::decode(&Bytes::new()),
+            RevertReason::Raw(Bytes::new())
+        );
+    }
+}
@@ -3,8 +3,8 @@ use std::fmt::Debug;
 use revm::{
     db::{DatabaseComponents, StateRef},
     primitives::{
-        BlockEnv, CfgEnvWithHandlerCfg, EnvWithHandlerCfg, ExecutionResult, ResultAndState, SpecId,
-        TxEnv,
+        BlockEnv, CfgEnvWithHandlerCfg, EnvWithHandlerCfg, ExecutionResult, HashMap,
+        ResultAndState, SpecId, State, TxEnv, U256,
     },
     DatabaseCommit, Evm,
 };
@@ -12,16 +12,58 @@ use revm::{
 use crate::{
     blockchain::SyncBlockchain,
     debug::DebugContext,
-    state::{StateOverrides, StateRefOverrider, SyncState},
+    revert::{revert_reason, RevertReason},
+    state::{StateDiff, StateOverrides, StateRefOverrider, SyncState},
     transaction::TransactionError,
 };
 
-/// Asynchronous implementation of the Database super-trait
+/// Synchronous implementation of the Database super-trait
 pub type SyncDatabase<'blockchain, 'state, BlockchainErrorT, StateErrorT> = DatabaseComponents<
     &'state dyn StateRef<Error = StateErrorT>,
     &'blockchain dyn SyncBlockchain<BlockchainErrorT, StateErrorT>,
 >;
 
+/// The result of executing a transaction without committing the state.
+#[derive(Debug)]
+pub struct DryRunResult {
+    /// The result of executing the transaction
+    pub result: ExecutionResult,
+    /// The state after executing the transaction
+    pub state: State,
+    /// The decoded revert reason, if the transaction reverted
+    pub revert_reason: Option<RevertReason>,
+}
+
+impl From<ResultAndState> for DryRunResult {
+    fn from(value: ResultAndState) -> Self {
+        let ResultAndState { result, state } = value;
+
+        Self {
+            revert_reason: revert_reason(&result),
+            result,
+            state,
+        }
+    }
+}
+
+/// The result of executing a transaction, after committing the state.
+#[derive(Debug)]
+pub struct RunResult {
+    /// The result of executing the transaction
+    pub result: ExecutionResult,
+    /// The decoded revert reason, if the transaction reverted
+    pub revert_reason: Option<RevertReason>,
+}
+
+impl From<ExecutionResult> for RunResult {
+    fn from(result: ExecutionResult) -> Self {
+        Self {
+            revert_reason: revert_reason(&result),
+            result,
+        }
+    }
+}
+
 /// Runs a transaction without committing the state.
 #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
 pub fn dry_run<'blockchain, 'evm, 'overrides, 'state, DebugDataT, BlockchainErrorT, StateErrorT>(
@@ -39,7 +81,7 @@ pub fn dry_run<'blockchain, 'evm, 'overrides, 'state, DebugDataT, BlockchainErro
             StateRefOverrider<'overrides, &'evm dyn SyncState<StateErrorT>>,
         >,
     >,
-) -> Result<ResultAndState, TransactionError<BlockchainErrorT, StateErrorT>>
+) -> Result<DryRunResult, TransactionError<BlockchainErrorT, StateErrorT>>
 where
     'blockchain: 'evm,
     'state: 'evm,
@@ -71,7 +113,164 @@ where
         }
     };
 
-    result.map_err(TransactionError::from)
+    result
+        .map(DryRunResult::from)
+        .map_err(TransactionError::from)
+}
+
+/// The result of executing a transaction, along with the changes it made to
+/// the state.
+#[derive(Debug)]
+pub struct ResultAndStateDiff {
+    /// The result of executing the transaction
+    pub result: ExecutionResult,
+    /// The changes made to the state
+    pub state_diff: StateDiff,
+    /// The decoded revert reason, if the transaction reverted
+    pub revert_reason: Option<RevertReason>,
+}
+
+/// Runs a transaction without committing the state, returning the changes it
+/// made to the state.
+///
+/// In contrast to [`dry_run`], accounts that were only read are excluded, as
+/// are storage slots of which the value didn't change.
+#[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+pub fn dry_run_with_diff<
+    'blockchain,
+    'evm,
+    'overrides,
+    'state,
+    DebugDataT,
+    BlockchainErrorT,
+    StateErrorT,
+>(
+    blockchain: &'blockchain dyn SyncBlockchain<BlockchainErrorT, StateErrorT>,
+    state: &'state dyn SyncState<StateErrorT>,
+    state_overrides: &'overrides StateOverrides,
+    cfg: CfgEnvWithHandlerCfg,
+    transaction: TxEnv,
+    block: BlockEnv,
+    debug_context: Option<
+        DebugContext<
+            'evm,
+            BlockchainErrorT,
+            DebugDataT,
+            StateRefOverrider<'overrides, &'evm dyn SyncState<StateErrorT>>,
+        >,
+    >,
+) -> Result<ResultAndStateDiff, TransactionError<BlockchainErrorT, StateErrorT>>
+where
+    'blockchain: 'evm,
+    'state: 'evm,
+    BlockchainErrorT: Debug + Send,
+    StateErrorT: Debug + Send,
+{
+    let DryRunResult {
+        result,
+        state,
+        revert_reason,
+    } = dry_run(
+        blockchain,
+        state,
+        state_overrides,
+        cfg,
+        transaction,
+        block,
+        debug_context,
+    )?;
+
+    let state_diff = state
+        .into_iter()
+        .filter(|(_address, account)| account.is_touched())
+        .map(|(address, mut account)| {
+            account.storage.retain(|_index, slot| slot.is_changed());
+
+            (address, account)
+        })
+        .collect::<HashMap<_, _>>();
+
+    Ok(ResultAndStateDiff {
+        result,
+        state_diff: StateDiff::from(state_diff),
+        revert_reason,
+    })
+}
+
+/// Overrides for the block environment of a dry run.
+#[derive(Clone, Debug, Default)]
+pub struct BlockOverrides {
+    /// The block number
+    pub number: Option<U256>,
+    /// The block timestamp
+    pub timestamp: Option<U256>,
+}
+
+impl BlockOverrides {
+    /// Applies the overrides to the provided block environment.
+    pub fn apply(&self, block: &mut BlockEnv) {
+        if let Some(number) = self.number {
+            block.number = number;
+        }
+
+        if let Some(timestamp) = self.timestamp {
+            block.timestamp = timestamp;
+        }
+    }
+}
+
+/// Runs a transaction without committing the state, after applying the
+/// provided overrides to the block environment.
+///
+/// The overrides only affect the block environment, not the state or the
+/// blockchain. As such, they can be inconsistent with each other. E.g. when
+/// overriding the block number of a historical state, `NUMBER` returns the
+/// overridden value, but `BLOCKHASH` is still resolved using the blockchain,
+/// so block hashes after the state's block are unavailable.
+#[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+#[allow(clippy::too_many_arguments)]
+pub fn dry_run_with_block_overrides<
+    'blockchain,
+    'evm,
+    'overrides,
+    'state,
+    DebugDataT,
+    BlockchainErrorT,
+    StateErrorT,
+>(
+    blockchain: &'blockchain dyn SyncBlockchain<BlockchainErrorT, StateErrorT>,
+    state: &'state dyn SyncState<StateErrorT>,
+    state_overrides: &'overrides StateOverrides,
+    block_overrides: &BlockOverrides,
+    cfg: CfgEnvWithHandlerCfg,
+    transaction: TxEnv,
+    mut block: BlockEnv,
+    debug_context: Option<
+        DebugContext<
+            'evm,
+            BlockchainErrorT,
+            DebugDataT,
+            StateRefOverrider<'overrides, &'evm dyn SyncState<StateErrorT>>,
+        >,
+    >,
+) -> Result<DryRunResult, TransactionError<BlockchainErrorT, StateErrorT>>
+where
+    'blockchain: 'evm,
+    'state: 'evm,
+    BlockchainErrorT: Debug + Send,
+    StateErrorT: Debug + Send,
+{
+    block_overrides.apply(&mut block);
+
+    dry_run(
+        blockchain,
+        state,
+        state_overrides,
+        cfg,
+        transaction,
+        block,
+        debug_context,
+    )
 }
 
 /// Runs a transaction without committing the state, while disabling balance
@@ -100,7 +299,7 @@ pub fn guaranteed_dry_run<
             StateRefOverrider<'overrides, &'evm dyn SyncState<StateErrorT>>,
         >,
     >,
-) -> Result<ResultAndState, TransactionError<BlockchainErrorT, StateErrorT>>
+) -> Result<DryRunResult, TransactionError<BlockchainErrorT, StateErrorT>>
 where
     'blockchain: 'evm,
     'state: 'evm,
@@ -130,7 +329,7 @@ pub fn run<'blockchain, 'evm, BlockchainErrorT, DebugDataT, StateT>(
     transaction: TxEnv,
     block: BlockEnv,
     debug_context: Option<DebugContext<'evm, BlockchainErrorT, DebugDataT, StateT>>,
-) -> Result<ExecutionResult, TransactionError<BlockchainErrorT, StateT::Error>>
+) -> Result<RunResult, TransactionError<BlockchainErrorT, StateT::Error>>
 where
     'blockchain: 'evm,
     BlockchainErrorT: Debug + Send,
@@ -159,7 +358,7 @@ where
         evm.transact_commit()
     }?;
 
-    Ok(result)
+    Ok(RunResult::from(result))
 }
 
 fn validate_configuration<BlockchainErrorT, StateErrorT>(
@@ -177,3 +376,415 @@ fn validate_configuration<BlockchainErrorT, StateErrorT>(
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::BTreeMap, sync::Arc};
+
+    use edr_eth::{
+        access_list::AccessListItem,
+        transaction::{Eip2930TransactionRequest, TransactionKind},
+        Address, Bytes, B256,
+    };
+    use revm::{
+        handler::register::EvmHandler,
+        primitives::{Bytecode, CfgEnv, EVMError, Output, TransactTo},
+        Database, FrameOrResult,
+    };
+
+    use super::*;
+    use crate::{
+        blockchain::{Blockchain, GenesisBlockOptions, LocalBlockchain},
+        debug::GetContextData,
+        state::{AccountOverride, StateDiff, StateError, TrieState},
+        ExecutableTransaction,
+    };
+
+    /// A custom debugger that records the gas limit of every call.
+    #[derive(Debug, Default)]
+    struct CallGasProfiler {
+        call_gas_limits: Vec<(Address, u64)>,
+    }
+
+    impl GetContextData<CallGasProfiler> for CallGasProfiler {
+        fn get_context_data(&mut self) -> &mut CallGasProfiler {
+            self
+        }
+    }
+
+    fn register_call_gas_profiler_handles<
+        DatabaseT: Database,
+        ContextT: GetContextData<CallGasProfiler>,
+    >(
+        handler: &mut EvmHandler<'_, ContextT, DatabaseT>,
+    ) {
+        let old_handle = handler.execution.call.clone();
+        handler.execution.call = Arc::new(
+            move |ctx, inputs| -> Result<FrameOrResult, EVMError<DatabaseT::Error>> {
+                let profiler = ctx.external.get_context_data();
+                profiler
+                    .call_gas_limits
+                    .push((inputs.contract, inputs.gas_limit));
+
+                old_handle(ctx, inputs)
+            },
+        );
+    }
+
+    /// A local blockchain with a contract at a random address.
+    struct ContractFixture {
+        blockchain: LocalBlockchain,
+        state: Box<dyn SyncState<StateError>>,
+        state_overrides: StateOverrides,
+        contract_address: Address,
+    }
+
+    impl ContractFixture {
+        /// Constructs a fixture with a contract that has the provided code in
+        /// the state of the genesis block.
+        fn new(code: Bytes) -> anyhow::Result<Self> {
+            let blockchain = LocalBlockchain::new(
+                StateDiff::default(),
+                1,
+                SpecId::LATEST,
+                GenesisBlockOptions {
+                    mix_hash: Some(B256::ZERO),
+                    ..GenesisBlockOptions::default()
+                },
+            )?;
+
+            let state = blockchain.state_at_block_number(0, &BTreeMap::new())?;
+
+            let contract_address = Address::random();
+            let state_overrides = StateOverrides::new(
+                [(
+                    contract_address,
+                    AccountOverride {
+                        balance: None,
+                        nonce: None,
+                        code: Some(Bytecode::new_raw(code)),
+                        storage: None,
+                    },
+                )]
+                .into_iter()
+                .collect(),
+            );
+
+            Ok(Self {
+                blockchain,
+                state,
+                state_overrides,
+                contract_address,
+            })
+        }
+
+        fn cfg() -> CfgEnvWithHandlerCfg {
+            CfgEnvWithHandlerCfg::new_with_spec_id(CfgEnv::default(), SpecId::LATEST)
+        }
+
+        fn block() -> BlockEnv {
+            BlockEnv {
+                prevrandao: Some(B256::ZERO),
+                ..BlockEnv::default()
+            }
+        }
+
+        /// Constructs a transaction that calls the contract.
+        fn call(&self) -> TxEnv {
+            TxEnv {
+                transact_to: TransactTo::Call(self.contract_address),
+                gas_limit: 100_000,
+                ..TxEnv::default()
+            }
+        }
+    }
+
+    #[test]
+    fn dry_run_with_custom_debug_context() -> anyhow::Result<()> {
+        // STOP
+        const STOP_CODE: [u8; 1] = [0x00];
+
+        let fixture = ContractFixture::new(Bytes::from_static(&STOP_CODE))?;
+
+        let mut profiler = CallGasProfiler::default();
+        let DryRunResult { result, .. } = dry_run(
+            &fixture.blockchain,
+            &*fixture.state,
+            &fixture.state_overrides,
+            ContractFixture::cfg(),
+            fixture.call(),
+            ContractFixture::block(),
+            Some(DebugContext {
+                data: &mut profiler,
+                register_handles_fn: register_call_gas_profiler_handles,
+            }),
+        )?;
+        assert!(result.is_success());
+
+        // The intrinsic gas cost is deducted before the call
+        assert_eq!(
+            profiler.call_gas_limits,
+            vec![(fixture.contract_address, 100_000 - 21_000)]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn run_without_tokio_runtime() -> anyhow::Result<()> {
+        // Local execution must not rely on an async runtime, e.g. for WASM targets
+        assert!(tokio::runtime::Handle::try_current().is_err());
+
+        let blockchain = LocalBlockchain::new(
+            StateDiff::default(),
+            1,
+            SpecId::LATEST,
+            GenesisBlockOptions {
+                mix_hash: Some(B256::ZERO),
+                ..GenesisBlockOptions::default()
+            },
+        )?;
+
+        let cfg = CfgEnvWithHandlerCfg::new_with_spec_id(CfgEnv::default(), SpecId::LATEST);
+        let transaction = TxEnv {
+            caller: Address::random(),
+            transact_to: TransactTo::Call(Address::random()),
+            gas_limit: 21_000,
+            ..TxEnv::default()
+        };
+        let block = BlockEnv {
+            prevrandao: Some(B256::ZERO),
+            ..BlockEnv::default()
+        };
+
+        let result = run::<_, (), _>(
+            &blockchain,
+            TrieState::default(),
+            cfg,
+            transaction,
+            block,
+            None,
+        )?;
+        assert!(result.result.is_success());
+        assert_eq!(result.result.gas_used(), 21_000);
+        assert_eq!(result.revert_reason, None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn dry_run_with_overridden_block_number() -> anyhow::Result<()> {
+        // NUMBER PUSH1 0x00 MSTORE PUSH1 0x20 PUSH1 0x00 RETURN
+        const NUMBER_CODE: [u8; 9] = [0x43, 0x60, 0x00, 0x52, 0x60, 0x20, 0x60, 0x00, 0xf3];
+
+        let fixture = ContractFixture::new(Bytes::from_static(&NUMBER_CODE))?;
+
+        let block_overrides = BlockOverrides {
+            number: Some(U256::from(100)),
+            ..BlockOverrides::default()
+        };
+
+        let DryRunResult { result, .. } = dry_run_with_block_overrides::<(), _, _>(
+            &fixture.blockchain,
+            &*fixture.state,
+            &fixture.state_overrides,
+            &block_overrides,
+            ContractFixture::cfg(),
+            fixture.call(),
+            ContractFixture::block(),
+            None,
+        )?;
+
+        let ExecutionResult::Success {
+            output: Output::Call(output),
+            ..
+        } = result
+        else {
+            anyhow::bail!("Expected a successful call, got: {result:?}");
+        };
+        assert_eq!(U256::from_be_slice(&output), U256::from(100));
+
+        Ok(())
+    }
+
+    #[test]
+    fn dry_run_with_diff_contains_changed_storage_slot() -> anyhow::Result<()> {
+        // PUSH1 0x00 SLOAD PUSH1 0x02 SSTORE PUSH1 0x2a PUSH1 0x01 SSTORE STOP
+        const STORE_CODE: [u8; 12] = [
+            0x60, 0x00, 0x54, 0x60, 0x02, 0x55, 0x60, 0x2a, 0x60, 0x01, 0x55, 0x00,
+        ];
+
+        let fixture = ContractFixture::new(Bytes::from_static(&STORE_CODE))?;
+
+        let ResultAndStateDiff {
+            result, state_diff, ..
+        } = dry_run_with_diff::<(), _, _>(
+            &fixture.blockchain,
+            &*fixture.state,
+            &fixture.state_overrides,
+            ContractFixture::cfg(),
+            fixture.call(),
+            ContractFixture::block(),
+            None,
+        )?;
+        assert!(result.is_success());
+
+        let contract = state_diff
+            .as_inner()
+            .get(&fixture.contract_address)
+            .expect("contract should be touched");
+
+        // Slot 0 was only read and slot 2 was overwritten with its original value
+        assert_eq!(contract.storage.len(), 1);
+        let slot = contract
+            .storage
+            .get(&U256::from(1))
+            .expect("slot 1 should be changed");
+        assert_eq!(slot.present_value(), U256::from(0x2a));
+        assert_eq!(slot.original_value(), U256::ZERO);
+
+        Ok(())
+    }
+
+    /// Dry runs a call to a contract that reverts with the provided revert
+    /// data.
+    fn dry_run_reverting_contract(revert_data: &[u8]) -> anyhow::Result<DryRunResult> {
+        // PUSH1 <length> DUP1 PUSH1 0x0b PUSH1 0x00 CODECOPY PUSH1 0x00 REVERT
+        const PREFIX_LENGTH: u8 = 11;
+
+        let length = u8::try_from(revert_data.len())?;
+        let mut code = vec![
+            0x60,
+            length,
+            0x80,
+            0x60,
+            PREFIX_LENGTH,
+            0x60,
+            0x00,
+            0x39,
+            0x60,
+            0x00,
+            0xfd,
+        ];
+        code.extend_from_slice(revert_data);
+
+        let fixture = ContractFixture::new(Bytes::from(code))?;
+
+        let result = dry_run::<(), _, _>(
+            &fixture.blockchain,
+            &*fixture.state,
+            &fixture.state_overrides,
+            ContractFixture::cfg(),
+            fixture.call(),
+            ContractFixture::block(),
+            None,
+        )?;
+
+        Ok(result)
+    }
+
+    #[test]
+    fn dry_run_decodes_require_revert_reason() -> anyhow::Result<()> {
+        // require(false, "msg")
+        let mut revert_data = crate::ERROR_SELECTOR.to_vec();
+        revert_data.extend_from_slice(&U256::from(32).to_be_bytes::<32>());
+        revert_data.extend_from_slice(&U256::from(3).to_be_bytes::<32>());
+        let mut reason = [0u8; 32];
+        reason[..3].copy_from_slice(b"msg");
+        revert_data.extend_from_slice(&reason);
+
+        let result = dry_run_reverting_contract(&revert_data)?;
+        assert!(matches!(result.result, ExecutionResult::Revert { .. }));
+        assert_eq!(
+            result.revert_reason,
+            Some(RevertReason::Error(String::from("msg")))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn dry_run_decodes_overflow_panic() -> anyhow::Result<()> {
+        // Panic(0x11)
+        let mut revert_data = crate::PANIC_SELECTOR.to_vec();
+        revert_data.extend_from_slice(&U256::from(0x11).to_be_bytes::<32>());
+
+        let result = dry_run_reverting_contract(&revert_data)?;
+        assert_eq!(
+            result.revert_reason,
+            Some(RevertReason::Panic(U256::from(0x11)))
+        );
+
+        Ok(())
+    }
+
+    /// Dry runs an EIP-2930 transaction that calls a contract which reads
+    /// storage slot zero, returning the gas used.
+    fn dry_run_eip2930_storage_read(warm_slot: bool) -> anyhow::Result<u64> {
+        // PUSH1 0x00 SLOAD STOP
+        const SLOAD_CODE: [u8; 4] = [0x60, 0x00, 0x54, 0x00];
+
+        let fixture = ContractFixture::new(Bytes::from_static(&SLOAD_CODE))?;
+
+        let access_list = if warm_slot {
+            vec![AccessListItem {
+                address: fixture.contract_address,
+                storage_keys: vec![B256::ZERO],
+            }]
+        } else {
+            Vec::new()
+        };
+
+        let caller = Address::random();
+        let request = Eip2930TransactionRequest {
+            chain_id: 1,
+            nonce: 0,
+            gas_price: U256::ZERO,
+            gas_limit: 100_000,
+            kind: TransactionKind::Call(fixture.contract_address),
+            value: U256::ZERO,
+            input: Bytes::new(),
+            access_list,
+        };
+        let transaction = ExecutableTransaction::with_caller(
+            SpecId::LATEST,
+            request.fake_sign(&caller).into(),
+            caller,
+        )?;
+
+        let DryRunResult { result, .. } = dry_run::<(), _, _>(
+            &fixture.blockchain,
+            &*fixture.state,
+            &fixture.state_overrides,
+            ContractFixture::cfg(),
+            transaction.into(),
+            ContractFixture::block(),
+            None,
+        )?;
+        assert!(result.is_success());
+
+        Ok(result.gas_used())
+    }
+
+    #[test]
+    fn dry_run_eip2930_access_list_warms_storage_slot() -> anyhow::Result<()> {
+        // 21,000 base + 3 (PUSH1) + 2,100 (cold SLOAD)
+        let cold_gas_used = dry_run_eip2930_storage_read(false)?;
+        assert_eq!(cold_gas_used, 23_103);
+
+        // 21,000 base + 2,400 (access list address) + 1,900 (access list storage
+        // key) + 3 (PUSH1) + 100 (warm SLOAD)
+        let warm_gas_used = dry_run_eip2930_storage_read(true)?;
+        assert_eq!(warm_gas_used, 25_403);
+
+        // The SLOAD is charged the warm price, which is 2,000 gas less than the
+        // cold price
+        let intrinsic_access_list_cost = 2_400 + 1_900;
+        assert_eq!(
+            cold_gas_used - (warm_gas_used - intrinsic_access_list_cost),
+            2_000
+        );
+
+        Ok(())
+    }
+}
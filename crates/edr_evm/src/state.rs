@@ -42,6 +42,10 @@ pub enum StateError {
         /// Whether the state root was intended for a fork
         is_fork: bool,
     },
+    /// Merkle proofs cannot be generated for forked state, as the local state
+    /// root doesn't correspond to the remote state trie
+    #[error("Merkle proofs are not supported for forked state.")]
+    ProofsUnsupported,
     /// Error from the underlying RPC client
     #[error(transparent)]
     Remote(#[from] RpcClientError),
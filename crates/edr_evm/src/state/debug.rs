@@ -1,7 +1,7 @@
 use std::{fmt::Debug, ops::Deref};
 
 use auto_impl::auto_impl;
-use edr_eth::{Address, B256, U256};
+use edr_eth::{Address, Bytes, B256, U256};
 use revm::primitives::{AccountInfo, Bytecode};
 
 type BoxedAccountModifierFn = Box<dyn Fn(&mut U256, &mut u64, &mut Option<Bytecode>) + Send>;
@@ -42,6 +42,10 @@ pub trait StateDebug {
     /// The state's error type.
     type Error;
 
+    /// Generates a Merkle proof of the account at the specified address. If
+    /// the account doesn't exist, the proof shows its absence.
+    fn account_proof(&self, address: &Address) -> Result<Vec<Bytes>, Self::Error>;
+
     /// Retrieves the storage root of the account at the specified address.
     fn account_storage_root(&self, address: &Address) -> Result<Option<B256>, Self::Error>;
 
@@ -81,4 +85,8 @@ pub trait StateDebug {
 
     /// Retrieves the storage root of the database.
     fn state_root(&self) -> Result<B256, Self::Error>;
+
+    /// Generates a Merkle proof of the storage slot at the specified address
+    /// and index. If the slot doesn't exist, the proof shows its absence.
+    fn storage_proof(&self, address: &Address, index: &U256) -> Result<Vec<Bytes>, Self::Error>;
 }
@@ -1,6 +1,6 @@
 use std::sync::Arc;
 
-use edr_eth::{remote::RpcClient, trie::KECCAK_NULL_RLP, Address, B256, U256};
+use edr_eth::{remote::RpcClient, trie::KECCAK_NULL_RLP, Address, Bytes, B256, U256};
 use parking_lot::{Mutex, RwLock, RwLockUpgradableReadGuard};
 use revm::{
     db::components::{State, StateRef},
@@ -123,6 +123,10 @@ impl DatabaseCommit for ForkState {
 impl StateDebug for ForkState {
     type Error = StateError;
 
+    fn account_proof(&self, _address: &Address) -> Result<Vec<Bytes>, Self::Error> {
+        Err(StateError::ProofsUnsupported)
+    }
+
     fn account_storage_root(&self, _address: &Address) -> Result<Option<B256>, Self::Error> {
         // HACK: Hardhat ignores the storage root, so we set it to the default value
         Ok(Some(KECCAK_NULL_RLP))
@@ -217,6 +221,10 @@ impl StateDebug for ForkState {
             next_state_root
         })
     }
+
+    fn storage_proof(&self, _address: &Address, _index: &U256) -> Result<Vec<Bytes>, Self::Error> {
+        Err(StateError::ProofsUnsupported)
+    }
 }
 
 #[cfg(all(test, feature = "test-remote"))]
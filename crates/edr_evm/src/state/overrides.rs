@@ -280,3 +280,95 @@ impl<'state, StateT: StateRef> StateRef for StateRefOverrider<'state, StateT> {
             .account_storage_at(&self.state, &address, &index)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::{StateDebug, StateError, TrieState};
+
+    fn state_with_storage(address: Address) -> Result<TrieState, StateError> {
+        let mut state = TrieState::default();
+        state.insert_account(
+            address,
+            AccountInfo {
+                balance: U256::from(100),
+                ..AccountInfo::default()
+            },
+        )?;
+        state.set_account_storage_slot(address, U256::from(1), U256::from(10))?;
+        state.set_account_storage_slot(address, U256::from(2), U256::from(20))?;
+
+        Ok(state)
+    }
+
+    fn storage_options(slot: u64, value: u64) -> Option<HashMap<B256, U256>> {
+        Some(
+            [(B256::from(U256::from(slot)), U256::from(value))]
+                .into_iter()
+                .collect(),
+        )
+    }
+
+    fn overrides_for(
+        address: Address,
+        storage: Option<HashMap<B256, U256>>,
+        storage_diff: Option<HashMap<B256, U256>>,
+    ) -> Result<StateOverrides, AccountOverrideConversionError> {
+        let options = AccountOverrideOptions {
+            balance: None,
+            nonce: Some(7),
+            code: None,
+            storage,
+            storage_diff,
+        };
+
+        StateOverrides::try_from(
+            [(address, options)]
+                .into_iter()
+                .collect::<StateOverrideOptions>(),
+        )
+    }
+
+    #[test]
+    fn full_storage_override_replaces_storage() -> anyhow::Result<()> {
+        let address = Address::random();
+        let state = state_with_storage(address)?;
+        let overrides = overrides_for(address, storage_options(1, 11), None)?;
+
+        let overrider = StateRefOverrider::new(&overrides, &state);
+        assert_eq!(overrider.storage(address, U256::from(1))?, U256::from(11));
+        assert_eq!(overrider.storage(address, U256::from(2))?, U256::ZERO);
+
+        let account = overrider.basic(address)?.expect("account exists");
+        assert_eq!(account.balance, U256::from(100));
+        assert_eq!(account.nonce, 7);
+
+        Ok(())
+    }
+
+    #[test]
+    fn storage_diff_override_patches_storage() -> anyhow::Result<()> {
+        let address = Address::random();
+        let state = state_with_storage(address)?;
+        let overrides = overrides_for(address, None, storage_options(1, 11))?;
+
+        let overrider = StateRefOverrider::new(&overrides, &state);
+        assert_eq!(overrider.storage(address, U256::from(1))?, U256::from(11));
+        assert_eq!(overrider.storage(address, U256::from(2))?, U256::from(20));
+
+        // The underlying state is untouched
+        assert_eq!(state.storage(address, U256::from(1))?, U256::from(10));
+
+        Ok(())
+    }
+
+    #[test]
+    fn full_and_diff_storage_overrides_conflict() {
+        let address = Address::random();
+
+        assert!(matches!(
+            overrides_for(address, storage_options(1, 11), storage_options(2, 22)),
+            Err(AccountOverrideConversionError::StorageOverrideConflict)
+        ));
+    }
+}
@@ -1,6 +1,6 @@
 mod account;
 
-use edr_eth::{account::KECCAK_EMPTY, Address, B256, U256};
+use edr_eth::{account::KECCAK_EMPTY, Address, Bytes, B256, U256};
 use revm::{
     db::StateRef,
     primitives::{Account, AccountInfo, Bytecode, HashMap},
@@ -173,6 +173,10 @@ impl DatabaseCommit for TrieState {
 impl StateDebug for TrieState {
     type Error = StateError;
 
+    fn account_proof(&self, address: &Address) -> Result<Vec<Bytes>, Self::Error> {
+        Ok(self.accounts.account_proof(address))
+    }
+
     fn account_storage_root(&self, address: &Address) -> Result<Option<B256>, Self::Error> {
         Ok(self.accounts.storage_root(address))
     }
@@ -243,4 +247,8 @@ impl StateDebug for TrieState {
     fn state_root(&self) -> Result<B256, Self::Error> {
         Ok(self.accounts.state_root())
     }
+
+    fn storage_proof(&self, address: &Address, index: &U256) -> Result<Vec<Bytes>, Self::Error> {
+        Ok(self.accounts.account_storage_proof(address, index))
+    }
 }
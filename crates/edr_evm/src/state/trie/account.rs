@@ -1,8 +1,8 @@
-use std::{collections::BTreeMap, fmt::Debug, sync::Arc};
+use std::{collections::BTreeMap, fmt::Debug, num::NonZeroUsize, sync::Arc};
 
 use alloy_rlp::Decodable;
 use cita_trie::{MemoryDB, PatriciaTrie, Trie as CitaTrie};
-use edr_eth::{account::BasicAccount, Address, B256, U256};
+use edr_eth::{account::BasicAccount, Address, Bytes, B256, U256};
 use hasher::{Hasher, HasherKeccak};
 use revm::primitives::{Account, AccountInfo, HashMap};
 
@@ -13,6 +13,46 @@ type AccountStorageTries = HashMap<Address, (Arc<MemoryDB>, B256)>;
 
 type Trie = PatriciaTrie<MemoryDB, HasherKeccak>;
 
+/// The number of changed storage slots above which [`AccountTrie::commit`]
+/// computes storage roots concurrently. For smaller change sets, spawning
+/// threads costs more than it saves.
+const PARALLEL_COMMIT_STORAGE_THRESHOLD: usize = 1024;
+
+/// A pending change to the storage trie of an account.
+struct StorageUpdate<'a> {
+    address: &'a Address,
+    account: &'a Account,
+    storage_trie_db: Arc<MemoryDB>,
+    storage_root: B256,
+}
+
+impl<'a> StorageUpdate<'a> {
+    /// Applies the account's storage changes to its storage trie, updating the
+    /// storage root.
+    fn apply(&mut self) {
+        if self.account.storage.is_empty() {
+            return;
+        }
+
+        let mut storage_trie = Trie::from(
+            self.storage_trie_db.clone(),
+            Arc::new(HasherKeccak::new()),
+            self.storage_root.as_slice(),
+        )
+        .expect("Invalid storage root");
+
+        self.account.storage.iter().for_each(|(index, value)| {
+            AccountTrie::set_account_storage_slot_in(
+                index,
+                &value.present_value,
+                &mut storage_trie,
+            );
+        });
+
+        self.storage_root = B256::from_slice(&storage_trie.root().unwrap());
+    }
+}
+
 /// A trie for maintaining the state of accounts and their storage.
 #[derive(Debug)]
 pub struct AccountTrie {
@@ -145,6 +185,53 @@ impl AccountTrie {
             .map(|encoded_account| BasicAccount::decode(&mut encoded_account.as_slice()).unwrap())
     }
 
+    /// Generates a Merkle proof of the account at the specified address,
+    /// consisting of the RLP-encoded state trie nodes along its path. If the
+    /// account doesn't exist, the proof shows its absence.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub fn account_proof(&self, address: &Address) -> Vec<Bytes> {
+        let state_trie = Trie::from(
+            self.state_trie_db.clone(),
+            Arc::new(HasherKeccak::new()),
+            self.state_root.as_slice(),
+        )
+        .expect("Invalid state root");
+
+        let hashed_address = HasherKeccak::new().digest(address.as_slice());
+        state_trie
+            .get_proof(&hashed_address)
+            .unwrap()
+            .into_iter()
+            .map(Bytes::from)
+            .collect()
+    }
+
+    /// Generates a Merkle proof of the storage slot at the specified address
+    /// and index, consisting of the RLP-encoded storage trie nodes along its
+    /// path. If the slot doesn't exist, the proof shows its absence.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub fn account_storage_proof(&self, address: &Address, index: &U256) -> Vec<Bytes> {
+        self.storage_trie_dbs.get(address).map_or_else(
+            Vec::new,
+            |(storage_trie_db, storage_root)| {
+                let storage_trie = Trie::from(
+                    storage_trie_db.clone(),
+                    Arc::new(HasherKeccak::new()),
+                    storage_root.as_slice(),
+                )
+                .expect("Invalid storage root");
+
+                let hashed_index = HasherKeccak::new().digest(&index.to_be_bytes::<32>());
+                storage_trie
+                    .get_proof(&hashed_index)
+                    .unwrap()
+                    .into_iter()
+                    .map(Bytes::from)
+                    .collect()
+            },
+        )
+    }
+
     /// Retrieves the storage storage corresponding to the account at the
     /// specified address and the specified index, if they exist.
     pub fn account_storage_slot(&self, address: &Address, index: &U256) -> Option<U256> {
@@ -167,8 +254,34 @@ impl AccountTrie {
     }
 
     /// Commits changes to the state.
+    ///
+    /// Storage tries of different accounts are independent, so for large
+    /// change sets their roots are computed concurrently, using the available
+    /// parallelism. Otherwise, all changes are applied on the calling thread.
     #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
     pub fn commit(&mut self, changes: &HashMap<Address, Account>) {
+        let num_storage_updates: usize =
+            changes.values().map(|account| account.storage.len()).sum();
+
+        let parallelism = if num_storage_updates > PARALLEL_COMMIT_STORAGE_THRESHOLD {
+            std::thread::available_parallelism().unwrap_or(NonZeroUsize::new(1).unwrap())
+        } else {
+            NonZeroUsize::new(1).unwrap()
+        };
+
+        self.commit_with_parallelism(changes, parallelism);
+    }
+
+    /// Commits changes to the state, computing the accounts' storage roots on
+    /// at most `parallelism` threads.
+    ///
+    /// A parallelism of one applies all changes on the calling thread.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub fn commit_with_parallelism(
+        &mut self,
+        changes: &HashMap<Address, Account>,
+        parallelism: NonZeroUsize,
+    ) {
         let mut state_trie = Trie::from(
             self.state_trie_db.clone(),
             Arc::new(HasherKeccak::new()),
@@ -176,6 +289,7 @@ impl AccountTrie {
         )
         .expect("Invalid state root");
 
+        let mut storage_updates = Vec::new();
         changes.iter().for_each(|(address, account)| {
             if account.is_touched() {
                 if (account.is_empty() && !account.is_created()) || account.is_selfdestructed() {
@@ -203,33 +317,51 @@ impl AccountTrie {
                             (storage_trie_db, storage_root)
                         });
 
-                    if !account.storage.is_empty() {
-                        let mut storage_trie = Trie::from(
-                            storage_trie_db.clone(),
-                            Arc::new(HasherKeccak::new()),
-                            storage_root.as_slice(),
-                        )
-                        .expect("Invalid storage root");
-
-                        account.storage.iter().for_each(|(index, value)| {
-                            Self::set_account_storage_slot_in(
-                                index,
-                                &value.present_value,
-                                &mut storage_trie,
-                            );
-                        });
+                    storage_updates.push(StorageUpdate {
+                        address,
+                        account,
+                        storage_trie_db: storage_trie_db.clone(),
+                        storage_root: *storage_root,
+                    });
+                }
+            }
+        });
 
-                        *storage_root = B256::from_slice(&storage_trie.root().unwrap());
-                    }
+        Self::update_storage_roots(&mut storage_updates, parallelism);
 
-                    Self::set_account_in(address, &account.info, *storage_root, &mut state_trie);
-                }
+        storage_updates.into_iter().for_each(|update| {
+            if let Some((_db, storage_root)) = self.storage_trie_dbs.get_mut(update.address) {
+                *storage_root = update.storage_root;
             }
+
+            Self::set_account_in(
+                update.address,
+                &update.account.info,
+                update.storage_root,
+                &mut state_trie,
+            );
         });
 
         self.state_root = B256::from_slice(&state_trie.root().unwrap());
     }
 
+    /// Helper function for applying the storage changes of the provided
+    /// updates to their storage tries, spreading the work over at most
+    /// `parallelism` threads.
+    fn update_storage_roots(updates: &mut [StorageUpdate<'_>], parallelism: NonZeroUsize) {
+        let chunk_size = updates.len().div_ceil(parallelism.get()).max(1);
+        if chunk_size >= updates.len() {
+            updates.iter_mut().for_each(StorageUpdate::apply);
+            return;
+        }
+
+        std::thread::scope(|scope| {
+            updates.chunks_mut(chunk_size).for_each(|chunk| {
+                scope.spawn(|| chunk.iter_mut().for_each(StorageUpdate::apply));
+            });
+        });
+    }
+
     /// Sets the provided account at the specified address.
     #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
     pub fn set_account(&mut self, address: &Address, account_info: &AccountInfo) {
@@ -759,4 +891,115 @@ mod tests {
         let storage_value = state.account_storage_slot(&expected_address, &expected_index);
         assert_eq!(storage_value, None);
     }
+
+    #[test]
+    fn commit_with_parallelism_matches_serial_commit() {
+        use revm::primitives::{AccountStatus, StorageSlot};
+
+        const NUM_ACCOUNTS: u64 = 64;
+        const NUM_SLOTS: u64 = 8;
+
+        let changes: HashMap<Address, Account> = (1..=NUM_ACCOUNTS)
+            .map(|idx| {
+                let address = Address::with_last_byte(idx as u8);
+                let storage = (0..NUM_SLOTS)
+                    .map(|slot| {
+                        (
+                            U256::from(slot),
+                            StorageSlot::new_changed(U256::ZERO, U256::from(idx * slot + 1)),
+                        )
+                    })
+                    .collect();
+
+                let account = Account {
+                    info: AccountInfo {
+                        balance: U256::from(idx),
+                        nonce: idx,
+                        ..AccountInfo::default()
+                    },
+                    storage,
+                    status: AccountStatus::Touched,
+                };
+
+                (address, account)
+            })
+            .collect();
+
+        let mut serial = AccountTrie::default();
+        serial.commit_with_parallelism(&changes, NonZeroUsize::new(1).unwrap());
+
+        let mut parallel = AccountTrie::default();
+        parallel.commit_with_parallelism(&changes, NonZeroUsize::new(4).unwrap());
+
+        assert_ne!(serial.state_root(), KECCAK_NULL_RLP);
+        assert_eq!(serial.state_root(), parallel.state_root());
+
+        for (address, account) in &changes {
+            assert_eq!(serial.storage_root(address), parallel.storage_root(address));
+
+            for (index, slot) in &account.storage {
+                assert_eq!(
+                    parallel.account_storage_slot(address, index),
+                    Some(slot.present_value)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn account_proofs_verify_against_roots() {
+        let existing_address = Address::with_last_byte(1);
+        let missing_address = Address::with_last_byte(2);
+        let index = U256::from(100);
+        let value = U256::from(42);
+
+        let mut storage = Storage::new();
+        storage.insert(index, value);
+
+        let account = BasicAccount {
+            nonce: 1,
+            balance: U256::from(100u32),
+            storage_root: storage_root(storage.iter()),
+            code_hash: KECCAK_EMPTY,
+        };
+
+        let changes: Vec<Vec<AccountChange<'_>>> =
+            vec![vec![(&existing_address, Some((account.clone(), &storage)))]];
+        let state = AccountTrie::from_changes(changes);
+
+        let verifier = Trie::new(Arc::new(MemoryDB::new(true)), Arc::new(HasherKeccak::new()));
+        let verify = |root: B256, key: &[u8], proof: Vec<Bytes>| {
+            verifier
+                .verify_proof(
+                    root.as_slice(),
+                    &HasherKeccak::new().digest(key),
+                    proof.into_iter().map(Vec::from).collect(),
+                )
+                .expect("Proof is valid")
+        };
+
+        // Inclusion proof of the account
+        let proven_account = verify(
+            state.state_root(),
+            existing_address.as_slice(),
+            state.account_proof(&existing_address),
+        );
+        assert_eq!(proven_account, Some(alloy_rlp::encode(&account)));
+
+        // Inclusion proof of the storage slot
+        let proven_value = verify(
+            account.storage_root,
+            &index.to_be_bytes::<32>(),
+            state.account_storage_proof(&existing_address, &index),
+        );
+        assert_eq!(proven_value, Some(alloy_rlp::encode(value)));
+
+        // Exclusion proof of a non-existent account
+        let proof = state.account_proof(&missing_address);
+        assert!(!proof.is_empty());
+        assert_eq!(
+            verify(state.state_root(), missing_address.as_slice(), proof),
+            None
+        );
+    }
 }
@@ -0,0 +1,56 @@
+use std::{
+    fmt::Debug,
+    sync::atomic::{AtomicU64, Ordering},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+/// A source of the current time, used for deriving block timestamps.
+pub trait TimeSource: Debug + Send + Sync {
+    /// Returns the current time in seconds since the UNIX epoch.
+    fn unix_timestamp(&self) -> u64;
+}
+
+/// A [`TimeSource`] that uses the system's wall-clock time.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct SystemTimeSource;
+
+impl TimeSource for SystemTimeSource {
+    fn unix_timestamp(&self) -> u64 {
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Current time must be after unix epoch")
+            .as_secs()
+    }
+}
+
+/// A [`TimeSource`] that only advances when explicitly told to, for
+/// deterministic tests.
+#[derive(Debug, Default)]
+pub struct ManualTimeSource {
+    timestamp: AtomicU64,
+}
+
+impl ManualTimeSource {
+    /// Constructs a new instance that starts at the provided UNIX timestamp.
+    pub fn new(timestamp: u64) -> Self {
+        Self {
+            timestamp: AtomicU64::new(timestamp),
+        }
+    }
+
+    /// Advances the time by the provided number of seconds.
+    pub fn advance(&self, seconds: u64) {
+        self.timestamp.fetch_add(seconds, Ordering::SeqCst);
+    }
+
+    /// Sets the time to the provided UNIX timestamp.
+    pub fn set(&self, timestamp: u64) {
+        self.timestamp.store(timestamp, Ordering::SeqCst);
+    }
+}
+
+impl TimeSource for ManualTimeSource {
+    fn unix_timestamp(&self) -> u64 {
+        self.timestamp.load(Ordering::SeqCst)
+    }
+}
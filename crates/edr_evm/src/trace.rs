@@ -1,4 +1,4 @@
-use std::{cell::RefCell, fmt::Debug, rc::Rc, sync::Arc};
+use std::{cell::RefCell, collections::BTreeMap, fmt::Debug, rc::Rc, sync::Arc};
 
 use edr_eth::{Address, Bytes, U256};
 use revm::{
@@ -148,6 +148,11 @@ fn instruction_handler<
 
             // execute instruction.
             instruction(interpreter, host);
+
+            host.context
+                .external
+                .get_context_data()
+                .step_end(interpreter);
         },
     )
 }
@@ -193,6 +198,31 @@ pub struct Trace {
     pub messages: Vec<TraceMessage>,
     /// The return value of the call
     pub return_value: Bytes,
+    /// The maximum call depth that was reached, where the depth of the
+    /// top-level call is zero
+    pub max_depth: usize,
+}
+
+/// A call or create frame, reconstructed from a [`Trace`]'s before and after
+/// messages.
+#[derive(Clone, Debug)]
+pub struct CallFrame {
+    /// Call depth, where the depth of the top-level call is zero
+    pub depth: usize,
+    /// Caller
+    pub caller: Address,
+    /// Callee. None for create frames.
+    pub to: Option<Address>,
+    /// Address of the created contract, if a create frame succeeded
+    pub created: Option<Address>,
+    /// Value
+    pub value: U256,
+    /// Input data, or init code for create frames
+    pub input: Bytes,
+    /// The result of the frame
+    pub result: ExecutionResult,
+    /// Frames that were entered from this frame
+    pub children: Vec<CallFrame>,
 }
 
 /// A single EVM step.
@@ -206,10 +236,14 @@ pub struct Step {
     pub opcode: u8,
     /// The top entry on the stack. None if the stack is empty.
     pub stack_top: Option<U256>,
-    // /// The amount of gas that was used by the step
-    // pub gas_cost: u64,
-    // /// The amount of gas that was refunded by the step
-    // pub gas_refunded: i64,
+    /// The amount of gas remaining before executing the step
+    pub gas_remaining: u64,
+    /// The amount of gas that was used by the step, including its dynamic
+    /// portion (e.g. storage writes, memory expansion, and gas forwarded to
+    /// a call)
+    pub gas_cost: u64,
+    /// The running refund counter after executing the step
+    pub refund_counter: i64,
     // /// The contract being executed
     // pub contract: AccountInfo,
     // /// The address of the contract
@@ -219,6 +253,7 @@ pub struct Step {
 impl Trace {
     /// Adds a before message
     pub fn add_before(&mut self, message: BeforeMessage) {
+        self.max_depth = self.max_depth.max(message.depth);
         self.messages.push(TraceMessage::Before(message));
     }
 
@@ -227,13 +262,126 @@ impl Trace {
         self.messages.push(TraceMessage::After(result));
     }
 
-    /// Adds a VM step to the trace.
-    pub fn add_step(&mut self, depth: u64, pc: usize, opcode: u8, stack_top: Option<U256>) {
+    /// Reconstructs the tree of call and create frames from the trace's
+    /// messages. Frames that have not exited are omitted.
+    pub fn call_frames(&self) -> Vec<CallFrame> {
+        let mut roots = Vec::new();
+        let mut pending: Vec<(&BeforeMessage, Vec<CallFrame>)> = Vec::new();
+
+        for message in &self.messages {
+            match message {
+                TraceMessage::Before(before) => pending.push((before, Vec::new())),
+                TraceMessage::Step(_) => (),
+                TraceMessage::After(result) => {
+                    let Some((before, children)) = pending.pop() else {
+                        continue;
+                    };
+
+                    let created = match result {
+                        ExecutionResult::Success {
+                            output: Output::Create(_, address),
+                            ..
+                        } => *address,
+                        _ => None,
+                    };
+
+                    let frame = CallFrame {
+                        depth: before.depth,
+                        caller: before.caller,
+                        to: before.to,
+                        created,
+                        value: before.value,
+                        input: before.data.clone(),
+                        result: result.clone(),
+                        children,
+                    };
+
+                    if let Some((_, siblings)) = pending.last_mut() {
+                        siblings.push(frame);
+                    } else {
+                        roots.push(frame);
+                    }
+                }
+            }
+        }
+
+        roots
+    }
+
+    /// Folds the trace's call frames into the folded stacks format of Brendan
+    /// Gregg's flamegraph tools: one `frame1;frame2 gas` line per call stack,
+    /// where `gas` is the gas used by the innermost frame itself, excluding
+    /// the gas used by the frames that it entered.
+    ///
+    /// Frames are labelled by their contract address, followed by the function
+    /// selector if the call's input contains one. Create frames are labelled by
+    /// the created contract's address, if any. Identical stacks are merged and
+    /// stacks that didn't use any gas are omitted.
+    pub fn to_folded_stacks(&self) -> String {
+        fn fold_frame(frame: &CallFrame, parent_stack: &str, stacks: &mut BTreeMap<String, u64>) {
+            let address = frame
+                .to
+                .or(frame.created)
+                .map_or_else(|| String::from("CREATE"), |address| address.to_string());
+
+            let label = match frame.input.get(..4) {
+                Some(selector) if frame.to.is_some() => {
+                    format!("{address}:{}", Bytes::copy_from_slice(selector))
+                }
+                _ => address,
+            };
+
+            let stack = if parent_stack.is_empty() {
+                label
+            } else {
+                format!("{parent_stack};{label}")
+            };
+
+            let children_gas_used: u64 = frame
+                .children
+                .iter()
+                .map(|child| child.result.gas_used())
+                .sum();
+
+            *stacks.entry(stack.clone()).or_default() +=
+                frame.result.gas_used().saturating_sub(children_gas_used);
+
+            for child in &frame.children {
+                fold_frame(child, &stack, stacks);
+            }
+        }
+
+        let mut stacks = BTreeMap::new();
+        for frame in self.call_frames() {
+            fold_frame(&frame, "", &mut stacks);
+        }
+
+        stacks
+            .into_iter()
+            .filter(|(_, gas)| *gas > 0)
+            .map(|(stack, gas)| format!("{stack} {gas}\n"))
+            .collect()
+    }
+
+    /// Adds a VM step to the trace. Its gas cost is unknown until the step has
+    /// been executed.
+    pub fn add_step(
+        &mut self,
+        depth: u64,
+        pc: usize,
+        opcode: u8,
+        stack_top: Option<U256>,
+        gas_remaining: u64,
+        refund_counter: i64,
+    ) {
         self.messages.push(TraceMessage::Step(Step {
             pc: pc as u64,
             depth,
             opcode,
             stack_top,
+            gas_remaining,
+            gas_cost: 0,
+            refund_counter,
         }));
     }
 }
@@ -245,6 +393,7 @@ pub struct TraceCollector {
     traces: Vec<Trace>,
     pending_before: Option<BeforeMessage>,
     is_new_trace: bool,
+    is_step_pending: bool,
 }
 
 impl TraceCollector {
@@ -443,8 +592,24 @@ impl TraceCollector {
                 interp.program_counter(),
                 interp.current_opcode(),
                 interp.stack.data().last().cloned(),
+                interp.gas.remaining(),
+                interp.gas.refunded(),
             );
         }
+
+        self.is_step_pending = !skip_step;
+    }
+
+    fn step_end(&mut self, interp: &Interpreter) {
+        if !self.is_step_pending {
+            return;
+        }
+        self.is_step_pending = false;
+
+        if let Some(TraceMessage::Step(step)) = self.current_trace_mut().messages.last_mut() {
+            step.gas_cost = step.gas_remaining.saturating_sub(interp.gas.remaining());
+            step.refund_counter = interp.gas.refunded();
+        }
     }
 
     fn call_transaction_end<DatabaseT: Database>(
@@ -474,6 +639,7 @@ impl Default for TraceCollector {
             traces: Vec::new(),
             pending_before: None,
             is_new_trace: true,
+            is_step_pending: false,
         }
     }
 }
@@ -483,3 +649,283 @@ impl GetContextData<TraceCollector> for TraceCollector {
         self
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use edr_eth::{SpecId, B256};
+    use revm::primitives::{BlockEnv, CfgEnv, CfgEnvWithHandlerCfg, HashMap, TransactTo, TxEnv};
+
+    use super::*;
+    use crate::{
+        blockchain::{Blockchain, GenesisBlockOptions, LocalBlockchain},
+        dry_run,
+        state::{AccountOverride, StateDiff, StateOverrides, StorageOverride},
+        DebugContext,
+    };
+
+    #[test]
+    fn steps_record_dynamic_sstore_gas_costs() -> anyhow::Result<()> {
+        // PUSH1 0x00 SLOAD POP
+        // PUSH1 0x01 PUSH1 0x00 SSTORE
+        // PUSH1 0x02 PUSH1 0x01 SSTORE
+        // PUSH1 0x03 PUSH1 0x01 SSTORE
+        // PUSH1 0x01 PUSH1 0x01 SSTORE
+        // STOP
+        const STORE_CODE: [u8; 25] = [
+            0x60, 0x00, 0x54, 0x50, 0x60, 0x01, 0x60, 0x00, 0x55, 0x60, 0x02, 0x60, 0x01, 0x55,
+            0x60, 0x03, 0x60, 0x01, 0x55, 0x60, 0x01, 0x60, 0x01, 0x55, 0x00,
+        ];
+        const GAS_LIMIT: u64 = 100_000;
+
+        let blockchain = LocalBlockchain::new(
+            StateDiff::default(),
+            1,
+            SpecId::LATEST,
+            GenesisBlockOptions {
+                mix_hash: Some(B256::ZERO),
+                ..GenesisBlockOptions::default()
+            },
+        )?;
+
+        let state = blockchain.state_at_block_number(0, &BTreeMap::new())?;
+
+        let contract_address = Address::random();
+        let state_overrides = StateOverrides::new(
+            [(
+                contract_address,
+                AccountOverride {
+                    balance: None,
+                    nonce: None,
+                    code: Some(Bytecode::new_raw(Bytes::from_static(&STORE_CODE))),
+                    storage: Some(StorageOverride::Diff(
+                        [(U256::from(1), U256::from(1))]
+                            .into_iter()
+                            .collect::<HashMap<_, _>>(),
+                    )),
+                },
+            )]
+            .into_iter()
+            .collect(),
+        );
+
+        let cfg = CfgEnvWithHandlerCfg::new_with_spec_id(CfgEnv::default(), SpecId::LATEST);
+        let transaction = TxEnv {
+            transact_to: TransactTo::Call(contract_address),
+            gas_limit: GAS_LIMIT,
+            ..TxEnv::default()
+        };
+        let block = BlockEnv {
+            prevrandao: Some(B256::ZERO),
+            ..BlockEnv::default()
+        };
+
+        let mut trace_collector = TraceCollector::default();
+        let result = dry_run(
+            &blockchain,
+            &*state,
+            &state_overrides,
+            cfg,
+            transaction,
+            block,
+            Some(DebugContext {
+                data: &mut trace_collector,
+                register_handles_fn: register_trace_collector_handles,
+            }),
+        )?;
+        assert!(result.result.is_success());
+
+        let traces = trace_collector.into_traces();
+        assert_eq!(traces.len(), 1);
+
+        let steps: Vec<&Step> = traces[0]
+            .messages
+            .iter()
+            .filter_map(|message| match message {
+                TraceMessage::Step(step) => Some(step),
+                _ => None,
+            })
+            .collect();
+
+        // The intrinsic gas cost is deducted before the first step
+        assert_eq!(steps[0].gas_remaining, GAS_LIMIT - 21_000);
+
+        // Each step's cost is reflected in the next step's remaining gas
+        for window in steps.windows(2) {
+            assert_eq!(
+                window[0].gas_remaining - window[0].gas_cost,
+                window[1].gas_remaining
+            );
+        }
+
+        // The cold SLOAD warms up slot 0
+        let sload = steps
+            .iter()
+            .find(|step| step.opcode == opcode::SLOAD)
+            .expect("SLOAD is executed");
+        assert_eq!(sload.gas_cost, 2_100);
+
+        let sstores: Vec<(u64, i64)> = steps
+            .iter()
+            .filter(|step| step.opcode == opcode::SSTORE)
+            .map(|step| (step.gas_cost, step.refund_counter))
+            .collect();
+
+        assert_eq!(
+            sstores,
+            vec![
+                // Setting a warm, zero slot
+                (20_000, 0),
+                // Updating a cold, non-zero slot
+                (5_000, 0),
+                // Updating a dirty slot
+                (100, 0),
+                // Restoring a dirty slot to its original value
+                (100, 2_800),
+            ]
+        );
+
+        Ok(())
+    }
+
+    /// Dry runs a call to a contract that calls another contract, returning
+    /// the trace, the transaction's result, and the caller and callee
+    /// addresses.
+    fn trace_nested_call() -> anyhow::Result<(Trace, ExecutionResult, Address, Address)> {
+        let blockchain = LocalBlockchain::new(
+            StateDiff::default(),
+            1,
+            SpecId::LATEST,
+            GenesisBlockOptions {
+                mix_hash: Some(B256::ZERO),
+                ..GenesisBlockOptions::default()
+            },
+        )?;
+
+        let state = blockchain.state_at_block_number(0, &BTreeMap::new())?;
+
+        let caller_address = Address::random();
+        let callee_address = Address::random();
+
+        // PUSH1 0x00 PUSH1 0x00 PUSH1 0x00 PUSH1 0x00 PUSH1 0x00
+        // PUSH20 <callee> GAS CALL STOP
+        let mut caller_code = vec![0x60, 0x00, 0x60, 0x00, 0x60, 0x00, 0x60, 0x00, 0x60, 0x00];
+        caller_code.push(0x73);
+        caller_code.extend_from_slice(callee_address.as_slice());
+        caller_code.extend_from_slice(&[0x5a, 0xf1, 0x00]);
+
+        // PUSH1 0x01 POP STOP
+        let callee_code = vec![0x60, 0x01, 0x50, 0x00];
+
+        let code_override = |code: Vec<u8>| AccountOverride {
+            balance: None,
+            nonce: None,
+            code: Some(Bytecode::new_raw(Bytes::from(code))),
+            storage: None,
+        };
+
+        let state_overrides = StateOverrides::new(
+            [
+                (caller_address, code_override(caller_code)),
+                (callee_address, code_override(callee_code)),
+            ]
+            .into_iter()
+            .collect(),
+        );
+
+        let cfg = CfgEnvWithHandlerCfg::new_with_spec_id(CfgEnv::default(), SpecId::LATEST);
+        let transaction = TxEnv {
+            transact_to: TransactTo::Call(caller_address),
+            gas_limit: 100_000,
+            ..TxEnv::default()
+        };
+        let block = BlockEnv {
+            prevrandao: Some(B256::ZERO),
+            ..BlockEnv::default()
+        };
+
+        let mut trace_collector = TraceCollector::default();
+        let result = dry_run(
+            &blockchain,
+            &*state,
+            &state_overrides,
+            cfg,
+            transaction,
+            block,
+            Some(DebugContext {
+                data: &mut trace_collector,
+                register_handles_fn: register_trace_collector_handles,
+            }),
+        )?;
+
+        let mut traces = trace_collector.into_traces();
+        assert_eq!(traces.len(), 1);
+
+        Ok((
+            traces.remove(0),
+            result.result,
+            caller_address,
+            callee_address,
+        ))
+    }
+
+    #[test]
+    fn call_frames_form_a_tree() -> anyhow::Result<()> {
+        let (trace, result, caller_address, callee_address) = trace_nested_call()?;
+        assert!(result.is_success());
+
+        let frames = trace.call_frames();
+        assert_eq!(frames.len(), 1);
+
+        let root = &frames[0];
+        assert_eq!(root.depth, 0);
+        assert_eq!(root.to, Some(caller_address));
+        assert_eq!(root.created, None);
+        assert!(root.result.is_success());
+        assert_eq!(root.children.len(), 1);
+
+        let child = &root.children[0];
+        assert_eq!(child.depth, 1);
+        assert_eq!(child.caller, caller_address);
+        assert_eq!(child.to, Some(callee_address));
+        assert_eq!(child.value, U256::ZERO);
+        assert!(child.input.is_empty());
+        assert!(child.result.is_success());
+        assert!(child.children.is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn folded_stacks_sum_to_total_gas() -> anyhow::Result<()> {
+        let (trace, result, caller_address, callee_address) = trace_nested_call()?;
+        assert!(result.is_success());
+
+        let folded = trace.to_folded_stacks();
+        let stacks = folded
+            .lines()
+            .map(|line| {
+                let (stack, gas) = line.rsplit_once(' ').expect("Line contains gas");
+                Ok((stack.to_owned(), gas.parse::<u64>()?))
+            })
+            .collect::<anyhow::Result<Vec<_>>>()?;
+
+        let stack_names: Vec<&str> = stacks.iter().map(|(stack, _)| stack.as_str()).collect();
+        let caller_stack = caller_address.to_string();
+        let callee_stack = format!("{caller_address};{callee_address}");
+        assert_eq!(
+            stack_names,
+            vec![caller_stack.as_str(), callee_stack.as_str()]
+        );
+
+        // PUSH1 0x01 POP STOP
+        assert_eq!(stacks[1].1, 5);
+
+        // The folded stacks exclude the intrinsic gas cost
+        let total_gas: u64 = stacks.iter().map(|(_, gas)| gas).sum();
+        assert_eq!(total_gas, result.gas_used() - 21_000);
+
+        Ok(())
+    }
+}
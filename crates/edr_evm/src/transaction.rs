@@ -23,6 +23,10 @@ pub enum TransactionError<BE, SE> {
     /// EIP-1559 is not supported
     #[error("Cannot run transaction: EIP 1559 is not activated.")]
     Eip1559Unsupported,
+    /// EIP-7702 transactions cannot be executed, as their authorization list
+    /// would not be applied
+    #[error("Cannot run transaction: EIP-7702 transactions are not supported.")]
+    Eip7702Unsupported,
     /// Corrupt transaction data
     #[error("Invalid transaction: {0:?}")]
     InvalidTransaction(InvalidTransaction),
@@ -6,7 +6,8 @@ use edr_eth::{
     signature::Signature,
     transaction::{
         Eip1559SignedTransaction, Eip155SignedTransaction, Eip2930SignedTransaction,
-        Eip4844SignedTransaction, LegacySignedTransaction, SignedTransaction, TransactionKind,
+        Eip4844SignedTransaction, Eip7702SignedTransaction, LegacySignedTransaction,
+        SignedTransaction, TransactionKind,
     },
     Address, U256,
 };
@@ -79,6 +80,18 @@ impl ExecutableTransaction {
         initial_cost(spec_id, &self.transaction)
     }
 
+    /// The EIP-7623 calldata floor cost of the transaction, if the provided
+    /// hardfork activates it.
+    pub fn calldata_floor_cost(&self, spec_id: SpecId) -> Option<u64> {
+        (spec_id >= CALLDATA_FLOOR_SPEC_ID).then(|| calldata_floor_cost(self.transaction.data()))
+    }
+
+    /// Returns whether the transaction can be executed. EIP-7702 transactions
+    /// can't, as revm doesn't support applying their authorization list.
+    pub fn is_executable(&self) -> bool {
+        !matches!(self.transaction, SignedTransaction::Eip7702(_))
+    }
+
     /// Returns the inner [`SignedTransaction`]
     pub fn as_inner(&self) -> &SignedTransaction {
         &self.transaction
@@ -223,6 +236,32 @@ impl From<ExecutableTransaction> for TxEnv {
                 blob_hashes,
                 max_fee_per_blob_gas: Some(max_fee_per_blob_gas),
             },
+            // The authorization list is not applied, as revm does not support
+            // EIP-7702.
+            SignedTransaction::Eip7702(Eip7702SignedTransaction {
+                nonce,
+                max_priority_fee_per_gas,
+                max_fee_per_gas,
+                gas_limit,
+                to,
+                value,
+                input,
+                access_list,
+                ..
+            }) => Self {
+                caller: transaction.caller,
+                gas_limit,
+                gas_price: max_fee_per_gas,
+                gas_priority_fee: Some(max_priority_fee_per_gas),
+                transact_to: TransactTo::Call(to),
+                value,
+                data: input,
+                chain_id,
+                nonce: Some(nonce),
+                access_list: access_list.into(),
+                blob_hashes: Vec::new(),
+                max_fee_per_blob_gas: None,
+            },
         }
     }
 }
@@ -233,6 +272,9 @@ pub enum TransactionConversionError {
     /// Missing access list
     #[error("Missing access list")]
     MissingAccessList,
+    /// EIP-7702 transaction is missing the authorization list
+    #[error("Missing authorization list")]
+    MissingAuthorizationList,
     /// EIP-4844 transaction is missing blob (versioned) hashes
     #[error("Missing blob hashes")]
     MissingBlobHashes,
@@ -248,7 +290,7 @@ pub enum TransactionConversionError {
     /// EIP-4844 transaction is missing the max fee per blob gas
     #[error("Missing max fee per blob gas")]
     MissingMaxFeePerBlobGas,
-    /// EIP-4844 transaction is missing the receiver (to) address
+    /// EIP-4844 or EIP-7702 transaction is missing the receiver (to) address
     #[error("Missing receiver (to) address")]
     MissingReceiverAddress,
 }
@@ -257,16 +299,14 @@ impl TryFrom<Transaction> for ExecutableTransaction {
     type Error = TransactionConversionError;
 
     fn try_from(value: Transaction) -> Result<Self, Self::Error> {
-        let kind = if let Some(to) = &value.to {
-            TransactionKind::Call(*to)
-        } else {
-            TransactionKind::Create
-        };
+        let kind = TransactionKind::from(value.to);
 
         let caller = value.from;
 
         let transaction = match value.transaction_type {
             Some(0) | None => {
+                // Remote transactions have already been accepted by the network, so any `v`
+                // value that isn't pre-EIP-155 is assumed to be post-EIP-155.
                 if value.is_legacy() {
                     SignedTransaction::PreEip155Legacy(LegacySignedTransaction {
                         nonce: value.nonce,
@@ -281,6 +321,7 @@ impl TryFrom<Transaction> for ExecutableTransaction {
                             v: value.v,
                         },
                         hash: OnceLock::from(value.hash),
+                        caller: OnceLock::new(),
                         is_fake: false,
                     })
                 } else {
@@ -297,6 +338,7 @@ impl TryFrom<Transaction> for ExecutableTransaction {
                             v: value.v,
                         },
                         hash: OnceLock::from(value.hash),
+                        caller: OnceLock::new(),
                         is_fake: false,
                     })
                 }
@@ -319,6 +361,7 @@ impl TryFrom<Transaction> for ExecutableTransaction {
                 r: value.r,
                 s: value.s,
                 hash: OnceLock::from(value.hash),
+                caller: OnceLock::new(),
                 is_fake: false,
             }),
             Some(2) => SignedTransaction::Eip1559(Eip1559SignedTransaction {
@@ -344,6 +387,7 @@ impl TryFrom<Transaction> for ExecutableTransaction {
                 r: value.r,
                 s: value.s,
                 hash: OnceLock::from(value.hash),
+                caller: OnceLock::new(),
                 is_fake: false,
             }),
             Some(3) => SignedTransaction::Eip4844(Eip4844SignedTransaction {
@@ -377,6 +421,38 @@ impl TryFrom<Transaction> for ExecutableTransaction {
                 r: value.r,
                 s: value.s,
                 hash: OnceLock::from(value.hash),
+                caller: OnceLock::new(),
+                is_fake: false,
+            }),
+            Some(4) => SignedTransaction::Eip7702(Eip7702SignedTransaction {
+                odd_y_parity: value.odd_y_parity(),
+                chain_id: value
+                    .chain_id
+                    .ok_or(TransactionConversionError::MissingChainId)?,
+                nonce: value.nonce,
+                max_priority_fee_per_gas: value
+                    .max_priority_fee_per_gas
+                    .ok_or(TransactionConversionError::MissingMaxPriorityFeePerGas)?,
+                max_fee_per_gas: value
+                    .max_fee_per_gas
+                    .ok_or(TransactionConversionError::MissingMaxFeePerGas)?,
+                gas_limit: value.gas.to(),
+                to: value
+                    .to
+                    .ok_or(TransactionConversionError::MissingReceiverAddress)?,
+                value: value.value,
+                input: value.input,
+                access_list: value
+                    .access_list
+                    .ok_or(TransactionConversionError::MissingAccessList)?
+                    .into(),
+                authorization_list: value
+                    .authorization_list
+                    .ok_or(TransactionConversionError::MissingAuthorizationList)?,
+                r: value.r,
+                s: value.s,
+                hash: OnceLock::from(value.hash),
+                caller: OnceLock::new(),
                 is_fake: false,
             }),
             Some(r#type) => {
@@ -395,6 +471,7 @@ impl TryFrom<Transaction> for ExecutableTransaction {
                         v: value.v,
                     },
                     hash: OnceLock::from(value.hash),
+                    caller: OnceLock::new(),
                     is_fake: false,
                 })
             }
@@ -407,6 +484,28 @@ impl TryFrom<Transaction> for ExecutableTransaction {
     }
 }
 
+/// The first hardfork that activates the EIP-7623 calldata floor cost. The
+/// current revm version doesn't define Prague, so only [`SpecId::LATEST`]
+/// activates it.
+const CALLDATA_FLOOR_SPEC_ID: SpecId = SpecId::LATEST;
+
+/// Gas charged per calldata token by the EIP-7623 floor cost.
+const TOTAL_COST_FLOOR_PER_TOKEN: u64 = 10;
+
+/// Computes the EIP-7623 calldata floor cost: the base transaction cost plus
+/// a fixed price per calldata token, where each zero byte counts as one token
+/// and each non-zero byte as four.
+fn calldata_floor_cost(data: &[u8]) -> u64 {
+    const TRANSACTION_BASE_COST: u64 = 21_000;
+    const NON_ZERO_BYTE_TOKENS: u64 = 4;
+
+    let zero_bytes = data.iter().filter(|byte| **byte == 0).count() as u64;
+    let non_zero_bytes = data.len() as u64 - zero_bytes;
+    let tokens = zero_bytes + NON_ZERO_BYTE_TOKENS * non_zero_bytes;
+
+    TRANSACTION_BASE_COST + TOTAL_COST_FLOOR_PER_TOKEN * tokens
+}
+
 fn initial_cost(spec_id: SpecId, transaction: &SignedTransaction) -> u64 {
     let access_list: Option<Vec<(Address, Vec<U256>)>> =
         transaction.access_list().cloned().map(Into::into);
@@ -484,10 +583,45 @@ fn initial_cost(spec_id: SpecId, transaction: &SignedTransaction) -> u64 {
 
 #[cfg(test)]
 mod tests {
-    use edr_eth::{transaction::Eip155TransactionRequest, Bytes};
+    use edr_eth::{
+        authorization::SignedAuthorization, transaction::Eip155TransactionRequest, Bytes,
+    };
 
     use super::*;
 
+    #[test]
+    fn calldata_floor_cost_counts_tokens() {
+        assert_eq!(calldata_floor_cost(&[]), 21_000);
+        // 2 zero bytes and 3 non-zero bytes are 14 tokens
+        assert_eq!(calldata_floor_cost(&[0, 1, 0, 2, 3]), 21_000 + 10 * 14);
+    }
+
+    #[test]
+    fn calldata_floor_cost_is_spec_gated() -> anyhow::Result<()> {
+        let request = Eip155TransactionRequest {
+            nonce: 0,
+            gas_price: U256::ZERO,
+            gas_limit: 1_000_000,
+            kind: TransactionKind::Call(Address::random()),
+            value: U256::ZERO,
+            input: Bytes::from(vec![0u8; 100]),
+            chain_id: 123,
+        };
+
+        let caller = Address::random();
+        let transaction = request.fake_sign(&caller);
+        let transaction =
+            ExecutableTransaction::with_caller(SpecId::LATEST, transaction.into(), caller)?;
+
+        assert_eq!(transaction.calldata_floor_cost(SpecId::CANCUN), None);
+        assert_eq!(
+            transaction.calldata_floor_cost(SpecId::LATEST),
+            Some(21_000 + 10 * 100)
+        );
+
+        Ok(())
+    }
+
     #[test]
     fn gas_limit_less_than_base_fee() -> anyhow::Result<()> {
         const TOO_LOW_GAS_LIMIT: u64 = 100;
@@ -553,4 +687,117 @@ mod tests {
 
         Ok(())
     }
+
+    /// The first transaction on Ethereum mainnet, which predates EIP-155.
+    fn pre_eip155_mainnet_transaction() -> anyhow::Result<Transaction> {
+        Ok(Transaction {
+            hash: "0x5c504ed432cb51138bcf09aa5e8a410dd4a1e204ef84bfed1be16dfba1b22060".parse()?,
+            nonce: 0,
+            from: "0xa1e4380a3b1f749673e270229993ee55f35663b4".parse()?,
+            to: Some("0x5df9b87991262f6ba471f09758cde1c0fc1de734".parse()?),
+            value: U256::from(31_337),
+            gas_price: U256::from(50_000_000_000_000u64),
+            gas: U256::from(21_000),
+            input: Bytes::new(),
+            v: 28,
+            r: "0x88ff6cf0fefd94db46111149ae4bfc179e9b94721fffd821d38d16464b3f71d0".parse()?,
+            s: "0x45e0aff800961cfce805daef7016b9b675c137a6a41a548f7b60a3484c06a33a".parse()?,
+            ..Transaction::default()
+        })
+    }
+
+    #[test]
+    fn convert_pre_eip155_mainnet_transaction() -> anyhow::Result<()> {
+        let value = pre_eip155_mainnet_transaction()?;
+        let expected_caller = value.from;
+
+        let transaction = ExecutableTransaction::try_from(value)?;
+        assert!(transaction.is_legacy());
+        assert_eq!(transaction.recover()?, expected_caller);
+
+        Ok(())
+    }
+
+    #[test]
+    fn convert_post_eip155_legacy_transaction() -> anyhow::Result<()> {
+        let value = Transaction {
+            v: 37,
+            chain_id: Some(1),
+            ..pre_eip155_mainnet_transaction()?
+        };
+
+        let transaction = ExecutableTransaction::try_from(value)?;
+        assert!(transaction.is_eip155());
+
+        Ok(())
+    }
+
+    #[test]
+    fn convert_legacy_transaction_with_non_standard_v() -> anyhow::Result<()> {
+        for (v, chain_id) in [(0, None), (1, None), (29, None), (37, Some(5))] {
+            let value = Transaction {
+                v,
+                chain_id,
+                ..pre_eip155_mainnet_transaction()?
+            };
+
+            let transaction = ExecutableTransaction::try_from(value)?;
+            assert!(transaction.is_eip155());
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn convert_eip7702_transaction() -> anyhow::Result<()> {
+        let authorization = SignedAuthorization {
+            chain_id: 1,
+            address: Address::random(),
+            nonce: 0,
+            y_parity: 0,
+            r: U256::from(1),
+            s: U256::from(1),
+        };
+
+        let value = Transaction {
+            transaction_type: Some(4),
+            chain_id: Some(1),
+            v: 1,
+            max_fee_per_gas: Some(U256::from(2_000_000_000u64)),
+            max_priority_fee_per_gas: Some(U256::from(1_000_000_000u64)),
+            access_list: Some(Vec::new()),
+            authorization_list: Some(vec![authorization.clone()]),
+            ..pre_eip155_mainnet_transaction()?
+        };
+        let expected_hash = value.hash;
+
+        let transaction = ExecutableTransaction::try_from(value)?;
+        let SignedTransaction::Eip7702(transaction) = transaction.as_inner() else {
+            anyhow::bail!("Expected an EIP-7702 transaction");
+        };
+        assert_eq!(transaction.authorization_list, vec![authorization]);
+        assert!(transaction.odd_y_parity);
+        assert_eq!(*transaction.hash(), expected_hash);
+
+        Ok(())
+    }
+
+    #[test]
+    fn convert_eip7702_transaction_without_authorization_list() -> anyhow::Result<()> {
+        let value = Transaction {
+            transaction_type: Some(4),
+            chain_id: Some(1),
+            max_fee_per_gas: Some(U256::from(2_000_000_000u64)),
+            max_priority_fee_per_gas: Some(U256::from(1_000_000_000u64)),
+            access_list: Some(Vec::new()),
+            ..pre_eip155_mainnet_transaction()?
+        };
+
+        assert!(matches!(
+            ExecutableTransaction::try_from(value),
+            Err(TransactionConversionError::MissingAuthorizationList)
+        ));
+
+        Ok(())
+    }
 }
@@ -545,6 +545,101 @@ fn transaction_by_hash_remove_future_after_update() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[test]
+fn remove_pending_transaction_moves_subsequent_to_future() -> anyhow::Result<()> {
+    let sender = Address::random();
+
+    let mut fixture = MemPoolTestFixture::with_accounts(&[(sender, AccountInfo::default())]);
+
+    let transaction1 = dummy_eip155_transaction(sender, 0)?;
+    fixture.add_transaction(transaction1.clone())?;
+
+    let transaction2 = dummy_eip155_transaction(sender, 1)?;
+    fixture.add_transaction(transaction2.clone())?;
+
+    let transaction3 = dummy_eip155_transaction(sender, 2)?;
+    fixture.add_transaction(transaction3.clone())?;
+
+    let removed = fixture.mem_pool.remove_transaction(transaction2.hash());
+    assert_eq!(
+        removed.as_ref().map(OrderedTransaction::pending),
+        Some(&transaction2)
+    );
+    assert!(fixture
+        .mem_pool
+        .transaction_by_hash(transaction2.hash())
+        .is_none());
+
+    let pending_transactions = fixture.mem_pool.pending_transactions().collect::<Vec<_>>();
+    assert_eq!(pending_transactions.len(), 1);
+    assert_eq!(*pending_transactions[0].pending(), transaction1);
+
+    let future_transactions = fixture.mem_pool.future_transactions().collect::<Vec<_>>();
+    assert_eq!(future_transactions.len(), 1);
+    assert_eq!(*future_transactions[0].pending(), transaction3);
+
+    // Filling the nonce gap moves the queued transaction back to pending
+    fixture.add_transaction(transaction2.clone())?;
+
+    let pending_transactions = fixture.mem_pool.pending_transactions().collect::<Vec<_>>();
+    assert_eq!(pending_transactions.len(), 3);
+    assert_eq!(*pending_transactions[0].pending(), transaction1);
+    assert_eq!(*pending_transactions[1].pending(), transaction2);
+    assert_eq!(*pending_transactions[2].pending(), transaction3);
+    assert!(!fixture.mem_pool.has_future_transactions());
+
+    Ok(())
+}
+
+#[test]
+fn remove_future_transaction() -> anyhow::Result<()> {
+    let sender = Address::random();
+
+    let mut fixture = MemPoolTestFixture::with_accounts(&[(sender, AccountInfo::default())]);
+
+    let transaction1 = dummy_eip155_transaction(sender, 0)?;
+    fixture.add_transaction(transaction1.clone())?;
+
+    let transaction2 = dummy_eip155_transaction(sender, 2)?;
+    fixture.add_transaction(transaction2.clone())?;
+
+    let removed = fixture.mem_pool.remove_transaction(transaction2.hash());
+    assert_eq!(
+        removed.as_ref().map(OrderedTransaction::pending),
+        Some(&transaction2)
+    );
+    assert!(fixture
+        .mem_pool
+        .transaction_by_hash(transaction2.hash())
+        .is_none());
+    assert!(!fixture.mem_pool.has_future_transactions());
+
+    let pending_transactions = fixture.mem_pool.pending_transactions().collect::<Vec<_>>();
+    assert_eq!(pending_transactions.len(), 1);
+    assert_eq!(*pending_transactions[0].pending(), transaction1);
+
+    Ok(())
+}
+
+#[test]
+fn remove_unknown_transaction() -> anyhow::Result<()> {
+    let sender = Address::random();
+
+    let mut fixture = MemPoolTestFixture::with_accounts(&[(sender, AccountInfo::default())]);
+
+    let transaction = dummy_eip155_transaction(sender, 0)?;
+    fixture.add_transaction(transaction.clone())?;
+
+    let unknown = dummy_eip155_transaction(sender, 1)?;
+    assert!(fixture
+        .mem_pool
+        .remove_transaction(unknown.hash())
+        .is_none());
+    assert_eq!(fixture.mem_pool.pending_transactions().count(), 1);
+
+    Ok(())
+}
+
 #[test]
 fn last_pending_nonce_with_pending() -> anyhow::Result<()> {
     let sender = Address::random();
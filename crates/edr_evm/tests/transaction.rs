@@ -41,10 +41,54 @@ mod alchemy {
         };
     }
 
+    macro_rules! impl_test_transactions_root_remote {
+        ($(
+            $name:ident => $block_number:literal,
+        )+) => {
+            $(
+                paste::item! {
+                    #[tokio::test]
+                    async fn [<transactions_root_remote_ $name>]() {
+                        use edr_eth::{
+                            remote::{RpcClient, PreEip1898BlockSpec},
+                            trie::ordered_trie_root,
+                        };
+                        use edr_evm::ExecutableTransaction;
+                        use edr_test_utils::env::get_alchemy_url;
+
+                        let client = RpcClient::new(&get_alchemy_url(), edr_defaults::CACHE_DIR.into(), None).expect("url ok");
+
+                        let block = client
+                            .get_block_by_number_with_transaction_data(PreEip1898BlockSpec::Number($block_number))
+                            .await
+                            .expect("Should succeed");
+
+                        let transactions =
+                                block.transactions.into_iter().map(ExecutableTransaction::try_from).collect::<Result<Vec<_>, _>>()
+                                    .expect("Conversion must succeed, as we're not retrieving a pending block");
+
+                        let transactions_root = ordered_trie_root(transactions.iter().map(alloy_rlp::encode));
+
+                        assert_eq!(block.transactions_root, transactions_root);
+                    }
+                }
+            )+
+        };
+    }
+
     impl_test_transaction_remote_hash! {
         legacy => 1_500_000u64,
         eip155 => 2_675_000u64,
         eip2930 => 12_244_000u64,
         eip1559 => 12_965_000u64,
+        eip4844 => 19_500_000u64, // post-Cancun block
+    }
+
+    impl_test_transactions_root_remote! {
+        legacy => 1_500_000u64,
+        eip155 => 2_675_000u64,
+        eip2930 => 12_244_000u64,
+        eip1559 => 12_965_000u64,
+        eip4844 => 19_500_000u64, // post-Cancun block
     }
 }
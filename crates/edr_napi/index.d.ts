@@ -3,6 +3,12 @@
 
 /* auto-generated by NAPI-RS */
 
+export interface AccessListItem {
+  /** The accessed account's address */
+  address: Buffer
+  /** The accessed storage slots of the account */
+  storageKeys: Array<Buffer>
+}
 /** An account that needs to be created during the genesis block. */
 export interface GenesisAccount {
   /** Account secret key */
@@ -58,6 +64,24 @@ export interface BlobGas {
    */
   excessGas: bigint
 }
+/**
+ * Override of the depth at which blocks of a chain are considered safe from a
+ * reorg
+ */
+export interface SafeBlockDepth {
+  /** The chain ID */
+  chainId: bigint
+  /** The number of blocks below the latest block that are considered safe */
+  safeBlockDepth: bigint
+}
+/**
+ * Retrieves the largest block number that is safe from a reorg for the
+ * provided chain, based on its latest block number. Returns `null` if all
+ * blocks fall within the chain's safe block depth.
+ *
+ * The optional reorg config overrides the built-in safe block depths.
+ */
+export function largestSafeBlockNumber(chainId: bigint, latestBlockNumber: bigint, reorgConfig?: Array<SafeBlockDepth> | undefined | null): bigint | null
 /** The result of executing a call override. */
 export interface CallOverrideResult {
   result: Buffer
@@ -132,6 +156,11 @@ export interface DebugTraceLogItem {
   memory?: Array<string>
   /** Map of all stored values with keys and values encoded as hex strings. */
   storage?: Record<string, string>
+  /**
+   * Whether the storage slot accessed by an `SLOAD` or `SSTORE` was "cold"
+   * or "warm" (EIP-2929).
+   */
+  storageAccess?: string
 }
 /** Ethereum execution log. */
 export interface ExecutionLog {
@@ -203,18 +232,41 @@ export interface MiningConfig {
   interval?: bigint | IntervalRange
   memPool: MemPoolConfig
 }
+/** Parameters of the EIP-1559 base fee adjustment. */
+export interface BaseFeeParams {
+  /** The ratio between a block's gas limit and its gas target */
+  elasticityMultiplier: bigint
+  /** The inverse of the maximum relative change of the base fee between consecutive blocks */
+  maxChangeDenominator: bigint
+}
 /** Configuration for a provider */
 export interface ProviderConfig {
   /** Whether to allow blocks with the same timestamp */
   allowBlocksWithSameTimestamp: boolean
   /** Whether to allow unlimited contract size */
   allowUnlimitedContractSize: boolean
+  /** Whether to accept transactions with a zero gas price. Defaults to true. */
+  allowZeroGasPrice?: boolean
   /** Whether to return an `Err` when `eth_call` fails */
   bailOnCallFailure: boolean
+  /**
+   * Whether to return an `Err` when `eth_call` is sent to an account
+   * without code, instead of succeeding with empty return data. Defaults to
+   * false.
+   */
+  bailOnCallWithoutCode?: boolean
   /** Whether to return an `Err` when a `eth_sendTransaction` fails */
   bailOnTransactionFailure: boolean
-  /** The gas limit of each block */
-  blockGasLimit: bigint
+  /**
+   * The parameters of the EIP-1559 base fee adjustment between blocks.
+   * Defaults to Ethereum mainnet's.
+   */
+  baseFeeParams?: BaseFeeParams
+  /**
+   * The gas limit of the genesis block. Defaults to the chain's block gas
+   * limit for known chains.
+   */
+  blockGasLimit?: bigint
   /** The directory to cache remote JSON-RPC responses */
   cacheDir?: string
   /** The chain ID of the blockchain */
@@ -228,10 +280,28 @@ export interface ProviderConfig {
    * blockchain will be created
    */
   fork?: ForkConfig
+  /**
+   * Whether `eth_call` and `eth_estimateGas` first generate an access list
+   * for transactions without one, and then execute them with the generated
+   * access list, which is returned in `Response.accessList`. Defaults to
+   * false.
+   */
+  generateAccessListsForCalls?: boolean
   /** The genesis accounts of the blockchain */
   genesisAccounts: Array<GenesisAccount>
+  /**
+   * The maximum number of blocks that a single `eth_getLogs` request may
+   * span. Defaults to no limit.
+   */
+  getLogsBlockRangeLimit?: bigint
   /** The hardfork of the blockchain */
   hardfork: SpecId
+  /**
+   * The schedule of hardfork activations of a local blockchain, which
+   * overrides `hardfork` from the first activation onwards. Ignored when
+   * forking.
+   */
+  hardforkActivations?: Array<HardforkActivation>
   /**
    * The initial base fee per gas of the blockchain. Required for EIP-1559
    * transactions and later
@@ -252,6 +322,23 @@ export interface ProviderConfig {
   mining: MiningConfig
   /** The network ID of the blockchain */
   networkId: bigint
+  /**
+   * The seed for the generator of prevrandao values of mined blocks, used
+   * when not explicitly set
+   */
+  prevRandaoSeed?: Buffer
+  /**
+   * The number of most recent blocks of which the transaction receipts are
+   * retained. Must be non-zero, as the receipts of the latest block are
+   * always retained. Defaults to retaining all receipts.
+   */
+  receiptRetention?: bigint
+  /**
+   * Overrides of the depth at which blocks are considered safe from a
+   * reorg, per chain. When forking, this determines which remote responses
+   * are cached and the default fork block number.
+   */
+  reorgConfig?: Array<SafeBlockDepth>
 }
 /** The possible reasons for successful termination of the EVM. */
 export const enum SuccessReason {
@@ -395,7 +482,17 @@ export class Response {
   get json(): string
   get solidityTrace(): RawTrace | null
   get traces(): Array<RawTrace>
+  /**
+   * The access list that an `eth_call` or `eth_estimateGas` request was
+   * executed with, if it was generated.
+   */
+  get accessList(): Array<AccessListItem> | null
 }
 export class RawTrace {
   trace(): Array<TracingMessage | TracingStep | TracingMessageResult>
+  /**
+   * Returns the maximum call depth that was reached, where the depth of the
+   * top-level call is zero.
+   */
+  maxDepth(): number
 }
@@ -295,8 +295,9 @@ if (!nativeBinding) {
   throw new Error(`Failed to load native binding`)
 }
 
-const { SpecId, EdrContext, MineOrdering, Provider, Response, SuccessReason, ExceptionalHalt, RawTrace } = nativeBinding
+const { largestSafeBlockNumber, SpecId, EdrContext, MineOrdering, Provider, Response, SuccessReason, ExceptionalHalt, RawTrace } = nativeBinding
 
+module.exports.largestSafeBlockNumber = largestSafeBlockNumber
 module.exports.SpecId = SpecId
 module.exports.EdrContext = EdrContext
 module.exports.MineOrdering = MineOrdering
@@ -0,0 +1,23 @@
+use napi::bindgen_prelude::Buffer;
+use napi_derive::napi;
+
+#[napi(object)]
+pub struct AccessListItem {
+    /// The accessed account's address
+    pub address: Buffer,
+    /// The accessed storage slots of the account
+    pub storage_keys: Vec<Buffer>,
+}
+
+impl From<edr_eth::access_list::AccessListItem> for AccessListItem {
+    fn from(item: edr_eth::access_list::AccessListItem) -> Self {
+        Self {
+            address: Buffer::from(item.address.as_slice()),
+            storage_keys: item
+                .storage_keys
+                .into_iter()
+                .map(|storage_key| Buffer::from(storage_key.as_slice()))
+                .collect(),
+        }
+    }
+}
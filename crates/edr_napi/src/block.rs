@@ -75,6 +75,7 @@ impl TryFrom<BlockOptions> for edr_eth::block::BlockOptions {
             base_fee: value
                 .base_fee
                 .map_or(Ok(None), |basefee| basefee.try_cast().map(Some))?,
+            base_fee_params: None,
             withdrawals: value
                 .withdrawals
                 .map(|withdrawals| {
@@ -119,3 +120,57 @@ impl TryFrom<BlobGas> for edr_eth::block::BlobGas {
         })
     }
 }
+
+/// Override of the depth at which blocks of a chain are considered safe from a
+/// reorg
+#[napi(object)]
+pub struct SafeBlockDepth {
+    /// The chain ID
+    pub chain_id: BigInt,
+    /// The number of blocks below the latest block that are considered safe
+    pub safe_block_depth: BigInt,
+}
+
+impl TryCast<edr_eth::block::ReorgConfig> for Vec<SafeBlockDepth> {
+    type Error = napi::Error;
+
+    fn try_cast(self) -> Result<edr_eth::block::ReorgConfig, Self::Error> {
+        self.into_iter()
+            .map(
+                |SafeBlockDepth {
+                     chain_id,
+                     safe_block_depth,
+                 }| {
+                    let chain_id: u64 = chain_id.try_cast()?;
+                    let safe_block_depth: u64 = safe_block_depth.try_cast()?;
+
+                    napi::Result::Ok((chain_id, safe_block_depth))
+                },
+            )
+            .collect()
+    }
+}
+
+/// Retrieves the largest block number that is safe from a reorg for the
+/// provided chain, based on its latest block number. Returns `null` if all
+/// blocks fall within the chain's safe block depth.
+///
+/// The optional reorg config overrides the built-in safe block depths.
+#[napi]
+pub fn largest_safe_block_number(
+    chain_id: BigInt,
+    latest_block_number: BigInt,
+    reorg_config: Option<Vec<SafeBlockDepth>>,
+) -> napi::Result<Option<BigInt>> {
+    let reorg_config: Option<edr_eth::block::ReorgConfig> =
+        reorg_config.map(TryCast::try_cast).transpose()?;
+
+    let largest_safe_block_number =
+        edr_eth::block::largest_safe_block_number(edr_eth::block::LargestSafeBlockNumberArgs {
+            chain_id: chain_id.try_cast()?,
+            latest_block_number: latest_block_number.try_cast()?,
+            reorg_config: reorg_config.as_ref(),
+        });
+
+    Ok(largest_safe_block_number.map(BigInt::from))
+}
@@ -35,4 +35,7 @@ pub struct DebugTraceLogItem {
     pub memory: Option<Vec<String>>,
     /// Map of all stored values with keys and values encoded as hex strings.
     pub storage: Option<HashMap<String, String>>,
+    /// Whether the storage slot accessed by an `SLOAD` or `SSTORE` was "cold"
+    /// or "warm" (EIP-2929).
+    pub storage_access: Option<String>,
 }
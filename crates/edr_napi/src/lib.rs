@@ -5,6 +5,7 @@
 #[global_allocator]
 static ALLOC: mimalloc::MiMalloc = mimalloc::MiMalloc;
 
+mod access_list;
 mod account;
 mod block;
 mod call_override;
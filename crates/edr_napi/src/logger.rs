@@ -342,6 +342,7 @@ impl LogCollector {
             console_log_inputs,
             execution_result,
             trace,
+            access_list: _,
         } = result;
 
         self.state = LoggingState::Empty;
@@ -9,6 +9,7 @@ use napi_derive::napi;
 
 use self::config::ProviderConfig;
 use crate::{
+    access_list::AccessListItem,
     call_override::CallOverrideCallback,
     context::EdrContext,
     logger::{Logger, LoggerConfig, LoggerError},
@@ -116,6 +117,7 @@ impl Provider {
                         solidity_trace: None,
                         json: json_response,
                         traces: Vec::new(),
+                        access_list: None,
                     });
             }
         };
@@ -157,6 +159,11 @@ impl Provider {
             Err(_) => Vec::new(),
         };
 
+        let access_list = response
+            .as_mut()
+            .ok()
+            .and_then(|response| response.access_list.take());
+
         let response = jsonrpc::ResponseData::from(response.map(|response| response.result));
 
         serde_json::to_string(&response)
@@ -165,6 +172,7 @@ impl Provider {
                 solidity_trace,
                 json: json_response,
                 traces: traces.into_iter().map(Arc::new).collect(),
+                access_list,
             })
     }
 
@@ -197,6 +205,9 @@ pub struct Response {
     solidity_trace: Option<Arc<edr_evm::trace::Trace>>,
     /// This may contain zero or more traces, depending on the (batch) request
     traces: Vec<Arc<edr_evm::trace::Trace>>,
+    /// The access list that an `eth_call` or `eth_estimateGas` request was
+    /// executed with, if it was generated.
+    access_list: Option<Vec<edr_eth::access_list::AccessListItem>>,
 }
 
 #[napi]
@@ -220,4 +231,13 @@ impl Response {
             .map(|trace| RawTrace::new(trace.clone()))
             .collect()
     }
+
+    /// The access list that an `eth_call` or `eth_estimateGas` request was
+    /// executed with, if it was generated.
+    #[napi(getter)]
+    pub fn access_list(&self) -> Option<Vec<AccessListItem>> {
+        self.access_list
+            .as_ref()
+            .map(|access_list| access_list.iter().cloned().map(Into::into).collect())
+    }
 }
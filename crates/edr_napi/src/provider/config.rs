@@ -1,4 +1,5 @@
 use std::{
+    num::NonZeroU64,
     path::PathBuf,
     time::{Duration, SystemTime},
 };
@@ -11,7 +12,12 @@ use napi::{
 };
 use napi_derive::napi;
 
-use crate::{account::GenesisAccount, block::BlobGas, cast::TryCast, config::SpecId};
+use crate::{
+    account::GenesisAccount,
+    block::{BlobGas, SafeBlockDepth},
+    cast::TryCast,
+    config::SpecId,
+};
 
 /// Configuration for a chain
 #[napi(object)]
@@ -78,6 +84,16 @@ pub struct MiningConfig {
     pub mem_pool: MemPoolConfig,
 }
 
+/// Parameters of the EIP-1559 base fee adjustment.
+#[napi(object)]
+pub struct BaseFeeParams {
+    /// The ratio between a block's gas limit and its gas target
+    pub elasticity_multiplier: BigInt,
+    /// The inverse of the maximum relative change of the base fee between
+    /// consecutive blocks
+    pub max_change_denominator: BigInt,
+}
+
 /// Configuration for a provider
 #[napi(object)]
 pub struct ProviderConfig {
@@ -85,12 +101,22 @@ pub struct ProviderConfig {
     pub allow_blocks_with_same_timestamp: bool,
     /// Whether to allow unlimited contract size
     pub allow_unlimited_contract_size: bool,
+    /// Whether to accept transactions with a zero gas price. Defaults to true.
+    pub allow_zero_gas_price: Option<bool>,
     /// Whether to return an `Err` when `eth_call` fails
     pub bail_on_call_failure: bool,
+    /// Whether to return an `Err` when `eth_call` is sent to an account
+    /// without code, instead of succeeding with empty return data. Defaults to
+    /// false.
+    pub bail_on_call_without_code: Option<bool>,
     /// Whether to return an `Err` when a `eth_sendTransaction` fails
     pub bail_on_transaction_failure: bool,
-    /// The gas limit of each block
-    pub block_gas_limit: BigInt,
+    /// The parameters of the EIP-1559 base fee adjustment between blocks.
+    /// Defaults to Ethereum mainnet's.
+    pub base_fee_params: Option<BaseFeeParams>,
+    /// The gas limit of the genesis block. Defaults to the chain's block gas
+    /// limit for known chains.
+    pub block_gas_limit: Option<BigInt>,
     /// The directory to cache remote JSON-RPC responses
     pub cache_dir: Option<String>,
     /// The chain ID of the blockchain
@@ -102,10 +128,22 @@ pub struct ProviderConfig {
     /// The configuration for forking a blockchain. If not provided, a local
     /// blockchain will be created
     pub fork: Option<ForkConfig>,
+    /// Whether `eth_call` and `eth_estimateGas` first generate an access list
+    /// for transactions without one, and then execute them with the generated
+    /// access list, which is returned in `Response.accessList`. Defaults to
+    /// false.
+    pub generate_access_lists_for_calls: Option<bool>,
     /// The genesis accounts of the blockchain
     pub genesis_accounts: Vec<GenesisAccount>,
+    /// The maximum number of blocks that a single `eth_getLogs` request may
+    /// span. Defaults to no limit.
+    pub get_logs_block_range_limit: Option<BigInt>,
     /// The hardfork of the blockchain
     pub hardfork: SpecId,
+    /// The schedule of hardfork activations of a local blockchain, which
+    /// overrides `hardfork` from the first activation onwards. Ignored when
+    /// forking.
+    pub hardfork_activations: Option<Vec<HardforkActivation>>,
     /// The initial base fee per gas of the blockchain. Required for EIP-1559
     /// transactions and later
     pub initial_base_fee_per_gas: Option<BigInt>,
@@ -122,6 +160,17 @@ pub struct ProviderConfig {
     pub mining: MiningConfig,
     /// The network ID of the blockchain
     pub network_id: BigInt,
+    /// The seed for the generator of prevrandao values of mined blocks, used
+    /// when not explicitly set
+    pub prev_randao_seed: Option<Buffer>,
+    /// The number of most recent blocks of which the transaction receipts are
+    /// retained. Must be non-zero, as the receipts of the latest block are
+    /// always retained. Defaults to retaining all receipts.
+    pub receipt_retention: Option<BigInt>,
+    /// Overrides of the depth at which blocks are considered safe from a
+    /// reorg, per chain. When forking, this determines which remote responses
+    /// are cached and the default fork block number.
+    pub reorg_config: Option<Vec<SafeBlockDepth>>,
 }
 
 impl TryFrom<ForkConfig> for edr_provider::hardhat_rpc_types::ForkConfig {
@@ -144,6 +193,28 @@ impl TryFrom<ForkConfig> for edr_provider::hardhat_rpc_types::ForkConfig {
     }
 }
 
+impl TryFrom<BaseFeeParams> for edr_eth::block::BaseFeeParams {
+    type Error = napi::Error;
+
+    fn try_from(value: BaseFeeParams) -> Result<Self, Self::Error> {
+        fn non_zero(value: BigInt) -> napi::Result<NonZeroU64> {
+            let value: u64 = value.try_cast()?;
+
+            NonZeroU64::new(value).ok_or_else(|| {
+                napi::Error::new(
+                    napi::Status::InvalidArg,
+                    "Base fee parameters must be non-zero".to_string(),
+                )
+            })
+        }
+
+        Ok(Self {
+            elasticity_multiplier: non_zero(value.elasticity_multiplier)?,
+            max_change_denominator: non_zero(value.max_change_denominator)?,
+        })
+    }
+}
+
 impl From<MemPoolConfig> for edr_provider::MemPoolConfig {
     fn from(value: MemPoolConfig) -> Self {
         Self {
@@ -196,6 +267,27 @@ impl TryFrom<ProviderConfig> for edr_provider::ProviderConfig {
     type Error = napi::Error;
 
     fn try_from(value: ProviderConfig) -> Result<Self, Self::Error> {
+        fn hardfork_activations(
+            hardforks: Vec<HardforkActivation>,
+        ) -> napi::Result<edr_eth::spec::HardforkActivations> {
+            let hardforks = hardforks
+                .into_iter()
+                .map(
+                    |HardforkActivation {
+                         block_number,
+                         spec_id,
+                     }| {
+                        let block_number = block_number.try_cast()?;
+                        let spec_id = spec_id.into();
+
+                        Ok((block_number, spec_id))
+                    },
+                )
+                .collect::<napi::Result<Vec<_>>>()?;
+
+            Ok(edr_eth::spec::HardforkActivations::new(hardforks))
+        }
+
         let chains = value
             .chains
             .into_iter()
@@ -204,23 +296,8 @@ impl TryFrom<ProviderConfig> for edr_provider::ProviderConfig {
                      chain_id,
                      hardforks,
                  }| {
-                    let hardforks = hardforks
-                        .into_iter()
-                        .map(
-                            |HardforkActivation {
-                                 block_number,
-                                 spec_id,
-                             }| {
-                                let block_number = block_number.try_cast()?;
-                                let spec_id = spec_id.into();
-
-                                Ok((block_number, spec_id))
-                            },
-                        )
-                        .collect::<napi::Result<Vec<_>>>()?;
-
                     let chain_id = chain_id.try_cast()?;
-                    Ok((chain_id, edr_eth::spec::HardforkActivations::new(hardforks)))
+                    Ok((chain_id, hardfork_activations(hardforks)?))
                 },
             )
             .collect::<napi::Result<_>>()?;
@@ -233,9 +310,15 @@ impl TryFrom<ProviderConfig> for edr_provider::ProviderConfig {
                 .collect::<napi::Result<Vec<_>>>()?,
             allow_blocks_with_same_timestamp: value.allow_blocks_with_same_timestamp,
             allow_unlimited_contract_size: value.allow_unlimited_contract_size,
+            allow_zero_gas_price: value.allow_zero_gas_price.unwrap_or(true),
             bail_on_call_failure: value.bail_on_call_failure,
+            bail_on_call_without_code: value.bail_on_call_without_code.unwrap_or(false),
             bail_on_transaction_failure: value.bail_on_transaction_failure,
-            block_gas_limit: value.block_gas_limit.try_cast()?,
+            base_fee_params: value
+                .base_fee_params
+                .map(edr_eth::block::BaseFeeParams::try_from)
+                .transpose()?,
+            block_gas_limit: value.block_gas_limit.map(TryCast::try_cast).transpose()?,
             cache_dir: PathBuf::from(
                 value
                     .cache_dir
@@ -245,8 +328,17 @@ impl TryFrom<ProviderConfig> for edr_provider::ProviderConfig {
             chains,
             coinbase: value.coinbase.try_cast()?,
             fork: value.fork.map(TryInto::try_into).transpose()?,
+            generate_access_lists_for_calls: value.generate_access_lists_for_calls.unwrap_or(false),
             genesis_accounts: HashMap::new(),
+            get_logs_block_range_limit: value
+                .get_logs_block_range_limit
+                .map(TryCast::try_cast)
+                .transpose()?,
             hardfork: value.hardfork.into(),
+            hardfork_activations: value
+                .hardfork_activations
+                .map(hardfork_activations)
+                .transpose()?,
             initial_base_fee_per_gas: value
                 .initial_base_fee_per_gas
                 .map(TryCast::try_cast)
@@ -266,6 +358,21 @@ impl TryFrom<ProviderConfig> for edr_provider::ProviderConfig {
             mining: value.mining.try_into()?,
             min_gas_price: value.min_gas_price.try_cast()?,
             network_id: value.network_id.try_cast()?,
+            prev_randao_seed: value.prev_randao_seed.map(TryCast::try_cast).transpose()?,
+            receipt_retention: value
+                .receipt_retention
+                .map(|receipt_retention| {
+                    let receipt_retention: u64 = receipt_retention.try_cast()?;
+
+                    NonZeroU64::new(receipt_retention).ok_or_else(|| {
+                        napi::Error::new(
+                            napi::Status::InvalidArg,
+                            "Receipt retention must be non-zero".to_string(),
+                        )
+                    })
+                })
+                .transpose()?,
+            reorg_config: value.reorg_config.map(TryCast::try_cast).transpose()?,
         })
     }
 }
@@ -145,4 +145,11 @@ impl RawTrace {
             })
             .collect::<napi::Result<_>>()
     }
+
+    /// Returns the maximum call depth that was reached, where the depth of the
+    /// top-level call is zero.
+    #[napi]
+    pub fn max_depth(&self) -> u32 {
+        u32::try_from(self.inner.max_depth).expect("call depth is limited to 1024")
+    }
 }
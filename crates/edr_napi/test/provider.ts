@@ -101,4 +101,66 @@ describe("Provider", () => {
 
     await assert.isFulfilled(provider);
   });
+
+  it("reports the type of typed transaction receipts", async function () {
+    const provider = await Provider.withConfig(
+      context,
+      {
+        ...providerConfig,
+        genesisAccounts: [
+          {
+            secretKey:
+              "0xac0974bec39a17e36ba4a6b4d238ff944bacb478cbed5efcae784d7bf4f2ff80",
+            balance: 1000n * 10n ** 18n,
+          },
+        ],
+      },
+      loggerConfig,
+      (_event: SubscriptionEvent) => {}
+    );
+
+    async function sendTransactionAndGetReceipt(transaction: object) {
+      const sendResponse = await provider.handleRequest(
+        JSON.stringify({
+          id: 1,
+          jsonrpc: "2.0",
+          method: "eth_sendTransaction",
+          params: [
+            {
+              from: "0xf39fd6e51aad88f6f4ce6ab8827279cfffb92266",
+              to: "0x0000000000000000000000000000000000000001",
+              value: "0x1",
+              ...transaction,
+            },
+          ],
+        })
+      );
+      const transactionHash = JSON.parse(sendResponse.json).result;
+
+      const receiptResponse = await provider.handleRequest(
+        JSON.stringify({
+          id: 2,
+          jsonrpc: "2.0",
+          method: "eth_getTransactionReceipt",
+          params: [transactionHash],
+        })
+      );
+
+      return JSON.parse(receiptResponse.json).result;
+    }
+
+    const eip2930Receipt = await sendTransactionAndGetReceipt({
+      gasPrice: "0x2540be400",
+      accessList: [],
+    });
+    assert.equal(eip2930Receipt.type, "0x1");
+    assert.equal(eip2930Receipt.effectiveGasPrice, "0x2540be400");
+
+    const eip1559Receipt = await sendTransactionAndGetReceipt({
+      maxFeePerGas: "0x2540be400",
+      maxPriorityFeePerGas: "0x3b9aca00",
+    });
+    assert.equal(eip1559Receipt.type, "0x2");
+    assert.isString(eip1559Receipt.effectiveGasPrice);
+  });
 });
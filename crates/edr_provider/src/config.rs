@@ -1,7 +1,9 @@
-use std::{path::PathBuf, time::SystemTime};
+use std::{num::NonZeroU64, path::PathBuf, time::SystemTime};
 
 use edr_eth::{
-    block::BlobGas, spec::HardforkActivations, AccountInfo, Address, HashMap, SpecId, B256, U256,
+    block::{BaseFeeParams, BlobGas, ReorgConfig},
+    spec::{chain_block_gas_limit, HardforkActivations},
+    AccountInfo, Address, HashMap, SpecId, B256, U256,
 };
 use edr_evm::{alloy_primitives::ChainId, MineOrdering};
 use rand::Rng;
@@ -9,6 +11,9 @@ use serde::{Deserialize, Serialize};
 
 use crate::{requests::hardhat::rpc_types::ForkConfig, OneUsizeOrTwo};
 
+/// The block gas limit that is used for chains without a known default.
+pub const DEFAULT_BLOCK_GAS_LIMIT: u64 = 30_000_000;
+
 /// Configuration for interval mining.
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub enum IntervalConfig {
@@ -60,20 +65,42 @@ pub struct MiningConfig {
 pub struct ProviderConfig {
     pub allow_blocks_with_same_timestamp: bool,
     pub allow_unlimited_contract_size: bool,
+    /// Whether to accept transactions with a zero gas price
+    pub allow_zero_gas_price: bool,
     pub accounts: Vec<AccountConfig>,
     /// Whether to return an `Err` when `eth_call` fails
     pub bail_on_call_failure: bool,
+    /// Whether to return an `Err` when `eth_call` is sent to an account
+    /// without code, instead of succeeding with empty return data
+    pub bail_on_call_without_code: bool,
     /// Whether to return an `Err` when a `eth_sendTransaction` fails
     pub bail_on_transaction_failure: bool,
-    pub block_gas_limit: u64,
+    /// The parameters of the EIP-1559 base fee adjustment between blocks.
+    /// Defaults to Ethereum mainnet's.
+    pub base_fee_params: Option<BaseFeeParams>,
+    /// The gas limit of the genesis block. Defaults to the chain's block gas
+    /// limit for known chains, or [`DEFAULT_BLOCK_GAS_LIMIT`] otherwise.
+    pub block_gas_limit: Option<u64>,
     pub cache_dir: PathBuf,
     pub chain_id: ChainId,
     pub chains: HashMap<ChainId, HardforkActivations>,
     pub coinbase: Address,
     pub fork: Option<ForkConfig>,
+    /// Whether `eth_call` and `eth_estimateGas` first generate an access list
+    /// for transactions without one, and then execute them with the generated
+    /// access list. This matches how some wallets estimate gas. The generated
+    /// access list is returned in [`crate::ResponseWithTraces::access_list`].
+    pub generate_access_lists_for_calls: bool,
     // Genesis accounts in addition to accounts. Useful for adding impersonated accounts for tests.
     pub genesis_accounts: HashMap<Address, AccountInfo>,
+    /// The maximum number of blocks that a single `eth_getLogs` request may
+    /// span. Defaults to no limit.
+    pub get_logs_block_range_limit: Option<u64>,
     pub hardfork: SpecId,
+    /// The schedule of hardfork activations of a local blockchain, which
+    /// overrides `hardfork` from the first activation onwards. Ignored when
+    /// forking.
+    pub hardfork_activations: Option<HardforkActivations>,
     pub initial_base_fee_per_gas: Option<U256>,
     pub initial_blob_gas: Option<BlobGas>,
     pub initial_date: Option<SystemTime>,
@@ -81,6 +108,29 @@ pub struct ProviderConfig {
     pub min_gas_price: U256,
     pub mining: MiningConfig,
     pub network_id: u64,
+    /// Seed for the generator of the prevrandao values of mined blocks, used
+    /// when not explicitly set. Defaults to a fixed seed.
+    pub prev_randao_seed: Option<B256>,
+    /// The number of most recent blocks of which the transaction receipts are
+    /// retained. Receipts of older blocks are pruned to bound memory usage.
+    /// The receipts of the latest block are always retained. Defaults to
+    /// retaining all receipts.
+    pub receipt_retention: Option<NonZeroU64>,
+    /// Overrides of the depth at which blocks are considered safe from a
+    /// reorg, keyed by chain id. When forking, this determines which remote
+    /// responses are cached and the default fork block number. Chains without
+    /// an override use the built-in safe block depths.
+    pub reorg_config: Option<ReorgConfig>,
+}
+
+impl ProviderConfig {
+    /// Returns the gas limit of the genesis block, taking into account the
+    /// chain's default if none was configured.
+    pub fn genesis_block_gas_limit(&self) -> u64 {
+        self.block_gas_limit
+            .or_else(|| chain_block_gas_limit(self.chain_id))
+            .unwrap_or(DEFAULT_BLOCK_GAS_LIMIT)
+    }
 }
 
 /// Configuration input for a single account
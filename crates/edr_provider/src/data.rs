@@ -8,46 +8,57 @@ use std::{
     collections::BTreeMap,
     ffi::OsString,
     fmt::Debug,
-    num::NonZeroUsize,
+    num::{NonZeroU64, NonZeroUsize},
     sync::Arc,
-    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
+    time::{SystemTime, UNIX_EPOCH},
 };
 
 use edr_eth::{
-    block::{calculate_next_base_fee, miner_reward, BlobGas, BlockOptions},
+    access_list::AccessListItem,
+    block::{
+        calculate_next_base_fee_with_params, largest_safe_block_number, miner_reward,
+        BaseFeeParams, BlockOptions, Header, LargestSafeBlockNumberArgs, PartialHeader,
+    },
     log::FilterLog,
     receipt::BlockReceipt,
     remote::{
-        client::{HeaderMap, HttpError},
-        eth::FeeHistoryResult,
+        client::{
+            header::{HeaderName, HeaderValue},
+            HeaderMap, HttpError,
+        },
+        eth::{AccessListResult, AccountProof, FeeHistoryResult, StorageProof},
         filter::{FilteredEvents, LogOutput, SubscriptionType},
         BlockSpec, BlockTag, Eip1898BlockSpec, RpcClient, RpcClientError,
     },
     reward_percentile::RewardPercentile,
-    signature::{RecoveryMessage, Signature},
+    signature::Signature,
     transaction::TransactionRequestAndSender,
+    trie::KECCAK_NULL_RLP,
+    utils::hash_message,
     Address, Bytes, SpecId, B256, U256,
 };
 use edr_evm::{
+    block_env_from_header,
     blockchain::{
         Blockchain, BlockchainError, ForkedBlockchain, ForkedCreationError, GenesisBlockOptions,
         LocalBlockchain, LocalCreationError, SyncBlockchain,
     },
     db::StateRef,
-    debug_trace_transaction, execution_result_to_debug_result, mempool, mine_block,
-    register_eip_3155_tracer_handles,
+    debug_trace_transaction, execution_result_to_debug_result, guaranteed_dry_run, mempool,
+    mine_block, register_eip_3155_tracer_handles,
     state::{
         AccountModifierFn, IrregularState, StateDiff, StateError, StateOverride, StateOverrides,
         SyncState,
     },
+    time::TimeSource,
     trace::Trace,
-    Account, AccountInfo, BlobExcessGasAndPrice, Block, BlockEnv, Bytecode, CfgEnv,
-    CfgEnvWithHandlerCfg, DebugContext, DebugTraceConfig, DebugTraceResult, ExecutableTransaction,
-    ExecutionResult, HashMap, HashSet, MemPool, OrderedTransaction, RandomHashGenerator,
-    StorageSlot, SyncBlock, TracerEip3155, TxEnv, KECCAK_EMPTY,
+    Account, AccountInfo, Block, BlockEnv, Bytecode, CfgEnv, CfgEnvWithHandlerCfg, DebugContext,
+    DebugTraceConfig, DebugTraceResult, ExecutableTransaction, ExecutionResult, HashMap, HashSet,
+    MemPool, OrderedTransaction, RandomHashGenerator, StorageSlot, SyncBlock, TracerEip3155, TxEnv,
+    KECCAK_EMPTY,
 };
 use ethers_core::types::transaction::eip712::{Eip712, TypedData};
-use gas::gas_used_ratio;
+use gas::{effective_reward, gas_used_ratio, remote_effective_rewards};
 use indexmap::IndexMap;
 use itertools::izip;
 use lru::LruCache;
@@ -66,15 +77,19 @@ use crate::{
     logger::SyncLogger,
     mock::{Mocker, SyncCallOverride},
     pending::BlockchainWithPending,
-    requests::hardhat::rpc_types::{ForkConfig, ForkMetadata},
+    requests::hardhat::rpc_types::{AccountSummary, ForkConfig, ForkMetadata, GasFees},
     snapshot::Snapshot,
-    MiningConfig, ProviderConfig, ProviderError, SubscriptionEvent, SubscriptionEventData,
+    MiningConfig, ProviderConfig, ProviderError, Signer, SubscriptionEvent, SubscriptionEventData,
     SyncSubscriberCallback,
 };
 
 const DEFAULT_INITIAL_BASE_FEE_PER_GAS: u64 = 1_000_000_000;
 const EDR_MAX_CACHED_STATES_ENV_VAR: &str = "__EDR_MAX_CACHED_STATES";
 const DEFAULT_MAX_CACHED_STATES: usize = 10;
+/// The number of most recent blocks of which the transactions' tips are used
+/// to suggest a max priority fee per gas.
+const GAS_FEES_BLOCK_COUNT: u64 = 20;
+const SUGGESTED_PRIORITY_FEE_PER_GAS: u64 = 1_000_000_000;
 
 /// The result of executing an `eth_call`.
 #[derive(Clone, Debug)]
@@ -82,12 +97,33 @@ pub struct CallResult {
     pub console_log_inputs: Vec<Bytes>,
     pub execution_result: ExecutionResult,
     pub trace: Trace,
+    /// The access list that the call was executed with, if it was generated.
+    /// See [`ProviderConfig::generate_access_lists_for_calls`].
+    pub access_list: Option<Vec<AccessListItem>>,
 }
 
 #[derive(Clone)]
 pub struct EstimateGasResult {
     pub estimation: u64,
     pub traces: Vec<Trace>,
+    /// The access list that the estimation was performed with, if it was
+    /// generated. See [`ProviderConfig::generate_access_lists_for_calls`].
+    pub access_list: Option<Vec<AccessListItem>>,
+}
+
+/// The effective gas price of a pending transaction, given a base fee per
+/// gas.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PendingEffectiveGasPrice {
+    /// The transaction can be included in a block with the base fee per gas,
+    /// paying the contained gas price.
+    Includable(U256),
+    /// The transaction's max fee per gas is lower than the base fee per gas,
+    /// so it cannot be included in a block.
+    MaxFeeTooLow {
+        max_fee_per_gas: U256,
+        base_fee_per_gas: U256,
+    },
 }
 
 pub struct SendTransactionResult {
@@ -132,6 +168,7 @@ pub struct ProviderData<LoggerErrorT: Debug> {
     parent_beacon_block_root_generator: RandomHashGenerator,
     prev_randao_generator: RandomHashGenerator,
     block_time_offset_seconds: i64,
+    time_source: Arc<dyn TimeSource>,
     fork_metadata: Option<ForkMetadata>,
     // Must be set if the provider is created with a fork config.
     // Hack to get around the type erasure with the dyn blockchain trait.
@@ -139,13 +176,14 @@ pub struct ProviderData<LoggerErrorT: Debug> {
     instance_id: B256,
     is_auto_mining: bool,
     next_block_base_fee_per_gas: Option<U256>,
+    next_block_difficulty: Option<U256>,
     next_block_timestamp: Option<u64>,
     next_snapshot_id: u64,
     snapshots: BTreeMap<u64, Snapshot>,
     allow_blocks_with_same_timestamp: bool,
     allow_unlimited_contract_size: bool,
     // IndexMap to preserve account order for logging.
-    local_accounts: IndexMap<Address, k256::SecretKey>,
+    local_accounts: IndexMap<Address, Box<dyn Signer>>,
     filters: HashMap<U256, Filter>,
     last_filter_id: U256,
     logger: Box<dyn SyncLogger<BlockchainError = BlockchainError, LoggerError = LoggerErrorT>>,
@@ -165,6 +203,7 @@ impl<LoggerErrorT: Debug> ProviderData<LoggerErrorT> {
         logger: Box<dyn SyncLogger<BlockchainError = BlockchainError, LoggerError = LoggerErrorT>>,
         subscriber_callback: Box<dyn SyncSubscriberCallback>,
         call_override: Option<Arc<dyn SyncCallOverride>>,
+        time_source: Arc<dyn TimeSource>,
         config: ProviderConfig,
     ) -> Result<Self, CreationError> {
         let InitialAccounts {
@@ -181,7 +220,12 @@ impl<LoggerErrorT: Debug> ProviderData<LoggerErrorT> {
             prev_randao_generator,
             block_time_offset_seconds,
             next_block_base_fee_per_gas,
-        } = create_blockchain_and_state(runtime_handle.clone(), &config, genesis_accounts)?;
+        } = create_blockchain_and_state(
+            runtime_handle.clone(),
+            &config,
+            &*time_source,
+            genesis_accounts,
+        )?;
 
         let max_cached_states = std::env::var(EDR_MAX_CACHED_STATES_ENV_VAR).map_or_else(
             |err| match err {
@@ -205,7 +249,7 @@ impl<LoggerErrorT: Debug> ProviderData<LoggerErrorT> {
         let allow_blocks_with_same_timestamp = config.allow_blocks_with_same_timestamp;
         let allow_unlimited_contract_size = config.allow_unlimited_contract_size;
         let beneficiary = config.coinbase;
-        let block_gas_limit = config.block_gas_limit;
+        let block_gas_limit = config.genesis_block_gas_limit();
         let is_auto_mining = config.mining.auto_mine;
         let min_gas_price = config.min_gas_price;
 
@@ -234,11 +278,13 @@ impl<LoggerErrorT: Debug> ProviderData<LoggerErrorT> {
             parent_beacon_block_root_generator,
             prev_randao_generator,
             block_time_offset_seconds,
+            time_source,
             fork_metadata,
             rpc_client,
             instance_id: B256::random(),
             is_auto_mining,
             next_block_base_fee_per_gas,
+            next_block_difficulty: None,
             next_block_timestamp: None,
             // Start with 1 to mimic Ganache
             next_snapshot_id: 1,
@@ -271,6 +317,7 @@ impl<LoggerErrorT: Debug> ProviderData<LoggerErrorT> {
             self.logger.clone(),
             self.subscriber_callback.clone(),
             self.call_override.clone(),
+            self.time_source.clone(),
             config,
         )?;
 
@@ -348,6 +395,24 @@ impl<LoggerErrorT: Debug> ProviderData<LoggerErrorT> {
         self.blockchain.last_block_number()
     }
 
+    /// Returns the largest block number that is safe from a reorg, based on
+    /// the forked network's chain id - or the local chain id when not
+    /// forking - and the latest block number.
+    ///
+    /// Returns `None` if all blocks fall within the safe block depth.
+    pub fn largest_safe_block_number(&self) -> Option<u64> {
+        let chain_id = self
+            .fork_metadata
+            .as_ref()
+            .map_or_else(|| self.chain_id(), |fork_metadata| fork_metadata.chain_id);
+
+        largest_safe_block_number(LargestSafeBlockNumberArgs {
+            chain_id,
+            latest_block_number: self.last_block_number(),
+            reorg_config: self.initial_config.reorg_config.as_ref(),
+        })
+    }
+
     /// Adds a filter for new blocks to the provider.
     pub fn add_block_filter<const IS_SUBSCRIPTION: bool>(
         &mut self,
@@ -410,6 +475,12 @@ impl<LoggerErrorT: Debug> ProviderData<LoggerErrorT> {
         self.initial_config.bail_on_transaction_failure
     }
 
+    /// The maximum number of blocks that a single `eth_getLogs` request may
+    /// span, if any.
+    pub fn get_logs_block_range_limit(&self) -> Option<u64> {
+        self.initial_config.get_logs_block_range_limit
+    }
+
     /// Fetch a block by block spec.
     /// Returns `None` if the block spec is `pending`.
     /// Returns `ProviderError::InvalidBlockSpec` error if the block spec is a
@@ -550,23 +621,7 @@ impl<LoggerErrorT: Debug> ProviderData<LoggerErrorT> {
         self.execute_in_block_context(
             prev_block_spec.as_ref(),
             |blockchain, _prev_block, state| {
-                let block_env = BlockEnv {
-                    number: U256::from(header.number),
-                    coinbase: header.beneficiary,
-                    timestamp: U256::from(header.timestamp),
-                    gas_limit: U256::from(header.gas_limit),
-                    basefee: header.base_fee_per_gas.unwrap_or_default(),
-                    difficulty: U256::from(header.difficulty),
-                    prevrandao: if cfg_env.handler_cfg.spec_id >= SpecId::MERGE {
-                        Some(header.mix_hash)
-                    } else {
-                        None
-                    },
-                    blob_excess_gas_and_price: header
-                        .blob_gas
-                        .as_ref()
-                        .map(|BlobGas { excess_gas, .. }| BlobExcessGasAndPrice::new(*excess_gas)),
-                };
+                let block_env = block_env_from_header(header, cfg_env.handler_cfg.spec_id);
 
                 debug_trace_transaction(
                     blockchain,
@@ -613,6 +668,10 @@ impl<LoggerErrorT: Debug> ProviderData<LoggerErrorT> {
     }
 
     /// Estimate the gas cost of a transaction. Matches Hardhat behavior.
+    ///
+    /// If the block spec is pending, the estimation is performed on top of the
+    /// pending block, i.e. the next block's number, timestamp, and base fee,
+    /// with all mem pool transactions applied to the state.
     pub fn estimate_gas(
         &mut self,
         transaction: ExecutableTransaction,
@@ -620,9 +679,14 @@ impl<LoggerErrorT: Debug> ProviderData<LoggerErrorT> {
     ) -> Result<EstimateGasResult, ProviderError<LoggerErrorT>> {
         let cfg_env = self.create_evm_config(Some(block_spec))?;
         // Minimum gas cost that is required for transaction to be included in
-        // a block
-        let minimum_cost = transaction.initial_cost(self.spec_id());
-        let tx_env: TxEnv = transaction.into();
+        // a block, including the EIP-7623 calldata floor cost once active
+        let spec_id = self.spec_id();
+        let initial_cost = transaction.initial_cost(spec_id);
+        let minimum_cost = transaction
+            .calldata_floor_cost(spec_id)
+            .map_or(initial_cost, |floor_cost| floor_cost.max(initial_cost));
+        let mut tx_env: TxEnv = transaction.into();
+        let should_generate_access_list = self.should_generate_access_list(&cfg_env, &tx_env);
 
         let state_overrides = StateOverrides::default();
 
@@ -631,6 +695,23 @@ impl<LoggerErrorT: Debug> ProviderData<LoggerErrorT> {
         self.execute_in_block_context(Some(block_spec), |blockchain, block, state| {
             let header = block.header();
 
+            let access_list = if should_generate_access_list {
+                let access_list = call::generate_access_list(RunCallArgs {
+                    blockchain,
+                    header,
+                    state,
+                    state_overrides: &state_overrides,
+                    cfg_env: cfg_env.clone(),
+                    tx_env: tx_env.clone(),
+                    debug_context: None,
+                })?;
+
+                tx_env.access_list = access_list.iter().cloned().map(Into::into).collect();
+                Some(access_list)
+            } else {
+                None
+            };
+
             // Measure the gas used by the transaction with optional limit from call request
             // defaulting to block limit. Report errors from initial call as if from
             // `eth_call`.
@@ -704,6 +785,7 @@ impl<LoggerErrorT: Debug> ProviderData<LoggerErrorT> {
                 return Ok(EstimateGasResult {
                     estimation: initial_estimation,
                     traces: trace_collector.into_traces(),
+                    access_list,
                 });
             }
 
@@ -723,7 +805,11 @@ impl<LoggerErrorT: Debug> ProviderData<LoggerErrorT> {
             })?;
 
             let traces = trace_collector.into_traces();
-            Ok(EstimateGasResult { estimation, traces })
+            Ok(EstimateGasResult {
+                estimation,
+                traces,
+                access_list,
+            })
         })?
     }
 
@@ -871,7 +957,10 @@ impl<LoggerErrorT: Debug> ProviderData<LoggerErrorT> {
                 let block = pending_block.as_ref().expect("We mined the pending block");
                 result
                     .base_fee_per_gas
-                    .push(calculate_next_base_fee(block.header()));
+                    .push(calculate_next_base_fee_with_params(
+                        block.header(),
+                        &self.base_fee_params(),
+                    ));
             }
         }
 
@@ -884,7 +973,6 @@ impl<LoggerErrorT: Debug> ProviderData<LoggerErrorT> {
 
     pub fn gas_price(&self) -> Result<U256, ProviderError<LoggerErrorT>> {
         const PRE_EIP_1559_GAS_PRICE: u64 = 8_000_000_000;
-        const SUGGESTED_PRIORITY_FEE_PER_GAS: u64 = 1_000_000_000;
 
         if let Some(next_block_gas_fee_per_gas) = self.next_block_base_fee_per_gas()? {
             Ok(next_block_gas_fee_per_gas + U256::from(SUGGESTED_PRIORITY_FEE_PER_GAS))
@@ -894,6 +982,109 @@ impl<LoggerErrorT: Debug> ProviderData<LoggerErrorT> {
         }
     }
 
+    /// Suggests fees for a transaction to be included in the next block. The
+    /// suggested max priority fee per gas is the median tip of the
+    /// transactions in the most recent blocks, defaulting to 1 gwei if there
+    /// are none. Remote blocks are read without their transactions, using
+    /// their headers and receipts instead.
+    pub fn gas_fees(&self) -> Result<GasFees, ProviderError<LoggerErrorT>> {
+        let Some(base_fee_per_gas) = self.next_block_base_fee_per_gas()? else {
+            return Err(ProviderError::UnmetHardfork {
+                actual: self.spec_id(),
+                minimum: SpecId::LONDON,
+            });
+        };
+
+        let last_block_number = self.last_block_number();
+        let oldest_block_number = last_block_number.saturating_sub(GAS_FEES_BLOCK_COUNT - 1);
+
+        let last_remote_block_number = self
+            .fork_metadata
+            .as_ref()
+            .map(|metadata| cmp::min(metadata.fork_block_number, last_block_number))
+            .filter(|block_number| *block_number >= oldest_block_number);
+
+        let (mut rewards, oldest_local_block_number) =
+            if let Some(last_remote_block_number) = last_remote_block_number {
+                let rpc_client = self
+                    .rpc_client
+                    .as_ref()
+                    .expect("we checked that there is a fork");
+
+                let rewards = tokio::task::block_in_place(|| {
+                    self.runtime_handle.block_on(remote_effective_rewards(
+                        rpc_client,
+                        oldest_block_number..=last_remote_block_number,
+                    ))
+                })?;
+
+                (rewards, last_remote_block_number + 1)
+            } else {
+                (Vec::new(), oldest_block_number)
+            };
+
+        for block_number in oldest_local_block_number..=last_block_number {
+            let block = self
+                .blockchain
+                .block_by_number(block_number)?
+                .expect("Block must exist as its number is not higher than the last block number");
+
+            let block_base_fee_per_gas = block.header().base_fee_per_gas.unwrap_or_default();
+            rewards.extend(
+                block
+                    .transactions()
+                    .iter()
+                    .map(|transaction| effective_reward(transaction, block_base_fee_per_gas)),
+            );
+        }
+
+        rewards.sort_unstable();
+        let max_priority_fee_per_gas = rewards
+            .get(rewards.len() / 2)
+            .copied()
+            .unwrap_or_else(|| U256::from(SUGGESTED_PRIORITY_FEE_PER_GAS));
+
+        Ok(GasFees {
+            base_fee_per_gas,
+            max_priority_fee_per_gas,
+            max_fee_per_gas: U256::from(2) * base_fee_per_gas + max_priority_fee_per_gas,
+        })
+    }
+
+    /// Retrieves the balance, nonce, and code hash of the accounts at the
+    /// provided addresses, in the same order. Non-existent accounts are
+    /// returned as empty accounts.
+    pub fn get_accounts(
+        &mut self,
+        addresses: &[Address],
+        block_spec: Option<&BlockSpec>,
+    ) -> Result<Vec<AccountSummary>, ProviderError<LoggerErrorT>> {
+        self.execute_in_block_context::<Result<Vec<AccountSummary>, ProviderError<LoggerErrorT>>>(
+            block_spec,
+            move |_blockchain, _block, state| {
+                addresses
+                    .iter()
+                    .map(|address| {
+                        let summary = state.basic(*address)?.map_or_else(
+                            AccountSummary::default,
+                            |account_info| AccountSummary {
+                                balance: account_info.balance,
+                                nonce: account_info.nonce,
+                                code_hash: if account_info.is_empty() {
+                                    B256::ZERO
+                                } else {
+                                    account_info.code_hash
+                                },
+                            },
+                        );
+
+                        Ok(summary)
+                    })
+                    .collect()
+            },
+        )?
+    }
+
     pub fn get_code(
         &mut self,
         address: Address,
@@ -915,6 +1106,31 @@ impl<LoggerErrorT: Debug> ProviderData<LoggerErrorT> {
         })?
     }
 
+    /// Retrieves the code hash of the account at the provided address, as
+    /// returned by the `EXTCODEHASH` opcode (EIP-1052). Returns zero for empty
+    /// and non-existent accounts.
+    pub fn get_code_hash(
+        &mut self,
+        address: Address,
+        block_spec: Option<&BlockSpec>,
+    ) -> Result<B256, ProviderError<LoggerErrorT>> {
+        self.execute_in_block_context::<Result<B256, ProviderError<LoggerErrorT>>>(
+            block_spec,
+            move |_blockchain, _block, state| {
+                // Forked accounts' code hashes are derived from the fetched code.
+                let code_hash = state.basic(address)?.map_or(B256::ZERO, |account_info| {
+                    if account_info.is_empty() {
+                        B256::ZERO
+                    } else {
+                        account_info.code_hash
+                    }
+                });
+
+                Ok(code_hash)
+            },
+        )?
+    }
+
     pub fn get_filter_changes(&mut self, filter_id: &U256) -> Option<FilteredEvents> {
         self.filters.get_mut(filter_id).map(Filter::take_events)
     }
@@ -939,6 +1155,47 @@ impl<LoggerErrorT: Debug> ProviderData<LoggerErrorT> {
             .transpose()
     }
 
+    /// Generates Merkle proofs of the account at the provided address and of
+    /// each of the provided storage slots, in the context of the specified
+    /// block.
+    pub fn get_proof(
+        &mut self,
+        address: Address,
+        storage_keys: Vec<U256>,
+        block_spec: &BlockSpec,
+    ) -> Result<AccountProof, ProviderError<LoggerErrorT>> {
+        self.execute_in_block_context::<Result<AccountProof, ProviderError<LoggerErrorT>>>(
+            Some(block_spec),
+            move |_blockchain, _block, state| {
+                let account_info = state.basic(address)?.unwrap_or_default();
+                let storage_hash = state
+                    .account_storage_root(&address)?
+                    .unwrap_or(KECCAK_NULL_RLP);
+
+                let storage_proof = storage_keys
+                    .into_iter()
+                    .map(|key| {
+                        Ok(StorageProof {
+                            key,
+                            value: state.storage(address, key)?,
+                            proof: state.storage_proof(&address, &key)?,
+                        })
+                    })
+                    .collect::<Result<Vec<_>, StateError>>()?;
+
+                Ok(AccountProof {
+                    address,
+                    account_proof: state.account_proof(&address)?,
+                    balance: account_info.balance,
+                    code_hash: account_info.code_hash,
+                    nonce: account_info.nonce,
+                    storage_hash,
+                    storage_proof,
+                })
+            },
+        )?
+    }
+
     pub fn get_storage_at(
         &mut self,
         address: Address,
@@ -977,6 +1234,36 @@ impl<LoggerErrorT: Debug> ProviderData<LoggerErrorT> {
         self.block_time_offset_seconds
     }
 
+    /// Sets the clock to the provided timestamp, such that subsequent blocks
+    /// are mined relative to it. Returns the new block time offset.
+    ///
+    /// A timestamp set using [`Self::set_next_block_timestamp`] still takes
+    /// precedence for the next block.
+    pub fn set_block_time(&mut self, timestamp: u64) -> Result<i64, ProviderError<LoggerErrorT>> {
+        let latest_block = self.blockchain.last_block()?;
+        let latest_block_timestamp = latest_block.header().timestamp;
+        if timestamp < latest_block_timestamp {
+            return Err(ProviderError::TimestampLowerThanPrevious {
+                proposed: timestamp,
+                previous: latest_block_timestamp,
+            });
+        }
+
+        let timestamp = i64::try_from(timestamp).map_err(|_error| {
+            ProviderError::InvalidInput(format!(
+                "Timestamp must be at most {}, but {timestamp} was provided",
+                i64::MAX
+            ))
+        })?;
+
+        let current_timestamp =
+            i64::try_from(self.time_source.unix_timestamp()).expect("timestamp too large");
+
+        self.block_time_offset_seconds = timestamp - current_timestamp;
+
+        Ok(self.block_time_offset_seconds)
+    }
+
     pub fn instance_id(&self) -> &B256 {
         &self.instance_id
     }
@@ -1023,10 +1310,11 @@ impl<LoggerErrorT: Debug> ProviderData<LoggerErrorT> {
             irregular_state: self.irregular_state.clone(),
             mem_pool: self.mem_pool.clone(),
             next_block_base_fee_per_gas: self.next_block_base_fee_per_gas,
+            next_block_difficulty: self.next_block_difficulty,
             next_block_timestamp: self.next_block_timestamp,
             parent_beacon_block_root_generator: self.parent_beacon_block_root_generator.clone(),
             prev_randao_generator: self.prev_randao_generator.clone(),
-            time: Instant::now(),
+            timestamp: self.time_source.unix_timestamp(),
         };
         self.snapshots.insert(id, snapshot);
 
@@ -1055,6 +1343,18 @@ impl<LoggerErrorT: Debug> ProviderData<LoggerErrorT> {
             .update(&result.state)
             .map_err(ProviderError::MemPoolUpdate)?;
 
+        if let Some(receipt_retention) = self.initial_config.receipt_retention {
+            if let Some(last_pruned_block_number) = self
+                .blockchain
+                .last_block_number()
+                .checked_sub(receipt_retention.get())
+            {
+                self.blockchain
+                    .prune_receipts(last_pruned_block_number)
+                    .map_err(ProviderError::Blockchain)?;
+            }
+        }
+
         if let Some(new_offset) = new_offset {
             self.block_time_offset_seconds = new_offset;
         }
@@ -1062,6 +1362,9 @@ impl<LoggerErrorT: Debug> ProviderData<LoggerErrorT> {
         // Reset the next block base fee per gas upon successful execution
         self.next_block_base_fee_per_gas.take();
 
+        // Reset the next block difficulty upon successful execution
+        self.next_block_difficulty.take();
+
         // Reset next block time stamp
         self.next_block_timestamp.take();
 
@@ -1217,6 +1520,11 @@ impl<LoggerErrorT: Debug> ProviderData<LoggerErrorT> {
         self.initial_config.network_id.to_string()
     }
 
+    /// The parameters of the EIP-1559 base fee adjustment between blocks.
+    pub fn base_fee_params(&self) -> BaseFeeParams {
+        self.initial_config.base_fee_params.unwrap_or_default()
+    }
+
     /// Calculates the next block's base fee per gas.
     pub fn next_block_base_fee_per_gas(&self) -> Result<Option<U256>, BlockchainError> {
         if self.spec_id() < SpecId::LONDON {
@@ -1228,7 +1536,10 @@ impl<LoggerErrorT: Debug> ProviderData<LoggerErrorT> {
                 || {
                     let last_block = self.last_block()?;
 
-                    let base_fee = calculate_next_base_fee(last_block.header());
+                    let base_fee = calculate_next_base_fee_with_params(
+                        last_block.header(),
+                        &self.base_fee_params(),
+                    );
 
                     Ok(base_fee)
                 },
@@ -1277,10 +1588,71 @@ impl<LoggerErrorT: Debug> ProviderData<LoggerErrorT> {
             )
     }
 
+    /// Computes the effective gas price of the pending transaction with the
+    /// provided hash, if it exists.
+    ///
+    /// If no base fee per gas is provided, the next block's base fee per gas is
+    /// used. If the transaction's max fee per gas is lower than the base fee
+    /// per gas, the transaction cannot be included in a block.
+    pub fn pending_transaction_effective_gas_price(
+        &self,
+        transaction_hash: &B256,
+        base_fee_per_gas: Option<U256>,
+    ) -> Result<Option<PendingEffectiveGasPrice>, ProviderError<LoggerErrorT>> {
+        let Some(transaction) = self.mem_pool.transaction_by_hash(transaction_hash) else {
+            return Ok(None);
+        };
+
+        let base_fee_per_gas = if let Some(base_fee_per_gas) = base_fee_per_gas {
+            Some(base_fee_per_gas)
+        } else {
+            self.next_block_base_fee_per_gas()?
+        };
+
+        let transaction = transaction.pending();
+        let Some(base_fee_per_gas) = base_fee_per_gas else {
+            // Without EIP-1559, the gas price is always paid in full.
+            return Ok(Some(PendingEffectiveGasPrice::Includable(
+                transaction.gas_price(),
+            )));
+        };
+
+        let max_fee_per_gas = transaction
+            .max_fee_per_gas()
+            .unwrap_or_else(|| transaction.gas_price());
+
+        let effective_gas_price = if max_fee_per_gas < base_fee_per_gas {
+            PendingEffectiveGasPrice::MaxFeeTooLow {
+                max_fee_per_gas,
+                base_fee_per_gas,
+            }
+        } else {
+            PendingEffectiveGasPrice::Includable(transaction.effective_gas_price(base_fee_per_gas))
+        };
+
+        Ok(Some(effective_gas_price))
+    }
+
     pub fn pending_transactions(&self) -> impl Iterator<Item = &ExecutableTransaction> {
         self.mem_pool.transactions()
     }
 
+    /// Retrieves the mem pool's executable transactions; i.e. those that are
+    /// eligible for inclusion in the next block.
+    pub fn executable_pending_transactions(&self) -> impl Iterator<Item = &ExecutableTransaction> {
+        self.mem_pool
+            .pending_transactions()
+            .map(OrderedTransaction::pending)
+    }
+
+    /// Retrieves the mem pool's queued transactions; i.e. those whose nonce is
+    /// too high to be executed.
+    pub fn queued_transactions(&self) -> impl Iterator<Item = &ExecutableTransaction> {
+        self.mem_pool
+            .future_transactions()
+            .map(OrderedTransaction::pending)
+    }
+
     pub fn remove_filter(&mut self, filter_id: &U256) -> bool {
         self.remove_filter_impl::</* IS_SUBSCRIPTION */ false>(filter_id)
     }
@@ -1298,6 +1670,50 @@ impl<LoggerErrorT: Debug> ProviderData<LoggerErrorT> {
         self.mem_pool.remove_transaction(transaction_hash)
     }
 
+    /// Rolls the blockchain back to the block with the provided number,
+    /// removing all later blocks and their state. Overrides of the next
+    /// block's configuration are reset.
+    pub fn rollback_to_block(
+        &mut self,
+        block_number: u64,
+    ) -> Result<(), ProviderError<LoggerErrorT>> {
+        let latest_block_number = self.last_block_number();
+        if block_number > latest_block_number {
+            return Err(ProviderError::RollbackToFutureBlock {
+                block_number,
+                latest_block_number,
+            });
+        }
+
+        if let Some(fork_metadata) = self.fork_metadata.as_ref() {
+            if block_number < fork_metadata.fork_block_number {
+                return Err(ProviderError::RollbackBeforeForkBlock {
+                    block_number,
+                    fork_block_number: fork_metadata.fork_block_number,
+                });
+            }
+        }
+
+        self.blockchain.revert_to_block(block_number)?;
+
+        self.block_number_to_state_id
+            .retain(|state_block_number, _| *state_block_number <= block_number);
+        self.irregular_state.remove_overrides_after(block_number);
+
+        // Snapshots of removed blocks can no longer be reverted to
+        self.snapshots
+            .retain(|_, snapshot| snapshot.block_number <= block_number);
+
+        self.next_block_base_fee_per_gas = None;
+        self.next_block_difficulty = None;
+        self.next_block_timestamp = None;
+
+        let state = self.current_state()?;
+        self.mem_pool.update(&**state)?;
+
+        Ok(())
+    }
+
     pub fn revert_to_snapshot(&mut self, snapshot_id: u64) -> bool {
         // Ensure that, if the snapshot exists, we also remove all subsequent snapshots,
         // as they can only be used once in Ganache.
@@ -1312,19 +1728,21 @@ impl<LoggerErrorT: Debug> ProviderData<LoggerErrorT> {
                 irregular_state,
                 mem_pool,
                 next_block_base_fee_per_gas,
+                next_block_difficulty,
                 next_block_timestamp,
                 parent_beacon_block_root_generator,
                 prev_randao_generator,
-                time,
+                timestamp,
             } = snapshot;
 
             self.block_number_to_state_id = block_number_to_state_id;
 
             // We compute a new offset such that:
             // now + new_offset == snapshot_date + old_offset
-            let duration_since_snapshot = Instant::now().duration_since(time);
+            let seconds_since_snapshot =
+                self.time_source.unix_timestamp().saturating_sub(timestamp);
             self.block_time_offset_seconds = block_time_offset_seconds
-                + i64::try_from(duration_since_snapshot.as_secs()).expect("duration too large");
+                - i64::try_from(seconds_since_snapshot).expect("duration too large");
 
             self.beneficiary = coinbase;
             self.blockchain
@@ -1334,6 +1752,7 @@ impl<LoggerErrorT: Debug> ProviderData<LoggerErrorT> {
             self.irregular_state = irregular_state;
             self.mem_pool = mem_pool;
             self.next_block_base_fee_per_gas = next_block_base_fee_per_gas;
+            self.next_block_difficulty = next_block_difficulty;
             self.next_block_timestamp = next_block_timestamp;
             self.parent_beacon_block_root_generator = parent_beacon_block_root_generator;
             self.prev_randao_generator = prev_randao_generator;
@@ -1344,6 +1763,12 @@ impl<LoggerErrorT: Debug> ProviderData<LoggerErrorT> {
         }
     }
 
+    /// Runs a transaction as a call in the context of the provided block spec,
+    /// defaulting to the latest block.
+    ///
+    /// If the block spec is pending, the call is performed on top of the
+    /// pending block, i.e. the next block's number and timestamp, with all mem
+    /// pool transactions applied to the state.
     pub fn run_call(
         &mut self,
         transaction: ExecutableTransaction,
@@ -1351,11 +1776,41 @@ impl<LoggerErrorT: Debug> ProviderData<LoggerErrorT> {
         state_overrides: &StateOverrides,
     ) -> Result<CallResult, ProviderError<LoggerErrorT>> {
         let cfg_env = self.create_evm_config(block_spec)?;
-        let tx_env = transaction.into();
+        let mut tx_env: TxEnv = transaction.into();
+        let should_generate_access_list = self.should_generate_access_list(&cfg_env, &tx_env);
+        let bail_on_call_without_code = self.initial_config.bail_on_call_without_code;
 
         let mut debugger = Debugger::with_mocker(Mocker::new(self.call_override.clone()));
 
         self.execute_in_block_context(block_spec, |blockchain, block, state| {
+            if bail_on_call_without_code {
+                if let Some(address) = call::callee_without_code(
+                    state,
+                    state_overrides,
+                    cfg_env.handler_cfg.spec_id,
+                    &tx_env,
+                )? {
+                    return Err(ProviderError::CallWithoutCode { address });
+                }
+            }
+
+            let access_list = if should_generate_access_list {
+                let access_list = call::generate_access_list(RunCallArgs {
+                    blockchain,
+                    header: block.header(),
+                    state,
+                    state_overrides,
+                    cfg_env: cfg_env.clone(),
+                    tx_env: tx_env.clone(),
+                    debug_context: None,
+                })?;
+
+                tx_env.access_list = access_list.iter().cloned().map(Into::into).collect();
+                Some(access_list)
+            } else {
+                None
+            };
+
             let execution_result = call::run_call(RunCallArgs {
                 blockchain,
                 header: block.header(),
@@ -1383,10 +1838,116 @@ impl<LoggerErrorT: Debug> ProviderData<LoggerErrorT> {
                 console_log_inputs: console_logger.into_encoded_messages(),
                 execution_result,
                 trace: traces.pop().expect("Must have a trace"),
+                access_list,
+            })
+        })?
+    }
+
+    /// Generates an access list of the accounts and storage slots accessed by
+    /// the transaction, along with the gas used by the transaction when
+    /// executed with and without it. Any access list provided with the
+    /// transaction is ignored.
+    pub fn create_access_list(
+        &mut self,
+        transaction: ExecutableTransaction,
+        block_spec: Option<&BlockSpec>,
+        state_overrides: &StateOverrides,
+    ) -> Result<AccessListResult, ProviderError<LoggerErrorT>> {
+        let cfg_env = self.create_evm_config(block_spec)?;
+        let mut tx_env: TxEnv = transaction.into();
+        tx_env.access_list = Vec::new();
+
+        self.execute_in_block_context(block_spec, |blockchain, block, state| {
+            let execution_result = call::run_call::<(), _>(RunCallArgs {
+                blockchain,
+                header: block.header(),
+                state,
+                state_overrides,
+                cfg_env: cfg_env.clone(),
+                tx_env: tx_env.clone(),
+                debug_context: None,
+            })?;
+            let gas_used_without_access_list = execution_result.gas_used();
+
+            let access_list = call::generate_access_list(RunCallArgs {
+                blockchain,
+                header: block.header(),
+                state,
+                state_overrides,
+                cfg_env: cfg_env.clone(),
+                tx_env: tx_env.clone(),
+                debug_context: None,
+            })?;
+
+            tx_env.access_list = access_list.iter().cloned().map(Into::into).collect();
+            let execution_result = call::run_call::<(), _>(RunCallArgs {
+                blockchain,
+                header: block.header(),
+                state,
+                state_overrides,
+                cfg_env,
+                tx_env,
+                debug_context: None,
+            })?;
+
+            Ok(AccessListResult {
+                access_list,
+                gas_used: execution_result.gas_used(),
+                gas_used_without_access_list,
             })
         })?
     }
 
+    /// Simulates the execution of a transaction at the top of the next block,
+    /// on top of the latest state. Unlike [`ProviderData::run_call`], the
+    /// block environment - number, timestamp, and base fee - is that of the
+    /// block that would be mined next.
+    pub fn run_call_in_next_block(
+        &mut self,
+        transaction: ExecutableTransaction,
+        state_overrides: &StateOverrides,
+    ) -> Result<CallResult, ProviderError<LoggerErrorT>> {
+        let cfg_env = self.create_evm_config(None)?;
+        let block_env = self.next_block_env(cfg_env.handler_cfg.spec_id)?;
+        let tx_env = transaction.into();
+
+        let state = self.current_state()?;
+
+        let mut debugger = Debugger::with_mocker(Mocker::new(self.call_override.clone()));
+
+        let execution_result = guaranteed_dry_run(
+            &*self.blockchain,
+            &**state,
+            state_overrides,
+            cfg_env,
+            tx_env,
+            block_env,
+            Some(DebugContext {
+                data: &mut debugger,
+                register_handles_fn: register_debugger_handles,
+            }),
+        )
+        .map_err(ProviderError::RunTransaction)?
+        .result;
+
+        let Debugger {
+            console_logger,
+            trace_collector,
+            ..
+        } = debugger;
+
+        let mut traces = trace_collector.into_traces();
+        // Should only have a single raw trace
+        assert_eq!(traces.len(), 1);
+
+        Ok(CallResult {
+            console_log_inputs: console_logger.into_encoded_messages(),
+            execution_result,
+            trace: traces.pop().expect("Must have a trace"),
+            access_list: None,
+        })
+    }
+
     pub fn transaction_receipt(
         &self,
         transaction_hash: &B256,
@@ -1590,6 +2151,22 @@ impl<LoggerErrorT: Debug> ProviderData<LoggerErrorT> {
         Ok(())
     }
 
+    /// Sets the next block's difficulty. Post-merge blocks have a difficulty of
+    /// zero, use [`ProviderData::set_next_prev_randao`] instead.
+    pub fn set_next_block_difficulty(
+        &mut self,
+        difficulty: U256,
+    ) -> Result<(), ProviderError<LoggerErrorT>> {
+        let spec_id = self.spec_id();
+        if spec_id >= SpecId::MERGE {
+            return Err(ProviderError::SetNextBlockDifficultyUnsupported { spec_id });
+        }
+
+        self.next_block_difficulty = Some(difficulty);
+
+        Ok(())
+    }
+
     /// Set the next block timestamp.
     pub fn set_next_block_timestamp(
         &mut self,
@@ -1679,12 +2256,29 @@ impl<LoggerErrorT: Debug> ProviderData<LoggerErrorT> {
         address: Address,
         index: U256,
         value: U256,
+    ) -> Result<(), ProviderError<LoggerErrorT>> {
+        self.set_account_storage_slots(address, &[(index, value)])
+    }
+
+    /// Sets the provided storage slots of the account at the provided address,
+    /// as a single state modification.
+    pub fn set_account_storage_slots(
+        &mut self,
+        address: Address,
+        entries: &[(U256, U256)],
     ) -> Result<(), ProviderError<LoggerErrorT>> {
         // We clone to automatically revert in case of subsequent errors.
         let mut modified_state = (*self.current_state()?).clone();
-        let old_value = modified_state.set_account_storage_slot(address, index, value)?;
 
-        let slot = StorageSlot::new_changed(old_value, value);
+        let slots = entries
+            .iter()
+            .map(|(index, value)| {
+                let old_value = modified_state.set_account_storage_slot(address, *index, *value)?;
+
+                Ok((*index, StorageSlot::new_changed(old_value, *value)))
+            })
+            .collect::<Result<Vec<_>, ProviderError<LoggerErrorT>>>()?;
+
         let account_info = modified_state.basic(address).and_then(|mut account_info| {
             // Retrieve the code if it's not empty. This is needed for the irregular state.
             if let Some(account_info) = &mut account_info {
@@ -1699,24 +2293,35 @@ impl<LoggerErrorT: Debug> ProviderData<LoggerErrorT> {
         let state_root = modified_state.state_root()?;
 
         let block_number = self.blockchain.last_block_number();
-        self.irregular_state
+        let state_override = self
+            .irregular_state
             .state_override_at_block_number(block_number)
-            .or_insert_with(|| StateOverride::with_state_root(state_root))
-            .diff
-            .apply_storage_change(address, index, slot, account_info);
+            .or_insert_with(|| StateOverride::with_state_root(state_root));
+
+        for (index, slot) in slots {
+            state_override
+                .diff
+                .apply_storage_change(address, index, slot, account_info.clone());
+        }
 
         self.add_state_to_cache(modified_state, block_number);
 
         Ok(())
     }
 
+    /// Registers the provided signer for its account, replacing the account's
+    /// existing signer, if any.
+    pub fn add_signer(&mut self, signer: Box<dyn Signer>) {
+        self.local_accounts.insert(signer.address(), signer);
+    }
+
     pub fn sign(
         &self,
         address: &Address,
         message: Bytes,
     ) -> Result<Signature, ProviderError<LoggerErrorT>> {
         match self.local_accounts.get(address) {
-            Some(secret_key) => Ok(Signature::new(&message[..], secret_key)?),
+            Some(signer) => Ok(signer.sign_hash(hash_message(&message))?),
             None => Err(ProviderError::UnknownAddress { address: *address }),
         }
     }
@@ -1727,9 +2332,9 @@ impl<LoggerErrorT: Debug> ProviderData<LoggerErrorT> {
         message: &TypedData,
     ) -> Result<Signature, ProviderError<LoggerErrorT>> {
         match self.local_accounts.get(address) {
-            Some(secret_key) => {
+            Some(signer) => {
                 let hash: B256 = message.encode_eip712()?.into();
-                Ok(Signature::new(RecoveryMessage::Hash(hash), secret_key)?)
+                Ok(signer.sign_hash(hash)?)
             }
             None => Err(ProviderError::UnknownAddress { address: *address }),
         }
@@ -1800,7 +2405,28 @@ impl<LoggerErrorT: Debug> ProviderData<LoggerErrorT> {
     ) -> Result<B256, ProviderError<LoggerErrorT>> {
         let transaction_hash = *transaction.hash();
 
-        let state = self.current_state()?;
+        if !self.initial_config.allow_zero_gas_price && transaction.gas_price() == U256::ZERO {
+            return Err(ProviderError::ZeroGasPriceNotAllowed { transaction_hash });
+        }
+
+        if self.min_gas_price > U256::ZERO {
+            // EIP-1559 transactions are compared using the price that they would pay in
+            // the next block
+            let effective_gas_price = self.next_block_base_fee_per_gas()?.map_or_else(
+                || transaction.gas_price(),
+                |base_fee| transaction.effective_gas_price(base_fee),
+            );
+
+            if effective_gas_price < self.min_gas_price {
+                return Err(ProviderError::GasPriceBelowMinimum {
+                    transaction_hash,
+                    expected: self.min_gas_price,
+                    actual: effective_gas_price,
+                });
+            }
+        }
+
+        let state = self.current_state()?;
         // Handles validation
         self.mem_pool.add_transaction(&*state, transaction)?;
 
@@ -1847,6 +2473,12 @@ impl<LoggerErrorT: Debug> ProviderData<LoggerErrorT> {
         Ok(CfgEnvWithHandlerCfg::new_with_spec_id(cfg_env, spec_id))
     }
 
+    /// Executes the provided function in the context of the block
+    /// corresponding to the block spec, defaulting to the latest block.
+    ///
+    /// For the pending block spec, a pending block is mined - without
+    /// committing it - from the mem pool's transactions. The function then
+    /// receives the pending block and the state after its execution.
     fn execute_in_block_context<T>(
         &mut self,
         block_spec: Option<&BlockSpec>,
@@ -1884,6 +2516,46 @@ impl<LoggerErrorT: Debug> ProviderData<LoggerErrorT> {
         }
     }
 
+    /// Whether an access list should be generated for the provided call, before
+    /// executing it.
+    fn should_generate_access_list(&self, cfg_env: &CfgEnvWithHandlerCfg, tx_env: &TxEnv) -> bool {
+        self.initial_config.generate_access_lists_for_calls
+            && cfg_env.handler_cfg.spec_id >= SpecId::BERLIN
+            && tx_env.access_list.is_empty()
+    }
+
+    /// Constructs the block environment of the next block, based on the
+    /// current head and the provider's configuration values.
+    fn next_block_env(&self, spec_id: SpecId) -> Result<BlockEnv, ProviderError<LoggerErrorT>> {
+        let last_block = self.blockchain.last_block()?;
+        let (timestamp, _new_offset) = self.next_block_timestamp(None)?;
+
+        let header = PartialHeader::new(
+            spec_id,
+            BlockOptions {
+                beneficiary: Some(self.beneficiary),
+                gas_limit: Some(self.mem_pool.block_gas_limit()),
+                timestamp: Some(timestamp),
+                mix_hash: if spec_id >= SpecId::MERGE {
+                    Some(self.prev_randao_generator.next_value())
+                } else {
+                    None
+                },
+                base_fee: self.next_block_base_fee_per_gas,
+                base_fee_params: Some(self.base_fee_params()),
+                difficulty: self.next_block_difficulty,
+                ..BlockOptions::default()
+            },
+            Some(last_block.header()),
+        );
+
+        // Only the fields of the partial header make up the block environment, so
+        // the remaining roots are irrelevant.
+        let header = Header::new(header, KECCAK_NULL_RLP, KECCAK_NULL_RLP, None);
+
+        Ok(block_env_from_header(&header, spec_id))
+    }
+
     /// Mine a block using the provided options. If an option has not been
     /// specified, it will be set using the provider's configuration values.
     fn mine_block(
@@ -1891,6 +2563,8 @@ impl<LoggerErrorT: Debug> ProviderData<LoggerErrorT> {
         mut options: BlockOptions,
     ) -> Result<DebugMineBlockResultAndState<StateError>, ProviderError<LoggerErrorT>> {
         options.base_fee = options.base_fee.or(self.next_block_base_fee_per_gas);
+        options.difficulty = options.difficulty.or(self.next_block_difficulty);
+        options.base_fee_params = options.base_fee_params.or(Some(self.base_fee_params()));
         options.beneficiary = Some(options.beneficiary.unwrap_or(self.beneficiary));
         options.gas_limit = Some(
             options
@@ -1947,9 +2621,17 @@ impl<LoggerErrorT: Debug> ProviderData<LoggerErrorT> {
     ) -> Result<DebugMineBlockResultAndState<StateError>, ProviderError<LoggerErrorT>> {
         let (block_timestamp, _new_offset) = self.next_block_timestamp(None)?;
 
-        // Mining a pending block shouldn't affect the mix hash.
+        // Mining a pending block shouldn't advance the prevrandao generator, but it
+        // should use the same value as the next mined block.
+        let mix_hash = if self.blockchain.spec_id() >= SpecId::MERGE {
+            Some(self.prev_randao_generator.next_value())
+        } else {
+            None
+        };
+
         self.mine_block(BlockOptions {
             timestamp: Some(block_timestamp),
+            mix_hash,
             ..BlockOptions::default()
         })
     }
@@ -1968,8 +2650,7 @@ impl<LoggerErrorT: Debug> ProviderData<LoggerErrorT> {
         let latest_block_header = latest_block.header();
 
         let current_timestamp =
-            i64::try_from(SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs())
-                .expect("timestamp too large");
+            i64::try_from(self.time_source.unix_timestamp()).expect("timestamp too large");
 
         let (mut block_timestamp, mut new_offset) = if let Some(timestamp) = timestamp {
             timestamp.checked_sub(latest_block_header.timestamp).ok_or(
@@ -2036,12 +2717,12 @@ impl<LoggerErrorT: Debug> ProviderData<LoggerErrorT> {
                 sender,
             )?)
         } else {
-            let secret_key = self
+            let signer = self
                 .local_accounts
                 .get(&sender)
                 .ok_or(ProviderError::UnknownAddress { address: sender })?;
 
-            let signed_transaction = request.sign(secret_key)?;
+            let signed_transaction = signer.sign_transaction(request)?;
             Ok(ExecutableTransaction::new(
                 self.blockchain.spec_id(),
                 signed_transaction,
@@ -2160,7 +2841,10 @@ impl StateId {
     }
 }
 
-fn block_time_offset_seconds(config: &ProviderConfig) -> Result<i64, CreationError> {
+fn block_time_offset_seconds(
+    config: &ProviderConfig,
+    time_source: &dyn TimeSource,
+) -> Result<i64, CreationError> {
     config.initial_date.map_or(Ok(0), |initial_date| {
         let initial_timestamp = i64::try_from(
             initial_date
@@ -2170,13 +2854,8 @@ fn block_time_offset_seconds(config: &ProviderConfig) -> Result<i64, CreationErr
         )
         .expect("initial date must be representable as i64");
 
-        let current_timestamp = i64::try_from(
-            SystemTime::now()
-                .duration_since(UNIX_EPOCH)
-                .expect("current time must be after UNIX epoch")
-                .as_secs(),
-        )
-        .expect("Current timestamp must be representable as i64");
+        let current_timestamp = i64::try_from(time_source.unix_timestamp())
+            .expect("Current timestamp must be representable as i64");
 
         Ok(initial_timestamp - current_timestamp)
     })
@@ -2196,9 +2875,14 @@ struct BlockchainAndState {
 fn create_blockchain_and_state(
     runtime: runtime::Handle,
     config: &ProviderConfig,
+    time_source: &dyn TimeSource,
     mut genesis_accounts: HashMap<Address, Account>,
 ) -> Result<BlockchainAndState, CreationError> {
-    let mut prev_randao_generator = RandomHashGenerator::with_seed(edr_defaults::MIX_HASH_SEED);
+    let mut prev_randao_generator = if let Some(prev_randao_seed) = &config.prev_randao_seed {
+        RandomHashGenerator::with_value(*prev_randao_seed)
+    } else {
+        RandomHashGenerator::with_seed(edr_defaults::MIX_HASH_SEED)
+    };
 
     if let Some(fork_config) = &config.fork {
         let state_root_generator = Arc::new(parking_lot::Mutex::new(
@@ -2208,7 +2892,18 @@ fn create_blockchain_and_state(
         let http_headers = fork_config
             .http_headers
             .as_ref()
-            .map(|headers| HeaderMap::try_from(headers).map_err(CreationError::InvalidHttpHeaders))
+            .map(|headers| {
+                headers
+                    .iter()
+                    .map(|(name, value)| {
+                        Ok((
+                            HeaderName::try_from(name.as_str())?,
+                            HeaderValue::try_from(value.as_str())?,
+                        ))
+                    })
+                    .collect::<Result<HeaderMap, HttpError>>()
+                    .map_err(CreationError::InvalidHttpHeaders)
+            })
             .transpose()?;
 
         let (blockchain, mut irregular_state) =
@@ -2223,7 +2918,8 @@ fn create_blockchain_and_state(
                         config.cache_dir.clone(),
                         http_headers.clone(),
                     )
-                    .expect("url ok"),
+                    .expect("url ok")
+                    .with_reorg_config(config.reorg_config.clone()),
                     fork_config.block_number,
                     &mut irregular_state,
                     state_root_generator.clone(),
@@ -2240,7 +2936,8 @@ fn create_blockchain_and_state(
             config.cache_dir.clone(),
             http_headers,
         )
-        .expect("url ok");
+        .expect("url ok")
+        .with_reorg_config(config.reorg_config.clone());
 
         if !genesis_accounts.is_empty() {
             let genesis_addresses = genesis_accounts.keys().cloned().collect::<Vec<_>>();
@@ -2291,19 +2988,16 @@ fn create_blockchain_and_state(
             .expect("Fork state must exist");
 
         let block_time_offset_seconds = {
-            let fork_block_timestamp = UNIX_EPOCH
-                + Duration::from_secs(
-                    blockchain
-                        .last_block()
-                        .map_err(CreationError::Blockchain)?
-                        .header()
-                        .timestamp,
-                );
-
-            let elapsed_time = SystemTime::now()
-                .duration_since(fork_block_timestamp)
-                .expect("current time must be after fork block")
-                .as_secs();
+            let fork_block_timestamp = blockchain
+                .last_block()
+                .map_err(CreationError::Blockchain)?
+                .header()
+                .timestamp;
+
+            let elapsed_time = time_source
+                .unix_timestamp()
+                .checked_sub(fork_block_timestamp)
+                .expect("current time must be after fork block");
 
             -i64::try_from(elapsed_time)
                 .expect("Elapsed time since fork block must be representable as i64")
@@ -2354,29 +3048,36 @@ fn create_blockchain_and_state(
             None
         };
 
-        let blockchain = LocalBlockchain::new(
+        let mut blockchain = LocalBlockchain::new(
             StateDiff::from(genesis_accounts),
             config.chain_id,
             config.hardfork,
             GenesisBlockOptions {
-                gas_limit: Some(config.block_gas_limit),
-                timestamp: config.initial_date.map(|d| {
-                    d.duration_since(UNIX_EPOCH)
-                        .expect("initial date must be after UNIX epoch")
-                        .as_secs()
-                }),
+                gas_limit: Some(config.genesis_block_gas_limit()),
+                timestamp: Some(config.initial_date.map_or_else(
+                    || time_source.unix_timestamp(),
+                    |d| {
+                        d.duration_since(UNIX_EPOCH)
+                            .expect("initial date must be after UNIX epoch")
+                            .as_secs()
+                    },
+                )),
                 mix_hash,
                 base_fee: config.initial_base_fee_per_gas,
                 blob_gas: config.initial_blob_gas.clone(),
             },
         )?;
 
+        if let Some(hardfork_activations) = config.hardfork_activations.clone() {
+            blockchain = blockchain.with_hardfork_activations(hardfork_activations);
+        }
+
         let irregular_state = IrregularState::default();
         let state = blockchain
             .state_at_block_number(0, irregular_state.state_overrides())
             .expect("Genesis state must exist");
 
-        let block_time_offset_seconds = block_time_offset_seconds(config)?;
+        let block_time_offset_seconds = block_time_offset_seconds(config, time_source)?;
 
         Ok(BlockchainAndState {
             fork_metadata: None,
@@ -2417,6 +3118,7 @@ pub(crate) mod test_utils {
 
     use anyhow::anyhow;
     use edr_eth::transaction::{Eip155TransactionRequest, TransactionKind, TransactionRequest};
+    use edr_evm::time::SystemTimeSource;
     use edr_test_utils::env::get_alchemy_url;
 
     use super::*;
@@ -2454,23 +3156,33 @@ pub(crate) mod test_utils {
 
             let config = create_test_config_with_fork(fork);
 
+            Self::new(config)
+        }
+
+        /// Constructs a fixture with the provided configuration and a
+        /// dedicated runtime.
+        pub fn new(config: ProviderConfig) -> anyhow::Result<Self> {
+            Self::new_with_time_source(config, Arc::new(SystemTimeSource))
+        }
+
+        /// Constructs a fixture with the provided configuration, time source,
+        /// and a dedicated runtime.
+        pub fn new_with_time_source(
+            mut config: ProviderConfig,
+            time_source: Arc<dyn TimeSource>,
+        ) -> anyhow::Result<Self> {
             let runtime = runtime::Builder::new_multi_thread()
                 .worker_threads(1)
                 .enable_all()
                 .thread_name("provider-data-test")
                 .build()?;
 
-            Self::new(runtime, config)
-        }
-
-        pub fn new(
-            runtime: tokio::runtime::Runtime,
-            mut config: ProviderConfig,
-        ) -> anyhow::Result<Self> {
             let logger = Box::<NoopLogger>::default();
             let subscription_callback_noop = Box::new(|_| ());
 
-            let impersonated_account = Address::random();
+            // A fixed address keeps the genesis state, and thereby block hashes,
+            // reproducible across fixtures
+            let impersonated_account = Address::repeat_byte(0x1f);
             config.genesis_accounts.insert(
                 impersonated_account,
                 AccountInfo {
@@ -2486,6 +3198,7 @@ pub(crate) mod test_utils {
                 logger,
                 subscription_callback_noop,
                 None,
+                time_source,
                 config.clone(),
             )?;
 
@@ -2553,12 +3266,28 @@ pub(crate) mod test_utils {
 
 #[cfg(test)]
 mod tests {
-    use std::convert::Infallible;
+    use std::{
+        convert::Infallible,
+        sync::atomic::{self, AtomicUsize},
+        time::Duration,
+    };
 
     use alloy_sol_types::{sol, SolCall};
     use anyhow::Context;
-    use edr_eth::remote::eth::CallRequest;
-    use edr_evm::{hex, MineOrdering, TransactionError};
+    use edr_eth::{
+        log::CallLog,
+        remote::eth::CallRequest,
+        signature::{secret_key_from_str, SignatureError},
+        spec::HardforkActivations,
+        transaction::{
+            Eip1559TransactionRequest, Eip155TransactionRequest, SignedTransaction,
+            TransactionKind, TransactionRequest,
+        },
+    };
+    use edr_evm::{
+        hex, interpreter::opcode, keccak256, time::ManualTimeSource, MineOrdering, StorageAccess,
+        TransactionError,
+    };
     use edr_test_utils::env::get_alchemy_url;
     use serde_json::json;
 
@@ -2569,7 +3298,7 @@ mod tests {
         test_utils::{
             create_test_config, create_test_config_with_fork, one_ether, FORK_BLOCK_NUMBER,
         },
-        MemPoolConfig, MiningConfig, ProviderConfig,
+        LocalSigner, MemPoolConfig, MiningConfig, ProviderConfig, DEFAULT_BLOCK_GAS_LIMIT,
     };
 
     #[test]
@@ -2680,6 +3409,280 @@ mod tests {
         test_add_pending_transaction(&mut fixture, transaction)
     }
 
+    fn zero_gas_price_transaction(
+        fixture: &ProviderTestFixture,
+    ) -> anyhow::Result<ExecutableTransaction> {
+        let request = TransactionRequest::Eip155(Eip155TransactionRequest {
+            kind: TransactionKind::Call(Address::ZERO),
+            gas_limit: 30_000,
+            gas_price: U256::ZERO,
+            value: U256::from(1),
+            input: Bytes::default(),
+            nonce: 0,
+            chain_id: fixture.config.chain_id,
+        });
+        let sender = fixture.nth_local_account(0)?;
+
+        Ok(fixture
+            .provider_data
+            .sign_transaction_request(TransactionRequestAndSender { request, sender })?)
+    }
+
+    #[test]
+    fn add_pending_transaction_with_zero_gas_price_allowed() -> anyhow::Result<()> {
+        let mut fixture = ProviderTestFixture::new_local()?;
+        assert!(fixture.config.allow_zero_gas_price);
+
+        let transaction = zero_gas_price_transaction(&fixture)?;
+
+        test_add_pending_transaction(&mut fixture, transaction)
+    }
+
+    #[test]
+    fn add_pending_transaction_with_zero_gas_price_disallowed() -> anyhow::Result<()> {
+        let config = ProviderConfig {
+            allow_zero_gas_price: false,
+            ..create_test_config()
+        };
+
+        let mut fixture = ProviderTestFixture::new(config)?;
+
+        let transaction = zero_gas_price_transaction(&fixture)?;
+        let expected_hash = *transaction.hash();
+
+        let result = fixture.provider_data.add_pending_transaction(transaction);
+        assert!(matches!(
+            result,
+            Err(ProviderError::ZeroGasPriceNotAllowed { transaction_hash }) if transaction_hash == expected_hash
+        ));
+        assert_eq!(fixture.provider_data.mem_pool.transactions().count(), 0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn gas_fees_with_recent_transactions() -> anyhow::Result<()> {
+        const GWEI: u64 = 1_000_000_000;
+
+        let mut fixture = ProviderTestFixture::new_local()?;
+
+        // Without any transactions, the default tip is suggested
+        let gas_fees = fixture.provider_data.gas_fees()?;
+        let base_fee_per_gas = fixture
+            .provider_data
+            .next_block_base_fee_per_gas()?
+            .expect("London is active");
+        assert_eq!(gas_fees.base_fee_per_gas, base_fee_per_gas);
+        assert_eq!(gas_fees.max_priority_fee_per_gas, U256::from(GWEI));
+
+        let sender = fixture.nth_local_account(0)?;
+        for (nonce, tip) in [3u64, 1, 2].into_iter().enumerate() {
+            let request = TransactionRequest::Eip1559(Eip1559TransactionRequest {
+                chain_id: fixture.config.chain_id,
+                nonce: nonce as u64,
+                max_priority_fee_per_gas: U256::from(tip * GWEI),
+                max_fee_per_gas: U256::from(100 * GWEI),
+                gas_limit: 30_000,
+                kind: TransactionKind::Call(Address::ZERO),
+                value: U256::from(1),
+                input: Bytes::default(),
+                access_list: Vec::new(),
+            });
+            let transaction = fixture
+                .provider_data
+                .sign_transaction_request(TransactionRequestAndSender { request, sender })?;
+
+            fixture.provider_data.add_pending_transaction(transaction)?;
+            fixture
+                .provider_data
+                .mine_and_commit_block(BlockOptions::default())?;
+        }
+
+        let gas_fees = fixture.provider_data.gas_fees()?;
+        let base_fee_per_gas = fixture
+            .provider_data
+            .next_block_base_fee_per_gas()?
+            .expect("London is active");
+        assert_eq!(gas_fees.base_fee_per_gas, base_fee_per_gas);
+        assert_eq!(gas_fees.max_priority_fee_per_gas, U256::from(2 * GWEI));
+        assert_eq!(
+            gas_fees.max_fee_per_gas,
+            U256::from(2) * base_fee_per_gas + U256::from(2 * GWEI)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn gas_fees_fork_mode() -> anyhow::Result<()> {
+        let fixture = ProviderTestFixture::new_forked(None)?;
+
+        // The suggested tip is derived from the remote receipts, which must match
+        // the tips of the remote blocks' transactions
+        let last_block_number = fixture.provider_data.last_block_number();
+        let oldest_block_number = last_block_number.saturating_sub(GAS_FEES_BLOCK_COUNT - 1);
+
+        let mut rewards = Vec::new();
+        for block_number in oldest_block_number..=last_block_number {
+            let block = fixture
+                .provider_data
+                .blockchain
+                .block_by_number(block_number)?
+                .expect("block exists");
+
+            let base_fee_per_gas = block.header().base_fee_per_gas.unwrap_or_default();
+            rewards.extend(
+                block
+                    .transactions()
+                    .iter()
+                    .map(|transaction| effective_reward(transaction, base_fee_per_gas)),
+            );
+        }
+        rewards.sort_unstable();
+        let expected_max_priority_fee_per_gas = rewards[rewards.len() / 2];
+
+        let gas_fees = fixture.provider_data.gas_fees()?;
+        let base_fee_per_gas = fixture
+            .provider_data
+            .next_block_base_fee_per_gas()?
+            .expect("London is active");
+        assert_eq!(gas_fees.base_fee_per_gas, base_fee_per_gas);
+        assert_eq!(
+            gas_fees.max_priority_fee_per_gas,
+            expected_max_priority_fee_per_gas
+        );
+        assert_eq!(
+            gas_fees.max_fee_per_gas,
+            U256::from(2) * base_fee_per_gas + expected_max_priority_fee_per_gas
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn gas_fees_pre_london() -> anyhow::Result<()> {
+        let config = ProviderConfig {
+            hardfork: SpecId::BERLIN,
+            ..create_test_config()
+        };
+
+        let fixture = ProviderTestFixture::new(config)?;
+
+        let result = fixture.provider_data.gas_fees();
+        assert!(matches!(
+            result,
+            Err(ProviderError::UnmetHardfork {
+                actual: SpecId::BERLIN,
+                minimum: SpecId::LONDON,
+            })
+        ));
+
+        Ok(())
+    }
+
+    fn fixture_with_min_gas_price(min_gas_price: U256) -> anyhow::Result<ProviderTestFixture> {
+        let config = ProviderConfig {
+            min_gas_price,
+            ..create_test_config()
+        };
+
+        let mut fixture = ProviderTestFixture::new(config)?;
+        fixture.provider_data.set_auto_mining(false);
+
+        Ok(fixture)
+    }
+
+    #[test]
+    fn add_pending_transaction_legacy_below_min_gas_price() -> anyhow::Result<()> {
+        let min_gas_price = U256::from(10_000_000_000u64);
+        let mut fixture = fixture_with_min_gas_price(min_gas_price)?;
+
+        let sender = fixture.nth_local_account(0)?;
+        let transaction =
+            fixture
+                .provider_data
+                .sign_transaction_request(TransactionRequestAndSender {
+                    request: TransactionRequest::Eip155(Eip155TransactionRequest {
+                        kind: TransactionKind::Call(Address::ZERO),
+                        gas_limit: 30_000,
+                        gas_price: min_gas_price - U256::from(1),
+                        value: U256::from(1),
+                        input: Bytes::default(),
+                        nonce: 0,
+                        chain_id: fixture.config.chain_id,
+                    }),
+                    sender,
+                })?;
+        let expected_hash = *transaction.hash();
+
+        let result = fixture.provider_data.add_pending_transaction(transaction);
+        assert!(matches!(
+            result,
+            Err(ProviderError::GasPriceBelowMinimum {
+                transaction_hash,
+                expected,
+                actual,
+            }) if transaction_hash == expected_hash
+                && expected == min_gas_price
+                && actual == min_gas_price - U256::from(1)
+        ));
+        assert_eq!(fixture.provider_data.mem_pool.transactions().count(), 0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn add_pending_transaction_eip1559_below_min_gas_price() -> anyhow::Result<()> {
+        let min_gas_price = U256::from(10_000_000_000u64);
+        let mut fixture = fixture_with_min_gas_price(min_gas_price)?;
+
+        let base_fee = fixture
+            .provider_data
+            .next_block_base_fee_per_gas()?
+            .context("EIP-1559 should be active")?;
+        assert!(base_fee < min_gas_price);
+
+        let sender = fixture.nth_local_account(0)?;
+        let eip1559_transaction = |nonce, max_priority_fee_per_gas| {
+            fixture
+                .provider_data
+                .sign_transaction_request(TransactionRequestAndSender {
+                    request: TransactionRequest::Eip1559(Eip1559TransactionRequest {
+                        chain_id: fixture.config.chain_id,
+                        nonce,
+                        max_priority_fee_per_gas,
+                        // The max fee exceeds the minimum, but the effective gas price doesn't
+                        max_fee_per_gas: min_gas_price * U256::from(10),
+                        gas_limit: 30_000,
+                        kind: TransactionKind::Call(Address::ZERO),
+                        value: U256::from(1),
+                        input: Bytes::default(),
+                        access_list: Vec::new(),
+                    }),
+                    sender,
+                })
+        };
+
+        let underpriced_transaction =
+            eip1559_transaction(0, min_gas_price - base_fee - U256::from(1))?;
+        let transaction = eip1559_transaction(0, min_gas_price - base_fee)?;
+
+        let result = fixture
+            .provider_data
+            .add_pending_transaction(underpriced_transaction);
+        assert!(matches!(
+            result,
+            Err(ProviderError::GasPriceBelowMinimum { actual, .. })
+                if actual == min_gas_price - U256::from(1)
+        ));
+
+        // The effective gas price of base fee plus tip meets the minimum
+        fixture.provider_data.add_pending_transaction(transaction)?;
+        assert_eq!(fixture.provider_data.mem_pool.transactions().count(), 1);
+
+        Ok(())
+    }
+
     #[test]
     fn add_pending_transaction_from_impersonated_account() -> anyhow::Result<()> {
         let mut fixture = ProviderTestFixture::new_local()?;
@@ -2785,6 +3788,77 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn mine_and_commit_block_receipts_cumulative_gas_used() -> anyhow::Result<()> {
+        let mut fixture = ProviderTestFixture::new_local()?;
+        fixture.provider_data.set_auto_mining(false);
+
+        let transaction_hashes = (0..3)
+            .map(|nonce| {
+                let transaction = fixture.signed_dummy_transaction(0, Some(nonce))?;
+                let transaction_hash = *transaction.hash();
+                fixture.provider_data.send_transaction(transaction)?;
+
+                Ok(transaction_hash)
+            })
+            .collect::<anyhow::Result<Vec<_>>>()?;
+
+        let result = fixture
+            .provider_data
+            .mine_and_commit_block(BlockOptions::default())?;
+        assert_eq!(result.block.transactions().len(), 3);
+
+        let mut cumulative_gas_used = 0;
+        for (transaction_index, transaction_hash) in transaction_hashes.iter().enumerate() {
+            let receipt = fixture
+                .provider_data
+                .transaction_receipt(transaction_hash)?
+                .context("receipt should exist")?;
+
+            cumulative_gas_used += receipt.gas_used;
+            assert_eq!(receipt.transaction_index, transaction_index as u64);
+            assert_eq!(receipt.cumulative_gas_used, cumulative_gas_used);
+        }
+        assert_eq!(result.block.header().gas_used, cumulative_gas_used);
+
+        Ok(())
+    }
+
+    #[test]
+    fn mine_block_without_commit_leaves_state_untouched() -> anyhow::Result<()> {
+        let mut fixture = ProviderTestFixture::new_local()?;
+        fixture.provider_data.set_auto_mining(false);
+
+        for nonce in 0..2 {
+            let transaction = fixture.signed_dummy_transaction(0, Some(nonce))?;
+            fixture.provider_data.send_transaction(transaction)?;
+        }
+
+        let last_block_number = fixture.provider_data.last_block_number();
+        let state_root = fixture.provider_data.current_state()?.state_root()?;
+
+        let (block_timestamp, _) = fixture.provider_data.next_block_timestamp(None)?;
+        let prevrandao = fixture.provider_data.prev_randao_generator.next_value();
+        let result = fixture.provider_data.mine_block(BlockOptions {
+            timestamp: Some(block_timestamp),
+            mix_hash: Some(prevrandao),
+            ..BlockOptions::default()
+        })?;
+        assert_eq!(result.block.transactions().len(), 2);
+        assert_ne!(result.state.state_root()?, state_root);
+
+        // Discard the mined block without committing it
+        drop(result);
+
+        assert_eq!(fixture.provider_data.last_block_number(), last_block_number);
+        assert_eq!(
+            fixture.provider_data.current_state()?.state_root()?,
+            state_root
+        );
+
+        Ok(())
+    }
+
     #[test]
     fn console_log_mine_block() -> anyhow::Result<()> {
         let mut fixture = ProviderTestFixture::new_local()?;
@@ -3086,13 +4160,7 @@ mod tests {
             ..default_config
         };
 
-        let runtime = runtime::Builder::new_multi_thread()
-            .worker_threads(1)
-            .enable_all()
-            .thread_name("provider-data-test")
-            .build()?;
-
-        let mut fixture = ProviderTestFixture::new(runtime, config)?;
+        let mut fixture = ProviderTestFixture::new(config)?;
 
         let transaction1 = fixture.signed_dummy_transaction(0, None)?;
         let transaction2 = fixture.signed_dummy_transaction(1, None)?;
@@ -3172,13 +4240,7 @@ mod tests {
             ..default_config
         };
 
-        let runtime = runtime::Builder::new_multi_thread()
-            .worker_threads(1)
-            .enable_all()
-            .thread_name("provider-data-test")
-            .build()?;
-
-        let mut fixture = ProviderTestFixture::new(runtime, config)?;
+        let mut fixture = ProviderTestFixture::new(config)?;
 
         let miner = fixture.provider_data.beneficiary;
         let previous_miner_balance = fixture
@@ -3203,6 +4265,73 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn mine_and_commit_block_across_hardfork_activation() -> anyhow::Result<()> {
+        const ACTIVATION_BLOCK_NUMBER: u64 = 2;
+
+        let config = ProviderConfig {
+            hardfork: SpecId::MERGE,
+            hardfork_activations: Some(HardforkActivations::new(vec![(
+                ACTIVATION_BLOCK_NUMBER,
+                SpecId::SHANGHAI,
+            )])),
+            ..create_test_config()
+        };
+
+        let mut fixture = ProviderTestFixture::new(config)?;
+        let sender = fixture.nth_local_account(0)?;
+
+        let mut results = Vec::new();
+        for nonce in 0..2 {
+            // PUSH0 is only a valid opcode from Shanghai onwards
+            let request = TransactionRequestAndSender {
+                request: TransactionRequest::Eip155(Eip155TransactionRequest {
+                    kind: TransactionKind::Create,
+                    gas_limit: 100_000,
+                    gas_price: U256::from(42_000_000_000_u64),
+                    value: U256::ZERO,
+                    input: Bytes::from_static(&[opcode::PUSH0, opcode::STOP]),
+                    nonce,
+                    chain_id: fixture.config.chain_id,
+                }),
+                sender,
+            };
+
+            let transaction = fixture.provider_data.sign_transaction_request(request)?;
+            fixture.provider_data.add_pending_transaction(transaction)?;
+
+            let result = fixture
+                .provider_data
+                .mine_and_commit_block(BlockOptions::default())?;
+
+            results.push((result.block.header().number, result.transaction_results));
+        }
+
+        let (block_number, transaction_results) = &results[0];
+        assert_eq!(*block_number, ACTIVATION_BLOCK_NUMBER - 1);
+        assert!(!transaction_results[0].is_success());
+        assert_eq!(
+            fixture
+                .provider_data
+                .blockchain
+                .spec_at_block_number(*block_number)?,
+            SpecId::MERGE
+        );
+
+        let (block_number, transaction_results) = &results[1];
+        assert_eq!(*block_number, ACTIVATION_BLOCK_NUMBER);
+        assert!(transaction_results[0].is_success());
+        assert_eq!(
+            fixture
+                .provider_data
+                .blockchain
+                .spec_at_block_number(*block_number)?,
+            SpecId::SHANGHAI
+        );
+
+        Ok(())
+    }
+
     #[test]
     fn mine_and_commit_blocks_increases_block_number() -> anyhow::Result<()> {
         const NUM_MINED_BLOCKS: u64 = 10;
@@ -3228,8 +4357,192 @@ mod tests {
     }
 
     #[test]
-    fn mine_and_commit_blocks_works_with_snapshots() -> anyhow::Result<()> {
-        const NUM_MINED_BLOCKS: u64 = 10;
+    fn mine_and_commit_blocks_reserves_queryable_empty_blocks() -> anyhow::Result<()> {
+        const NUM_MINED_BLOCKS: u64 = 1_000_000;
+        const INTERVAL: u64 = 12;
+
+        let mut fixture = ProviderTestFixture::new_local()?;
+
+        let previous_block_number = fixture.provider_data.last_block_number();
+
+        let mined_blocks = fixture
+            .provider_data
+            .mine_and_commit_blocks(NUM_MINED_BLOCKS, INTERVAL)?;
+
+        // Only the blocks surrounding the reservation are built
+        assert!(mined_blocks.len() < 10);
+        assert_eq!(
+            fixture.provider_data.last_block_number(),
+            previous_block_number + NUM_MINED_BLOCKS
+        );
+
+        let first_timestamp = mined_blocks[0].block.header().timestamp;
+        let reserved_offset = NUM_MINED_BLOCKS / 2;
+        let reserved_number = previous_block_number + reserved_offset;
+
+        let reserved_block = fixture
+            .provider_data
+            .blockchain
+            .block_by_number(reserved_number)?
+            .context("reserved block should exist")?;
+        assert_eq!(reserved_block.header().number, reserved_number);
+        assert_eq!(
+            reserved_block.header().timestamp,
+            first_timestamp + (reserved_offset - 1) * INTERVAL
+        );
+
+        let block_by_hash = fixture
+            .provider_data
+            .block_by_hash(reserved_block.hash())?
+            .context("reserved block should be queryable by hash")?;
+        assert_eq!(block_by_hash.header().number, reserved_number);
+
+        let next_block = fixture
+            .provider_data
+            .blockchain
+            .block_by_number(reserved_number + 1)?
+            .context("next reserved block should exist")?;
+        assert_eq!(next_block.header().parent_hash, *reserved_block.hash());
+
+        Ok(())
+    }
+
+    #[test]
+    fn rollback_to_block_removes_later_blocks() -> anyhow::Result<()> {
+        const NUM_MINED_BLOCKS: u64 = 10;
+        const ROLLBACK_BLOCK_NUMBER: u64 = 5;
+
+        let mut fixture = ProviderTestFixture::new_local()?;
+        assert_eq!(fixture.provider_data.last_block_number(), 0);
+
+        let sender = fixture.nth_local_account(0)?;
+
+        let mut balance_at_rollback_block = None;
+        let mut removed_block_hash = None;
+        for nonce in 0..NUM_MINED_BLOCKS {
+            let transaction = fixture.signed_dummy_transaction(0, Some(nonce))?;
+            fixture.provider_data.add_pending_transaction(transaction)?;
+
+            let result = fixture
+                .provider_data
+                .mine_and_commit_block(BlockOptions::default())?;
+
+            match result.block.header().number {
+                ROLLBACK_BLOCK_NUMBER => {
+                    balance_at_rollback_block = Some(
+                        fixture
+                            .provider_data
+                            .balance(sender, Some(&BlockSpec::latest()))?,
+                    );
+                }
+                number if number == ROLLBACK_BLOCK_NUMBER + 1 => {
+                    removed_block_hash = Some(*result.block.hash());
+                }
+                _ => (),
+            }
+        }
+
+        assert_eq!(fixture.provider_data.last_block_number(), NUM_MINED_BLOCKS);
+
+        let rollback_block_hash = *fixture
+            .provider_data
+            .blockchain
+            .block_by_number(ROLLBACK_BLOCK_NUMBER)?
+            .context("block should exist")?
+            .hash();
+
+        fixture
+            .provider_data
+            .set_next_block_base_fee_per_gas(U256::from(1_000_000_000u64))?;
+
+        fixture
+            .provider_data
+            .rollback_to_block(ROLLBACK_BLOCK_NUMBER)?;
+
+        assert_eq!(
+            fixture.provider_data.last_block_number(),
+            ROLLBACK_BLOCK_NUMBER
+        );
+        assert_eq!(
+            *fixture.provider_data.last_block()?.hash(),
+            rollback_block_hash
+        );
+        assert!(fixture
+            .provider_data
+            .block_by_hash(&removed_block_hash.expect("block was mined"))?
+            .is_none());
+        assert_eq!(fixture.provider_data.next_block_base_fee_per_gas, None);
+
+        // The state is restored to that of the rollback block
+        let balance = fixture
+            .provider_data
+            .balance(sender, Some(&BlockSpec::latest()))?;
+        assert_eq!(Some(balance), balance_at_rollback_block);
+
+        let transaction = fixture.signed_dummy_transaction(0, None)?;
+        assert_eq!(transaction.nonce(), ROLLBACK_BLOCK_NUMBER);
+
+        // Newly mined blocks build on top of the rollback block
+        let result = fixture
+            .provider_data
+            .mine_and_commit_block(BlockOptions::default())?;
+        assert_eq!(result.block.header().number, ROLLBACK_BLOCK_NUMBER + 1);
+        assert_eq!(result.block.header().parent_hash, rollback_block_hash);
+
+        Ok(())
+    }
+
+    #[test]
+    fn rollback_to_future_block() -> anyhow::Result<()> {
+        let mut fixture = ProviderTestFixture::new_local()?;
+
+        fixture.provider_data.mine_and_commit_blocks(2, 1)?;
+
+        let result = fixture.provider_data.rollback_to_block(3);
+        assert!(matches!(
+            result,
+            Err(ProviderError::RollbackToFutureBlock {
+                block_number: 3,
+                latest_block_number: 2,
+            })
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn rollback_before_fork_block() -> anyhow::Result<()> {
+        let mut fixture = ProviderTestFixture::new_forked(None)?;
+
+        let fork_block_number = fixture
+            .provider_data
+            .fork_metadata()
+            .context("provider should be forked")?
+            .fork_block_number;
+
+        fixture.provider_data.mine_and_commit_blocks(2, 1)?;
+
+        let result = fixture
+            .provider_data
+            .rollback_to_block(fork_block_number - 1);
+        assert!(matches!(
+            result,
+            Err(ProviderError::RollbackBeforeForkBlock {
+                block_number,
+                fork_block_number: error_fork_block_number,
+            }) if block_number == fork_block_number - 1 && error_fork_block_number == fork_block_number
+        ));
+
+        // Rolling back to the fork block itself removes all local blocks
+        fixture.provider_data.rollback_to_block(fork_block_number)?;
+        assert_eq!(fixture.provider_data.last_block_number(), fork_block_number);
+
+        Ok(())
+    }
+
+    #[test]
+    fn mine_and_commit_blocks_works_with_snapshots() -> anyhow::Result<()> {
+        const NUM_MINED_BLOCKS: u64 = 10;
 
         let mut fixture = ProviderTestFixture::new_local()?;
 
@@ -3293,74 +4606,1230 @@ mod tests {
     }
 
     #[test]
-    fn next_filter_id() -> anyhow::Result<()> {
+    fn revert_to_snapshot_restores_mem_pool() -> anyhow::Result<()> {
         let mut fixture = ProviderTestFixture::new_local()?;
 
-        let mut prev_filter_id = fixture.provider_data.last_filter_id;
-        for _ in 0..10 {
-            let filter_id = fixture.provider_data.next_filter_id();
-            assert!(prev_filter_id < filter_id);
-            prev_filter_id = filter_id;
+        let pending_transaction = fixture.signed_dummy_transaction(0, None)?;
+        let future_transaction = fixture.signed_dummy_transaction(0, Some(2))?;
+
+        let original_block_number = fixture.provider_data.last_block_number();
+
+        let pending_hash = fixture
+            .provider_data
+            .add_pending_transaction(pending_transaction)?;
+        let future_hash = fixture
+            .provider_data
+            .add_pending_transaction(future_transaction)?;
+
+        let snapshot_id = fixture.provider_data.make_snapshot();
+
+        let result = fixture
+            .provider_data
+            .mine_and_commit_block(BlockOptions::default())?;
+        assert_eq!(result.block.transactions().len(), 1);
+        assert_eq!(
+            fixture
+                .provider_data
+                .mem_pool
+                .pending_transactions()
+                .count(),
+            0
+        );
+
+        let reverted = fixture.provider_data.revert_to_snapshot(snapshot_id);
+        assert!(reverted);
+
+        assert_eq!(
+            fixture.provider_data.last_block_number(),
+            original_block_number
+        );
+
+        let pending_hashes = fixture
+            .provider_data
+            .mem_pool
+            .pending_transactions()
+            .map(|transaction| *transaction.pending().hash())
+            .collect::<Vec<_>>();
+        assert_eq!(pending_hashes, vec![pending_hash]);
+
+        let future_hashes = fixture
+            .provider_data
+            .mem_pool
+            .future_transactions()
+            .map(|transaction| *transaction.pending().hash())
+            .collect::<Vec<_>>();
+        assert_eq!(future_hashes, vec![future_hash]);
+
+        let transaction = fixture
+            .provider_data
+            .transaction_by_hash(&pending_hash)?
+            .context("transaction not found")?;
+        assert!(transaction.is_pending);
+
+        Ok(())
+    }
+
+    /// Mines three empty blocks and returns their base fees, followed by the
+    /// predicted base fee of the next block.
+    fn mine_empty_blocks_and_collect_base_fees(
+        config: ProviderConfig,
+    ) -> anyhow::Result<Vec<U256>> {
+        let mut fixture = ProviderTestFixture::new(config)?;
+
+        let mut base_fees = Vec::new();
+        for _ in 0..3 {
+            let result = fixture
+                .provider_data
+                .mine_and_commit_block(BlockOptions::default())?;
+
+            base_fees.push(
+                result
+                    .block
+                    .header()
+                    .base_fee_per_gas
+                    .context("post-London blocks have a base fee")?,
+            );
+        }
+
+        base_fees.push(
+            fixture
+                .provider_data
+                .next_block_base_fee_per_gas()?
+                .context("post-London blocks have a base fee")?,
+        );
+
+        Ok(base_fees)
+    }
+
+    #[test]
+    fn empty_blocks_decrease_base_fee_by_an_eighth() -> anyhow::Result<()> {
+        let base_fees = mine_empty_blocks_and_collect_base_fees(create_test_config())?;
+
+        for window in base_fees.windows(2) {
+            assert_eq!(window[1], window[0] - window[0] / U256::from(8));
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn base_fee_params_are_configurable() -> anyhow::Result<()> {
+        let mut config = create_test_config();
+        config.base_fee_params = Some(BaseFeeParams {
+            elasticity_multiplier: NonZeroU64::new(2).expect("constant is non-zero"),
+            max_change_denominator: NonZeroU64::new(4).expect("constant is non-zero"),
+        });
+
+        let base_fees = mine_empty_blocks_and_collect_base_fees(config)?;
+
+        for window in base_fees.windows(2) {
+            assert_eq!(window[1], window[0] - window[0] / U256::from(4));
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn revert_to_snapshot_restores_block_time_offset() -> anyhow::Result<()> {
+        const ELAPSED_SECONDS: u64 = 100;
+
+        let mut fixture = ProviderTestFixture::new_local()?;
+
+        let original_offset = fixture.provider_data.block_time_offset_seconds;
+
+        let snapshot_id = fixture.provider_data.make_snapshot();
+        fixture.provider_data.increase_block_time(1_000);
+
+        // Simulate that time has passed since the snapshot was taken
+        let snapshot = fixture
+            .provider_data
+            .snapshots
+            .get_mut(&snapshot_id)
+            .context("snapshot not found")?;
+        snapshot.timestamp = snapshot
+            .timestamp
+            .checked_sub(ELAPSED_SECONDS)
+            .context("snapshot timestamp precedes the elapsed time")?;
+
+        assert!(fixture.provider_data.revert_to_snapshot(snapshot_id));
+
+        // now + new_offset == snapshot_date + old_offset
+        assert_eq!(
+            fixture.provider_data.block_time_offset_seconds,
+            original_offset - i64::try_from(ELAPSED_SECONDS)?
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn increase_time_with_manual_time_source() -> anyhow::Result<()> {
+        const INITIAL_TIMESTAMP: u64 = 1_700_000_000;
+
+        let config = ProviderConfig {
+            initial_date: None,
+            ..create_test_config()
+        };
+
+        let time_source = Arc::new(ManualTimeSource::new(INITIAL_TIMESTAMP));
+        let mut fixture = ProviderTestFixture::new_with_time_source(config, time_source.clone())?;
+
+        let genesis_timestamp = fixture.provider_data.last_block()?.header().timestamp;
+        assert_eq!(genesis_timestamp, INITIAL_TIMESTAMP);
+
+        fixture.provider_data.increase_block_time(3600);
+        let result = fixture
+            .provider_data
+            .mine_and_commit_block(BlockOptions::default())?;
+        assert_eq!(result.block.header().timestamp, INITIAL_TIMESTAMP + 3600);
+
+        // Only the time source moves the clock forward
+        time_source.advance(10);
+        let result = fixture
+            .provider_data
+            .mine_and_commit_block(BlockOptions::default())?;
+        assert_eq!(result.block.header().timestamp, INITIAL_TIMESTAMP + 3610);
+
+        Ok(())
+    }
+
+    #[test]
+    fn revert_to_stale_snapshot_returns_false() -> anyhow::Result<()> {
+        let mut fixture = ProviderTestFixture::new_local()?;
+
+        let first_snapshot_id = fixture.provider_data.make_snapshot();
+        fixture
+            .provider_data
+            .mine_and_commit_block(BlockOptions::default())?;
+
+        let second_snapshot_id = fixture.provider_data.make_snapshot();
+        fixture
+            .provider_data
+            .mine_and_commit_block(BlockOptions::default())?;
+
+        assert!(fixture.provider_data.revert_to_snapshot(first_snapshot_id));
+
+        // Reverting to a snapshot invalidates it and all subsequent snapshots
+        assert!(!fixture.provider_data.revert_to_snapshot(first_snapshot_id));
+        assert!(!fixture.provider_data.revert_to_snapshot(second_snapshot_id));
+
+        // Unknown snapshot ids are rejected
+        assert!(!fixture.provider_data.revert_to_snapshot(u64::MAX));
+
+        Ok(())
+    }
+
+    #[test]
+    fn mine_and_commit_block_prev_randao_seed_is_reproducible() -> anyhow::Result<()> {
+        fn mine_block_hashes(prev_randao_seed: B256) -> anyhow::Result<Vec<B256>> {
+            let default_config = create_test_config();
+            let config = ProviderConfig {
+                initial_date: Some(UNIX_EPOCH + Duration::from_secs(1_700_000_000)),
+                prev_randao_seed: Some(prev_randao_seed),
+                ..default_config
+            };
+
+            let mut fixture = ProviderTestFixture::new(config)?;
+
+            let genesis_timestamp = fixture.provider_data.last_block()?.header().timestamp;
+            (1..=3)
+                .map(|offset| {
+                    let result = fixture.provider_data.mine_and_commit_block(BlockOptions {
+                        timestamp: Some(genesis_timestamp + offset),
+                        ..BlockOptions::default()
+                    })?;
+
+                    Ok(*result.block.hash())
+                })
+                .collect()
         }
 
+        let seed = B256::from(U256::from(42));
+
+        let first_run = mine_block_hashes(seed)?;
+        let second_run = mine_block_hashes(seed)?;
+        assert_eq!(first_run, second_run);
+
+        let other_seed_run = mine_block_hashes(B256::from(U256::from(43)))?;
+        assert_ne!(first_run, other_seed_run);
+
+        Ok(())
+    }
+
+    #[test]
+    fn get_code_hash() -> anyhow::Result<()> {
+        let mut fixture = ProviderTestFixture::new_local()?;
+
+        let code = Bytes::from_static(&[0x60, 0x00, 0x60, 0x00, 0xfd]);
+        let contract_address = Address::random();
+        fixture
+            .provider_data
+            .set_code(contract_address, code.clone())?;
+
+        let code_hash = fixture
+            .provider_data
+            .get_code_hash(contract_address, Some(&BlockSpec::latest()))?;
+        assert_eq!(code_hash, keccak256(&code));
+
+        // Accounts without code have the hash of empty code
+        let local_account = fixture.nth_local_account(0)?;
+        let code_hash = fixture
+            .provider_data
+            .get_code_hash(local_account, Some(&BlockSpec::latest()))?;
+        assert_eq!(code_hash, KECCAK_EMPTY);
+
+        // Empty accounts have a code hash of zero
+        let code_hash = fixture
+            .provider_data
+            .get_code_hash(Address::random(), Some(&BlockSpec::latest()))?;
+        assert_eq!(code_hash, B256::ZERO);
+
+        Ok(())
+    }
+
+    #[test]
+    fn next_filter_id() -> anyhow::Result<()> {
+        let mut fixture = ProviderTestFixture::new_local()?;
+
+        let mut prev_filter_id = fixture.provider_data.last_filter_id;
+        for _ in 0..10 {
+            let filter_id = fixture.provider_data.next_filter_id();
+            assert!(prev_filter_id < filter_id);
+            prev_filter_id = filter_id;
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn pending_transactions_returns_pending_and_queued() -> anyhow::Result<()> {
+        let mut fixture = ProviderTestFixture::new_local().unwrap();
+
+        let transaction1 = fixture.signed_dummy_transaction(0, Some(0))?;
+        fixture
+            .provider_data
+            .add_pending_transaction(transaction1.clone())?;
+
+        let transaction2 = fixture.signed_dummy_transaction(0, Some(2))?;
+        fixture
+            .provider_data
+            .add_pending_transaction(transaction2.clone())?;
+
+        let transaction3 = fixture.signed_dummy_transaction(0, Some(3))?;
+        fixture
+            .provider_data
+            .add_pending_transaction(transaction3.clone())?;
+
+        let pending_transactions = fixture
+            .provider_data
+            .pending_transactions()
+            .cloned()
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            pending_transactions,
+            vec![transaction1, transaction2, transaction3]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn set_balance_updates_mem_pool() -> anyhow::Result<()> {
+        let mut fixture = ProviderTestFixture::new_local()?;
+
+        let transaction = fixture.impersonated_dummy_transaction()?;
+        let transaction_hash = fixture.provider_data.add_pending_transaction(transaction)?;
+
+        assert!(fixture
+            .provider_data
+            .mem_pool
+            .transaction_by_hash(&transaction_hash)
+            .is_some());
+
+        fixture
+            .provider_data
+            .set_balance(fixture.impersonated_account, U256::from(100))?;
+
+        assert!(fixture
+            .provider_data
+            .mem_pool
+            .transaction_by_hash(&transaction_hash)
+            .is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn run_call_in_next_block() -> anyhow::Result<()> {
+        // NUMBER PUSH1 0x00 MSTORE BASEFEE PUSH1 0x20 MSTORE PUSH1 0x40 PUSH1 0x00
+        // RETURN
+        const NUMBER_AND_BASEFEE_CODE: [u8; 13] = [
+            0x43, 0x60, 0x00, 0x52, 0x48, 0x60, 0x20, 0x52, 0x60, 0x40, 0x60, 0x00, 0xf3,
+        ];
+
+        let mut fixture = ProviderTestFixture::new_local()?;
+
+        let contract_address = Address::random();
+        fixture.provider_data.set_code(
+            contract_address,
+            Bytes::from_static(&NUMBER_AND_BASEFEE_CODE),
+        )?;
+
+        // Make sure the next block's base fee differs from the latest block's
+        fixture
+            .provider_data
+            .set_next_block_base_fee_per_gas(U256::from(2_000_000_000u64))?;
+
+        let next_base_fee = fixture
+            .provider_data
+            .next_block_base_fee_per_gas()?
+            .expect("London is active");
+
+        let state_overrides = StateOverrides::default();
+        let transaction = resolve_call_request(
+            &mut fixture.provider_data,
+            CallRequest {
+                from: Some(fixture.nth_local_account(0)?),
+                to: Some(contract_address),
+                gas_price: Some(next_base_fee),
+                ..CallRequest::default()
+            },
+            None,
+            &state_overrides,
+        )?;
+
+        let result = fixture
+            .provider_data
+            .run_call_in_next_block(transaction, &state_overrides)?;
+
+        let ExecutionResult::Success { output, .. } = result.execution_result else {
+            anyhow::bail!("Expected success, got: {:?}", result.execution_result);
+        };
+        let output = output.into_data();
+
+        let block_number = U256::from_be_slice(&output[..32]);
+        assert_eq!(
+            block_number,
+            U256::from(fixture.provider_data.last_block_number() + 1)
+        );
+
+        let base_fee = U256::from_be_slice(&output[32..]);
+        assert_eq!(base_fee, next_base_fee);
+
+        Ok(())
+    }
+
+    #[test]
+    fn mine_pending_block_uses_next_mix_hash() -> anyhow::Result<()> {
+        let mut fixture = ProviderTestFixture::new_local()?;
+
+        let pending = fixture.provider_data.mine_pending_block()?;
+        let pending_mix_hash = pending.block.header().mix_hash;
+
+        // Mining the pending block again doesn't advance the prevrandao generator
+        let pending = fixture.provider_data.mine_pending_block()?;
+        assert_eq!(pending.block.header().mix_hash, pending_mix_hash);
+
+        let mined = fixture
+            .provider_data
+            .mine_and_commit_block(BlockOptions::default())?;
+        assert_eq!(mined.block.header().mix_hash, pending_mix_hash);
+
+        Ok(())
+    }
+
+    #[test]
+    fn estimate_gas_in_pending_block() -> anyhow::Result<()> {
+        // Stores 1 in slot 0 when called with calldata. Otherwise, reverts unless slot
+        // 0 is non-zero.
+        const REQUIRE_STORED_CODE: [u8; 24] = [
+            0x36, 0x60, 0x11, 0x57, 0x60, 0x00, 0x54, 0x60, 0x0f, 0x57, 0x60, 0x00, 0x60, 0x00,
+            0xfd, 0x5b, 0x00, 0x5b, 0x60, 0x01, 0x60, 0x00, 0x55, 0x00,
+        ];
+
+        let mut fixture = ProviderTestFixture::new_local()?;
+
+        let contract_address = Address::random();
+        fixture
+            .provider_data
+            .set_code(contract_address, Bytes::from_static(&REQUIRE_STORED_CODE))?;
+
+        let request = TransactionRequest::Eip1559(Eip1559TransactionRequest {
+            chain_id: fixture.config.chain_id,
+            nonce: 0,
+            max_priority_fee_per_gas: U256::from(1_000_000_000u64),
+            max_fee_per_gas: U256::from(10_000_000_000u64),
+            gas_limit: 100_000,
+            kind: TransactionKind::Call(contract_address),
+            value: U256::ZERO,
+            input: Bytes::from_static(&[0x01]),
+            access_list: Vec::new(),
+        });
+        let sender = fixture.nth_local_account(0)?;
+        let pending_transaction = fixture
+            .provider_data
+            .sign_transaction_request(TransactionRequestAndSender { request, sender })?;
+
+        fixture
+            .provider_data
+            .add_pending_transaction(pending_transaction)?;
+
+        let state_overrides = StateOverrides::default();
+        let transaction = resolve_call_request(
+            &mut fixture.provider_data,
+            CallRequest {
+                from: Some(fixture.nth_local_account(1)?),
+                to: Some(contract_address),
+                ..CallRequest::default()
+            },
+            None,
+            &state_overrides,
+        )?;
+
+        // The latest state doesn't include the pending transaction's change
+        let latest_result = fixture
+            .provider_data
+            .estimate_gas(transaction.clone(), &BlockSpec::latest());
+        assert!(latest_result.is_err());
+
+        let pending_result = fixture
+            .provider_data
+            .estimate_gas(transaction, &BlockSpec::pending())?;
+        assert!(pending_result.estimation > 21_000);
+
+        Ok(())
+    }
+
+    #[test]
+    fn estimate_gas_applies_calldata_floor_cost() -> anyhow::Result<()> {
+        // 10_000 zero bytes cost 40_000 gas in calldata, but 100_000 gas under
+        // the EIP-7623 calldata floor
+        const CALLDATA_FLOOR_COST: u64 = 21_000 + 10 * 10_000;
+
+        fn estimate(spec_id: SpecId) -> anyhow::Result<u64> {
+            let mut config = create_test_config();
+            config.hardfork = spec_id;
+
+            let mut fixture = ProviderTestFixture::new(config)?;
+
+            let state_overrides = StateOverrides::default();
+            let transaction = resolve_call_request(
+                &mut fixture.provider_data,
+                CallRequest {
+                    from: Some(fixture.nth_local_account(0)?),
+                    to: Some(Address::random()),
+                    data: Some(Bytes::from(vec![0u8; 10_000])),
+                    ..CallRequest::default()
+                },
+                None,
+                &state_overrides,
+            )?;
+
+            let result = fixture
+                .provider_data
+                .estimate_gas(transaction, &BlockSpec::latest())?;
+
+            Ok(result.estimation)
+        }
+
+        assert!(estimate(SpecId::CANCUN)? < CALLDATA_FLOOR_COST);
+        assert!(estimate(SpecId::LATEST)? >= CALLDATA_FLOOR_COST);
+
+        Ok(())
+    }
+
+    #[test]
+    fn estimate_gas_revert_returns_revert_data() -> anyhow::Result<()> {
+        use crate::error::TransactionFailureReason;
+
+        // PUSH4 0xdeadbeef PUSH1 0x00 MSTORE PUSH1 0x04 PUSH1 0x1c REVERT
+        const REVERT_CODE: [u8; 13] = [
+            0x63, 0xde, 0xad, 0xbe, 0xef, 0x60, 0x00, 0x52, 0x60, 0x04, 0x60, 0x1c, 0xfd,
+        ];
+
+        let mut fixture = ProviderTestFixture::new_local()?;
+
+        let contract_address = Address::random();
+        fixture
+            .provider_data
+            .set_code(contract_address, Bytes::from_static(&REVERT_CODE))?;
+
+        let state_overrides = StateOverrides::default();
+        let transaction = resolve_call_request(
+            &mut fixture.provider_data,
+            CallRequest {
+                from: Some(fixture.nth_local_account(0)?),
+                to: Some(contract_address),
+                ..CallRequest::default()
+            },
+            None,
+            &state_overrides,
+        )?;
+
+        let error = fixture
+            .provider_data
+            .estimate_gas(transaction, &BlockSpec::latest())
+            .expect_err("transaction reverts even with the block gas limit");
+
+        assert!(matches!(
+            error,
+            ProviderError::EstimateGasTransactionFailure(EstimateGasFailure {
+                transaction_failure: TransactionFailureWithTraces {
+                    failure: TransactionFailure {
+                        reason: TransactionFailureReason::Revert(output),
+                        ..
+                    },
+                    ..
+                },
+                ..
+            }) if output.as_ref() == [0xde, 0xad, 0xbe, 0xef]
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn estimate_gas_accounts_for_nested_call_gas_retention() -> anyhow::Result<()> {
+        // PUSH1 0x01 PUSH1 0x00 SSTORE STOP
+        const INNER_CODE: [u8; 6] = [0x60, 0x01, 0x60, 0x00, 0x55, 0x00];
+
+        let mut fixture = ProviderTestFixture::new_local()?;
+
+        let inner_address = Address::random();
+        fixture
+            .provider_data
+            .set_code(inner_address, Bytes::from_static(&INNER_CODE))?;
+
+        // Calls the inner contract with all available gas and reverts if the call
+        // fails:
+        // PUSH1 0x00 PUSH1 0x00 PUSH1 0x00 PUSH1 0x00 PUSH1 0x00 PUSH20 <inner>
+        // GAS CALL ISZERO PUSH1 0x26 JUMPI STOP JUMPDEST PUSH1 0x00 DUP1 REVERT
+        let mut outer_code = vec![
+            0x60, 0x00, 0x60, 0x00, 0x60, 0x00, 0x60, 0x00, 0x60, 0x00, 0x73,
+        ];
+        outer_code.extend_from_slice(inner_address.as_slice());
+        outer_code.extend_from_slice(&[
+            0x5a, 0xf1, 0x15, 0x60, 0x26, 0x57, 0x00, 0x5b, 0x60, 0x00, 0x80, 0xfd,
+        ]);
+
+        let outer_address = Address::random();
+        fixture
+            .provider_data
+            .set_code(outer_address, Bytes::from(outer_code))?;
+
+        let sender = fixture.nth_local_account(0)?;
+        let call_request = |gas: Option<u64>| CallRequest {
+            from: Some(sender),
+            to: Some(outer_address),
+            gas,
+            ..CallRequest::default()
+        };
+
+        let state_overrides = StateOverrides::default();
+        let transaction = resolve_call_request(
+            &mut fixture.provider_data,
+            call_request(None),
+            None,
+            &state_overrides,
+        )?;
+        let gas_used = fixture
+            .provider_data
+            .run_call(transaction.clone(), None, &state_overrides)?
+            .execution_result
+            .gas_used();
+
+        // Only 63/64 of the remaining gas is forwarded to the inner call, so the gas
+        // used is insufficient as gas limit.
+        let transaction_with_gas_used = resolve_call_request(
+            &mut fixture.provider_data,
+            call_request(Some(gas_used)),
+            None,
+            &state_overrides,
+        )?;
+        let result =
+            fixture
+                .provider_data
+                .run_call(transaction_with_gas_used, None, &state_overrides)?;
+        assert!(!result.execution_result.is_success());
+
+        let estimation = fixture
+            .provider_data
+            .estimate_gas(transaction, &BlockSpec::latest())?
+            .estimation;
+        assert!(estimation > gas_used);
+
+        let transaction_with_estimation = resolve_call_request(
+            &mut fixture.provider_data,
+            call_request(Some(estimation)),
+            None,
+            &state_overrides,
+        )?;
+        let result =
+            fixture
+                .provider_data
+                .run_call(transaction_with_estimation, None, &state_overrides)?;
+        assert!(result.execution_result.is_success());
+
+        Ok(())
+    }
+
+    #[test]
+    fn run_call_with_generated_access_list() -> anyhow::Result<()> {
+        let external_address = Address::random();
+
+        // SLOAD slots 0, 1, and 2, followed by BALANCE of the external address
+        let mut code = vec![
+            0x60, 0x00, 0x54, 0x50, 0x60, 0x01, 0x54, 0x50, 0x60, 0x02, 0x54, 0x50, 0x73,
+        ];
+        code.extend_from_slice(external_address.as_slice());
+        code.extend_from_slice(&[0x31, 0x50, 0x00]);
+
+        let mut fixture = ProviderTestFixture::new_local()?;
+
+        let contract_address = Address::random();
+        fixture
+            .provider_data
+            .set_code(contract_address, Bytes::from(code))?;
+
+        let state_overrides = StateOverrides::default();
+        let transaction = resolve_call_request(
+            &mut fixture.provider_data,
+            CallRequest {
+                from: Some(fixture.nth_local_account(0)?),
+                to: Some(contract_address),
+                ..CallRequest::default()
+            },
+            None,
+            &state_overrides,
+        )?;
+
+        let single_pass =
+            fixture
+                .provider_data
+                .run_call(transaction.clone(), None, &state_overrides)?;
+        assert_eq!(single_pass.access_list, None);
+
+        fixture
+            .provider_data
+            .initial_config
+            .generate_access_lists_for_calls = true;
+
+        let two_pass =
+            fixture
+                .provider_data
+                .run_call(transaction.clone(), None, &state_overrides)?;
+
+        let mut expected_access_list = vec![
+            AccessListItem {
+                address: contract_address,
+                storage_keys: (0..3u64).map(|slot| B256::from(U256::from(slot))).collect(),
+            },
+            AccessListItem {
+                address: external_address,
+                storage_keys: Vec::new(),
+            },
+        ];
+        expected_access_list.sort_unstable_by_key(|item| item.address);
+        assert_eq!(two_pass.access_list.as_ref(), Some(&expected_access_list));
+
+        // Each pre-warmed storage slot (1900 instead of 2100 - 100) and account (2400
+        // instead of 2600 - 100) saves 100 gas, but the callee is warm regardless, so
+        // listing it costs an additional 2400 gas.
+        let single_pass_gas = single_pass.execution_result.gas_used();
+        let two_pass_gas = two_pass.execution_result.gas_used();
+        assert_eq!(two_pass_gas, single_pass_gas + 2_400 - 4 * 100);
+
+        let estimation = fixture
+            .provider_data
+            .estimate_gas(transaction, &BlockSpec::latest())?;
+        assert_eq!(estimation.access_list, Some(expected_access_list));
+
+        Ok(())
+    }
+
+    #[test]
+    fn create_access_list_with_gas_used() -> anyhow::Result<()> {
+        let external_address = Address::random();
+
+        // SLOAD slots 0 and 1, followed by BALANCE of the identity precompile and the
+        // external address
+        let mut code = vec![
+            0x60, 0x00, 0x54, 0x50, 0x60, 0x01, 0x54, 0x50, 0x60, 0x04, 0x31, 0x50, 0x73,
+        ];
+        code.extend_from_slice(external_address.as_slice());
+        code.extend_from_slice(&[0x31, 0x50, 0x00]);
+
+        let mut fixture = ProviderTestFixture::new_local()?;
+
+        let contract_address = Address::random();
+        fixture
+            .provider_data
+            .set_code(contract_address, Bytes::from(code))?;
+
+        let state_overrides = StateOverrides::default();
+        let transaction = resolve_call_request(
+            &mut fixture.provider_data,
+            CallRequest {
+                from: Some(fixture.nth_local_account(0)?),
+                to: Some(contract_address),
+                ..CallRequest::default()
+            },
+            None,
+            &state_overrides,
+        )?;
+
+        let result =
+            fixture
+                .provider_data
+                .create_access_list(transaction, None, &state_overrides)?;
+
+        // The precompile is excluded
+        let mut expected_access_list = vec![
+            AccessListItem {
+                address: contract_address,
+                storage_keys: (0..2u64).map(|slot| B256::from(U256::from(slot))).collect(),
+            },
+            AccessListItem {
+                address: external_address,
+                storage_keys: Vec::new(),
+            },
+        ];
+        expected_access_list.sort_unstable_by_key(|item| item.address);
+        assert_eq!(result.access_list, expected_access_list);
+
+        // Each pre-warmed storage slot and account saves 100 gas, but listing the warm
+        // callee costs an additional 2400 gas.
+        assert_eq!(
+            result.gas_used,
+            result.gas_used_without_access_list + 2_400 - 3 * 100
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn create_access_list_includes_coinbase_before_shanghai() -> anyhow::Result<()> {
+        // COINBASE BALANCE POP STOP
+        const COINBASE_BALANCE_CODE: [u8; 4] = [0x41, 0x31, 0x50, 0x00];
+
+        fn create_access_list(spec_id: SpecId) -> anyhow::Result<(Vec<AccessListItem>, Address)> {
+            let mut config = create_test_config();
+            config.hardfork = spec_id;
+
+            let mut fixture = ProviderTestFixture::new(config)?;
+
+            let contract_address = Address::random();
+            fixture
+                .provider_data
+                .set_code(contract_address, Bytes::from_static(&COINBASE_BALANCE_CODE))?;
+
+            let state_overrides = StateOverrides::default();
+            let transaction = resolve_call_request(
+                &mut fixture.provider_data,
+                CallRequest {
+                    from: Some(fixture.nth_local_account(0)?),
+                    to: Some(contract_address),
+                    ..CallRequest::default()
+                },
+                None,
+                &state_overrides,
+            )?;
+
+            let coinbase = fixture.provider_data.last_block()?.header().beneficiary;
+            let result =
+                fixture
+                    .provider_data
+                    .create_access_list(transaction, None, &state_overrides)?;
+
+            Ok((result.access_list, coinbase))
+        }
+
+        // Before EIP-3651, the coinbase is cold
+        let (access_list, coinbase) = create_access_list(SpecId::MERGE)?;
+        assert_eq!(
+            access_list,
+            vec![AccessListItem {
+                address: coinbase,
+                storage_keys: Vec::new(),
+            }]
+        );
+
+        let (access_list, _coinbase) = create_access_list(SpecId::SHANGHAI)?;
+        assert_eq!(access_list, Vec::new());
+
+        Ok(())
+    }
+
+    #[test]
+    fn run_call_records_max_call_depth() -> anyhow::Result<()> {
+        const RECURSION_DEPTH: usize = 5;
+
+        // Recursively calls itself with the calldata decremented, until it is zero:
+        // PUSH1 0x00 CALLDATALOAD DUP1 ISZERO PUSH1 0x1e JUMPI PUSH1 0x01 SWAP1 SUB
+        // PUSH1 0x00 MSTORE PUSH1 0x00 PUSH1 0x00 PUSH1 0x20 PUSH1 0x00 PUSH1 0x00
+        // ADDRESS GAS CALL POP STOP JUMPDEST STOP
+        const RECURSIVE_CODE: [u8; 32] = [
+            0x60, 0x00, 0x35, 0x80, 0x15, 0x60, 0x1e, 0x57, 0x60, 0x01, 0x90, 0x03, 0x60, 0x00,
+            0x52, 0x60, 0x00, 0x60, 0x00, 0x60, 0x20, 0x60, 0x00, 0x60, 0x00, 0x30, 0x5a, 0xf1,
+            0x50, 0x00, 0x5b, 0x00,
+        ];
+
+        let mut fixture = ProviderTestFixture::new_local()?;
+
+        let contract_address = Address::random();
+        fixture
+            .provider_data
+            .set_code(contract_address, Bytes::from_static(&RECURSIVE_CODE))?;
+
+        let state_overrides = StateOverrides::default();
+        let transaction = resolve_call_request(
+            &mut fixture.provider_data,
+            CallRequest {
+                from: Some(fixture.nth_local_account(0)?),
+                to: Some(contract_address),
+                data: Some(Bytes::from(
+                    U256::from(RECURSION_DEPTH).to_be_bytes::<32>().to_vec(),
+                )),
+                ..CallRequest::default()
+            },
+            None,
+            &state_overrides,
+        )?;
+
+        let result = fixture
+            .provider_data
+            .run_call(transaction, None, &state_overrides)?;
+
+        assert!(result.execution_result.is_success());
+        assert_eq!(result.trace.max_depth, RECURSION_DEPTH);
+
+        Ok(())
+    }
+
+    #[test]
+    fn run_call_logs() -> anyhow::Result<()> {
+        const TOPIC: B256 = B256::repeat_byte(0xab);
+
+        // PUSH1 0x2a PUSH1 0x00 MSTORE PUSH32 TOPIC PUSH1 0x20 PUSH1 0x00 LOG1 STOP
+        let mut code = vec![0x60, 0x2a, 0x60, 0x00, 0x52, 0x7f];
+        code.extend_from_slice(TOPIC.as_slice());
+        code.extend_from_slice(&[0x60, 0x20, 0x60, 0x00, 0xa1, 0x00]);
+
+        let mut fixture = ProviderTestFixture::new_local()?;
+
+        let contract_address = Address::random();
+        fixture
+            .provider_data
+            .set_code(contract_address, Bytes::from(code))?;
+
+        let state_overrides = StateOverrides::default();
+        let transaction = resolve_call_request(
+            &mut fixture.provider_data,
+            CallRequest {
+                from: Some(fixture.nth_local_account(0)?),
+                to: Some(contract_address),
+                ..CallRequest::default()
+            },
+            None,
+            &state_overrides,
+        )?;
+
+        let result = fixture
+            .provider_data
+            .run_call(transaction, None, &state_overrides)?;
+
+        let logs = CallLog::from_execution_result(&result.execution_result);
+        assert_eq!(logs.len(), 1);
+
+        let log = &logs[0];
+        assert_eq!(log.log_index, 0);
+        assert_eq!(log.address, contract_address);
+        assert_eq!(log.topics(), &[TOPIC]);
+        assert_eq!(
+            log.data.data,
+            Bytes::from(U256::from(0x2a).to_be_bytes::<32>().to_vec())
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn debug_trace_call_storage_access() -> anyhow::Result<()> {
+        // PUSH1 0x00 SLOAD POP PUSH1 0x00 SLOAD POP PUSH1 0x01 PUSH1 0x00 SSTORE
+        // PUSH1 0x01 SLOAD POP STOP
+        const CODE: [u8; 18] = [
+            0x60, 0x00, 0x54, 0x50, 0x60, 0x00, 0x54, 0x50, 0x60, 0x01, 0x60, 0x00, 0x55, 0x60,
+            0x01, 0x54, 0x50, 0x00,
+        ];
+
+        let mut fixture = ProviderTestFixture::new_local()?;
+
+        let contract_address = Address::random();
+        fixture
+            .provider_data
+            .set_code(contract_address, Bytes::from_static(&CODE))?;
+
+        let state_overrides = StateOverrides::default();
+        let transaction = resolve_call_request(
+            &mut fixture.provider_data,
+            CallRequest {
+                from: Some(fixture.nth_local_account(0)?),
+                to: Some(contract_address),
+                ..CallRequest::default()
+            },
+            None,
+            &state_overrides,
+        )?;
+
+        let result = fixture.provider_data.debug_trace_call(
+            transaction,
+            None,
+            DebugTraceConfig::default(),
+        )?;
+
+        let storage_accesses = result
+            .logs
+            .iter()
+            .filter(|log| log.op == opcode::SLOAD || log.op == opcode::SSTORE)
+            .map(|log| (log.op, log.storage_access))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            storage_accesses,
+            vec![
+                (opcode::SLOAD, Some(StorageAccess::Cold)),
+                (opcode::SLOAD, Some(StorageAccess::Warm)),
+                (opcode::SSTORE, Some(StorageAccess::Warm)),
+                (opcode::SLOAD, Some(StorageAccess::Cold)),
+            ]
+        );
+        assert!(result
+            .logs
+            .iter()
+            .filter(|log| log.op != opcode::SLOAD && log.op != opcode::SSTORE)
+            .all(|log| log.storage_access.is_none()));
+
+        Ok(())
+    }
+
+    fn call_to(fixture: &mut ProviderTestFixture, to: Address) -> anyhow::Result<CallResult> {
+        let state_overrides = StateOverrides::default();
+        let transaction = resolve_call_request(
+            &mut fixture.provider_data,
+            CallRequest {
+                from: Some(fixture.nth_local_account(0)?),
+                to: Some(to),
+                ..CallRequest::default()
+            },
+            None,
+            &state_overrides,
+        )?;
+
+        Ok(fixture
+            .provider_data
+            .run_call(transaction, None, &state_overrides)?)
+    }
+
+    #[test]
+    fn run_call_without_code_succeeds_by_default() -> anyhow::Result<()> {
+        let mut fixture = ProviderTestFixture::new_local()?;
+        assert!(!fixture.config.bail_on_call_without_code);
+
+        let result = call_to(&mut fixture, Address::random())?;
+        assert!(result.execution_result.is_success());
+        assert_eq!(result.execution_result.output(), Some(&Bytes::new()));
+
+        Ok(())
+    }
+
+    #[test]
+    fn run_call_without_code_bails() -> anyhow::Result<()> {
+        let mut fixture = ProviderTestFixture::new_local()?;
+        fixture
+            .provider_data
+            .initial_config
+            .bail_on_call_without_code = true;
+
+        let address = Address::random();
+        let error = call_to(&mut fixture, address)
+            .expect_err("call to an account without code should fail")
+            .downcast::<ProviderError<Infallible>>()?;
+        assert!(matches!(
+            error,
+            ProviderError::CallWithoutCode { address: callee } if callee == address
+        ));
+
+        // Calls to accounts with code and precompiles still succeed
+        fixture
+            .provider_data
+            .set_code(address, Bytes::from_static(&[0x00]))?;
+        assert!(call_to(&mut fixture, address)?
+            .execution_result
+            .is_success());
+
+        let identity_precompile = Address::with_last_byte(4);
+        assert!(call_to(&mut fixture, identity_precompile)?
+            .execution_result
+            .is_success());
+
+        Ok(())
+    }
+
+    #[test]
+    fn genesis_block_gas_limit() -> anyhow::Result<()> {
+        fn genesis_gas_limit(config: ProviderConfig) -> anyhow::Result<u64> {
+            let fixture = ProviderTestFixture::new(config)?;
+            let genesis_block = fixture
+                .provider_data
+                .block_by_block_spec(&BlockSpec::Number(0))?
+                .context("genesis block should exist")?;
+
+            Ok(genesis_block.header().gas_limit)
+        }
+
+        const CONFIGURED_GAS_LIMIT: u64 = 12_345_678;
+
+        let configured = genesis_gas_limit(ProviderConfig {
+            block_gas_limit: Some(CONFIGURED_GAS_LIMIT),
+            ..create_test_config()
+        })?;
+        assert_eq!(configured, CONFIGURED_GAS_LIMIT);
+
+        // Kovan has a known default that differs from the fallback
+        let known_chain = genesis_gas_limit(ProviderConfig {
+            block_gas_limit: None,
+            chain_id: 42,
+            ..create_test_config()
+        })?;
+        assert_eq!(known_chain, 12_500_000);
+
+        let unknown_chain = genesis_gas_limit(ProviderConfig {
+            block_gas_limit: None,
+            chain_id: 123_456,
+            ..create_test_config()
+        })?;
+        assert_eq!(unknown_chain, DEFAULT_BLOCK_GAS_LIMIT);
+
         Ok(())
     }
 
     #[test]
-    fn pending_transactions_returns_pending_and_queued() -> anyhow::Result<()> {
-        let mut fixture = ProviderTestFixture::new_local().unwrap();
+    fn mine_and_commit_block_prunes_receipts() -> anyhow::Result<()> {
+        let config = ProviderConfig {
+            receipt_retention: NonZeroU64::new(2),
+            ..create_test_config()
+        };
 
-        let transaction1 = fixture.signed_dummy_transaction(0, Some(0))?;
-        fixture
+        let mut fixture = ProviderTestFixture::new(config)?;
+
+        let transaction = fixture.signed_dummy_transaction(0, None)?;
+        let transaction_hash = fixture.provider_data.add_pending_transaction(transaction)?;
+
+        let result = fixture
             .provider_data
-            .add_pending_transaction(transaction1.clone())?;
+            .mine_and_commit_block(BlockOptions::default())?;
+        let block_number = result.block.header().number;
 
-        let transaction2 = fixture.signed_dummy_transaction(0, Some(2))?;
+        // The block is within the retention window
         fixture
             .provider_data
-            .add_pending_transaction(transaction2.clone())?;
+            .mine_and_commit_block(BlockOptions::default())?;
 
-        let transaction3 = fixture.signed_dummy_transaction(0, Some(3))?;
-        fixture
+        let receipt = fixture
             .provider_data
-            .add_pending_transaction(transaction3.clone())?;
+            .transaction_receipt(&transaction_hash)?
+            .context("receipt should be retained")?;
+        assert_eq!(receipt.block_number, block_number);
 
-        let pending_transactions = fixture
+        // The block falls outside of the retention window
+        fixture
             .provider_data
-            .pending_transactions()
-            .cloned()
-            .collect::<Vec<_>>();
+            .mine_and_commit_block(BlockOptions::default())?;
 
-        assert_eq!(
-            pending_transactions,
-            vec![transaction1, transaction2, transaction3]
-        );
+        let error = fixture
+            .provider_data
+            .transaction_receipt(&transaction_hash)
+            .expect_err("receipt should be pruned");
+        assert!(matches!(
+            error,
+            ProviderError::Blockchain(BlockchainError::ReceiptsPruned {
+                block_number: pruned_block_number
+            }) if pruned_block_number == block_number
+        ));
 
         Ok(())
     }
 
     #[test]
-    fn set_balance_updates_mem_pool() -> anyhow::Result<()> {
+    fn pending_transaction_effective_gas_price() -> anyhow::Result<()> {
         let mut fixture = ProviderTestFixture::new_local()?;
 
-        let transaction = fixture.impersonated_dummy_transaction()?;
+        let request = TransactionRequest::Eip1559(Eip1559TransactionRequest {
+            chain_id: fixture.config.chain_id,
+            nonce: 0,
+            max_priority_fee_per_gas: U256::from(2_000_000_000u64),
+            max_fee_per_gas: U256::from(10_000_000_000u64),
+            gas_limit: 30_000,
+            kind: TransactionKind::Call(Address::ZERO),
+            value: U256::from(1),
+            input: Bytes::default(),
+            access_list: Vec::new(),
+        });
+        let sender = fixture.nth_local_account(0)?;
+        let transaction = fixture
+            .provider_data
+            .sign_transaction_request(TransactionRequestAndSender { request, sender })?;
+
         let transaction_hash = fixture.provider_data.add_pending_transaction(transaction)?;
 
-        assert!(fixture
+        // The priority fee fits within the max fee
+        let effective_gas_price = fixture
             .provider_data
-            .mem_pool
-            .transaction_by_hash(&transaction_hash)
-            .is_some());
+            .pending_transaction_effective_gas_price(
+                &transaction_hash,
+                Some(U256::from(5_000_000_000u64)),
+            )?;
+        assert_eq!(
+            effective_gas_price,
+            Some(PendingEffectiveGasPrice::Includable(U256::from(
+                7_000_000_000u64
+            )))
+        );
 
-        fixture
+        // The priority fee is capped by the max fee
+        let effective_gas_price = fixture
             .provider_data
-            .set_balance(fixture.impersonated_account, U256::from(100))?;
+            .pending_transaction_effective_gas_price(
+                &transaction_hash,
+                Some(U256::from(9_000_000_000u64)),
+            )?;
+        assert_eq!(
+            effective_gas_price,
+            Some(PendingEffectiveGasPrice::Includable(U256::from(
+                10_000_000_000u64
+            )))
+        );
 
-        assert!(fixture
+        // The base fee exceeds the max fee, so the transaction cannot be included
+        let effective_gas_price = fixture
             .provider_data
-            .mem_pool
-            .transaction_by_hash(&transaction_hash)
-            .is_none());
+            .pending_transaction_effective_gas_price(
+                &transaction_hash,
+                Some(U256::from(11_000_000_000u64)),
+            )?;
+        assert_eq!(
+            effective_gas_price,
+            Some(PendingEffectiveGasPrice::MaxFeeTooLow {
+                max_fee_per_gas: U256::from(10_000_000_000u64),
+                base_fee_per_gas: U256::from(11_000_000_000u64),
+            })
+        );
+
+        let unknown_transaction = fixture
+            .provider_data
+            .pending_transaction_effective_gas_price(&B256::ZERO, None)?;
+        assert_eq!(unknown_transaction, None);
 
         Ok(())
     }
@@ -3455,6 +5924,33 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn reset_local_to_forking_with_http_headers() -> anyhow::Result<()> {
+        let mut fixture = ProviderTestFixture::new_local()?;
+
+        let fork_config = ForkConfig {
+            json_rpc_url: get_alchemy_url(),
+            block_number: Some(FORK_BLOCK_NUMBER),
+            http_headers: Some(
+                [(String::from("x-edr-test"), String::from("reset"))]
+                    .into_iter()
+                    .collect(),
+            ),
+        };
+
+        fixture.provider_data.reset(Some(fork_config.clone()))?;
+
+        assert!(fixture
+            .provider_data
+            .block_by_block_spec(&BlockSpec::Number(FORK_BLOCK_NUMBER))?
+            .is_some());
+
+        // The headers are retained for subsequent resets
+        assert_eq!(fixture.provider_data.initial_config.fork, Some(fork_config));
+
+        Ok(())
+    }
+
     #[test]
     fn reset_forking_to_local() -> anyhow::Result<()> {
         let mut fixture = ProviderTestFixture::new_forked(None)?;
@@ -3533,6 +6029,160 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn sign_typed_data_v4_with_arrays_and_dynamic_types() -> anyhow::Result<()> {
+        let fixture = ProviderTestFixture::new_local()?;
+
+        let address = fixture.nth_local_account(0)?;
+        let message = json!({
+          "types": {
+            "EIP712Domain": [
+              { "name": "name", "type": "string" },
+              { "name": "chainId", "type": "uint256" },
+            ],
+            "Person": [
+              { "name": "name", "type": "string" },
+              { "name": "wallets", "type": "address[]" },
+            ],
+            "Group": [
+              { "name": "members", "type": "Person[]" },
+              { "name": "data", "type": "bytes" },
+              { "name": "tags", "type": "string[]" },
+              { "name": "count", "type": "uint8" },
+            ],
+          },
+          "primaryType": "Group",
+          "domain": {
+            "name": "Groups",
+            "chainId": 123,
+          },
+          "message": {
+            "members": [
+              {
+                "name": "Cow",
+                "wallets": [
+                  "0xCD2a3d9F938E13CD947Ec05AbC7FE734Df8DD826",
+                  "0xDeaDbeefdEAdbeefdEadbEEFdeadbeEFdEaDbeeF",
+                ],
+              },
+              {
+                "name": "Bob",
+                "wallets": ["0xbBbBBBBbbBBBbbbBbbBbbbbBBbBbbbbBbBbbBBbB"],
+              },
+            ],
+            "data": "0x0123456789abcdef",
+            "tags": ["first", "second"],
+            "count": 2,
+          },
+        });
+        let message: TypedData = serde_json::from_value(message)?;
+
+        let signature = fixture
+            .provider_data
+            .sign_typed_data_v4(&address, &message)?;
+
+        let hash: B256 = message.encode_eip712()?.into();
+        assert_eq!(signature.recover(hash)?, address);
+
+        Ok(())
+    }
+
+    #[test]
+    fn sign_with_custom_signer() -> anyhow::Result<()> {
+        #[derive(Debug)]
+        struct CountingSigner {
+            inner: LocalSigner,
+            num_signatures: Arc<AtomicUsize>,
+        }
+
+        impl Signer for CountingSigner {
+            fn address(&self) -> Address {
+                self.inner.address()
+            }
+
+            fn sign_hash(&self, hash: B256) -> Result<Signature, SignatureError> {
+                self.num_signatures.fetch_add(1, atomic::Ordering::Relaxed);
+                self.inner.sign_hash(hash)
+            }
+
+            fn sign_transaction(
+                &self,
+                request: TransactionRequest,
+            ) -> Result<SignedTransaction, SignatureError> {
+                self.num_signatures.fetch_add(1, atomic::Ordering::Relaxed);
+                self.inner.sign_transaction(request)
+            }
+        }
+
+        let mut fixture = ProviderTestFixture::new_local()?;
+
+        let num_signatures = Arc::new(AtomicUsize::new(0));
+        let signer = CountingSigner {
+            inner: LocalSigner::new(secret_key_from_str(
+                "e331b6d69882b4cb4ea581d88e0b604039a3de5967688d3dcffdd2270c0fd109",
+            )?),
+            num_signatures: num_signatures.clone(),
+        };
+        let address = signer.address();
+
+        fixture.provider_data.add_signer(Box::new(signer));
+        assert!(fixture
+            .provider_data
+            .accounts()
+            .any(|account| *account == address));
+
+        let message = Bytes::from_static(b"Hello, world!");
+        let signature = fixture.provider_data.sign(&address, message.clone())?;
+        assert_eq!(signature.recover(&message[..])?, address);
+        assert_eq!(num_signatures.load(atomic::Ordering::Relaxed), 1);
+
+        let mut request = fixture.dummy_transaction_request(0, 30_000, None)?;
+        request.sender = address;
+
+        let transaction = fixture.provider_data.sign_transaction_request(request)?;
+        assert_eq!(*transaction.caller(), address);
+        assert_eq!(num_signatures.load(atomic::Ordering::Relaxed), 2);
+
+        Ok(())
+    }
+
+    #[test]
+    fn sign_with_local_account() -> anyhow::Result<()> {
+        let fixture = ProviderTestFixture::new_local()?;
+
+        let address = fixture.nth_local_account(0)?;
+        let message = Bytes::from_static(b"Hello, world!");
+
+        let signature = fixture.provider_data.sign(&address, message.clone())?;
+
+        // The signature is over the `\x19Ethereum Signed Message:\n` prefixed hash
+        assert_eq!(signature.recover(hash_message(&message))?, address);
+        assert_eq!(signature.recover(&message[..])?, address);
+
+        Ok(())
+    }
+
+    #[test]
+    fn sign_rejects_impersonated_account() -> anyhow::Result<()> {
+        let mut fixture = ProviderTestFixture::new_local()?;
+
+        let impersonated_account = Address::random();
+        fixture
+            .provider_data
+            .impersonate_account(impersonated_account);
+
+        let result = fixture
+            .provider_data
+            .sign(&impersonated_account, Bytes::from_static(b"Hello, world!"));
+
+        assert!(matches!(
+            result,
+            Err(ProviderError::UnknownAddress { address }) if address == impersonated_account
+        ));
+
+        Ok(())
+    }
+
     #[test]
     fn run_call_in_hardfork_context() -> anyhow::Result<()> {
         sol! { function Hello() public pure returns (string); }
@@ -3568,12 +6218,6 @@ mod tests {
         let hello_world_contract_address: Address = HELLO_WORLD_CONTRACT_ADDRESS.parse()?;
         let hello_world_contract_call = HelloCall::new(());
 
-        let runtime = runtime::Builder::new_multi_thread()
-            .worker_threads(1)
-            .enable_all()
-            .thread_name("provider-data-test")
-            .build()?;
-
         let default_config = create_test_config_with_fork(Some(ForkConfig {
             json_rpc_url: get_alchemy_url(),
             block_number: Some(EIP_1559_ACTIVATION_BLOCK),
@@ -3581,7 +6225,7 @@ mod tests {
         }));
 
         let config = ProviderConfig {
-            block_gas_limit: 1_000_000,
+            block_gas_limit: Some(1_000_000),
             chain_id: 1,
             coinbase: Address::ZERO,
             hardfork: SpecId::LONDON,
@@ -3589,7 +6233,7 @@ mod tests {
             ..default_config
         };
 
-        let mut fixture = ProviderTestFixture::new(runtime, config)?;
+        let mut fixture = ProviderTestFixture::new(config)?;
 
         let default_call = CallRequest {
             from: Some(fixture.nth_local_account(0)?),
@@ -3750,4 +6394,172 @@ mod tests {
             url: get_alchemy_url(),
         },
     }
+
+    #[test]
+    fn get_proof_of_existing_and_missing_accounts() -> anyhow::Result<()> {
+        let mut fixture = ProviderTestFixture::new_local()?;
+
+        let address = fixture.nth_local_account(0)?;
+        let index = U256::from(1);
+        let value = U256::from(42);
+        fixture
+            .provider_data
+            .set_account_storage_slot(address, index, value)?;
+
+        let balance = fixture.provider_data.balance(address, None)?;
+        let proof = fixture.provider_data.get_proof(
+            address,
+            vec![index, U256::from(2)],
+            &BlockSpec::latest(),
+        )?;
+
+        assert_eq!(proof.address, address);
+        assert_eq!(proof.balance, balance);
+        assert_ne!(proof.storage_hash, KECCAK_NULL_RLP);
+        assert!(!proof.account_proof.is_empty());
+
+        assert_eq!(proof.storage_proof.len(), 2);
+        assert_eq!(proof.storage_proof[0].key, index);
+        assert_eq!(proof.storage_proof[0].value, value);
+        assert!(!proof.storage_proof[0].proof.is_empty());
+        assert_eq!(proof.storage_proof[1].value, U256::ZERO);
+
+        // Non-existent accounts result in an exclusion proof
+        let missing_address = Address::random();
+        let proof =
+            fixture
+                .provider_data
+                .get_proof(missing_address, vec![index], &BlockSpec::latest())?;
+
+        assert_eq!(proof.balance, U256::ZERO);
+        assert_eq!(proof.nonce, 0);
+        assert_eq!(proof.code_hash, KECCAK_EMPTY);
+        assert_eq!(proof.storage_hash, KECCAK_NULL_RLP);
+        assert!(!proof.account_proof.is_empty());
+        assert_eq!(proof.storage_proof[0].value, U256::ZERO);
+        assert!(proof.storage_proof[0].proof.is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn set_next_block_timestamp_rejects_non_increasing_timestamps() -> anyhow::Result<()> {
+        let mut fixture = ProviderTestFixture::new_local()?;
+
+        let previous = fixture
+            .provider_data
+            .blockchain
+            .last_block()?
+            .header()
+            .timestamp;
+
+        let result = fixture.provider_data.set_next_block_timestamp(previous - 1);
+        assert!(matches!(
+            result,
+            Err(ProviderError::TimestampLowerThanPrevious { proposed, previous: actual_previous })
+                if proposed == previous - 1 && actual_previous == previous
+        ));
+
+        let result = fixture.provider_data.set_next_block_timestamp(previous);
+        assert!(matches!(
+            result,
+            Err(ProviderError::TimestampEqualsPrevious { proposed }) if proposed == previous
+        ));
+
+        assert_eq!(fixture.provider_data.next_block_timestamp, None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn set_next_block_timestamp_applies_to_one_block() -> anyhow::Result<()> {
+        let mut fixture = ProviderTestFixture::new_local()?;
+
+        let previous = fixture
+            .provider_data
+            .blockchain
+            .last_block()?
+            .header()
+            .timestamp;
+        let next_timestamp = previous + 1_000;
+
+        fixture
+            .provider_data
+            .set_next_block_timestamp(next_timestamp)?;
+
+        let result = fixture
+            .provider_data
+            .mine_and_commit_block(BlockOptions::default())?;
+        assert_eq!(result.block.header().timestamp, next_timestamp);
+
+        // The override is cleared after mining, so subsequent blocks advance normally
+        assert_eq!(fixture.provider_data.next_block_timestamp, None);
+
+        let result = fixture
+            .provider_data
+            .mine_and_commit_block(BlockOptions::default())?;
+        assert!(result.block.header().timestamp > next_timestamp);
+
+        Ok(())
+    }
+
+    #[test]
+    fn get_storage_at_historical_blocks_fork_mode() -> anyhow::Result<()> {
+        let mut fixture = ProviderTestFixture::new_forked(None)?;
+
+        let address = Address::random();
+        let index = U256::from(1);
+
+        let mut get_storage_at = |block_spec: BlockSpec| {
+            fixture
+                .provider_data
+                .get_storage_at(address, index, Some(&block_spec))
+        };
+
+        // Blocks at or below the fork point are read from the remote
+        assert_eq!(
+            get_storage_at(BlockSpec::Number(FORK_BLOCK_NUMBER - 1))?,
+            U256::ZERO
+        );
+        assert_eq!(
+            get_storage_at(BlockSpec::Number(FORK_BLOCK_NUMBER))?,
+            U256::ZERO
+        );
+
+        // Local blocks are read from their respective state
+        let first_value = U256::from(42);
+        fixture
+            .provider_data
+            .set_account_storage_slot(address, index, first_value)?;
+        fixture
+            .provider_data
+            .mine_and_commit_block(BlockOptions::default())?;
+        let first_block_number = fixture.provider_data.last_block_number();
+
+        let second_value = U256::from(43);
+        fixture
+            .provider_data
+            .set_account_storage_slot(address, index, second_value)?;
+        fixture
+            .provider_data
+            .mine_and_commit_block(BlockOptions::default())?;
+
+        let mut get_storage_at = |block_spec: BlockSpec| {
+            fixture
+                .provider_data
+                .get_storage_at(address, index, Some(&block_spec))
+        };
+
+        assert_eq!(
+            get_storage_at(BlockSpec::Number(first_block_number))?,
+            first_value
+        );
+        assert_eq!(get_storage_at(BlockSpec::latest())?, second_value);
+        assert_eq!(
+            get_storage_at(BlockSpec::Number(FORK_BLOCK_NUMBER))?,
+            U256::ZERO
+        );
+
+        Ok(())
+    }
 }
@@ -1,11 +1,11 @@
-use edr_eth::{signature::public_key_to_address, Address};
+use edr_eth::Address;
 use edr_evm::{Account, AccountInfo, AccountStatus, HashMap, KECCAK_EMPTY};
 use indexmap::IndexMap;
 
-use crate::{AccountConfig, ProviderConfig};
+use crate::{AccountConfig, LocalSigner, ProviderConfig, Signer};
 
 pub(super) struct InitialAccounts {
-    pub local_accounts: IndexMap<Address, k256::SecretKey>,
+    pub local_accounts: IndexMap<Address, Box<dyn Signer>>,
     pub genesis_accounts: HashMap<Address, Account>,
 }
 
@@ -20,7 +20,9 @@ pub(super) fn create_accounts(config: &ProviderConfig) -> InitialAccounts {
                  secret_key,
                  balance,
              }| {
-                let address = public_key_to_address(secret_key.public_key());
+                let signer = LocalSigner::new(secret_key.clone());
+                let address = signer.address();
+
                 let genesis_account = AccountInfo {
                     balance: *balance,
                     nonce: 0,
@@ -28,7 +30,7 @@ pub(super) fn create_accounts(config: &ProviderConfig) -> InitialAccounts {
                     code_hash: KECCAK_EMPTY,
                 };
 
-                local_accounts.insert(address, secret_key.clone());
+                local_accounts.insert(address, Box::new(signer) as Box<dyn Signer>);
 
                 (address, genesis_account)
             },
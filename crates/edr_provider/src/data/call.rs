@@ -1,14 +1,17 @@
 use core::fmt::Debug;
+use std::sync::Arc;
 
-use edr_eth::{
-    block::{BlobGas, Header},
-    SpecId, U256,
-};
+use edr_eth::{access_list::AccessListItem, block::Header, Address, SpecId, B256, U256};
 use edr_evm::{
+    block_env_from_header,
     blockchain::{BlockchainError, SyncBlockchain},
+    db::Database,
+    evm::EvmHandler,
     guaranteed_dry_run,
+    precompile::{PrecompileSpecId, Precompiles},
     state::{StateError, StateOverrides, StateRefOverrider, SyncState},
-    BlobExcessGasAndPrice, BlockEnv, CfgEnvWithHandlerCfg, DebugContext, ExecutionResult, TxEnv,
+    BlockEnv, CfgEnvWithHandlerCfg, DebugContext, DryRunResult, EVMError, ExecutionResult,
+    TransactTo, TxEnv, KECCAK_EMPTY,
 };
 
 use crate::ProviderError;
@@ -37,6 +40,111 @@ where
 pub(super) fn run_call<'a, 'evm, DebugDataT, LoggerErrorT: Debug>(
     args: RunCallArgs<'a, 'evm, DebugDataT>,
 ) -> Result<ExecutionResult, ProviderError<LoggerErrorT>>
+where
+    'a: 'evm,
+{
+    dry_run_call(args).map(|result| result.result)
+}
+
+/// Returns the callee of the transaction if it's a call to an account without
+/// code. Precompiles are considered to have code.
+pub(super) fn callee_without_code(
+    state: &dyn SyncState<StateError>,
+    state_overrides: &StateOverrides,
+    spec_id: SpecId,
+    tx_env: &TxEnv,
+) -> Result<Option<Address>, StateError> {
+    let TransactTo::Call(callee) = tx_env.transact_to else {
+        return Ok(None);
+    };
+
+    let precompiles = Precompiles::new(PrecompileSpecId::from_spec_id(spec_id));
+    if precompiles.contains(&callee) {
+        return Ok(None);
+    }
+
+    let has_code = state_overrides
+        .account_info(&state, &callee)?
+        .map_or(false, |account_info| account_info.code_hash != KECCAK_EMPTY);
+
+    Ok(if has_code { None } else { Some(callee) })
+}
+
+/// Execute a transaction as a call and generate an access list of the
+/// accounts and storage slots that it accessed.
+///
+/// Similar to `eth_createAccessList`, the list excludes the sender, created
+/// contracts, and precompiles, as they are already warm. From Shanghai
+/// onwards, the coinbase is excluded as well (EIP-3651). The recipient is
+/// only included if any of its storage slots were accessed.
+///
+/// Any debug context provided in the arguments is ignored.
+pub(super) fn generate_access_list<'a, 'evm, LoggerErrorT: Debug>(
+    mut args: RunCallArgs<'a, 'evm, ()>,
+) -> Result<Vec<AccessListItem>, ProviderError<LoggerErrorT>>
+where
+    'a: 'evm,
+{
+    // Rewarding the coinbase loads it into the state, so the reward is skipped to
+    // only include the coinbase if the transaction accessed it.
+    args.debug_context = Some(DebugContext {
+        data: (),
+        register_handles_fn: register_skip_reward_handles,
+    });
+
+    let caller = args.tx_env.caller;
+    let recipient = match args.tx_env.transact_to {
+        TransactTo::Call(address) => Some(address),
+        TransactTo::Create(_) => None,
+    };
+    let coinbase = args.header.beneficiary;
+    let spec_id = args.cfg_env.handler_cfg.spec_id;
+    let precompiles = Precompiles::new(PrecompileSpecId::from_spec_id(spec_id));
+
+    let DryRunResult { state, .. } = dry_run_call(args)?;
+
+    let mut access_list = state
+        .into_iter()
+        .filter(|(address, account)| {
+            !account.is_created()
+                && *address != caller
+                && (spec_id < SpecId::SHANGHAI || *address != coinbase)
+                && !precompiles.contains(address)
+        })
+        .filter_map(|(address, account)| {
+            let mut storage_keys = account
+                .storage
+                .into_keys()
+                .map(B256::from)
+                .collect::<Vec<_>>();
+
+            if Some(address) == recipient && storage_keys.is_empty() {
+                return None;
+            }
+
+            storage_keys.sort_unstable();
+
+            Some(AccessListItem {
+                address,
+                storage_keys,
+            })
+        })
+        .collect::<Vec<_>>();
+
+    access_list.sort_unstable_by_key(|item| item.address);
+
+    Ok(access_list)
+}
+
+/// Registers handles that skip rewarding the beneficiary.
+fn register_skip_reward_handles<DatabaseT: Database>(handler: &mut EvmHandler<'_, (), DatabaseT>) {
+    handler.post_execution.reward_beneficiary =
+        Arc::new(|_ctx, _gas| -> Result<(), EVMError<DatabaseT::Error>> { Ok(()) });
+}
+
+fn dry_run_call<'a, 'evm, DebugDataT, LoggerErrorT: Debug>(
+    args: RunCallArgs<'a, 'evm, DebugDataT>,
+) -> Result<DryRunResult, ProviderError<LoggerErrorT>>
 where
     'a: 'evm,
 {
@@ -51,21 +159,9 @@ where
     } = args;
 
     let block = BlockEnv {
-        number: U256::from(header.number),
-        coinbase: header.beneficiary,
-        timestamp: U256::from(header.timestamp),
-        gas_limit: U256::from(header.gas_limit),
+        // Calls are executed without a base fee, as they don't pay for gas
         basefee: U256::ZERO,
-        difficulty: header.difficulty,
-        prevrandao: if cfg_env.handler_cfg.spec_id >= SpecId::MERGE {
-            Some(header.mix_hash)
-        } else {
-            None
-        },
-        blob_excess_gas_and_price: header
-            .blob_gas
-            .as_ref()
-            .map(|BlobGas { excess_gas, .. }| BlobExcessGasAndPrice::new(*excess_gas)),
+        ..block_env_from_header(header, cfg_env.handler_cfg.spec_id)
     };
 
     guaranteed_dry_run(
@@ -77,8 +173,5 @@ where
         block,
         debug_context,
     )
-    .map_or_else(
-        |error| Err(ProviderError::RunTransaction(error)),
-        |result| Ok(result.result),
-    )
+    .map_err(ProviderError::RunTransaction)
 }
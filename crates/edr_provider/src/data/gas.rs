@@ -1,12 +1,17 @@
 use core::fmt::Debug;
-use std::cmp;
+use std::{cmp, ops::RangeInclusive};
 
-use edr_eth::{block::Header, reward_percentile::RewardPercentile, U256};
+use edr_eth::{
+    block::Header,
+    remote::{PreEip1898BlockSpec, RpcClient, RpcClientError},
+    reward_percentile::RewardPercentile,
+    U256,
+};
 use edr_evm::{
     blockchain::{BlockchainError, SyncBlockchain},
     state::{StateError, StateOverrides, SyncState},
     trace::{register_trace_collector_handles, TraceCollector},
-    CfgEnvWithHandlerCfg, DebugContext, ExecutionResult, SyncBlock, TxEnv,
+    CfgEnvWithHandlerCfg, DebugContext, ExecutableTransaction, ExecutionResult, SyncBlock, TxEnv,
 };
 use itertools::Itertools;
 
@@ -145,6 +150,58 @@ fn min_difference(lower_bound: u64) -> u64 {
     }
 }
 
+/// Computes the priority fee per gas that the block's beneficiary receives
+/// for the transaction.
+pub(super) fn effective_reward(
+    transaction: &ExecutableTransaction,
+    base_fee_per_gas: U256,
+) -> U256 {
+    // gas price pre EIP-1559 and max fee per gas post EIP-1559
+    let gas_price = transaction.gas_price();
+
+    if let Some(max_priority_fee_per_gas) = transaction.max_priority_fee_per_gas() {
+        cmp::min(max_priority_fee_per_gas, gas_price - base_fee_per_gas)
+    } else {
+        gas_price.saturating_sub(base_fee_per_gas)
+    }
+}
+
+/// Retrieves the effective rewards of the transactions in the provided range
+/// of remote blocks. Only the blocks' headers and the transactions' receipts
+/// are fetched.
+pub(super) async fn remote_effective_rewards(
+    rpc_client: &RpcClient,
+    block_numbers: RangeInclusive<u64>,
+) -> Result<Vec<U256>, RpcClientError> {
+    let mut rewards = Vec::new();
+    for block_number in block_numbers {
+        let Some(block) = rpc_client
+            .get_block_by_number(PreEip1898BlockSpec::Number(block_number))
+            .await?
+        else {
+            continue;
+        };
+
+        if block.transactions.is_empty() {
+            continue;
+        }
+
+        let base_fee_per_gas = block.base_fee_per_gas.unwrap_or_default();
+        let receipts = rpc_client
+            .get_transaction_receipts(&block.transactions)
+            .await?
+            .unwrap_or_default();
+
+        rewards.extend(receipts.iter().filter_map(|receipt| {
+            receipt
+                .effective_gas_price
+                .map(|gas_price| gas_price.saturating_sub(base_fee_per_gas))
+        }));
+    }
+
+    Ok(rewards)
+}
+
 /// Compute miner rewards for percentiles.
 pub(super) fn compute_rewards<LoggerErrorT: Debug>(
     block: &dyn SyncBlock<Error = BlockchainError>,
@@ -164,15 +221,7 @@ pub(super) fn compute_rewards<LoggerErrorT: Debug>(
             let transaction = &block.transactions()[i];
 
             let gas_used = receipt.gas_used;
-            // gas price pre EIP-1559 and max fee per gas post EIP-1559
-            let gas_price = transaction.gas_price();
-
-            let effective_reward =
-                if let Some(max_priority_fee_per_gas) = transaction.max_priority_fee_per_gas() {
-                    cmp::min(max_priority_fee_per_gas, gas_price - base_fee_per_gas)
-                } else {
-                    gas_price.saturating_sub(base_fee_per_gas)
-                };
+            let effective_reward = effective_reward(transaction, base_fee_per_gas);
 
             (gas_used, effective_reward)
         })
@@ -1,18 +1,18 @@
 use core::fmt::Debug;
 use std::{num::TryFromIntError, time::SystemTimeError};
 
-use alloy_sol_types::{ContractError, SolInterface};
 use edr_eth::{
     remote::{filter::SubscriptionType, jsonrpc, BlockSpec, BlockTag, RpcClientError},
     Address, Bytes, SpecId, B256, U256,
 };
 use edr_evm::{
     blockchain::BlockchainError,
-    hex,
+    hex, revert_reason,
     state::{AccountOverrideConversionError, StateError},
     trace::Trace,
-    DebugTraceError, ExecutionResult, HaltReason, MemPoolAddTransactionError, MineBlockError,
-    OutOfGasError, TransactionCreationError, TransactionError,
+    BlockTransactionError, DebugTraceError, ExecutionResult, HaltReason,
+    MemPoolAddTransactionError, MineBlockError, OutOfGasError, RevertReason,
+    TransactionCreationError, TransactionError,
 };
 use ethers_core::types::transaction::eip712::Eip712Error;
 
@@ -44,17 +44,32 @@ pub enum ProviderError<LoggerErrorT> {
     /// Blockchain error
     #[error(transparent)]
     Blockchain(#[from] BlockchainError),
+    /// The call's recipient has no code, while
+    /// [`crate::config::ProviderConfig::bail_on_call_without_code`] was
+    /// enabled.
+    #[error("Call to {address} failed, as there is no code at the address. Disable the 'bailOnCallWithoutCode' option to allow this")]
+    CallWithoutCode { address: Address },
     #[error(transparent)]
     Creation(#[from] CreationError),
     #[error(transparent)]
     DebugTrace(#[from] DebugTraceError<BlockchainError, StateError>),
     #[error("An EIP-4844 (shard blob) transaction was received, but Hardhat doesn't have support for them yet.")]
     Eip4844TransactionUnsupported,
+    #[error("An EIP-7702 (set code) transaction was received, but Hardhat doesn't have support for them yet.")]
+    Eip7702TransactionUnsupported,
     #[error(transparent)]
     Eip712Error(#[from] Eip712Error),
     /// A transaction error occurred while estimating gas.
     #[error(transparent)]
     EstimateGasTransactionFailure(#[from] EstimateGasFailure),
+    /// The transaction's effective gas price is lower than the minimum gas
+    /// price.
+    #[error("Transaction {transaction_hash} has an effective gas price of {actual}, which is below the minimum gas price of {expected}")]
+    GasPriceBelowMinimum {
+        transaction_hash: B256,
+        expected: U256,
+        actual: U256,
+    },
     #[error("{0}")]
     InvalidArgument(String),
     /// Block number or hash doesn't exist in blockchain
@@ -110,6 +125,20 @@ pub enum ProviderError<LoggerErrorT> {
     /// An error occurred while mining a block.
     #[error(transparent)]
     MineBlock(#[from] MineBlockError<BlockchainError, StateError>),
+    /// Cannot roll back to a block that precedes the fork block.
+    #[error("Cannot roll back to block {block_number}, as it precedes the fork block {fork_block_number}")]
+    RollbackBeforeForkBlock {
+        block_number: u64,
+        fork_block_number: u64,
+    },
+    /// Cannot roll back to a block that has not been mined yet.
+    #[error(
+        "Cannot roll back to block {block_number}, as the latest block is {latest_block_number}"
+    )]
+    RollbackToFutureBlock {
+        block_number: u64,
+        latest_block_number: u64,
+    },
     /// Rpc client error
     #[error(transparent)]
     RpcClientError(#[from] RpcClientError),
@@ -135,6 +164,10 @@ pub enum ProviderError<LoggerErrorT> {
     /// an older hardfork.
     #[error("hardhat_setNextBlockBaseFeePerGas is disabled because EIP-1559 is not active")]
     SetNextBlockBaseFeePerGasUnsupported { spec_id: SpecId },
+    /// The `hardhat_setNextBlockDifficulty` method is not supported due to a
+    /// post-merge hardfork.
+    #[error("hardhat_setNextBlockDifficulty is only available in pre-merge hardforks, the current hardfork is {spec_id:?}. Use hardhat_setPrevRandao instead")]
+    SetNextBlockDifficultyUnsupported { spec_id: SpecId },
     /// The `hardhat_setPrevRandao` method is not supported due to an older
     /// hardfork.
     #[error("hardhat_setPrevRandao is only available in post-merge hardforks, the current hardfork is {spec_id:?}")]
@@ -185,68 +218,102 @@ pub enum ProviderError<LoggerErrorT> {
     },
     #[error("{method_name} - Method not supported")]
     UnsupportedMethod { method_name: String },
+    /// The transaction has a zero gas price, while zero gas price transactions
+    /// are not allowed.
+    #[error("Transaction {transaction_hash} has a gas price of zero, but zero gas price transactions are not allowed. Enable the 'allowZeroGasPrice' option to allow this")]
+    ZeroGasPriceNotAllowed { transaction_hash: B256 },
 }
 
-impl<LoggerErrorT: Debug> From<ProviderError<LoggerErrorT>> for jsonrpc::Error {
-    fn from(value: ProviderError<LoggerErrorT>) -> Self {
-        const INVALID_INPUT: i16 = -32000;
-        const INTERNAL_ERROR: i16 = -32603;
-        const INVALID_PARAMS: i16 = -32602;
+/// The JSON-RPC error codes that [`ProviderError`]s are mapped to.
+pub mod error_code {
+    /// Invalid input, e.g. a failed transaction execution.
+    pub const INVALID_INPUT: i16 = -32000;
+    /// The requested method is not supported.
+    pub const METHOD_NOT_SUPPORTED: i16 = -32004;
+    /// Invalid method parameters.
+    pub const INVALID_PARAMS: i16 = -32602;
+    /// Internal JSON-RPC error.
+    pub const INTERNAL_ERROR: i16 = -32603;
+}
 
+impl<LoggerErrorT> ProviderError<LoggerErrorT> {
+    /// Returns the JSON-RPC error code corresponding to the error.
+    pub fn error_code(&self) -> i16 {
         #[allow(clippy::match_same_arms)]
-        let code = match &value {
-            ProviderError::AccountOverrideConversionError(_) => INVALID_INPUT,
-            ProviderError::AutoMineGasPriceTooLow { .. } => INVALID_INPUT,
-            ProviderError::AutoMineMaxFeeTooLow { .. } => INVALID_INPUT,
-            ProviderError::AutoMineNonceTooHigh { .. } => INVALID_INPUT,
-            ProviderError::AutoMineNonceTooLow { .. } => INVALID_INPUT,
-            ProviderError::AutoMinePriorityFeeTooLow { .. } => INVALID_INPUT,
-            ProviderError::Blockchain(_) => INVALID_INPUT,
-            ProviderError::Creation(_) => INVALID_INPUT,
-            ProviderError::DebugTrace(_) => INTERNAL_ERROR,
-            ProviderError::Eip4844TransactionUnsupported => INVALID_INPUT,
-            ProviderError::Eip712Error(_) => INVALID_INPUT,
-            ProviderError::EstimateGasTransactionFailure(_) => INVALID_INPUT,
-            ProviderError::InvalidArgument(_) => INVALID_PARAMS,
-            ProviderError::InvalidBlockNumberOrHash { .. } => INVALID_INPUT,
-            ProviderError::InvalidBlockTag { .. } => INVALID_PARAMS,
-            ProviderError::InvalidChainId { .. } => INVALID_PARAMS,
-            ProviderError::InvalidDropTransactionHash(_) => INVALID_PARAMS,
-            ProviderError::InvalidEip155TransactionChainId => INVALID_PARAMS,
-            ProviderError::InvalidFilterSubscriptionType { .. } => INVALID_PARAMS,
-            ProviderError::InvalidInput(_) => INVALID_INPUT,
-            ProviderError::InvalidTransactionHash { .. } => INVALID_PARAMS,
-            ProviderError::InvalidTransactionIndex(_) => INVALID_PARAMS,
-            ProviderError::InvalidTransactionInput(_) => INVALID_INPUT,
-            ProviderError::InvalidTransactionType(_) => INVALID_PARAMS,
-            ProviderError::Logger(_) => INTERNAL_ERROR,
-            ProviderError::MemPoolAddTransaction(_) => INVALID_INPUT,
-            ProviderError::MemPoolUpdate(_) => INVALID_INPUT,
-            ProviderError::MineBlock(_) => INVALID_INPUT,
-            ProviderError::RpcClientError(_) => INTERNAL_ERROR,
-            ProviderError::RpcVersion(_) => INVALID_INPUT,
-            ProviderError::RunTransaction(_) => INVALID_INPUT,
-            ProviderError::Serialization(_) => INVALID_INPUT,
-            ProviderError::SetAccountNonceLowerThanCurrent { .. } => INVALID_INPUT,
-            ProviderError::SetAccountNonceWithPendingTransactions => INTERNAL_ERROR,
-            ProviderError::SetMinGasPriceUnsupported => INVALID_INPUT,
-            ProviderError::SetNextBlockBaseFeePerGasUnsupported { .. } => INVALID_INPUT,
-            ProviderError::SetNextPrevRandaoUnsupported { .. } => INVALID_INPUT,
-            ProviderError::Signature(_) => INVALID_INPUT,
-            ProviderError::State(_) => INVALID_INPUT,
-            ProviderError::SystemTime(_) => INVALID_INPUT,
-            ProviderError::TimestampLowerThanPrevious { .. } => INVALID_INPUT,
-            ProviderError::TimestampEqualsPrevious { .. } => INVALID_INPUT,
-            ProviderError::TransactionFailed(_) => INVALID_INPUT,
-            ProviderError::TransactionCreationError(_) => INVALID_INPUT,
-            ProviderError::TryFromIntError(_) => INVALID_INPUT,
-            ProviderError::Unimplemented(_) => INVALID_INPUT,
-            ProviderError::UnknownAddress { .. } => INVALID_INPUT,
-            ProviderError::UnmetHardfork { .. } => INVALID_PARAMS,
-            ProviderError::UnsupportedAccessListParameter { .. } => INVALID_PARAMS,
-            ProviderError::UnsupportedEIP1559Parameters { .. } => INVALID_PARAMS,
-            ProviderError::UnsupportedMethod { .. } => -32004,
-        };
+        match self {
+            ProviderError::AccountOverrideConversionError(_) => error_code::INVALID_INPUT,
+            ProviderError::AutoMineGasPriceTooLow { .. } => error_code::INVALID_INPUT,
+            ProviderError::AutoMineMaxFeeTooLow { .. } => error_code::INVALID_INPUT,
+            ProviderError::AutoMineNonceTooHigh { .. } => error_code::INVALID_INPUT,
+            ProviderError::AutoMineNonceTooLow { .. } => error_code::INVALID_INPUT,
+            ProviderError::AutoMinePriorityFeeTooLow { .. } => error_code::INVALID_INPUT,
+            ProviderError::Blockchain(_) => error_code::INVALID_INPUT,
+            ProviderError::CallWithoutCode { .. } => error_code::INVALID_INPUT,
+            ProviderError::Creation(_) => error_code::INVALID_INPUT,
+            ProviderError::DebugTrace(_) => error_code::INTERNAL_ERROR,
+            ProviderError::Eip4844TransactionUnsupported => error_code::INVALID_INPUT,
+            ProviderError::Eip7702TransactionUnsupported => error_code::INVALID_INPUT,
+            ProviderError::Eip712Error(_) => error_code::INVALID_INPUT,
+            ProviderError::EstimateGasTransactionFailure(_) => error_code::INVALID_INPUT,
+            ProviderError::GasPriceBelowMinimum { .. } => error_code::INVALID_INPUT,
+            ProviderError::InvalidArgument(_) => error_code::INVALID_PARAMS,
+            ProviderError::InvalidBlockNumberOrHash { .. } => error_code::INVALID_INPUT,
+            ProviderError::InvalidBlockTag { .. } => error_code::INVALID_PARAMS,
+            ProviderError::InvalidChainId { .. } => error_code::INVALID_PARAMS,
+            ProviderError::InvalidDropTransactionHash(_) => error_code::INVALID_PARAMS,
+            ProviderError::InvalidEip155TransactionChainId => error_code::INVALID_PARAMS,
+            ProviderError::InvalidFilterSubscriptionType { .. } => error_code::INVALID_PARAMS,
+            ProviderError::InvalidInput(_) => error_code::INVALID_INPUT,
+            ProviderError::InvalidTransactionHash { .. } => error_code::INVALID_PARAMS,
+            ProviderError::InvalidTransactionIndex(_) => error_code::INVALID_PARAMS,
+            ProviderError::InvalidTransactionInput(_) => error_code::INVALID_INPUT,
+            ProviderError::InvalidTransactionType(_) => error_code::INVALID_PARAMS,
+            ProviderError::Logger(_) => error_code::INTERNAL_ERROR,
+            ProviderError::MemPoolAddTransaction(_) => error_code::INVALID_INPUT,
+            ProviderError::MemPoolUpdate(_) => error_code::INVALID_INPUT,
+            ProviderError::MineBlock(_) => error_code::INVALID_INPUT,
+            ProviderError::RollbackBeforeForkBlock { .. } => error_code::INVALID_INPUT,
+            ProviderError::RollbackToFutureBlock { .. } => error_code::INVALID_INPUT,
+            ProviderError::RpcClientError(_) => error_code::INTERNAL_ERROR,
+            ProviderError::RpcVersion(_) => error_code::INVALID_INPUT,
+            ProviderError::RunTransaction(_) => error_code::INVALID_INPUT,
+            ProviderError::Serialization(_) => error_code::INVALID_INPUT,
+            ProviderError::SetAccountNonceLowerThanCurrent { .. } => error_code::INVALID_INPUT,
+            ProviderError::SetAccountNonceWithPendingTransactions => error_code::INTERNAL_ERROR,
+            ProviderError::SetMinGasPriceUnsupported => error_code::INVALID_INPUT,
+            ProviderError::SetNextBlockBaseFeePerGasUnsupported { .. } => error_code::INVALID_INPUT,
+            ProviderError::SetNextBlockDifficultyUnsupported { .. } => error_code::INVALID_INPUT,
+            ProviderError::SetNextPrevRandaoUnsupported { .. } => error_code::INVALID_INPUT,
+            ProviderError::Signature(_) => error_code::INVALID_INPUT,
+            ProviderError::State(_) => error_code::INVALID_INPUT,
+            ProviderError::SystemTime(_) => error_code::INVALID_INPUT,
+            ProviderError::TimestampLowerThanPrevious { .. } => error_code::INVALID_INPUT,
+            ProviderError::TimestampEqualsPrevious { .. } => error_code::INVALID_INPUT,
+            ProviderError::TransactionFailed(_) => error_code::INVALID_INPUT,
+            ProviderError::TransactionCreationError(_) => error_code::INVALID_INPUT,
+            ProviderError::TryFromIntError(_) => error_code::INVALID_INPUT,
+            ProviderError::Unimplemented(_) => error_code::INVALID_INPUT,
+            ProviderError::UnknownAddress { .. } => error_code::INVALID_INPUT,
+            ProviderError::UnmetHardfork { .. } => error_code::INVALID_PARAMS,
+            ProviderError::UnsupportedAccessListParameter { .. } => error_code::INVALID_PARAMS,
+            ProviderError::UnsupportedEIP1559Parameters { .. } => error_code::INVALID_PARAMS,
+            ProviderError::UnsupportedMethod { .. } => error_code::METHOD_NOT_SUPPORTED,
+            ProviderError::ZeroGasPriceNotAllowed { .. } => error_code::INVALID_INPUT,
+        }
+    }
+}
+
+impl<LoggerErrorT> From<BlockTransactionError<BlockchainError, StateError>>
+    for ProviderError<LoggerErrorT>
+{
+    fn from(value: BlockTransactionError<BlockchainError, StateError>) -> Self {
+        ProviderError::MineBlock(value.into())
+    }
+}
+
+impl<LoggerErrorT: Debug> From<ProviderError<LoggerErrorT>> for jsonrpc::Error {
+    fn from(value: ProviderError<LoggerErrorT>) -> Self {
+        let code = value.error_code();
 
         let data = match &value {
             ProviderError::EstimateGasTransactionFailure(EstimateGasFailure {
@@ -390,41 +457,36 @@ pub enum TransactionFailureReason {
     Revert(Bytes),
 }
 
+/// The revert reason returned for reverts without revert data, e.g. caused by
+/// a `require(condition)` without a message.
+pub const REVERT_WITHOUT_REASON: &str = "Transaction reverted without a reason";
+
+/// Decodes the revert reason of the provided execution result.
+///
+/// Returns [`REVERT_WITHOUT_REASON`] if the execution reverted without revert
+/// data and `None` if the execution did not revert.
+pub fn decode_revert_reason(execution_result: &ExecutionResult) -> Option<String> {
+    revert_reason(execution_result).map(|reason| revert_reason_message(&reason))
+}
+
 fn revert_error(output: &Bytes) -> String {
-    if output.is_empty() {
-        return "Transaction reverted without a reason".to_string();
-    }
+    revert_reason_message(&RevertReason::decode(output))
+}
 
-    match alloy_sol_types::GenericContractError::abi_decode(
-        output.as_ref(),
-        /* validate */ false,
-    ) {
-        Ok(contract_error) => {
-            match contract_error {
-                ContractError::CustomError(custom_error) => {
-                    format!("VM Exception while processing transaction: reverted with an unrecognized custom error (return data: {custom_error})")
-                }
-                ContractError::Revert(revert) => {
-                    format!("reverted with reason string '{}'", revert.reason())
-                }
-                ContractError::Panic(panic) => {
-                    format!(
-                        "VM Exception while processing transaction: reverted with panic code {} ({})",
-                        serde_json::to_string(&panic.code).unwrap().replace('\"', ""),
-                        panic_code_to_error_reason(panic.code.try_into().expect("panic code fits into u64"))
-                    )
-                }
-            }
+fn revert_reason_message(reason: &RevertReason) -> String {
+    match reason {
+        RevertReason::Error(reason) => format!("reverted with reason string '{reason}'"),
+        RevertReason::Panic(code) => {
+            format!(
+                "VM Exception while processing transaction: reverted with panic code {} ({})",
+                serde_json::to_string(code).unwrap().replace('\"', ""),
+                u64::try_from(*code).map_or("Unknown panic code", panic_code_to_error_reason)
+            )
+        }
+        RevertReason::Raw(output) if output.is_empty() => REVERT_WITHOUT_REASON.to_string(),
+        RevertReason::Raw(output) => {
+            format!("VM Exception while processing transaction: reverted with an unrecognized custom error (return data: 0x{})", hex::encode(output))
         }
-        Err(decode_error) => match decode_error {
-            alloy_sol_types::Error::TypeCheckFail { .. }
-            | alloy_sol_types::Error::UnknownSelector { .. } => {
-                format!("VM Exception while processing transaction: reverted with an unrecognized custom error (return data: 0x{})", hex::encode(output))
-            }
-            _ => format!(
-                "Internal: Since we are not validating, this error should not occur: {decode_error:?}"
-            ),
-        },
     }
 }
 
@@ -442,3 +504,110 @@ fn panic_code_to_error_reason(error_code: u64) -> &'static str {
         _ => "Unknown panic code",
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::convert::Infallible;
+
+    use edr_eth::{remote::eth::CallRequest, Address, Bytes, U256};
+    use edr_evm::state::StateOverrides;
+
+    use super::*;
+    use crate::{data::test_utils::ProviderTestFixture, requests::eth::resolve_call_request};
+
+    #[test]
+    fn decode_revert_reason_without_message() -> anyhow::Result<()> {
+        // PUSH1 0x00 PUSH1 0x00 REVERT
+        const REVERT_WITHOUT_DATA_CODE: [u8; 5] = [0x60, 0x00, 0x60, 0x00, 0xfd];
+
+        let mut fixture = ProviderTestFixture::new_local()?;
+
+        let contract_address = Address::random();
+        fixture.provider_data.set_code(
+            contract_address,
+            Bytes::from_static(&REVERT_WITHOUT_DATA_CODE),
+        )?;
+
+        let state_overrides = StateOverrides::default();
+        let transaction = resolve_call_request(
+            &mut fixture.provider_data,
+            CallRequest {
+                from: Some(fixture.nth_local_account(0)?),
+                to: Some(contract_address),
+                value: Some(U256::ZERO),
+                ..CallRequest::default()
+            },
+            None,
+            &state_overrides,
+        )?;
+
+        let result = fixture
+            .provider_data
+            .run_call(transaction, None, &state_overrides)?;
+
+        assert!(matches!(
+            result.execution_result,
+            ExecutionResult::Revert { ref output, .. } if output.is_empty()
+        ));
+        assert_eq!(
+            decode_revert_reason(&result.execution_result),
+            Some(REVERT_WITHOUT_REASON.to_string())
+        );
+        assert_eq!(
+            TransactionFailure::revert(
+                result.execution_result.into_output().unwrap_or_default(),
+                None,
+                result.trace
+            )
+            .to_string(),
+            REVERT_WITHOUT_REASON
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn internal_errors_map_to_json_rpc_error_codes() {
+        let cases: Vec<(ProviderError<Infallible>, i16)> = vec![
+            (
+                TransactionError::<BlockchainError, StateError>::Eip1559Unsupported.into(),
+                error_code::INVALID_INPUT,
+            ),
+            (
+                BlockTransactionError::<BlockchainError, StateError>::ExceedsBlockGasLimit.into(),
+                error_code::INVALID_INPUT,
+            ),
+            (
+                MineBlockError::<BlockchainError, StateError>::MissingPrevrandao.into(),
+                error_code::INVALID_INPUT,
+            ),
+            (StateError::CannotRevert.into(), error_code::INVALID_INPUT),
+            (
+                BlockchainError::UnknownBlockNumber.into(),
+                error_code::INVALID_INPUT,
+            ),
+            (
+                ProviderError::InvalidArgument(String::from("invalid")),
+                error_code::INVALID_PARAMS,
+            ),
+            (
+                ProviderError::SetAccountNonceWithPendingTransactions,
+                error_code::INTERNAL_ERROR,
+            ),
+            (
+                ProviderError::UnsupportedMethod {
+                    method_name: String::from("eth_foo"),
+                },
+                error_code::METHOD_NOT_SUPPORTED,
+            ),
+        ];
+
+        for (error, expected_code) in cases {
+            let message = error.to_string();
+            let json_rpc_error = jsonrpc::Error::from(error);
+
+            assert_eq!(json_rpc_error.code, expected_code);
+            assert_eq!(json_rpc_error.message, message);
+        }
+    }
+}
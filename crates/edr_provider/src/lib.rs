@@ -10,6 +10,7 @@ mod logger;
 mod mock;
 mod pending;
 mod requests;
+mod signer;
 mod snapshot;
 mod subscribe;
 /// Utilities for testing
@@ -19,7 +20,13 @@ pub mod test_utils;
 use core::fmt::Debug;
 use std::sync::Arc;
 
-use edr_evm::{blockchain::BlockchainError, trace::Trace, HashSet};
+use edr_eth::{access_list::AccessListItem, remote::jsonrpc};
+use edr_evm::{
+    blockchain::BlockchainError,
+    time::{SystemTimeSource, TimeSource},
+    trace::Trace,
+    HashSet,
+};
 use lazy_static::lazy_static;
 use logger::SyncLogger;
 use mock::SyncCallOverride;
@@ -29,21 +36,25 @@ use tokio::{runtime, sync::Mutex as AsyncMutex, task};
 
 pub use self::{
     config::*,
-    data::CallResult,
+    data::{CallResult, PendingEffectiveGasPrice},
     debug_mine::DebugMineBlockResult,
-    error::{EstimateGasFailure, ProviderError, TransactionFailure, TransactionFailureReason},
+    error::{
+        decode_revert_reason, error_code, EstimateGasFailure, ProviderError, TransactionFailure,
+        TransactionFailureReason, REVERT_WITHOUT_REASON,
+    },
     logger::{Logger, NoopLogger},
     mock::CallOverrideResult,
     requests::{
         hardhat::rpc_types as hardhat_rpc_types, InvalidRequestReason, MethodInvocation,
         OneUsizeOrTwo, ProviderRequest, U64OrUsize,
     },
+    signer::{LocalSigner, Signer},
     subscribe::*,
 };
 use self::{
     data::{CreationError, ProviderData},
     interval::IntervalMiner,
-    requests::{debug, eth, hardhat},
+    requests::{debug, eth, hardhat, txpool},
 };
 
 lazy_static! {
@@ -61,6 +72,20 @@ lazy_static! {
 pub struct ResponseWithTraces {
     pub result: serde_json::Value,
     pub traces: Vec<Trace>,
+    /// The access list that an `eth_call` or `eth_estimateGas` request was
+    /// executed with, if it was generated. See
+    /// [`ProviderConfig::generate_access_lists_for_calls`].
+    pub access_list: Option<Vec<AccessListItem>>,
+}
+
+/// The responses to a JSON-RPC batch request.
+#[derive(Clone, Debug)]
+pub struct BatchResponseWithTraces {
+    /// The responses, in the order of their requests. Notifications - i.e.
+    /// requests without an id - don't receive a response.
+    pub responses: Vec<jsonrpc::Response<serde_json::Value>>,
+    /// The traces of all requests in the batch.
+    pub traces: Vec<Trace>,
 }
 
 /// A JSON-RPC provider for Ethereum.
@@ -109,12 +134,31 @@ impl<LoggerErrorT: Debug + Send + Sync + 'static> Provider<LoggerErrorT> {
         logger: Box<dyn SyncLogger<BlockchainError = BlockchainError, LoggerError = LoggerErrorT>>,
         subscriber_callback: Box<dyn SyncSubscriberCallback>,
         config: ProviderConfig,
+    ) -> Result<Self, CreationError> {
+        Self::with_time_source(
+            runtime,
+            logger,
+            subscriber_callback,
+            config,
+            Arc::new(SystemTimeSource),
+        )
+    }
+
+    /// Constructs a new instance that derives block timestamps from the
+    /// provided time source, instead of the system's clock.
+    pub fn with_time_source(
+        runtime: runtime::Handle,
+        logger: Box<dyn SyncLogger<BlockchainError = BlockchainError, LoggerError = LoggerErrorT>>,
+        subscriber_callback: Box<dyn SyncSubscriberCallback>,
+        config: ProviderConfig,
+        time_source: Arc<dyn TimeSource>,
     ) -> Result<Self, CreationError> {
         let data = ProviderData::new(
             runtime.clone(),
             logger,
             subscriber_callback,
             None,
+            time_source,
             config.clone(),
         )?;
         let data = Arc::new(AsyncMutex::new(data));
@@ -152,6 +196,90 @@ impl<LoggerErrorT: Debug + Send + Sync + 'static> Provider<LoggerErrorT> {
         }
     }
 
+    /// Blocking method to handle a JSON-RPC batch request.
+    ///
+    /// The requests are executed sequentially and in order, without releasing
+    /// the provider's state in between. A request that fails results in an
+    /// error response, but doesn't prevent subsequent requests from being
+    /// executed. Invalid entries without a usable id receive an error response
+    /// with a `null` id. Only returns an error if the batch is not a JSON
+    /// array.
+    pub fn handle_batch_json_request(
+        &self,
+        json_batch: &str,
+    ) -> Result<BatchResponseWithTraces, serde_json::Error> {
+        let requests: Vec<serde_json::Value> = serde_json::from_str(json_batch)?;
+
+        let mut data = task::block_in_place(|| self.runtime.block_on(self.data.lock()));
+
+        let mut responses = Vec::with_capacity(requests.len());
+        let mut traces = Vec::new();
+
+        for request in requests {
+            // Requests without an id are notifications. An id that cannot be
+            // deserialized is reported as `null`.
+            let id = request.get("id").map(|id| {
+                serde_json::from_value::<jsonrpc::Id>(id.clone()).unwrap_or(jsonrpc::Id::Null)
+            });
+
+            // Deserialize from a string to match the error messages of single requests
+            let json_request = request.to_string();
+            let response_data = match serde_json::from_str::<MethodInvocation>(&json_request) {
+                Ok(method_invocation) => {
+                    let mut response = self.handle_single_request(&mut data, method_invocation);
+
+                    match &mut response {
+                        Ok(response) => traces.append(&mut response.traces),
+                        Err(ProviderError::TransactionFailed(failure)) => {
+                            traces.append(&mut failure.traces);
+                        }
+                        Err(_) => (),
+                    }
+
+                    jsonrpc::ResponseData::from(response.map(|response| response.result))
+                }
+                Err(error) => {
+                    let message = error.to_string();
+                    let reason = InvalidRequestReason::new(&json_request, &message);
+
+                    if let Some((method_name, provider_error)) =
+                        reason.provider_error::<LoggerErrorT>()
+                    {
+                        // Ignore potential failure of logging, as returning the original error is
+                        // more important
+                        let _result = data
+                            .logger_mut()
+                            .print_method_logs(&method_name, Some(&provider_error));
+                    }
+
+                    let response_data = jsonrpc::ResponseData::new_error(
+                        reason.error_code(),
+                        &reason.error_message(),
+                        Some(request),
+                    );
+
+                    // Invalid requests always receive a response, even if their id is missing
+                    responses.push(jsonrpc::Response {
+                        jsonrpc: jsonrpc::Version::V2_0,
+                        id: id.unwrap_or(jsonrpc::Id::Null),
+                        data: response_data,
+                    });
+                    continue;
+                }
+            };
+
+            if let Some(id) = id {
+                responses.push(jsonrpc::Response {
+                    jsonrpc: jsonrpc::Version::V2_0,
+                    id,
+                    data: response_data,
+                });
+            }
+        }
+
+        Ok(BatchResponseWithTraces { responses, traces })
+    }
+
     /// Blocking method to log a failed deserialization.
     pub fn log_failed_deserialization(
         &self,
@@ -180,7 +308,11 @@ impl<LoggerErrorT: Debug + Send + Sync + 'static> Provider<LoggerErrorT> {
         }
 
         let result = serde_json::to_value(results).map_err(ProviderError::Serialization)?;
-        Ok(ResponseWithTraces { result, traces })
+        Ok(ResponseWithTraces {
+            result,
+            traces,
+            access_list: None,
+        })
     }
 
     fn handle_single_request(
@@ -207,13 +339,16 @@ impl<LoggerErrorT: Debug + Send + Sync + 'static> Provider<LoggerErrorT> {
             }
             MethodInvocation::Call(request, block_spec, state_overrides) => {
                 eth::handle_call_request(data, request, block_spec, state_overrides)
-                    .and_then(to_json_with_trace)
+                    .and_then(to_json_with_trace_and_access_list)
             }
             MethodInvocation::ChainId(()) => eth::handle_chain_id_request(data).and_then(to_json),
             MethodInvocation::Coinbase(()) => eth::handle_coinbase_request(data).and_then(to_json),
+            MethodInvocation::CreateAccessList(request, block_spec) => {
+                eth::handle_create_access_list_request(data, request, block_spec).and_then(to_json)
+            }
             MethodInvocation::EstimateGas(call_request, block_spec) => {
                 eth::handle_estimate_gas(data, call_request, block_spec)
-                    .and_then(to_json_with_traces)
+                    .and_then(to_json_with_traces_and_access_list)
             }
             MethodInvocation::FeeHistory(block_count, newest_block, reward_percentiles) => {
                 eth::handle_fee_history(data, block_count, newest_block, reward_percentiles)
@@ -231,6 +366,9 @@ impl<LoggerErrorT: Debug + Send + Sync + 'static> Provider<LoggerErrorT> {
                 eth::handle_get_block_by_hash_request(data, block_hash, transaction_detail_flag)
                     .and_then(to_json)
             }
+            MethodInvocation::GetBlockReceipts(block_spec) => {
+                eth::handle_get_block_receipts_request(data, block_spec).and_then(to_json)
+            }
             MethodInvocation::GetBlockTransactionCountByHash(block_hash) => {
                 eth::handle_get_block_transaction_count_by_hash_request(data, block_hash)
                     .and_then(to_json)
@@ -251,6 +389,10 @@ impl<LoggerErrorT: Debug + Send + Sync + 'static> Provider<LoggerErrorT> {
             MethodInvocation::GetLogs(filter_options) => {
                 eth::handle_get_logs_request(data, filter_options).and_then(to_json)
             }
+            MethodInvocation::GetProof(address, storage_keys, block_spec) => {
+                eth::handle_get_proof_request(data, address, storage_keys, block_spec)
+                    .and_then(to_json)
+            }
             MethodInvocation::GetStorageAt(address, index, block_spec) => {
                 eth::handle_get_storage_at_request(data, address, index, block_spec)
                     .and_then(to_json)
@@ -355,6 +497,9 @@ impl<LoggerErrorT: Debug + Send + Sync + 'static> Provider<LoggerErrorT> {
             MethodInvocation::EvmSetNextBlockTimestamp(timestamp) => {
                 eth::handle_set_next_block_timestamp_request(data, timestamp).and_then(to_json)
             }
+            MethodInvocation::EvmSetTime(timestamp) => {
+                eth::handle_set_time_request(data, timestamp).and_then(to_json)
+            }
             MethodInvocation::EvmSnapshot(()) => {
                 eth::handle_snapshot_request(data).and_then(to_json)
             }
@@ -369,16 +514,36 @@ impl<LoggerErrorT: Debug + Send + Sync + 'static> Provider<LoggerErrorT> {
                     .and_then(to_json)
             }
 
+            // txpool_* methods
+            MethodInvocation::TxpoolContent(()) => {
+                txpool::handle_txpool_content(data).and_then(to_json)
+            }
+            MethodInvocation::TxpoolStatus(()) => {
+                txpool::handle_txpool_status(data).and_then(to_json)
+            }
+
             // hardhat_* methods
             MethodInvocation::AddCompilationResult(_, _, _) => Err(ProviderError::Unimplemented(
                 "AddCompilationResult".to_string(),
             )),
+            MethodInvocation::BatchQueryState(queries) => {
+                hardhat::handle_batch_query_state_request(data, queries).and_then(to_json)
+            }
             MethodInvocation::DropTransaction(transaction_hash) => {
                 hardhat::handle_drop_transaction(data, transaction_hash).and_then(to_json)
             }
+            MethodInvocation::GasFees(()) => {
+                hardhat::handle_gas_fees_request(data).and_then(to_json)
+            }
+            MethodInvocation::GetAccounts(addresses, block_spec) => {
+                hardhat::handle_get_accounts_request(data, addresses, block_spec).and_then(to_json)
+            }
             MethodInvocation::GetAutomine(()) => {
                 hardhat::handle_get_automine_request(data).and_then(to_json)
             }
+            MethodInvocation::GetCodeHash(address, block_spec) => {
+                hardhat::handle_get_code_hash_request(data, address, block_spec).and_then(to_json)
+            }
             MethodInvocation::GetStackTraceFailuresCount(()) => Err(ProviderError::Unimplemented(
                 "GetStackTraceFailuresCount".to_string(),
             )),
@@ -396,9 +561,18 @@ impl<LoggerErrorT: Debug + Send + Sync + 'static> Provider<LoggerErrorT> {
                 hardhat::handle_mine(data, number_of_blocks, interval).and_then(to_json_with_traces)
             }
             MethodInvocation::Reset(config) => self.reset(data, config).and_then(to_json),
+            MethodInvocation::Rollback(block_number) => {
+                hardhat::handle_rollback_request(data, block_number).and_then(to_json)
+            }
+            MethodInvocation::SafeBlockNumber(()) => {
+                hardhat::handle_safe_block_number_request(data).and_then(to_json)
+            }
             MethodInvocation::SetBalance(address, balance) => {
                 hardhat::handle_set_balance(data, address, balance).and_then(to_json)
             }
+            MethodInvocation::SetBlockGasLimit(gas_limit) => {
+                hardhat::handle_set_block_gas_limit_request(data, gas_limit).and_then(to_json)
+            }
             MethodInvocation::SetCode(address, code) => {
                 hardhat::handle_set_code(data, address, code).and_then(to_json)
             }
@@ -415,6 +589,10 @@ impl<LoggerErrorT: Debug + Send + Sync + 'static> Provider<LoggerErrorT> {
                 hardhat::handle_set_next_block_base_fee_per_gas_request(data, base_fee_per_gas)
                     .and_then(to_json)
             }
+            MethodInvocation::SetNextBlockDifficulty(difficulty) => {
+                hardhat::handle_set_next_block_difficulty_request(data, difficulty)
+                    .and_then(to_json)
+            }
             MethodInvocation::SetNonce(address, nonce) => {
                 hardhat::handle_set_nonce(data, address, nonce).and_then(to_json)
             }
@@ -424,6 +602,9 @@ impl<LoggerErrorT: Debug + Send + Sync + 'static> Provider<LoggerErrorT> {
             MethodInvocation::SetStorageAt(address, index, value) => {
                 hardhat::handle_set_storage_at(data, address, index, value).and_then(to_json)
             }
+            MethodInvocation::SetStorageBulk(address, entries) => {
+                hardhat::handle_set_storage_bulk(data, address, entries).and_then(to_json)
+            }
             MethodInvocation::StopImpersonatingAccount(address) => {
                 hardhat::handle_stop_impersonating_account_request(data, *address).and_then(to_json)
             }
@@ -467,27 +648,42 @@ fn to_json<T: serde::Serialize, LoggerErrorT: Debug>(
     Ok(ResponseWithTraces {
         result: response,
         traces: Vec::new(),
+        access_list: None,
     })
 }
 
-fn to_json_with_trace<T: serde::Serialize, LoggerErrorT: Debug>(
-    value: (T, Trace),
+fn to_json_with_traces<T: serde::Serialize, LoggerErrorT: Debug>(
+    value: (T, Vec<Trace>),
+) -> Result<ResponseWithTraces, ProviderError<LoggerErrorT>> {
+    let response = serde_json::to_value(value.0).map_err(ProviderError::Serialization)?;
+
+    Ok(ResponseWithTraces {
+        result: response,
+        traces: value.1,
+        access_list: None,
+    })
+}
+
+fn to_json_with_trace_and_access_list<T: serde::Serialize, LoggerErrorT: Debug>(
+    value: (T, Trace, Option<Vec<AccessListItem>>),
 ) -> Result<ResponseWithTraces, ProviderError<LoggerErrorT>> {
     let response = serde_json::to_value(value.0).map_err(ProviderError::Serialization)?;
 
     Ok(ResponseWithTraces {
         result: response,
         traces: vec![value.1],
+        access_list: value.2,
     })
 }
 
-fn to_json_with_traces<T: serde::Serialize, LoggerErrorT: Debug>(
-    value: (T, Vec<Trace>),
+fn to_json_with_traces_and_access_list<T: serde::Serialize, LoggerErrorT: Debug>(
+    value: (T, Vec<Trace>, Option<Vec<AccessListItem>>),
 ) -> Result<ResponseWithTraces, ProviderError<LoggerErrorT>> {
     let response = serde_json::to_value(value.0).map_err(ProviderError::Serialization)?;
 
     Ok(ResponseWithTraces {
         result: response,
         traces: value.1,
+        access_list: value.2,
     })
 }
@@ -204,6 +204,10 @@ impl<'blockchain> BlockchainMut for BlockchainWithPending<'blockchain> {
     fn revert_to_block(&mut self, _block_number: u64) -> Result<(), Self::Error> {
         panic!("Reverting blocks in a pending blockchain is not supported.");
     }
+
+    fn prune_receipts(&mut self, _block_number: u64) -> Result<(), Self::Error> {
+        panic!("Pruning receipts in a pending blockchain is not supported.");
+    }
 }
 
 impl<'blockchain> BlockHashRef for BlockchainWithPending<'blockchain> {
@@ -5,6 +5,8 @@ pub mod eth;
 pub mod hardhat;
 mod methods;
 mod serde;
+/// Transaction pool RPC request types
+pub mod txpool;
 mod validation;
 
 use std::fmt;
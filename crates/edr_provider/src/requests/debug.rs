@@ -115,3 +115,95 @@ impl From<DebugTraceConfig> for edr_evm::DebugTraceConfig {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use edr_eth::{Address, Bytes};
+
+    use super::*;
+    use crate::data::test_utils::ProviderTestFixture;
+
+    /// Mines a transaction that runs `PUSH1 0x01 PUSH1 0x02 ADD POP STOP`
+    /// and returns its hash.
+    fn mine_add_transaction(fixture: &mut ProviderTestFixture) -> anyhow::Result<B256> {
+        const ADD_CODE: [u8; 7] = [0x60, 0x01, 0x60, 0x02, 0x01, 0x50, 0x00];
+
+        // Dummy transactions call `Address::ZERO`
+        fixture
+            .provider_data
+            .set_code(Address::ZERO, Bytes::from_static(&ADD_CODE))?;
+
+        let transaction = fixture.signed_dummy_transaction(0, None)?;
+        let result = fixture.provider_data.send_transaction(transaction)?;
+
+        Ok(result.transaction_hash)
+    }
+
+    #[test]
+    fn debug_trace_transaction_struct_logs() -> anyhow::Result<()> {
+        let mut fixture = ProviderTestFixture::new_local()?;
+        let transaction_hash = mine_add_transaction(&mut fixture)?;
+
+        let result =
+            handle_debug_trace_transaction(&mut fixture.provider_data, transaction_hash, None)?;
+        assert!(result.pass);
+
+        let op_names: Vec<&str> = result.logs.iter().map(|log| log.op_name.as_str()).collect();
+        assert_eq!(op_names, vec!["PUSH1", "PUSH1", "ADD", "POP", "STOP"]);
+
+        let add = &result.logs[2];
+        assert_eq!(add.pc, 4);
+        assert_eq!(add.depth, 1);
+        assert_eq!(add.gas_cost, "0x3");
+
+        let stack = add.stack.as_ref().expect("stack is enabled by default");
+        assert_eq!(stack.len(), 2);
+
+        let pop = &result.logs[3];
+        let stack = pop.stack.as_ref().expect("stack is enabled by default");
+        assert_eq!(stack.len(), 1);
+        assert!(stack[0].ends_with('3'));
+
+        assert!(add.memory.is_some());
+        assert!(add.storage.is_some());
+
+        Ok(())
+    }
+
+    #[test]
+    fn debug_trace_transaction_disabled_fields() -> anyhow::Result<()> {
+        let mut fixture = ProviderTestFixture::new_local()?;
+        let transaction_hash = mine_add_transaction(&mut fixture)?;
+
+        let result = handle_debug_trace_transaction(
+            &mut fixture.provider_data,
+            transaction_hash,
+            Some(DebugTraceConfig {
+                tracer: None,
+                disable_storage: Some(true),
+                disable_memory: Some(true),
+                disable_stack: Some(true),
+            }),
+        )?;
+
+        assert_eq!(result.logs.len(), 5);
+        for log in &result.logs {
+            assert!(log.stack.is_none());
+            assert!(log.memory.is_none());
+            assert!(log.storage.is_none());
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn debug_trace_unknown_transaction() -> anyhow::Result<()> {
+        let mut fixture = ProviderTestFixture::new_local()?;
+
+        let result =
+            handle_debug_trace_transaction(&mut fixture.provider_data, B256::repeat_byte(1), None);
+        assert!(matches!(result, Err(ProviderError::InvalidInput(_))));
+
+        Ok(())
+    }
+}
@@ -2,6 +2,7 @@ use core::fmt::Debug;
 use std::sync::Arc;
 
 use edr_eth::{
+    receipt::BlockReceipt,
     remote::{eth, BlockSpec, PreEip1898BlockSpec},
     SpecId, B256, U256, U64,
 };
@@ -9,7 +10,10 @@ use edr_evm::{blockchain::BlockchainError, SyncBlock};
 
 use crate::{
     data::{BlockDataForTransaction, ProviderData, TransactionAndBlock},
-    requests::{eth::transaction_to_rpc_result, validation::validate_post_merge_block_tags},
+    requests::{
+        eth::{receipt_to_rpc_output, transaction_to_rpc_result},
+        validation::validate_post_merge_block_tags,
+    },
     ProviderError,
 };
 
@@ -64,6 +68,24 @@ pub fn handle_get_block_by_number_request<LoggerErrorT: Debug>(
         .transpose()
 }
 
+pub fn handle_get_block_receipts_request<LoggerErrorT: Debug>(
+    data: &mut ProviderData<LoggerErrorT>,
+    block_spec: BlockSpec,
+) -> Result<Option<Vec<Arc<BlockReceipt>>>, ProviderError<LoggerErrorT>> {
+    block_by_number(data, &block_spec)?
+        .map(|BlockByNumberResult { block, .. }| {
+            let spec_id = data.spec_id();
+            let receipts = block
+                .transaction_receipts()?
+                .into_iter()
+                .map(|receipt| receipt_to_rpc_output(spec_id, receipt))
+                .collect();
+
+            Ok(receipts)
+        })
+        .transpose()
+}
+
 pub fn handle_get_block_transaction_count_by_hash_request<LoggerErrorT: Debug>(
     data: &ProviderData<LoggerErrorT>,
     block_hash: B256,
@@ -161,12 +183,13 @@ fn block_to_rpc_output<LoggerErrorT: Debug>(
             .collect()
     };
 
+    let hash = if pending { None } else { Some(*block.hash()) };
     let mix_hash = if pending { None } else { Some(header.mix_hash) };
     let nonce = if pending { None } else { Some(header.nonce) };
     let number = if pending { None } else { Some(header.number) };
 
     Ok(eth::Block {
-        hash: Some(*block.hash()),
+        hash,
         parent_hash: header.parent_hash,
         sha3_uncles: header.ommers_hash,
         state_root: header.state_root,
@@ -196,3 +219,288 @@ fn block_to_rpc_output<LoggerErrorT: Debug>(
         parent_beacon_block_root: header.parent_beacon_block_root,
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use edr_eth::{
+        block::BlockOptions,
+        remote::{BlockTag, Eip1898BlockSpec},
+    };
+
+    use super::*;
+    use crate::{
+        data::test_utils::ProviderTestFixture, test_utils::create_test_config, ProviderConfig,
+    };
+
+    /// Mines a block containing two dummy transactions and returns the block
+    /// and the hashes of its transactions.
+    fn mine_block_with_transactions(
+        fixture: &mut ProviderTestFixture,
+    ) -> anyhow::Result<(Arc<dyn SyncBlock<Error = BlockchainError>>, Vec<B256>)> {
+        fixture.provider_data.set_auto_mining(false);
+
+        let mut transaction_hashes = Vec::new();
+        for nonce in 0..2 {
+            let transaction = fixture.signed_dummy_transaction(0, Some(nonce))?;
+            transaction_hashes.push(*transaction.hash());
+            fixture.provider_data.send_transaction(transaction)?;
+        }
+
+        let result = fixture
+            .provider_data
+            .mine_and_commit_block(BlockOptions::default())?;
+
+        Ok((result.block, transaction_hashes))
+    }
+
+    #[test]
+    fn get_block_receipts_of_empty_block() -> anyhow::Result<()> {
+        let mut fixture = ProviderTestFixture::new_local()?;
+
+        let receipts =
+            handle_get_block_receipts_request(&mut fixture.provider_data, BlockSpec::latest())?;
+        assert_eq!(receipts, Some(Vec::new()));
+
+        Ok(())
+    }
+
+    #[test]
+    fn get_block_receipts_of_mined_block() -> anyhow::Result<()> {
+        let mut fixture = ProviderTestFixture::new_local()?;
+        fixture.provider_data.set_auto_mining(false);
+
+        let mut transaction_hashes = Vec::new();
+        for nonce in 0..2 {
+            let transaction = fixture.signed_dummy_transaction(0, Some(nonce))?;
+            transaction_hashes.push(*transaction.hash());
+            fixture.provider_data.send_transaction(transaction)?;
+        }
+
+        let pending_receipts =
+            handle_get_block_receipts_request(&mut fixture.provider_data, BlockSpec::pending())?
+                .expect("pending block should exist");
+        assert_eq!(pending_receipts.len(), 2);
+
+        let result = fixture
+            .provider_data
+            .mine_and_commit_block(BlockOptions::default())?;
+
+        let block_specs = [
+            BlockSpec::latest(),
+            BlockSpec::Number(result.block.header().number),
+            BlockSpec::Eip1898(Eip1898BlockSpec::Hash {
+                block_hash: *result.block.hash(),
+                require_canonical: None,
+            }),
+        ];
+
+        for block_spec in block_specs {
+            let receipts =
+                handle_get_block_receipts_request(&mut fixture.provider_data, block_spec)?
+                    .expect("block should exist");
+
+            let receipt_hashes: Vec<B256> = receipts
+                .iter()
+                .map(|receipt| receipt.transaction_hash)
+                .collect();
+            assert_eq!(receipt_hashes, transaction_hashes);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn get_block_receipts_of_unknown_block() -> anyhow::Result<()> {
+        let mut fixture = ProviderTestFixture::new_local()?;
+
+        let receipts =
+            handle_get_block_receipts_request(&mut fixture.provider_data, BlockSpec::Number(100))?;
+        assert_eq!(receipts, None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn get_block_by_number_transaction_detail_flag() -> anyhow::Result<()> {
+        let mut fixture = ProviderTestFixture::new_local()?;
+        let (block, transaction_hashes) = mine_block_with_transactions(&mut fixture)?;
+        let block_number = block.header().number;
+
+        let with_hashes = handle_get_block_by_number_request(
+            &mut fixture.provider_data,
+            PreEip1898BlockSpec::Number(block_number),
+            false,
+        )?
+        .expect("block should exist");
+
+        let expected_hashes: Vec<HashOrTransaction> = transaction_hashes
+            .iter()
+            .copied()
+            .map(HashOrTransaction::Hash)
+            .collect();
+        assert_eq!(with_hashes.transactions, expected_hashes);
+
+        let with_transactions = handle_get_block_by_number_request(
+            &mut fixture.provider_data,
+            PreEip1898BlockSpec::Number(block_number),
+            true,
+        )?
+        .expect("block should exist");
+
+        assert_eq!(
+            with_transactions.transactions.len(),
+            transaction_hashes.len()
+        );
+        for ((transaction, expected_hash), index) in with_transactions
+            .transactions
+            .iter()
+            .zip(transaction_hashes.iter())
+            .zip(0u64..)
+        {
+            let HashOrTransaction::Transaction(transaction) = transaction else {
+                panic!("expected a full transaction object");
+            };
+
+            assert_eq!(transaction.hash, *expected_hash);
+            assert_eq!(transaction.block_hash, Some(*block.hash()));
+            assert_eq!(transaction.block_number, Some(U256::from(block_number)));
+            assert_eq!(transaction.transaction_index, Some(index));
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn get_block_by_hash_transaction_detail_flag() -> anyhow::Result<()> {
+        let mut fixture = ProviderTestFixture::new_local()?;
+        let (block, transaction_hashes) = mine_block_with_transactions(&mut fixture)?;
+
+        let with_hashes =
+            handle_get_block_by_hash_request(&fixture.provider_data, *block.hash(), false)?
+                .expect("block should exist");
+
+        let expected_hashes: Vec<HashOrTransaction> = transaction_hashes
+            .iter()
+            .copied()
+            .map(HashOrTransaction::Hash)
+            .collect();
+        assert_eq!(with_hashes.transactions, expected_hashes);
+
+        let with_transactions =
+            handle_get_block_by_hash_request(&fixture.provider_data, *block.hash(), true)?
+                .expect("block should exist");
+
+        let hashes: Vec<B256> = with_transactions
+            .transactions
+            .iter()
+            .map(|transaction| match transaction {
+                HashOrTransaction::Transaction(transaction) => transaction.hash,
+                HashOrTransaction::Hash(_) => panic!("expected a full transaction object"),
+            })
+            .collect();
+        assert_eq!(hashes, transaction_hashes);
+
+        // Apart from the transactions, both formats are identical
+        assert_eq!(
+            eth::Block {
+                transactions: Vec::new(),
+                ..with_hashes
+            },
+            eth::Block {
+                transactions: Vec::new(),
+                ..with_transactions
+            }
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn get_block_by_hash_header_fields() -> anyhow::Result<()> {
+        let mut fixture = ProviderTestFixture::new_local()?;
+        let (block, _transaction_hashes) = mine_block_with_transactions(&mut fixture)?;
+
+        let output =
+            handle_get_block_by_hash_request(&fixture.provider_data, *block.hash(), false)?
+                .expect("block should exist");
+
+        let expected_total_difficulty = fixture
+            .provider_data
+            .total_difficulty_by_hash(block.hash())?;
+
+        assert_eq!(output.hash, Some(*block.hash()));
+        assert_eq!(output.number, Some(block.header().number));
+        assert_eq!(output.total_difficulty, expected_total_difficulty);
+        assert!(output.total_difficulty.is_some());
+        assert_eq!(output.size, block.rlp_size());
+        assert_eq!(output.base_fee_per_gas, block.header().base_fee_per_gas);
+        assert!(output.base_fee_per_gas.is_some());
+
+        Ok(())
+    }
+
+    #[test]
+    fn get_block_by_hash_pre_london_has_no_base_fee() -> anyhow::Result<()> {
+        let config = ProviderConfig {
+            hardfork: SpecId::BERLIN,
+            ..create_test_config()
+        };
+
+        let mut fixture = ProviderTestFixture::new(config)?;
+        let (block, _transaction_hashes) = mine_block_with_transactions(&mut fixture)?;
+
+        let output =
+            handle_get_block_by_hash_request(&fixture.provider_data, *block.hash(), false)?
+                .expect("block should exist");
+
+        assert_eq!(output.base_fee_per_gas, None);
+        assert_eq!(output.size, block.rlp_size());
+
+        Ok(())
+    }
+
+    #[test]
+    fn get_block_by_number_pending() -> anyhow::Result<()> {
+        let mut fixture = ProviderTestFixture::new_local()?;
+        fixture.provider_data.set_auto_mining(false);
+
+        let transaction = fixture.signed_dummy_transaction(0, None)?;
+        let transaction_hash = *transaction.hash();
+        fixture.provider_data.send_transaction(transaction)?;
+
+        let last_block = fixture.provider_data.last_block()?;
+        let last_block_number = last_block.header().number;
+        let previous_total_difficulty = fixture
+            .provider_data
+            .total_difficulty_by_hash(last_block.hash())?
+            .expect("last block has total difficulty");
+
+        let pending = handle_get_block_by_number_request(
+            &mut fixture.provider_data,
+            PreEip1898BlockSpec::Tag(BlockTag::Pending),
+            false,
+        )?
+        .expect("pending block should exist");
+
+        assert_eq!(pending.hash, None);
+        assert_eq!(pending.number, None);
+        assert_eq!(pending.nonce, None);
+        assert_eq!(pending.mix_hash, None);
+        assert_eq!(pending.parent_hash, *last_block.hash());
+        assert_eq!(
+            pending.total_difficulty,
+            Some(previous_total_difficulty + pending.difficulty)
+        );
+        assert!(pending.base_fee_per_gas.is_some());
+        assert_eq!(
+            pending.transactions,
+            vec![HashOrTransaction::Hash(transaction_hash)]
+        );
+
+        // The pending block is not mined
+        assert_eq!(fixture.provider_data.last_block_number(), last_block_number);
+        assert_eq!(fixture.provider_data.pending_transactions().count(), 1);
+
+        Ok(())
+    }
+}
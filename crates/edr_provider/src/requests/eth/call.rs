@@ -1,7 +1,11 @@
 use core::fmt::Debug;
 
 use edr_eth::{
-    remote::{eth::CallRequest, BlockSpec, StateOverrideOptions},
+    access_list::AccessListItem,
+    remote::{
+        eth::{AccessListResult, CallRequest},
+        BlockSpec, StateOverrideOptions,
+    },
     transaction::{
         Eip1559TransactionRequest, Eip155TransactionRequest, Eip2930TransactionRequest,
         TransactionRequest,
@@ -20,7 +24,7 @@ pub fn handle_call_request<LoggerErrorT: Debug>(
     request: CallRequest,
     block_spec: Option<BlockSpec>,
     state_overrides: Option<StateOverrideOptions>,
-) -> Result<(Bytes, Trace), ProviderError<LoggerErrorT>> {
+) -> Result<(Bytes, Trace, Option<Vec<AccessListItem>>), ProviderError<LoggerErrorT>> {
     validate_call_request(data.spec_id(), &request, &block_spec)?;
 
     let state_overrides =
@@ -48,7 +52,27 @@ pub fn handle_call_request<LoggerErrorT: Debug>(
     }
 
     let output = result.execution_result.into_output().unwrap_or_default();
-    Ok((output, result.trace))
+    Ok((output, result.trace, result.access_list))
+}
+
+pub fn handle_create_access_list_request<LoggerErrorT: Debug>(
+    data: &mut ProviderData<LoggerErrorT>,
+    request: CallRequest,
+    block_spec: Option<BlockSpec>,
+) -> Result<AccessListResult, ProviderError<LoggerErrorT>> {
+    if data.spec_id() < SpecId::BERLIN {
+        return Err(ProviderError::UnmetHardfork {
+            actual: data.spec_id(),
+            minimum: SpecId::BERLIN,
+        });
+    }
+
+    validate_call_request(data.spec_id(), &request, &block_spec)?;
+
+    let state_overrides = StateOverrides::default();
+    let transaction = resolve_call_request(data, request, block_spec.as_ref(), &state_overrides)?;
+
+    data.create_access_list(transaction, block_spec.as_ref(), &state_overrides)
 }
 
 pub(crate) fn resolve_call_request<LoggerErrorT: Debug>(
@@ -160,9 +184,41 @@ pub(crate) fn resolve_call_request_inner<LoggerErrorT: Debug>(
 
 #[cfg(test)]
 mod tests {
+    use edr_eth::{remote::AccountOverrideOptions, Address, HashMap, B256};
+    use edr_evm::hex;
+
     use super::*;
     use crate::{data::test_utils::ProviderTestFixture, test_utils::pending_base_fee};
 
+    // PUSH1 0 SLOAD PUSH1 0 MSTORE PUSH1 32 PUSH1 0 RETURN
+    const RETURN_SLOT_ZERO_CODE: &str = "60005460005260206000f3";
+
+    fn storage_override(value: u64) -> Option<HashMap<B256, U256>> {
+        Some([(B256::ZERO, U256::from(value))].into_iter().collect())
+    }
+
+    fn call_with_overrides(
+        fixture: &mut ProviderTestFixture,
+        contract: Address,
+        account_override: AccountOverrideOptions,
+    ) -> Result<Bytes, ProviderError<std::convert::Infallible>> {
+        let request = CallRequest {
+            from: Some(fixture.nth_local_account(0).expect("account exists")),
+            to: Some(contract),
+            ..CallRequest::default()
+        };
+
+        let state_overrides = [(contract, account_override)].into_iter().collect();
+
+        handle_call_request(
+            &mut fixture.provider_data,
+            request,
+            None,
+            Some(state_overrides),
+        )
+        .map(|(output, _trace, _access_list)| output)
+    }
+
     #[test]
     fn resolve_call_request_inner_with_gas_price() -> anyhow::Result<()> {
         let mut fixture = ProviderTestFixture::new_local()?;
@@ -227,4 +283,58 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn call_with_state_overrides_does_not_leak() -> anyhow::Result<()> {
+        let mut fixture = ProviderTestFixture::new_local()?;
+
+        let contract = Address::random();
+        let output = call_with_overrides(
+            &mut fixture,
+            contract,
+            AccountOverrideOptions {
+                balance: Some(U256::from(1_000)),
+                nonce: Some(5),
+                code: Some(Bytes::from(hex::decode(RETURN_SLOT_ZERO_CODE)?)),
+                storage: None,
+                storage_diff: storage_override(42),
+            },
+        )?;
+        assert_eq!(output, Bytes::from(U256::from(42).to_be_bytes::<32>()));
+
+        // The overrides only applied to the call
+        let provider_data = &mut fixture.provider_data;
+        assert_eq!(provider_data.balance(contract, None)?, U256::ZERO);
+        assert_eq!(provider_data.account_next_nonce(&contract)?, 0);
+        assert!(provider_data.get_code(contract, None)?.is_empty());
+        assert_eq!(
+            provider_data.get_storage_at(contract, U256::ZERO, None)?,
+            U256::ZERO
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn call_with_full_and_diff_storage_overrides_fails() -> anyhow::Result<()> {
+        let mut fixture = ProviderTestFixture::new_local()?;
+
+        let result = call_with_overrides(
+            &mut fixture,
+            Address::random(),
+            AccountOverrideOptions {
+                balance: None,
+                nonce: None,
+                code: Some(Bytes::from(hex::decode(RETURN_SLOT_ZERO_CODE)?)),
+                storage: storage_override(1),
+                storage_diff: storage_override(2),
+            },
+        );
+        assert!(matches!(
+            result,
+            Err(ProviderError::AccountOverrideConversionError(_))
+        ));
+
+        Ok(())
+    }
 }
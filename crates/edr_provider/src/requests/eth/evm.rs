@@ -3,7 +3,14 @@ use core::fmt::Debug;
 use edr_eth::{block::BlockOptions, U64};
 use edr_evm::trace::Trace;
 
-use crate::{data::ProviderData, requests::methods::U64OrUsize, ProviderError};
+use crate::{
+    data::ProviderData,
+    requests::{
+        methods::U64OrUsize,
+        validation::{validate_block_gas_limit, MIN_BLOCK_GAS_LIMIT},
+    },
+    ProviderError,
+};
 
 pub fn handle_increase_time_request<LoggerErrorT: Debug>(
     data: &mut ProviderData<LoggerErrorT>,
@@ -55,7 +62,10 @@ pub fn handle_set_block_gas_limit_request<LoggerErrorT: Debug>(
     data: &mut ProviderData<LoggerErrorT>,
     gas_limit: U64,
 ) -> Result<bool, ProviderError<LoggerErrorT>> {
-    data.set_block_gas_limit(gas_limit.as_limbs()[0])?;
+    let gas_limit = gas_limit.as_limbs()[0];
+    validate_block_gas_limit(gas_limit, MIN_BLOCK_GAS_LIMIT)?;
+
+    data.set_block_gas_limit(gas_limit)?;
 
     Ok(true)
 }
@@ -70,6 +80,16 @@ pub fn handle_set_next_block_timestamp_request<LoggerErrorT: Debug>(
     Ok(new_timestamp.to_string())
 }
 
+pub fn handle_set_time_request<LoggerErrorT: Debug>(
+    data: &mut ProviderData<LoggerErrorT>,
+    timestamp: U64OrUsize,
+) -> Result<String, ProviderError<LoggerErrorT>> {
+    let new_block_time = data.set_block_time(timestamp.into())?;
+
+    // This RPC call is an exception: it returns a number as a string decimal
+    Ok(new_block_time.to_string())
+}
+
 pub fn handle_snapshot_request<LoggerErrorT: Debug>(
     data: &mut ProviderData<LoggerErrorT>,
 ) -> Result<U64, ProviderError<LoggerErrorT>> {
@@ -77,3 +97,228 @@ pub fn handle_snapshot_request<LoggerErrorT: Debug>(
 
     Ok(U64::from(snapshot_id))
 }
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use edr_eth::{
+        transaction::{
+            Eip155TransactionRequest, TransactionKind, TransactionRequest,
+            TransactionRequestAndSender,
+        },
+        Address, Bytes, U256,
+    };
+    use edr_evm::{time::ManualTimeSource, Block, ExecutableTransaction};
+
+    use super::*;
+    use crate::{
+        data::test_utils::ProviderTestFixture, test_utils::create_test_config, ProviderConfig,
+    };
+
+    const INITIAL_BLOCK_GAS_LIMIT: u64 = 100_000;
+    const TRANSACTION_GAS_LIMIT: u64 = 200_000;
+    const NEW_BLOCK_GAS_LIMIT: u64 = 2 * TRANSACTION_GAS_LIMIT;
+
+    fn create_fixture() -> anyhow::Result<ProviderTestFixture> {
+        let mut config = create_test_config();
+        config.block_gas_limit = Some(INITIAL_BLOCK_GAS_LIMIT);
+
+        let mut fixture = ProviderTestFixture::new(config)?;
+        fixture.provider_data.set_auto_mining(false);
+
+        Ok(fixture)
+    }
+
+    fn gas_heavy_transaction(
+        fixture: &ProviderTestFixture,
+    ) -> anyhow::Result<ExecutableTransaction> {
+        let request = TransactionRequest::Eip155(Eip155TransactionRequest {
+            kind: TransactionKind::Call(Address::ZERO),
+            gas_limit: TRANSACTION_GAS_LIMIT,
+            gas_price: U256::from(42_000_000_000_u64),
+            value: U256::from(1),
+            input: Bytes::default(),
+            nonce: 0,
+            chain_id: fixture.config.chain_id,
+        });
+        let sender = fixture.nth_local_account(0)?;
+
+        Ok(fixture
+            .provider_data
+            .sign_transaction_request(TransactionRequestAndSender { request, sender })?)
+    }
+
+    #[test]
+    fn set_block_gas_limit_fits_larger_transaction() -> anyhow::Result<()> {
+        let mut fixture = create_fixture()?;
+
+        let transaction = gas_heavy_transaction(&fixture)?;
+        let error = fixture
+            .provider_data
+            .send_transaction(transaction.clone())
+            .expect_err("transaction exceeds the block gas limit");
+        assert!(matches!(error, ProviderError::MemPoolAddTransaction(_)));
+
+        assert!(handle_set_block_gas_limit_request(
+            &mut fixture.provider_data,
+            U64::from(NEW_BLOCK_GAS_LIMIT)
+        )?);
+
+        let transaction_hash = fixture
+            .provider_data
+            .send_transaction(transaction)?
+            .transaction_hash;
+
+        let result = fixture
+            .provider_data
+            .mine_and_commit_block(BlockOptions::default())?;
+        assert_eq!(result.block.header().gas_limit, NEW_BLOCK_GAS_LIMIT);
+        assert_eq!(result.block.transactions().len(), 1);
+        assert_eq!(*result.block.transactions()[0].hash(), transaction_hash);
+
+        Ok(())
+    }
+
+    #[test]
+    fn set_block_gas_limit_below_minimum() -> anyhow::Result<()> {
+        let mut fixture = create_fixture()?;
+
+        let error = handle_set_block_gas_limit_request(
+            &mut fixture.provider_data,
+            U64::from(MIN_BLOCK_GAS_LIMIT - 1),
+        )
+        .expect_err("block gas limit is below the minimum");
+        assert!(matches!(error, ProviderError::InvalidInput(_)));
+
+        assert_eq!(
+            fixture.provider_data.block_gas_limit(),
+            INITIAL_BLOCK_GAS_LIMIT
+        );
+
+        Ok(())
+    }
+
+    const INITIAL_TIMESTAMP: u64 = 1_700_000_000;
+
+    fn create_manual_time_fixture() -> anyhow::Result<(ProviderTestFixture, Arc<ManualTimeSource>)>
+    {
+        let config = ProviderConfig {
+            initial_date: None,
+            ..create_test_config()
+        };
+
+        let time_source = Arc::new(ManualTimeSource::new(INITIAL_TIMESTAMP));
+        let fixture = ProviderTestFixture::new_with_time_source(config, time_source.clone())?;
+
+        Ok((fixture, time_source))
+    }
+
+    fn mine_block_timestamp(fixture: &mut ProviderTestFixture) -> anyhow::Result<u64> {
+        let result = fixture
+            .provider_data
+            .mine_and_commit_block(BlockOptions::default())?;
+
+        Ok(result.block.header().timestamp)
+    }
+
+    #[test]
+    fn increase_time_applies_to_all_future_blocks() -> anyhow::Result<()> {
+        let (mut fixture, time_source) = create_manual_time_fixture()?;
+
+        let offset =
+            handle_increase_time_request(&mut fixture.provider_data, U64OrUsize::Usize(3600))?;
+        assert_eq!(offset, "3600");
+
+        let offset =
+            handle_increase_time_request(&mut fixture.provider_data, U64OrUsize::Usize(400))?;
+        assert_eq!(offset, "4000");
+
+        assert_eq!(
+            mine_block_timestamp(&mut fixture)?,
+            INITIAL_TIMESTAMP + 4000
+        );
+
+        time_source.advance(10);
+        assert_eq!(
+            mine_block_timestamp(&mut fixture)?,
+            INITIAL_TIMESTAMP + 4010
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn set_time_sets_the_clock() -> anyhow::Result<()> {
+        let (mut fixture, time_source) = create_manual_time_fixture()?;
+
+        let offset = handle_set_time_request(
+            &mut fixture.provider_data,
+            U64OrUsize::Usize((INITIAL_TIMESTAMP + 500) as usize),
+        )?;
+        assert_eq!(offset, "500");
+
+        assert_eq!(mine_block_timestamp(&mut fixture)?, INITIAL_TIMESTAMP + 500);
+
+        time_source.advance(10);
+        assert_eq!(mine_block_timestamp(&mut fixture)?, INITIAL_TIMESTAMP + 510);
+
+        Ok(())
+    }
+
+    #[test]
+    fn set_time_rejects_timestamp_before_latest_block() -> anyhow::Result<()> {
+        let (mut fixture, _time_source) = create_manual_time_fixture()?;
+
+        let error = handle_set_time_request(
+            &mut fixture.provider_data,
+            U64OrUsize::Usize((INITIAL_TIMESTAMP - 1) as usize),
+        )
+        .expect_err("timestamp is before the genesis block");
+        assert!(matches!(
+            error,
+            ProviderError::TimestampLowerThanPrevious { .. }
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn set_time_rejects_timestamp_too_large() -> anyhow::Result<()> {
+        let (mut fixture, _time_source) = create_manual_time_fixture()?;
+
+        let error = handle_set_time_request(&mut fixture.provider_data, U64OrUsize::U64(U64::MAX))
+            .expect_err("timestamp doesn't fit the block time offset");
+        assert!(matches!(error, ProviderError::InvalidInput(_)));
+
+        Ok(())
+    }
+
+    #[test]
+    fn next_block_timestamp_takes_precedence_for_one_block() -> anyhow::Result<()> {
+        let (mut fixture, _time_source) = create_manual_time_fixture()?;
+
+        let next_block_timestamp = INITIAL_TIMESTAMP + 1_000;
+        handle_set_next_block_timestamp_request(
+            &mut fixture.provider_data,
+            U64OrUsize::Usize(next_block_timestamp as usize),
+        )?;
+
+        // Changing the clock afterwards doesn't clear the one-shot timestamp
+        handle_set_time_request(
+            &mut fixture.provider_data,
+            U64OrUsize::Usize((INITIAL_TIMESTAMP + 500) as usize),
+        )?;
+        handle_increase_time_request(&mut fixture.provider_data, U64OrUsize::Usize(100))?;
+
+        assert_eq!(mine_block_timestamp(&mut fixture)?, next_block_timestamp);
+
+        // Subsequent blocks continue from the one-shot timestamp
+        assert_eq!(
+            mine_block_timestamp(&mut fixture)?,
+            next_block_timestamp + 1
+        );
+
+        Ok(())
+    }
+}
@@ -49,6 +49,17 @@ pub fn handle_get_logs_request<LoggerErrorT: Debug>(
     }
 
     let filter = validate_filter_criteria::<true, LoggerErrorT>(data, filter_options)?;
+
+    if let Some(limit) = data.get_logs_block_range_limit() {
+        let to_block = filter.to_block.unwrap_or_else(|| data.last_block_number());
+        let block_range = to_block.saturating_sub(filter.from_block).saturating_add(1);
+        if block_range > limit {
+            return Err(ProviderError::InvalidInput(format!(
+                "eth_getLogs block range of {block_range} blocks exceeds the limit of {limit} blocks"
+            )));
+        }
+    }
+
     data.logs(filter)
         .map(|logs| logs.iter().map(LogOutput::from).collect())
 }
@@ -195,3 +206,97 @@ fn validate_filter_criteria<const SHOULD_RESOLVE_LATEST: bool, LoggerErrorT: Deb
         normalized_topics,
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use edr_eth::{Address, Bytes, B256};
+
+    use super::*;
+    use crate::{
+        data::test_utils::ProviderTestFixture, test_utils::create_test_config, ProviderConfig,
+    };
+
+    const TOPIC_A: B256 = B256::repeat_byte(0xaa);
+    const TOPIC_B: B256 = B256::repeat_byte(0xbb);
+
+    /// Emits a log with topics `[TOPIC_A, TOPIC_B]` from `Address::ZERO`, the
+    /// callee of dummy transactions, and mines it in a block.
+    fn emit_log(fixture: &mut ProviderTestFixture) -> anyhow::Result<()> {
+        // PUSH32 TOPIC_B PUSH32 TOPIC_A PUSH1 0x00 PUSH1 0x00 LOG2 STOP
+        let mut code = vec![0x7f];
+        code.extend_from_slice(TOPIC_B.as_slice());
+        code.push(0x7f);
+        code.extend_from_slice(TOPIC_A.as_slice());
+        code.extend_from_slice(&[0x60, 0x00, 0x60, 0x00, 0xa2, 0x00]);
+
+        fixture
+            .provider_data
+            .set_code(Address::ZERO, Bytes::from(code))?;
+
+        let transaction = fixture.signed_dummy_transaction(0, None)?;
+        fixture.provider_data.send_transaction(transaction)?;
+
+        Ok(())
+    }
+
+    fn filter_options(topics: Vec<Option<OneOrMore<B256>>>) -> LogFilterOptions {
+        LogFilterOptions {
+            from_block: Some(BlockSpec::Number(0)),
+            to_block: None,
+            block_hash: None,
+            address: Some(OneOrMore::One(Address::ZERO)),
+            topics: Some(topics),
+        }
+    }
+
+    #[test]
+    fn get_logs_matches_topic_wildcards_and_alternatives() -> anyhow::Result<()> {
+        let mut fixture = ProviderTestFixture::new_local()?;
+        emit_log(&mut fixture)?;
+
+        let other_topic = B256::repeat_byte(0xcc);
+
+        let logs = handle_get_logs_request(
+            &fixture.provider_data,
+            filter_options(vec![
+                None,
+                Some(OneOrMore::Many(vec![other_topic, TOPIC_B])),
+            ]),
+        )?;
+        assert_eq!(logs.len(), 1);
+
+        let logs = handle_get_logs_request(
+            &fixture.provider_data,
+            filter_options(vec![Some(OneOrMore::One(other_topic))]),
+        )?;
+        assert!(logs.is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn get_logs_exceeding_block_range_limit() -> anyhow::Result<()> {
+        let config = ProviderConfig {
+            get_logs_block_range_limit: Some(1),
+            ..create_test_config()
+        };
+
+        let mut fixture = ProviderTestFixture::new(config)?;
+        emit_log(&mut fixture)?;
+
+        // A single block is within the limit
+        let logs = handle_get_logs_request(
+            &fixture.provider_data,
+            LogFilterOptions {
+                from_block: Some(BlockSpec::Number(1)),
+                ..filter_options(Vec::new())
+            },
+        )?;
+        assert_eq!(logs.len(), 1);
+
+        let result = handle_get_logs_request(&fixture.provider_data, filter_options(Vec::new()));
+        assert!(matches!(result, Err(ProviderError::InvalidInput(_))));
+
+        Ok(())
+    }
+}
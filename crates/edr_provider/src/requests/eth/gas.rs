@@ -1,6 +1,7 @@
 use core::fmt::Debug;
 
 use edr_eth::{
+    access_list::AccessListItem,
     remote::{
         eth::{CallRequest, FeeHistoryResult},
         BlockSpec,
@@ -21,7 +22,7 @@ pub fn handle_estimate_gas<LoggerErrorT: Debug>(
     data: &mut ProviderData<LoggerErrorT>,
     call_request: CallRequest,
     block_spec: Option<BlockSpec>,
-) -> Result<(U64, Vec<Trace>), ProviderError<LoggerErrorT>> {
+) -> Result<(U64, Vec<Trace>, Option<Vec<AccessListItem>>), ProviderError<LoggerErrorT>> {
     validate_call_request(data.spec_id(), &call_request, &block_spec)?;
 
     // Matching Hardhat behavior in defaulting to "pending" instead of "latest" for
@@ -43,7 +44,11 @@ pub fn handle_estimate_gas<LoggerErrorT: Debug>(
         ))
     } else {
         let result = result?;
-        Ok((U64::from(result.estimation), result.traces))
+        Ok((
+            U64::from(result.estimation),
+            result.traces,
+            result.access_list,
+        ))
     }
 }
 
@@ -294,4 +299,63 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn fee_history_clamps_block_count_to_genesis() -> anyhow::Result<()> {
+        let mut fixture = ProviderTestFixture::new_local()?;
+
+        let transaction = fixture.signed_dummy_transaction(0, None)?;
+        fixture.provider_data.send_transaction(transaction)?;
+        assert_eq!(fixture.provider_data.last_block_number(), 1);
+
+        let result = handle_fee_history(
+            &mut fixture.provider_data,
+            U256::from(10),
+            BlockSpec::latest(),
+            Some(vec![10.0, 50.0, 50.0, 90.0]),
+        )?;
+
+        assert_eq!(result.oldest_block, 0);
+        assert_eq!(result.gas_used_ratio.len(), 2);
+        // Includes the base fee of the next block
+        assert_eq!(result.base_fee_per_gas.len(), 3);
+
+        let reward = result.reward.expect("reward percentiles were requested");
+        assert_eq!(reward.len(), 2);
+        assert!(reward.iter().all(|rewards| rewards.len() == 4));
+
+        Ok(())
+    }
+
+    #[test]
+    fn fee_history_rejects_decreasing_reward_percentiles() -> anyhow::Result<()> {
+        let mut fixture = ProviderTestFixture::new_local()?;
+
+        let result = handle_fee_history(
+            &mut fixture.provider_data,
+            U256::from(1),
+            BlockSpec::latest(),
+            Some(vec![50.0, 10.0]),
+        );
+        assert!(matches!(result, Err(ProviderError::InvalidInput(_))));
+
+        Ok(())
+    }
+
+    #[test]
+    fn fee_history_rejects_out_of_range_reward_percentiles() -> anyhow::Result<()> {
+        let mut fixture = ProviderTestFixture::new_local()?;
+
+        for percentile in [-1.0, 100.5] {
+            let result = handle_fee_history(
+                &mut fixture.provider_data,
+                U256::from(1),
+                BlockSpec::latest(),
+                Some(vec![percentile]),
+            );
+            assert!(matches!(result, Err(ProviderError::InvalidInput(_))));
+        }
+
+        Ok(())
+    }
 }
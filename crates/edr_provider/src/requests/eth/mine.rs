@@ -13,6 +13,14 @@ pub fn handle_set_interval_mining<LoggerErrorT: Debug + Send + Sync + 'static>(
     runtime: runtime::Handle,
     config: OneUsizeOrTwo,
 ) -> Result<bool, ProviderError<LoggerErrorT>> {
+    if let OneUsizeOrTwo::Two([min, max]) = config {
+        if min > max {
+            return Err(ProviderError::InvalidArgument(format!(
+                "Invalid interval range: the minimum ({min}) must not be greater than the maximum ({max})"
+            )));
+        }
+    }
+
     let config = IntervalConfig::try_from(config);
 
     *interval_miner = config
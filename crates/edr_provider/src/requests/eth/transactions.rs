@@ -5,6 +5,7 @@ use edr_eth::{
     receipt::{BlockReceipt, TransactionReceipt},
     remote::{self, PreEip1898BlockSpec},
     rlp::Decodable,
+    signature::SignatureError,
     transaction::{
         Eip1559TransactionRequest, Eip155TransactionRequest, Eip2930TransactionRequest,
         EthTransactionRequest, SignedTransaction, TransactionKind, TransactionRequest,
@@ -104,22 +105,27 @@ pub fn handle_get_transaction_receipt<LoggerErrorT: Debug>(
 ) -> Result<Option<Arc<BlockReceipt>>, ProviderError<LoggerErrorT>> {
     let receipt = data.transaction_receipt(&transaction_hash)?;
 
+    Ok(receipt.map(|receipt| receipt_to_rpc_output(data.spec_id(), receipt)))
+}
+
+pub(crate) fn receipt_to_rpc_output(
+    spec_id: SpecId,
+    receipt: Arc<BlockReceipt>,
+) -> Arc<BlockReceipt> {
     // The JSON-RPC layer should not return the gas price as effective gas price for
     // receipts in pre-London hardforks.
-    if let Some(receipt) = receipt.as_ref() {
-        if data.spec_id() < SpecId::LONDON && receipt.effective_gas_price.is_some() {
-            return Ok(Some(Arc::new(BlockReceipt {
-                inner: TransactionReceipt {
-                    effective_gas_price: None,
-                    ..receipt.inner.clone()
-                },
-                block_hash: receipt.block_hash,
-                block_number: receipt.block_number,
-            })));
-        }
+    if spec_id < SpecId::LONDON && receipt.effective_gas_price.is_some() {
+        Arc::new(BlockReceipt {
+            inner: TransactionReceipt {
+                effective_gas_price: None,
+                ..receipt.inner.clone()
+            },
+            block_hash: receipt.block_hash,
+            block_number: receipt.block_number,
+        })
+    } else {
+        receipt
     }
-
-    Ok(receipt)
 }
 
 fn transaction_from_block(
@@ -148,24 +154,17 @@ pub fn transaction_to_rpc_result<LoggerErrorT: Debug>(
         signed_transaction: &SignedTransaction,
         block: Option<&Arc<dyn SyncBlock<Error = BlockchainError>>>,
     ) -> U256 {
-        let max_fee_per_gas = signed_transaction
-            .max_fee_per_gas()
-            .expect("Transaction must be post EIP-1559 transaction.");
-        let max_priority_fee_per_gas = signed_transaction
-            .max_priority_fee_per_gas()
-            .expect("Transaction must be post EIP-1559 transaction.");
-
         if let Some(block) = block {
             let base_fee_per_gas = block.header().base_fee_per_gas.expect(
                 "Transaction must have base fee per gas in block metadata if EIP-1559 is active.",
             );
-            let priority_fee_per_gas =
-                max_priority_fee_per_gas.min(max_fee_per_gas - base_fee_per_gas);
-            base_fee_per_gas + priority_fee_per_gas
+            signed_transaction.effective_gas_price(base_fee_per_gas)
         } else {
             // We are following Hardhat's behavior of returning the max fee per gas for
             // pending transactions.
-            max_fee_per_gas
+            signed_transaction
+                .max_fee_per_gas()
+                .expect("Transaction must be post EIP-1559 transaction.")
         }
     }
 
@@ -182,9 +181,9 @@ pub fn transaction_to_rpc_result<LoggerErrorT: Debug>(
         SignedTransaction::PreEip155Legacy(tx) => tx.gas_price,
         SignedTransaction::PostEip155Legacy(tx) => tx.gas_price,
         SignedTransaction::Eip2930(tx) => tx.gas_price,
-        SignedTransaction::Eip1559(_) | SignedTransaction::Eip4844(_) => {
-            gas_price_for_post_eip1559(signed_transaction, block)
-        }
+        SignedTransaction::Eip1559(_)
+        | SignedTransaction::Eip4844(_)
+        | SignedTransaction::Eip7702(_) => gas_price_for_post_eip1559(signed_transaction, block),
     };
 
     let chain_id = match &signed_transaction {
@@ -194,6 +193,7 @@ pub fn transaction_to_rpc_result<LoggerErrorT: Debug>(
         SignedTransaction::Eip2930(tx) => Some(tx.chain_id),
         SignedTransaction::Eip1559(tx) => Some(tx.chain_id),
         SignedTransaction::Eip4844(tx) => Some(tx.chain_id),
+        SignedTransaction::Eip7702(tx) => Some(tx.chain_id),
     };
 
     let show_transaction_type = spec_id >= FIRST_HARDFORK_WITH_TRANSACTION_TYPE;
@@ -245,6 +245,7 @@ pub fn transaction_to_rpc_result<LoggerErrorT: Debug>(
         max_priority_fee_per_gas: signed_transaction.max_priority_fee_per_gas(),
         max_fee_per_blob_gas: signed_transaction.max_fee_per_blob_gas(),
         blob_versioned_hashes: signed_transaction.blob_hashes(),
+        authorization_list: signed_transaction.authorization_list().map(<[_]>::to_vec),
     })
 }
 
@@ -278,6 +279,10 @@ pub fn handle_send_raw_transaction_request<LoggerErrorT: Debug>(
         return Err(ProviderError::Eip4844TransactionUnsupported);
     }
 
+    if matches!(signed_transaction, SignedTransaction::Eip7702(_)) {
+        return Err(ProviderError::Eip7702TransactionUnsupported);
+    }
+
     validate_send_raw_transaction_request(data, &signed_transaction)?;
 
     let pending_transaction = ExecutableTransaction::new(data.spec_id(), signed_transaction)?;
@@ -369,10 +374,7 @@ fn resolve_transaction_request<LoggerErrorT: Debug>(
                 gas_limit,
                 value,
                 input,
-                kind: match to {
-                    Some(to) => TransactionKind::Call(to),
-                    None => TransactionKind::Create,
-                },
+                kind: TransactionKind::from(to),
                 chain_id,
                 access_list: access_list.unwrap_or_default(),
             })
@@ -384,10 +386,7 @@ fn resolve_transaction_request<LoggerErrorT: Debug>(
                 gas_limit,
                 value,
                 input,
-                kind: match to {
-                    Some(to) => TransactionKind::Call(to),
-                    None => TransactionKind::Create,
-                },
+                kind: TransactionKind::from(to),
                 chain_id,
                 access_list,
             })
@@ -398,10 +397,7 @@ fn resolve_transaction_request<LoggerErrorT: Debug>(
             gas_limit,
             value,
             input,
-            kind: match to {
-                Some(to) => TransactionKind::Call(to),
-                None => TransactionKind::Create,
-            },
+            kind: TransactionKind::from(to),
             chain_id,
         }),
     };
@@ -477,10 +473,24 @@ fn validate_send_raw_transaction_request<LoggerErrorT: Debug>(
     data: &ProviderData<LoggerErrorT>,
     signed_transaction: &SignedTransaction,
 ) -> Result<(), ProviderError<LoggerErrorT>> {
+    // Raw legacy transactions with a `v` value below 35 are decoded as
+    // pre-EIP-155, so their `v` value must be 27 or 28
+    if let SignedTransaction::PreEip155Legacy(transaction) = signed_transaction {
+        let v = transaction.signature.v;
+        if !matches!(v, 27 | 28) {
+            return Err(ProviderError::InvalidArgument(format!(
+                "Invalid signature v value {v} for legacy transaction"
+            )));
+        }
+    }
+
     // Validate signature
     let _ = signed_transaction
-        .recover()
-        .map_err(|_err| ProviderError::InvalidArgument("Invalid Signature".into()))?;
+        .recover_strict()
+        .map_err(|err| match err {
+            SignatureError::HighS => ProviderError::InvalidArgument(err.to_string()),
+            _ => ProviderError::InvalidArgument("Invalid Signature".into()),
+        })?;
 
     if let Some(tx_chain_id) = signed_transaction.chain_id() {
         let expected = data.chain_id();
@@ -520,19 +530,135 @@ You can use them by running Hardhat Network with 'hardfork' {minimum_hardfork:?}
 
 #[cfg(test)]
 mod tests {
+    use alloy_sol_types::{Revert, SolError};
     use anyhow::Context;
     use edr_eth::{
-        transaction::{Eip155TransactionRequest, TransactionKind, TransactionRequest},
+        authorization::Authorization,
+        block::BlockOptions,
+        remote::jsonrpc,
+        rlp::Encodable,
+        signature::secret_key_from_str,
+        transaction::{
+            Eip155TransactionRequest, Eip7702TransactionRequest, LegacyTransactionRequest,
+            TransactionKind, TransactionRequest,
+        },
         Address, Bytes, U256,
     };
-    use edr_evm::ExecutableTransaction;
+    use edr_evm::{keccak256, ExecutableTransaction, MemPoolAddTransactionError};
 
     use super::*;
     use crate::{
         data::{test_utils::ProviderTestFixture, SendTransactionResult},
-        test_utils::one_ether,
+        decode_revert_reason,
+        test_utils::{create_test_config, one_ether, TEST_SECRET_KEY},
     };
 
+    const CONSTRUCTOR_REVERT_REASON: &str = "Constructor failed";
+
+    /// Constructs init code of which the constructor reverts with the
+    /// provided reason, similar to a failing `require(condition, reason)`.
+    fn reverting_init_code(reason: &str) -> Bytes {
+        let revert_data = Revert {
+            reason: reason.to_string(),
+        }
+        .abi_encode();
+
+        let mut init_code = Vec::new();
+        for (index, chunk) in revert_data.chunks(32).enumerate() {
+            let mut word = [0u8; 32];
+            word[..chunk.len()].copy_from_slice(chunk);
+
+            let offset = u16::try_from(index * 32).expect("revert data is small");
+
+            // PUSH32 word PUSH2 offset MSTORE
+            init_code.push(0x7f);
+            init_code.extend_from_slice(&word);
+            init_code.push(0x61);
+            init_code.extend_from_slice(&offset.to_be_bytes());
+            init_code.push(0x52);
+        }
+
+        let length = u16::try_from(revert_data.len()).expect("revert data is small");
+
+        // PUSH2 length PUSH1 0x00 REVERT
+        init_code.push(0x61);
+        init_code.extend_from_slice(&length.to_be_bytes());
+        init_code.extend_from_slice(&[0x60, 0x00, 0xfd]);
+
+        Bytes::from(init_code)
+    }
+
+    fn create_fixture_with_bail_on_transaction_failure(
+        bail_on_transaction_failure: bool,
+    ) -> anyhow::Result<ProviderTestFixture> {
+        let mut config = create_test_config();
+        config.bail_on_transaction_failure = bail_on_transaction_failure;
+
+        let mut fixture = ProviderTestFixture::new(config)?;
+        fixture.provider_data.set_auto_mining(true);
+
+        Ok(fixture)
+    }
+
+    fn deployment_request(fixture: &ProviderTestFixture) -> anyhow::Result<EthTransactionRequest> {
+        Ok(EthTransactionRequest {
+            from: fixture.nth_local_account(0)?,
+            data: Some(reverting_init_code(CONSTRUCTOR_REVERT_REASON)),
+            ..EthTransactionRequest::default()
+        })
+    }
+
+    #[test]
+    fn failed_deployment_bails_with_revert_reason() -> anyhow::Result<()> {
+        let mut fixture = create_fixture_with_bail_on_transaction_failure(true)?;
+
+        let request = deployment_request(&fixture)?;
+        let error = handle_send_transaction_request(&mut fixture.provider_data, request)
+            .expect_err("deployment should fail");
+
+        let ProviderError::TransactionFailed(TransactionFailureWithTraces { failure, .. }) = &error
+        else {
+            panic!("expected a transaction failure, but got: {error:?}");
+        };
+
+        assert!(failure.transaction_hash.is_some());
+        assert_eq!(
+            failure.to_string(),
+            format!("reverted with reason string '{CONSTRUCTOR_REVERT_REASON}'")
+        );
+
+        let error = jsonrpc::Error::from(error);
+        assert_eq!(
+            error.message,
+            format!("reverted with reason string '{CONSTRUCTOR_REVERT_REASON}'")
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn failed_deployment_result_contains_revert_reason() -> anyhow::Result<()> {
+        let mut fixture = create_fixture_with_bail_on_transaction_failure(false)?;
+
+        let request = deployment_request(&fixture)?;
+        let request = resolve_transaction_request(&mut fixture.provider_data, request)?;
+        let transaction = fixture.provider_data.sign_transaction_request(request)?;
+
+        let SendTransactionResult {
+            transaction_result, ..
+        } = fixture.provider_data.send_transaction(transaction)?;
+        let (execution_result, _trace) = transaction_result.context("transaction was mined")?;
+
+        assert_eq!(
+            decode_revert_reason(&execution_result),
+            Some(format!(
+                "reverted with reason string '{CONSTRUCTOR_REVERT_REASON}'"
+            ))
+        );
+
+        Ok(())
+    }
+
     #[test]
     fn transaction_by_hash_for_impersonated_account() -> anyhow::Result<()> {
         let mut fixture = ProviderTestFixture::new_local()?;
@@ -580,4 +706,299 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn transaction_by_hash_before_and_after_mining() -> anyhow::Result<()> {
+        let mut fixture = ProviderTestFixture::new_local()?;
+        fixture.provider_data.set_auto_mining(false);
+
+        let transaction = fixture.signed_dummy_transaction(0, None)?;
+        let transaction_hash = fixture
+            .provider_data
+            .send_transaction(transaction)?
+            .transaction_hash;
+
+        let pending_transaction =
+            handle_get_transaction_by_hash(&fixture.provider_data, transaction_hash)?
+                .context("pending transaction not found")?;
+        assert_eq!(pending_transaction.hash, transaction_hash);
+        assert_eq!(pending_transaction.block_hash, None);
+        assert_eq!(pending_transaction.block_number, None);
+        assert_eq!(pending_transaction.transaction_index, None);
+
+        let result = fixture
+            .provider_data
+            .mine_and_commit_block(BlockOptions::default())?;
+
+        let mined_transaction =
+            handle_get_transaction_by_hash(&fixture.provider_data, transaction_hash)?
+                .context("mined transaction not found")?;
+        assert_eq!(mined_transaction.hash, transaction_hash);
+        assert_eq!(mined_transaction.block_hash, Some(*result.block.hash()));
+        assert_eq!(
+            mined_transaction.block_number,
+            Some(U256::from(result.block.header().number))
+        );
+        assert_eq!(mined_transaction.transaction_index, Some(0));
+
+        Ok(())
+    }
+
+    #[test]
+    fn transaction_by_block_and_index() -> anyhow::Result<()> {
+        let mut fixture = ProviderTestFixture::new_local()?;
+        fixture.provider_data.set_auto_mining(false);
+
+        let transaction_hashes = (0..2)
+            .map(|nonce| {
+                let transaction = fixture.signed_dummy_transaction(0, Some(nonce))?;
+                let result = fixture.provider_data.send_transaction(transaction)?;
+                Ok(result.transaction_hash)
+            })
+            .collect::<anyhow::Result<Vec<_>>>()?;
+
+        let result = fixture
+            .provider_data
+            .mine_and_commit_block(BlockOptions::default())?;
+        let block_hash = *result.block.hash();
+        let block_number = result.block.header().number;
+
+        let by_hash = handle_get_transaction_by_block_hash_and_index(
+            &fixture.provider_data,
+            block_hash,
+            U256::from(1),
+        )?
+        .context("transaction not found by block hash")?;
+        let by_number = handle_get_transaction_by_block_spec_and_index(
+            &mut fixture.provider_data,
+            PreEip1898BlockSpec::Number(block_number),
+            U256::from(1),
+        )?
+        .context("transaction not found by block number")?;
+
+        for transaction in [by_hash, by_number] {
+            assert_eq!(transaction.hash, transaction_hashes[1]);
+            assert_eq!(transaction.block_hash, Some(block_hash));
+            assert_eq!(transaction.block_number, Some(U256::from(block_number)));
+            assert_eq!(transaction.transaction_index, Some(1));
+        }
+
+        // Out-of-range indices return `null`
+        let by_hash = handle_get_transaction_by_block_hash_and_index(
+            &fixture.provider_data,
+            block_hash,
+            U256::from(2),
+        )?;
+        assert!(by_hash.is_none());
+
+        let by_number = handle_get_transaction_by_block_spec_and_index(
+            &mut fixture.provider_data,
+            PreEip1898BlockSpec::Number(block_number),
+            U256::from(2),
+        )?;
+        assert!(by_number.is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn send_raw_transaction_rejects_eip7702() -> anyhow::Result<()> {
+        const SECRET_KEY: &str = "ac0974bec39a17e36ba4a6b4d238ff944bacb478cbed5efcae784d7bf4f2ff80";
+
+        let mut fixture = ProviderTestFixture::new_local()?;
+        let secret_key = secret_key_from_str(SECRET_KEY)?;
+
+        let authorization = Authorization {
+            chain_id: fixture.provider_data.chain_id(),
+            address: Address::repeat_byte(0x42),
+            nonce: 1,
+        }
+        .sign(&secret_key)?;
+
+        let transaction = Eip7702TransactionRequest {
+            chain_id: fixture.provider_data.chain_id(),
+            nonce: 0,
+            max_priority_fee_per_gas: U256::from(1_000_000_000_u64),
+            max_fee_per_gas: U256::from(42_000_000_000_u64),
+            gas_limit: 100_000,
+            to: Address::ZERO,
+            value: U256::ZERO,
+            input: Bytes::default(),
+            access_list: Vec::new(),
+            authorization_list: vec![authorization],
+        }
+        .sign(&secret_key)?;
+
+        let mut raw_transaction = Vec::new();
+        SignedTransaction::Eip7702(transaction).encode(&mut raw_transaction);
+
+        let error = handle_send_raw_transaction_request(
+            &mut fixture.provider_data,
+            Bytes::from(raw_transaction),
+        )
+        .expect_err("EIP-7702 transactions are not supported");
+
+        assert!(matches!(
+            error,
+            ProviderError::Eip7702TransactionUnsupported
+        ));
+
+        Ok(())
+    }
+
+    /// Encodes an EIP-155 transaction that transfers the provided value from
+    /// the first test account.
+    fn raw_eip155_transaction(chain_id: u64, nonce: u64, value: U256) -> anyhow::Result<Bytes> {
+        let secret_key = secret_key_from_str(TEST_SECRET_KEY)?;
+
+        let transaction = Eip155TransactionRequest {
+            nonce,
+            gas_price: U256::from(42_000_000_000_u64),
+            gas_limit: 30_000,
+            kind: TransactionKind::Call(Address::repeat_byte(0x42)),
+            value,
+            input: Bytes::default(),
+            chain_id,
+        }
+        .sign(&secret_key)?;
+
+        let mut raw_transaction = Vec::new();
+        SignedTransaction::PostEip155Legacy(transaction).encode(&mut raw_transaction);
+
+        Ok(Bytes::from(raw_transaction))
+    }
+
+    #[test]
+    fn send_raw_transaction() -> anyhow::Result<()> {
+        let mut fixture = ProviderTestFixture::new_local()?;
+
+        let value = U256::from(1_000);
+        let raw_transaction = raw_eip155_transaction(fixture.provider_data.chain_id(), 0, value)?;
+
+        let (transaction_hash, _traces) = handle_send_raw_transaction_request(
+            &mut fixture.provider_data,
+            raw_transaction.clone(),
+        )?;
+
+        assert_eq!(transaction_hash, keccak256(&raw_transaction));
+
+        let transaction = fixture
+            .provider_data
+            .transaction_by_hash(&transaction_hash)?
+            .context("transaction should exist")?;
+        assert_eq!(
+            transaction.transaction.caller(),
+            &fixture.nth_local_account(0)?
+        );
+
+        let balance = fixture
+            .provider_data
+            .balance(Address::repeat_byte(0x42), None)?;
+        assert_eq!(balance, value);
+
+        Ok(())
+    }
+
+    #[test]
+    fn send_raw_transaction_rejects_invalid_chain_id() -> anyhow::Result<()> {
+        let mut fixture = ProviderTestFixture::new_local()?;
+
+        let raw_transaction =
+            raw_eip155_transaction(fixture.provider_data.chain_id() + 1, 0, U256::from(1))?;
+
+        let error =
+            handle_send_raw_transaction_request(&mut fixture.provider_data, raw_transaction)
+                .expect_err("transaction has an invalid chain id");
+
+        assert!(matches!(
+            error,
+            ProviderError::InvalidEip155TransactionChainId
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn send_raw_transaction_rejects_invalid_legacy_v() -> anyhow::Result<()> {
+        let mut fixture = ProviderTestFixture::new_local()?;
+
+        let secret_key = secret_key_from_str(TEST_SECRET_KEY)?;
+        let mut transaction = LegacyTransactionRequest {
+            nonce: 0,
+            gas_price: U256::from(42_000_000_000_u64),
+            gas_limit: 30_000,
+            kind: TransactionKind::Call(Address::repeat_byte(0x42)),
+            value: U256::from(1),
+            input: Bytes::default(),
+        }
+        .sign(&secret_key)?;
+
+        // Neither pre-EIP-155 (27 or 28) nor post-EIP-155 (35 or more)
+        transaction.signature.v = 29;
+
+        let mut raw_transaction = Vec::new();
+        SignedTransaction::PreEip155Legacy(transaction).encode(&mut raw_transaction);
+
+        let error = handle_send_raw_transaction_request(
+            &mut fixture.provider_data,
+            Bytes::from(raw_transaction),
+        )
+        .expect_err("transaction has an invalid v value");
+
+        assert!(matches!(
+            error,
+            ProviderError::InvalidArgument(message) if message.contains("v value 29")
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn send_raw_transaction_rejects_insufficient_funds() -> anyhow::Result<()> {
+        let mut fixture = ProviderTestFixture::new_local()?;
+
+        // The test account's balance cannot cover the value, let alone the gas
+        let raw_transaction =
+            raw_eip155_transaction(fixture.provider_data.chain_id(), 0, one_ether())?;
+
+        let error =
+            handle_send_raw_transaction_request(&mut fixture.provider_data, raw_transaction)
+                .expect_err("sender has insufficient funds");
+
+        assert!(matches!(
+            error,
+            ProviderError::MemPoolAddTransaction(
+                MemPoolAddTransactionError::InsufficientFunds { .. }
+            )
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn send_raw_transaction_rejects_used_nonce() -> anyhow::Result<()> {
+        let mut fixture = ProviderTestFixture::new_local()?;
+        let chain_id = fixture.provider_data.chain_id();
+
+        handle_send_raw_transaction_request(
+            &mut fixture.provider_data,
+            raw_eip155_transaction(chain_id, 0, U256::from(1))?,
+        )?;
+
+        let error = handle_send_raw_transaction_request(
+            &mut fixture.provider_data,
+            raw_eip155_transaction(chain_id, 0, U256::from(2))?,
+        )
+        .expect_err("nonce was already used");
+
+        assert!(matches!(
+            error,
+            ProviderError::AutoMineNonceTooLow {
+                expected: 1,
+                actual: 0
+            }
+        ));
+
+        Ok(())
+    }
 }
@@ -1,13 +1,23 @@
 use core::fmt::Debug;
 
-use edr_eth::{Address, B256, U256};
+use edr_eth::{Address, B256, U256, U64};
 
 use crate::{
     data::ProviderData,
-    requests::{eth::client_version, hardhat::rpc_types::Metadata},
+    requests::{
+        eth::client_version,
+        hardhat::rpc_types::{GasFees, Metadata},
+        validation::{validate_block_gas_limit, MIN_HARDHAT_BLOCK_GAS_LIMIT},
+    },
     ProviderError,
 };
 
+pub fn handle_gas_fees_request<LoggerErrorT: Debug>(
+    data: &ProviderData<LoggerErrorT>,
+) -> Result<GasFees, ProviderError<LoggerErrorT>> {
+    data.gas_fees()
+}
+
 pub fn handle_get_automine_request<LoggerErrorT: Debug>(
     data: &ProviderData<LoggerErrorT>,
 ) -> Result<bool, ProviderError<LoggerErrorT>> {
@@ -27,10 +37,39 @@ pub fn handle_metadata_request<LoggerErrorT: Debug>(
     })
 }
 
+pub fn handle_safe_block_number_request<LoggerErrorT: Debug>(
+    data: &ProviderData<LoggerErrorT>,
+) -> Result<Option<U64>, ProviderError<LoggerErrorT>> {
+    Ok(data.largest_safe_block_number().map(U64::from))
+}
+
+pub fn handle_set_block_gas_limit_request<LoggerErrorT: Debug>(
+    data: &mut ProviderData<LoggerErrorT>,
+    gas_limit: U256,
+) -> Result<bool, ProviderError<LoggerErrorT>> {
+    let gas_limit = u64::try_from(gas_limit).map_err(|_error| {
+        ProviderError::InvalidInput(format!(
+            "Block gas limit must be at most {}, but {gas_limit} was provided",
+            u64::MAX
+        ))
+    })?;
+    validate_block_gas_limit(gas_limit, MIN_HARDHAT_BLOCK_GAS_LIMIT)?;
+
+    data.set_block_gas_limit(gas_limit)?;
+
+    Ok(true)
+}
+
 pub fn handle_set_coinbase_request<LoggerErrorT: Debug>(
     data: &mut ProviderData<LoggerErrorT>,
     coinbase: Address,
 ) -> Result<bool, ProviderError<LoggerErrorT>> {
+    if coinbase == Address::ZERO {
+        return Err(ProviderError::InvalidInput(
+            "Coinbase address cannot be the zero address".into(),
+        ));
+    }
+
     data.set_coinbase(coinbase);
 
     Ok(true)
@@ -54,6 +93,15 @@ pub fn handle_set_next_block_base_fee_per_gas_request<LoggerErrorT: Debug>(
     Ok(true)
 }
 
+pub fn handle_set_next_block_difficulty_request<LoggerErrorT: Debug>(
+    data: &mut ProviderData<LoggerErrorT>,
+    difficulty: U256,
+) -> Result<bool, ProviderError<LoggerErrorT>> {
+    data.set_next_block_difficulty(difficulty)?;
+
+    Ok(true)
+}
+
 pub fn handle_set_prev_randao_request<LoggerErrorT: Debug>(
     data: &mut ProviderData<LoggerErrorT>,
     prev_randao: B256,
@@ -62,3 +110,289 @@ pub fn handle_set_prev_randao_request<LoggerErrorT: Debug>(
 
     Ok(true)
 }
+
+#[cfg(test)]
+mod tests {
+    use edr_eth::{block::BlockOptions, Bytes, SpecId};
+    use edr_evm::{Block, MemPoolAddTransactionError};
+
+    use super::*;
+    use crate::{
+        data::test_utils::ProviderTestFixture, test_utils::create_test_config, ProviderConfig,
+    };
+
+    #[test]
+    fn set_block_gas_limit_below_minimum() -> anyhow::Result<()> {
+        let mut fixture = ProviderTestFixture::new_local()?;
+        let previous_gas_limit = fixture.provider_data.block_gas_limit();
+
+        let error = handle_set_block_gas_limit_request(
+            &mut fixture.provider_data,
+            U256::from(MIN_HARDHAT_BLOCK_GAS_LIMIT - 1),
+        )
+        .expect_err("block gas limit is below the minimum");
+        assert!(matches!(error, ProviderError::InvalidInput(_)));
+
+        assert_eq!(fixture.provider_data.block_gas_limit(), previous_gas_limit);
+
+        Ok(())
+    }
+
+    #[test]
+    fn set_block_gas_limit_rejects_value_transfer_limit_minus_one() -> anyhow::Result<()> {
+        let mut fixture = ProviderTestFixture::new_local()?;
+
+        let error =
+            handle_set_block_gas_limit_request(&mut fixture.provider_data, U256::from(20_999))
+                .expect_err("block gas limit cannot fit a value transfer");
+        assert!(matches!(error, ProviderError::InvalidInput(_)));
+
+        // `evm_setBlockGasLimit` only enforces the yellow paper's minimum
+        assert!(crate::requests::eth::handle_set_block_gas_limit_request(
+            &mut fixture.provider_data,
+            U64::from(20_999),
+        )?);
+        assert_eq!(fixture.provider_data.block_gas_limit(), 20_999);
+
+        Ok(())
+    }
+
+    #[test]
+    fn set_block_gas_limit_rejects_larger_transactions() -> anyhow::Result<()> {
+        let mut fixture = ProviderTestFixture::new_local()?;
+
+        assert!(handle_set_block_gas_limit_request(
+            &mut fixture.provider_data,
+            U256::from(MIN_HARDHAT_BLOCK_GAS_LIMIT),
+        )?);
+        assert_eq!(
+            fixture.provider_data.block_gas_limit(),
+            MIN_HARDHAT_BLOCK_GAS_LIMIT
+        );
+
+        // The dummy transaction has a gas limit of 30,000
+        let transaction = fixture.signed_dummy_transaction(0, None)?;
+        let error = fixture
+            .provider_data
+            .send_transaction(transaction)
+            .expect_err("transaction exceeds the block gas limit");
+        assert!(matches!(
+            error,
+            ProviderError::MemPoolAddTransaction(
+                MemPoolAddTransactionError::ExceedsBlockGasLimit { block_gas_limit, .. }
+            ) if block_gas_limit == MIN_HARDHAT_BLOCK_GAS_LIMIT
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn set_coinbase_is_used_as_beneficiary() -> anyhow::Result<()> {
+        let mut fixture = ProviderTestFixture::new_local()?;
+
+        let first_coinbase = Address::random();
+        let coinbase = Address::random();
+        assert!(handle_set_coinbase_request(
+            &mut fixture.provider_data,
+            first_coinbase
+        )?);
+        assert!(handle_set_coinbase_request(
+            &mut fixture.provider_data,
+            coinbase
+        )?);
+        assert_eq!(fixture.provider_data.coinbase(), coinbase);
+
+        let result = fixture
+            .provider_data
+            .mine_and_commit_block(BlockOptions::default())?;
+        assert_eq!(result.block.header().beneficiary, coinbase);
+
+        Ok(())
+    }
+
+    #[test]
+    fn set_coinbase_rejects_zero_address() -> anyhow::Result<()> {
+        let mut fixture = ProviderTestFixture::new_local()?;
+        let previous_coinbase = fixture.provider_data.coinbase();
+
+        let error = handle_set_coinbase_request(&mut fixture.provider_data, Address::ZERO)
+            .expect_err("zero address is not a valid coinbase");
+        assert!(matches!(error, ProviderError::InvalidInput(_)));
+
+        assert_eq!(fixture.provider_data.coinbase(), previous_coinbase);
+
+        Ok(())
+    }
+
+    #[test]
+    fn metadata_reflects_latest_block() -> anyhow::Result<()> {
+        let mut fixture = ProviderTestFixture::new_local()?;
+
+        let result = fixture
+            .provider_data
+            .mine_and_commit_block(BlockOptions::default())?;
+
+        let metadata = handle_metadata_request(&fixture.provider_data)?;
+        assert_eq!(metadata.client_version, client_version());
+        assert_eq!(metadata.latest_block_number, result.block.header().number);
+        assert_eq!(metadata.latest_block_hash, *result.block.hash());
+        assert!(metadata.forked_network.is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn metadata_instance_id_changes_on_reset() -> anyhow::Result<()> {
+        let mut fixture = ProviderTestFixture::new_local()?;
+
+        let metadata = handle_metadata_request(&fixture.provider_data)?;
+
+        fixture.provider_data.reset(None)?;
+
+        let reset_metadata = handle_metadata_request(&fixture.provider_data)?;
+        assert_ne!(reset_metadata.instance_id, metadata.instance_id);
+        assert_eq!(reset_metadata.chain_id, metadata.chain_id);
+        assert_eq!(reset_metadata.latest_block_number, 0);
+
+        Ok(())
+    }
+
+    fn fixture_with_chain_id(chain_id: u64) -> anyhow::Result<ProviderTestFixture> {
+        let mut config = create_test_config();
+        config.chain_id = chain_id;
+
+        ProviderTestFixture::new(config)
+    }
+
+    #[test]
+    fn safe_block_number_mainnet() -> anyhow::Result<()> {
+        let mut fixture = fixture_with_chain_id(1)?;
+
+        fixture.provider_data.mine_and_commit_blocks(32, 1)?;
+        let latest_block_number = fixture.provider_data.last_block_number();
+        assert_eq!(latest_block_number, 32);
+
+        // Mainnet blocks are safe 32 blocks deep
+        let safe_block_number = handle_safe_block_number_request(&fixture.provider_data)?;
+        assert_eq!(safe_block_number, Some(U64::ZERO));
+
+        fixture.provider_data.mine_and_commit_blocks(8, 1)?;
+        let safe_block_number = handle_safe_block_number_request(&fixture.provider_data)?;
+        assert_eq!(safe_block_number, Some(U64::from(8)));
+
+        Ok(())
+    }
+
+    #[test]
+    fn safe_block_number_custom_chain() -> anyhow::Result<()> {
+        // Unknown chains use the default safe block depth of 128 blocks
+        let mut fixture = fixture_with_chain_id(31_337)?;
+
+        fixture.provider_data.mine_and_commit_blocks(127, 1)?;
+        let safe_block_number = handle_safe_block_number_request(&fixture.provider_data)?;
+        assert_eq!(safe_block_number, None);
+
+        fixture.provider_data.mine_and_commit_blocks(3, 1)?;
+        let safe_block_number = handle_safe_block_number_request(&fixture.provider_data)?;
+        assert_eq!(safe_block_number, Some(U64::from(2)));
+
+        Ok(())
+    }
+
+    #[test]
+    fn safe_block_number_with_reorg_config() -> anyhow::Result<()> {
+        let mut config = create_test_config();
+        config.chain_id = 31_337;
+        config.reorg_config = Some([(31_337, 4)].into_iter().collect());
+
+        let mut fixture = ProviderTestFixture::new(config)?;
+
+        fixture.provider_data.mine_and_commit_blocks(3, 1)?;
+        let safe_block_number = handle_safe_block_number_request(&fixture.provider_data)?;
+        assert_eq!(safe_block_number, None);
+
+        fixture.provider_data.mine_and_commit_blocks(3, 1)?;
+        let safe_block_number = handle_safe_block_number_request(&fixture.provider_data)?;
+        assert_eq!(safe_block_number, Some(U64::from(2)));
+
+        Ok(())
+    }
+
+    #[test]
+    fn set_prev_randao_changes_prevrandao_opcode() -> anyhow::Result<()> {
+        // PREVRANDAO PUSH1 0x00 SSTORE STOP
+        const PREVRANDAO_CODE: [u8; 5] = [0x44, 0x60, 0x00, 0x55, 0x00];
+
+        let mut fixture = ProviderTestFixture::new_local()?;
+
+        // The dummy transaction calls the zero address
+        let contract_address = Address::ZERO;
+        fixture
+            .provider_data
+            .set_code(contract_address, Bytes::from_static(&PREVRANDAO_CODE))?;
+
+        let prev_randao = B256::repeat_byte(0x42);
+        assert!(handle_set_prev_randao_request(
+            &mut fixture.provider_data,
+            prev_randao
+        )?);
+
+        let transaction = fixture.dummy_transaction_request(0, 100_000, None)?;
+        let transaction = fixture
+            .provider_data
+            .sign_transaction_request(transaction)?;
+        fixture.provider_data.send_transaction(transaction)?;
+
+        let last_block = fixture.provider_data.last_block()?;
+        assert_eq!(last_block.header().mix_hash, prev_randao);
+
+        let stored_value =
+            fixture
+                .provider_data
+                .get_storage_at(contract_address, U256::ZERO, None)?;
+        assert_eq!(stored_value, U256::from_be_bytes(prev_randao.0));
+
+        Ok(())
+    }
+
+    #[test]
+    fn set_next_block_difficulty_pre_merge() -> anyhow::Result<()> {
+        let mut fixture = ProviderTestFixture::new(ProviderConfig {
+            hardfork: SpecId::BERLIN,
+            ..create_test_config()
+        })?;
+
+        let difficulty = U256::from(123_456_789u64);
+        assert!(handle_set_next_block_difficulty_request(
+            &mut fixture.provider_data,
+            difficulty
+        )?);
+
+        let result = fixture
+            .provider_data
+            .mine_and_commit_block(BlockOptions::default())?;
+        assert_eq!(result.block.header().difficulty, difficulty);
+
+        // The override only applies to the next block
+        let result = fixture
+            .provider_data
+            .mine_and_commit_block(BlockOptions::default())?;
+        assert_ne!(result.block.header().difficulty, difficulty);
+
+        Ok(())
+    }
+
+    #[test]
+    fn set_next_block_difficulty_post_merge() -> anyhow::Result<()> {
+        let mut fixture = ProviderTestFixture::new_local()?;
+
+        let result =
+            handle_set_next_block_difficulty_request(&mut fixture.provider_data, U256::from(1));
+        assert!(matches!(
+            result,
+            Err(ProviderError::SetNextBlockDifficultyUnsupported { .. })
+        ));
+
+        Ok(())
+    }
+}
@@ -1,5 +1,6 @@
 use core::fmt::Debug;
 
+use edr_eth::U64;
 use edr_evm::trace::Trace;
 
 use crate::{data::ProviderData, ProviderError};
@@ -32,3 +33,12 @@ pub fn handle_mine<LoggerErrorT: Debug>(
 
     Ok((true, traces))
 }
+
+pub fn handle_rollback_request<LoggerErrorT: Debug>(
+    data: &mut ProviderData<LoggerErrorT>,
+    block_number: U64,
+) -> Result<bool, ProviderError<LoggerErrorT>> {
+    data.rollback_to_block(block_number.as_limbs()[0])?;
+
+    Ok(true)
+}
@@ -1,7 +1,11 @@
 mod compiler;
 mod config;
+mod fees;
 mod metadata;
+mod state;
 
 pub use compiler::{CompilerInput, CompilerInputSource, CompilerOutput, CompilerOutputContract};
 pub use config::{ForkConfig, ResetProviderConfig};
+pub use fees::GasFees;
 pub use metadata::{ForkMetadata, Metadata};
+pub use state::{AccountSummary, StateQuery, StateQueryKind, StateQueryResult};
@@ -1,4 +1,4 @@
-use std::collections::HashMap;
+use std::{collections::BTreeMap, fmt::Debug};
 
 #[derive(Clone, Debug, PartialEq, serde::Deserialize, serde::Serialize)]
 pub struct ResetProviderConfig {
@@ -6,10 +6,65 @@ pub struct ResetProviderConfig {
 }
 
 /// Configuration for forking a blockchain
-#[derive(Clone, Debug, PartialEq, serde::Deserialize, serde::Serialize)]
+#[derive(Clone, PartialEq, serde::Deserialize, serde::Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ForkConfig {
     pub json_rpc_url: String,
     pub block_number: Option<u64>,
-    pub http_headers: Option<HashMap<String, String>>,
+    /// HTTP headers that are attached to every JSON-RPC request sent to the
+    /// remote node, ordered by name.
+    pub http_headers: Option<BTreeMap<String, String>>,
+}
+
+impl Debug for ForkConfig {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        /// Header values commonly contain credentials, so they are redacted.
+        struct RedactedHeaders<'a>(&'a BTreeMap<String, String>);
+
+        impl Debug for RedactedHeaders<'_> {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.debug_map()
+                    .entries(self.0.keys().map(|name| (name, "<redacted>")))
+                    .finish()
+            }
+        }
+
+        f.debug_struct("ForkConfig")
+            .field("json_rpc_url", &self.json_rpc_url)
+            .field("block_number", &self.block_number)
+            .field(
+                "http_headers",
+                &self.http_headers.as_ref().map(RedactedHeaders),
+            )
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn debug_redacts_http_header_values() {
+        let config = ForkConfig {
+            json_rpc_url: String::from("http://localhost:8545"),
+            block_number: Some(1),
+            http_headers: Some(
+                [
+                    (
+                        String::from("Authorization"),
+                        String::from("Bearer secret-token"),
+                    ),
+                    (String::from("x-api-key"), String::from("secret-key")),
+                ]
+                .into_iter()
+                .collect(),
+            ),
+        };
+
+        let debug = format!("{config:?}");
+        assert!(debug.contains("Authorization"));
+        assert!(debug.contains("x-api-key"));
+        assert!(!debug.contains("secret"));
+    }
 }
@@ -0,0 +1,15 @@
+use edr_eth::U256;
+
+/// Suggested fees for a transaction to be included in the next block.
+#[derive(Clone, Debug, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GasFees {
+    /// The base fee per gas of the next block
+    pub base_fee_per_gas: U256,
+    /// The suggested max priority fee per gas, based on the tips of recent
+    /// transactions
+    pub max_priority_fee_per_gas: U256,
+    /// The suggested max fee per gas, which leaves room for the base fee to
+    /// double
+    pub max_fee_per_gas: U256,
+}
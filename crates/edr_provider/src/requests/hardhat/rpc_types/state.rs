@@ -0,0 +1,60 @@
+use edr_eth::{remote::BlockSpec, Address, Bytes, B256, U256};
+
+/// The kind of state that is queried by a [`StateQuery`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum StateQueryKind {
+    /// The account's balance
+    Balance,
+    /// The account's code
+    Code,
+    /// The value of one of the account's storage slots
+    Storage,
+}
+
+/// A single query that is part of a `hardhat_batchQueryState` request.
+#[derive(Clone, Debug, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StateQuery {
+    /// The kind of state to query
+    pub kind: StateQueryKind,
+    /// The address of the account
+    #[serde(deserialize_with = "crate::requests::serde::deserialize_address")]
+    pub address: Address,
+    /// The storage slot. Required for [`StateQueryKind::Storage`] queries and
+    /// ignored otherwise.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub slot: Option<U256>,
+    /// The block in which to execute the query. Defaults to the latest block.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub block: Option<BlockSpec>,
+}
+
+/// The result of a [`StateQuery`], serialized identically to the result of
+/// the corresponding `eth_getBalance`, `eth_getCode`, or `eth_getStorageAt`
+/// request.
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize)]
+#[serde(untagged)]
+pub enum StateQueryResult {
+    /// The account's balance
+    Balance(U256),
+    /// The account's code
+    Code(Bytes),
+    /// The value of the storage slot, as a 32-byte padded hex string
+    Storage(String),
+}
+
+/// An account's balance, nonce, and code hash, as returned by a
+/// `hardhat_getAccounts` request.
+#[derive(Clone, Debug, Default, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountSummary {
+    /// The account's balance
+    pub balance: U256,
+    /// The account's nonce
+    #[serde(with = "edr_eth::serde::u64")]
+    pub nonce: u64,
+    /// The account's code hash, as returned by the `EXTCODEHASH` opcode. Zero
+    /// for empty and non-existent accounts.
+    pub code_hash: B256,
+}
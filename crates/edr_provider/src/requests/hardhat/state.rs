@@ -1,8 +1,74 @@
 use core::fmt::Debug;
 
-use edr_eth::{Address, Bytes, U256};
+use edr_eth::{remote::BlockSpec, Address, Bytes, B256, U256};
 
-use crate::{data::ProviderData, ProviderError};
+use super::rpc_types::{AccountSummary, StateQuery, StateQueryKind, StateQueryResult};
+use crate::{
+    data::ProviderData,
+    requests::{eth, validation::validate_post_merge_block_tags},
+    ProviderError,
+};
+
+/// Executes a batch of balance, code, and storage queries in a single request,
+/// returning the results in the same order as the queries.
+pub fn handle_batch_query_state_request<LoggerErrorT: Debug>(
+    data: &mut ProviderData<LoggerErrorT>,
+    queries: Vec<StateQuery>,
+) -> Result<Vec<StateQueryResult>, ProviderError<LoggerErrorT>> {
+    queries
+        .into_iter()
+        .map(|query| {
+            let StateQuery {
+                kind,
+                address,
+                slot,
+                block,
+            } = query;
+
+            match kind {
+                StateQueryKind::Balance => eth::handle_get_balance_request(data, address, block)
+                    .map(StateQueryResult::Balance),
+                StateQueryKind::Code => {
+                    eth::handle_get_code_request(data, address, block).map(StateQueryResult::Code)
+                }
+                StateQueryKind::Storage => {
+                    let slot = slot.ok_or_else(|| {
+                        ProviderError::InvalidArgument(format!(
+                            "Missing storage slot for storage query of address {address}"
+                        ))
+                    })?;
+
+                    eth::handle_get_storage_at_request(data, address, slot, block)
+                        .map(StateQueryResult::Storage)
+                }
+            }
+        })
+        .collect()
+}
+
+pub fn handle_get_accounts_request<LoggerErrorT: Debug>(
+    data: &mut ProviderData<LoggerErrorT>,
+    addresses: Vec<Address>,
+    block_spec: Option<BlockSpec>,
+) -> Result<Vec<AccountSummary>, ProviderError<LoggerErrorT>> {
+    if let Some(block_spec) = block_spec.as_ref() {
+        validate_post_merge_block_tags(data.spec_id(), block_spec)?;
+    }
+
+    data.get_accounts(&addresses, block_spec.as_ref())
+}
+
+pub fn handle_get_code_hash_request<LoggerErrorT: Debug>(
+    data: &mut ProviderData<LoggerErrorT>,
+    address: Address,
+    block_spec: Option<BlockSpec>,
+) -> Result<B256, ProviderError<LoggerErrorT>> {
+    if let Some(block_spec) = block_spec.as_ref() {
+        validate_post_merge_block_tags(data.spec_id(), block_spec)?;
+    }
+
+    data.get_code_hash(address, block_spec.as_ref())
+}
 
 pub fn handle_set_balance<LoggerErrorT: Debug>(
     data: &mut ProviderData<LoggerErrorT>,
@@ -44,3 +110,161 @@ pub fn handle_set_storage_at<LoggerErrorT: Debug>(
 
     Ok(true)
 }
+
+pub fn handle_set_storage_bulk<LoggerErrorT: Debug>(
+    data: &mut ProviderData<LoggerErrorT>,
+    address: Address,
+    entries: Vec<(U256, U256)>,
+) -> Result<bool, ProviderError<LoggerErrorT>> {
+    data.set_account_storage_slots(address, &entries)?;
+
+    Ok(true)
+}
+
+#[cfg(test)]
+mod tests {
+    use edr_eth::utils::u256_to_padded_hex;
+    use edr_evm::KECCAK_EMPTY;
+
+    use super::*;
+    use crate::{data::test_utils::ProviderTestFixture, test_utils::one_ether};
+
+    #[test]
+    fn batch_query_state() -> anyhow::Result<()> {
+        let mut fixture = ProviderTestFixture::new_local()?;
+
+        let accounts = [Address::random(), Address::random(), Address::random()];
+        let slot = U256::from(1);
+
+        let mut queries = Vec::new();
+        for (index, address) in accounts.iter().enumerate() {
+            let multiplier = U256::from(index + 1);
+
+            fixture
+                .provider_data
+                .set_balance(*address, one_ether() * multiplier)?;
+            fixture
+                .provider_data
+                .set_account_storage_slot(*address, slot, multiplier)?;
+
+            queries.push(StateQuery {
+                kind: StateQueryKind::Balance,
+                address: *address,
+                slot: None,
+                block: None,
+            });
+            queries.push(StateQuery {
+                kind: StateQueryKind::Storage,
+                address: *address,
+                slot: Some(slot),
+                block: Some(BlockSpec::latest()),
+            });
+        }
+
+        let results = handle_batch_query_state_request(&mut fixture.provider_data, queries)?;
+
+        let expected = (1..=accounts.len())
+            .flat_map(|multiplier| {
+                let multiplier = U256::from(multiplier);
+                [
+                    StateQueryResult::Balance(one_ether() * multiplier),
+                    StateQueryResult::Storage(u256_to_padded_hex(&multiplier)),
+                ]
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(results, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn batch_query_state_storage_without_slot() -> anyhow::Result<()> {
+        let mut fixture = ProviderTestFixture::new_local()?;
+
+        let result = handle_batch_query_state_request(
+            &mut fixture.provider_data,
+            vec![StateQuery {
+                kind: StateQueryKind::Storage,
+                address: Address::random(),
+                slot: None,
+                block: None,
+            }],
+        );
+        assert!(matches!(result, Err(ProviderError::InvalidArgument(_))));
+
+        Ok(())
+    }
+
+    #[test]
+    fn get_accounts_preserves_order_and_defaults() -> anyhow::Result<()> {
+        let mut fixture = ProviderTestFixture::new_local()?;
+
+        let funded = Address::random();
+        let contract = Address::random();
+        let missing = Address::random();
+
+        fixture.provider_data.set_balance(funded, one_ether())?;
+        fixture.provider_data.set_nonce(funded, 3)?;
+        fixture
+            .provider_data
+            .set_code(contract, Bytes::from_static(&[0x60, 0x00]))?;
+
+        let expected_code_hash = fixture.provider_data.get_code_hash(contract, None)?;
+
+        let accounts = handle_get_accounts_request(
+            &mut fixture.provider_data,
+            vec![missing, contract, funded],
+            None,
+        )?;
+
+        assert_eq!(
+            accounts,
+            vec![
+                AccountSummary::default(),
+                AccountSummary {
+                    balance: U256::ZERO,
+                    nonce: 0,
+                    code_hash: expected_code_hash,
+                },
+                AccountSummary {
+                    balance: one_ether(),
+                    nonce: 3,
+                    code_hash: KECCAK_EMPTY,
+                },
+            ]
+        );
+        assert_ne!(expected_code_hash, B256::ZERO);
+
+        Ok(())
+    }
+
+    #[test]
+    fn set_storage_bulk_seeds_many_slots() -> anyhow::Result<()> {
+        let mut fixture = ProviderTestFixture::new_local()?;
+
+        let address = Address::random();
+        let entries = (0..1000u64)
+            .map(|index| (U256::from(index), U256::from(index * 2 + 1)))
+            .collect::<Vec<_>>();
+
+        assert!(handle_set_storage_bulk(
+            &mut fixture.provider_data,
+            address,
+            entries
+        )?);
+
+        for index in [0u64, 1, 499, 998, 999] {
+            let value = fixture
+                .provider_data
+                .get_storage_at(address, U256::from(index), None)?;
+            assert_eq!(value, U256::from(index * 2 + 1));
+        }
+
+        let untouched = fixture
+            .provider_data
+            .get_storage_at(address, U256::from(1000), None)?;
+        assert_eq!(untouched, U256::ZERO);
+
+        Ok(())
+    }
+}
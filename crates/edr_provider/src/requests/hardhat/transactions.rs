@@ -20,3 +20,135 @@ pub fn handle_drop_transaction<LoggerErrorT: Debug>(
         Ok(false)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use edr_eth::block::BlockOptions;
+    use edr_evm::Block;
+
+    use super::*;
+    use crate::data::test_utils::ProviderTestFixture;
+
+    fn create_fixture() -> anyhow::Result<ProviderTestFixture> {
+        let mut fixture = ProviderTestFixture::new_local()?;
+        fixture.provider_data.set_auto_mining(false);
+
+        Ok(fixture)
+    }
+
+    #[test]
+    fn drop_pending_transaction_queues_subsequent_nonces() -> anyhow::Result<()> {
+        let mut fixture = create_fixture()?;
+
+        let first = fixture.signed_dummy_transaction(0, Some(0))?;
+        let first_hash = fixture
+            .provider_data
+            .send_transaction(first)?
+            .transaction_hash;
+
+        let second = fixture.signed_dummy_transaction(0, Some(1))?;
+        let second_hash = fixture
+            .provider_data
+            .send_transaction(second)?
+            .transaction_hash;
+
+        assert!(handle_drop_transaction(
+            &mut fixture.provider_data,
+            first_hash
+        )?);
+        assert!(fixture
+            .provider_data
+            .transaction_by_hash(&first_hash)?
+            .is_none());
+        assert!(fixture
+            .provider_data
+            .transaction_by_hash(&second_hash)?
+            .is_some());
+
+        // The second transaction's nonce is no longer executable, so it is not mined
+        let result = fixture
+            .provider_data
+            .mine_and_commit_block(BlockOptions::default())?;
+        assert!(result.block.transactions().is_empty());
+        assert_eq!(fixture.provider_data.pending_transactions().count(), 1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn drop_queued_transaction() -> anyhow::Result<()> {
+        let mut fixture = create_fixture()?;
+
+        let pending = fixture.signed_dummy_transaction(0, Some(0))?;
+        let pending_hash = fixture
+            .provider_data
+            .send_transaction(pending)?
+            .transaction_hash;
+
+        let queued = fixture.signed_dummy_transaction(0, Some(2))?;
+        let queued_hash = fixture
+            .provider_data
+            .send_transaction(queued)?
+            .transaction_hash;
+
+        assert!(handle_drop_transaction(
+            &mut fixture.provider_data,
+            queued_hash
+        )?);
+        assert!(fixture
+            .provider_data
+            .transaction_by_hash(&queued_hash)?
+            .is_none());
+
+        // Dropping it a second time is a no-op
+        assert!(!handle_drop_transaction(
+            &mut fixture.provider_data,
+            queued_hash
+        )?);
+
+        let result = fixture
+            .provider_data
+            .mine_and_commit_block(BlockOptions::default())?;
+        assert_eq!(result.block.transactions().len(), 1);
+        assert_eq!(*result.block.transactions()[0].hash(), pending_hash);
+        assert_eq!(fixture.provider_data.pending_transactions().count(), 0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn drop_unknown_transaction() -> anyhow::Result<()> {
+        let mut fixture = create_fixture()?;
+
+        assert!(!handle_drop_transaction(
+            &mut fixture.provider_data,
+            B256::random()
+        )?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn drop_mined_transaction() -> anyhow::Result<()> {
+        let mut fixture = create_fixture()?;
+
+        let transaction = fixture.signed_dummy_transaction(0, None)?;
+        let transaction_hash = fixture
+            .provider_data
+            .send_transaction(transaction)?
+            .transaction_hash;
+
+        fixture
+            .provider_data
+            .mine_and_commit_block(BlockOptions::default())?;
+
+        let error = handle_drop_transaction(&mut fixture.provider_data, transaction_hash)
+            .expect_err("mined transactions cannot be dropped");
+        assert!(matches!(
+            error,
+            ProviderError::InvalidDropTransactionHash(hash) if hash == transaction_hash
+        ));
+
+        Ok(())
+    }
+}
@@ -13,7 +13,7 @@ use ethers_core::types::transaction::eip712::TypedData;
 use super::serde::RpcAddress;
 use crate::requests::{
     debug::DebugTraceConfig,
-    hardhat::rpc_types::{CompilerInput, CompilerOutput, ResetProviderConfig},
+    hardhat::rpc_types::{CompilerInput, CompilerOutput, ResetProviderConfig, StateQuery},
 };
 
 mod optional_block_spec {
@@ -55,6 +55,16 @@ pub enum MethodInvocation {
     /// eth_coinbase
     #[serde(rename = "eth_coinbase", with = "edr_eth::serde::empty_params")]
     Coinbase(()),
+    /// eth_createAccessList
+    #[serde(rename = "eth_createAccessList")]
+    CreateAccessList(
+        CallRequest,
+        #[serde(
+            skip_serializing_if = "Option::is_none",
+            default = "optional_block_spec::latest"
+        )]
+        Option<BlockSpec>,
+    ),
     /// eth_estimateGas
     #[serde(rename = "eth_estimateGas")]
     EstimateGas(
@@ -104,6 +114,9 @@ pub enum MethodInvocation {
         /// include transaction data
         bool,
     ),
+    /// eth_getBlockReceipts
+    #[serde(rename = "eth_getBlockReceipts", with = "edr_eth::serde::sequence")]
+    GetBlockReceipts(BlockSpec),
     /// eth_getBlockTransactionCountByHash
     #[serde(
         rename = "eth_getBlockTransactionCountByHash",
@@ -135,6 +148,13 @@ pub enum MethodInvocation {
     /// eth_getLogs
     #[serde(rename = "eth_getLogs", with = "edr_eth::serde::sequence")]
     GetLogs(LogFilterOptions),
+    /// eth_getProof
+    #[serde(rename = "eth_getProof")]
+    GetProof(
+        #[serde(deserialize_with = "crate::requests::serde::deserialize_address")] Address,
+        #[serde(deserialize_with = "crate::requests::serde::deserialize_storage_slots")] Vec<U256>,
+        BlockSpec,
+    ),
     /// eth_getStorageAt
     #[serde(rename = "eth_getStorageAt")]
     GetStorageAt(
@@ -270,6 +290,9 @@ pub enum MethodInvocation {
         with = "edr_eth::serde::sequence"
     )]
     EvmSetNextBlockTimestamp(U64OrUsize),
+    /// evm_setTime
+    #[serde(rename = "evm_setTime", with = "edr_eth::serde::sequence")]
+    EvmSetTime(U64OrUsize),
     /// evm_snapshot
     #[serde(rename = "evm_snapshot", with = "edr_eth::serde::empty_params")]
     EvmSnapshot(()),
@@ -285,6 +308,13 @@ pub enum MethodInvocation {
     #[serde(rename = "debug_traceTransaction")]
     DebugTraceTransaction(B256, #[serde(default)] Option<DebugTraceConfig>),
 
+    /// txpool_content
+    #[serde(rename = "txpool_content", with = "edr_eth::serde::empty_params")]
+    TxpoolContent(()),
+    /// txpool_status
+    #[serde(rename = "txpool_status", with = "edr_eth::serde::empty_params")]
+    TxpoolStatus(()),
+
     /// hardhat_addCompilationResult
     #[serde(rename = "hardhat_addCompilationResult")]
     AddCompilationResult(
@@ -293,12 +323,38 @@ pub enum MethodInvocation {
         CompilerInput,
         CompilerOutput,
     ),
+    /// hardhat_batchQueryState
+    #[serde(rename = "hardhat_batchQueryState", with = "edr_eth::serde::sequence")]
+    BatchQueryState(Vec<StateQuery>),
     /// hardhat_dropTransaction
     #[serde(rename = "hardhat_dropTransaction", with = "edr_eth::serde::sequence")]
     DropTransaction(B256),
+    /// hardhat_gasFees
+    #[serde(rename = "hardhat_gasFees", with = "edr_eth::serde::empty_params")]
+    GasFees(()),
+    /// hardhat_getAccounts
+    #[serde(rename = "hardhat_getAccounts")]
+    GetAccounts(
+        Vec<Address>,
+        #[serde(
+            skip_serializing_if = "Option::is_none",
+            default = "optional_block_spec::latest"
+        )]
+        Option<BlockSpec>,
+    ),
     /// hardhat_getAutomine
     #[serde(rename = "hardhat_getAutomine", with = "edr_eth::serde::empty_params")]
     GetAutomine(()),
+    /// hardhat_getCodeHash
+    #[serde(rename = "hardhat_getCodeHash")]
+    GetCodeHash(
+        #[serde(deserialize_with = "crate::requests::serde::deserialize_address")] Address,
+        #[serde(
+            skip_serializing_if = "Option::is_none",
+            default = "optional_block_spec::latest"
+        )]
+        Option<BlockSpec>,
+    ),
     /// hardhat_getStackTraceFailuresCount
     #[serde(
         rename = "hardhat_getStackTraceFailuresCount",
@@ -338,12 +394,24 @@ pub enum MethodInvocation {
         deserialize_with = "sequence_to_optional_single"
     )]
     Reset(Option<ResetProviderConfig>),
+    /// hardhat_rollback
+    #[serde(rename = "hardhat_rollback", with = "edr_eth::serde::sequence")]
+    Rollback(U64),
+    /// hardhat_safeBlockNumber
+    #[serde(
+        rename = "hardhat_safeBlockNumber",
+        with = "edr_eth::serde::empty_params"
+    )]
+    SafeBlockNumber(()),
     /// hardhat_setBalance
     #[serde(rename = "hardhat_setBalance")]
     SetBalance(
         #[serde(deserialize_with = "crate::requests::serde::deserialize_address")] Address,
         #[serde(deserialize_with = "crate::requests::serde::deserialize_quantity")] U256,
     ),
+    /// hardhat_setBlockGasLimit
+    #[serde(rename = "hardhat_setBlockGasLimit", with = "edr_eth::serde::sequence")]
+    SetBlockGasLimit(U256),
     /// hardhat_setCode
     #[serde(rename = "hardhat_setCode")]
     SetCode(
@@ -368,6 +436,12 @@ pub enum MethodInvocation {
         with = "edr_eth::serde::sequence"
     )]
     SetNextBlockBaseFeePerGas(U256),
+    /// hardhat_setNextBlockDifficulty
+    #[serde(
+        rename = "hardhat_setNextBlockDifficulty",
+        with = "edr_eth::serde::sequence"
+    )]
+    SetNextBlockDifficulty(U256),
     /// hardhat_setNonce
     #[serde(rename = "hardhat_setNonce")]
     SetNonce(
@@ -388,6 +462,12 @@ pub enum MethodInvocation {
         #[serde(deserialize_with = "crate::requests::serde::deserialize_storage_key")] U256,
         #[serde(with = "crate::requests::serde::storage_value")] U256,
     ),
+    /// hardhat_setStorageBulk
+    #[serde(rename = "hardhat_setStorageBulk")]
+    SetStorageBulk(
+        #[serde(deserialize_with = "crate::requests::serde::deserialize_address")] Address,
+        #[serde(with = "crate::requests::serde::storage_entries")] Vec<(U256, U256)>,
+    ),
     /// hardhat_stopImpersonatingAccount
     #[serde(
         rename = "hardhat_stopImpersonatingAccount",
@@ -405,12 +485,14 @@ impl MethodInvocation {
             MethodInvocation::Call(_, _, _) => "eth_call",
             MethodInvocation::ChainId(_) => "eth_chainId",
             MethodInvocation::Coinbase(_) => "eth_coinbase",
+            MethodInvocation::CreateAccessList(_, _) => "eth_createAccessList",
             MethodInvocation::EstimateGas(_, _) => "eth_estimateGas",
             MethodInvocation::FeeHistory(_, _, _) => "eth_feeHistory",
             MethodInvocation::GasPrice(_) => "eth_gasPrice",
             MethodInvocation::GetBalance(_, _) => "eth_getBalance",
             MethodInvocation::GetBlockByNumber(_, _) => "eth_getBlockByNumber",
             MethodInvocation::GetBlockByHash(_, _) => "eth_getBlockByHash",
+            MethodInvocation::GetBlockReceipts(_) => "eth_getBlockReceipts",
             MethodInvocation::GetBlockTransactionCountByHash(_) => {
                 "eth_getBlockTransactionCountByHash"
             }
@@ -421,6 +503,7 @@ impl MethodInvocation {
             MethodInvocation::GetFilterChanges(_) => "eth_getFilterChanges",
             MethodInvocation::GetFilterLogs(_) => "eth_getFilterLogs",
             MethodInvocation::GetLogs(_) => "eth_getLogs",
+            MethodInvocation::GetProof(_, _, _) => "eth_getProof",
             MethodInvocation::GetStorageAt(_, _, _) => "eth_getStorageAt",
             MethodInvocation::GetTransactionByBlockHashAndIndex(_, _) => {
                 "eth_getTransactionByBlockHashAndIndex"
@@ -456,27 +539,39 @@ impl MethodInvocation {
             MethodInvocation::EvmSetBlockGasLimit(_) => "evm_setBlockGasLimit",
             MethodInvocation::EvmSetIntervalMining(_) => "evm_setIntervalMining",
             MethodInvocation::EvmSetNextBlockTimestamp(_) => "evm_setNextBlockTimestamp",
+            MethodInvocation::EvmSetTime(_) => "evm_setTime",
             MethodInvocation::EvmSnapshot(_) => "evm_snapshot",
             MethodInvocation::DebugTraceCall(_, _, _) => "debug_traceCall",
             MethodInvocation::DebugTraceTransaction(_, _) => "debug_traceTransaction",
+            MethodInvocation::TxpoolContent(_) => "txpool_content",
+            MethodInvocation::TxpoolStatus(_) => "txpool_status",
             MethodInvocation::AddCompilationResult(_, _, _) => "hardhat_addCompilationResult",
+            MethodInvocation::BatchQueryState(_) => "hardhat_batchQueryState",
             MethodInvocation::DropTransaction(_) => "hardhat_dropTransaction",
+            MethodInvocation::GasFees(_) => "hardhat_gasFees",
+            MethodInvocation::GetAccounts(_, _) => "hardhat_getAccounts",
             MethodInvocation::GetAutomine(_) => "hardhat_getAutomine",
+            MethodInvocation::GetCodeHash(_, _) => "hardhat_getCodeHash",
             MethodInvocation::GetStackTraceFailuresCount(_) => "hardhat_getStackTraceFailuresCount",
             MethodInvocation::ImpersonateAccount(_) => "hardhat_impersonateAccount",
             MethodInvocation::IntervalMine(_) => "hardhat_intervalMine",
             MethodInvocation::Metadata(_) => "hardhat_metadata",
             MethodInvocation::Mine(_, _) => "hardhat_mine",
             MethodInvocation::Reset(_) => "hardhat_reset",
+            MethodInvocation::Rollback(_) => "hardhat_rollback",
+            MethodInvocation::SafeBlockNumber(_) => "hardhat_safeBlockNumber",
             MethodInvocation::SetBalance(_, _) => "hardhat_setBalance",
+            MethodInvocation::SetBlockGasLimit(_) => "hardhat_setBlockGasLimit",
             MethodInvocation::SetCode(_, _) => "hardhat_setCode",
             MethodInvocation::SetCoinbase(_) => "hardhat_setCoinbase",
             MethodInvocation::SetLoggingEnabled(_) => "hardhat_setLoggingEnabled",
             MethodInvocation::SetMinGasPrice(_) => "hardhat_setMinGasPrice",
             MethodInvocation::SetNextBlockBaseFeePerGas(_) => "hardhat_setNextBlockBaseFeePerGas",
+            MethodInvocation::SetNextBlockDifficulty(_) => "hardhat_setNextBlockDifficulty",
             MethodInvocation::SetNonce(_, _) => "hardhat_setNonce",
             MethodInvocation::SetPrevRandao(_) => "hardhat_setPrevRandao",
             MethodInvocation::SetStorageAt(_, _, _) => "hardhat_setStorageAt",
+            MethodInvocation::SetStorageBulk(_, _) => "hardhat_setStorageBulk",
             MethodInvocation::StopImpersonatingAccount(_) => "hardhat_stopImpersonatingAccount",
         }
     }
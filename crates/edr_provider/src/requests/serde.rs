@@ -7,7 +7,7 @@ use std::{
 use edr_eth::{Address, Bytes, U256, U64};
 use serde::{Deserialize, Deserializer, Serialize};
 
-use crate::ProviderError;
+use crate::{error::error_code, ProviderError};
 
 #[derive(Clone, Debug, Deserialize, PartialEq, Eq, PartialOrd, Ord, Serialize)]
 #[repr(transparent)]
@@ -83,10 +83,10 @@ impl<'a> InvalidRequestReason<'a> {
 
     pub fn error_code(&self) -> i16 {
         match self {
-            InvalidRequestReason::UnsupportedMethod { .. } => -32004,
+            InvalidRequestReason::UnsupportedMethod { .. } => error_code::METHOD_NOT_SUPPORTED,
             InvalidRequestReason::InvalidStorageKey { .. }
-            | InvalidRequestReason::InvalidStorageValue { .. } => -32000,
-            InvalidRequestReason::InvalidJson { .. } => -32602,
+            | InvalidRequestReason::InvalidStorageValue { .. } => error_code::INVALID_INPUT,
+            InvalidRequestReason::InvalidJson { .. } => error_code::INVALID_PARAMS,
         }
     }
 
@@ -346,6 +346,26 @@ where
     Ok(result)
 }
 
+/// Helper function for deserializing a sequence of JSON-RPC storage slots,
+/// validating each like [`deserialize_storage_slot`].
+pub(crate) fn deserialize_storage_slots<'de, D>(deserializer: D) -> Result<Vec<U256>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let slots = Vec::<serde_json::Value>::deserialize(deserializer)?;
+
+    slots
+        .into_iter()
+        .enumerate()
+        .map(|(index, slot)| {
+            deserialize_storage_slot(slot).map_err(|error| {
+                // Appended, to retain the error message's prefix
+                serde::de::Error::custom(format!("{error} (storage slot at index {index})"))
+            })
+        })
+        .collect()
+}
+
 /// Helper module for serializing/deserializing the JSON-RPC data type,
 /// specialized for a storage value.
 pub(crate) mod storage_value {
@@ -410,6 +430,56 @@ pub(crate) mod storage_value {
     }
 }
 
+/// Helper module for (de)serializing the storage key-value pairs of a
+/// `hardhat_setStorageBulk` request. Each pair is validated like the
+/// parameters of `hardhat_setStorageAt`, reporting the index of the first
+/// invalid pair.
+pub(crate) mod storage_entries {
+    use serde::{de::IntoDeserializer, Serializer};
+
+    use super::{deserialize_storage_key, storage_value, Deserialize, Deserializer, U256};
+
+    pub fn deserialize<'de, DeserializerT>(
+        deserializer: DeserializerT,
+    ) -> Result<Vec<(U256, U256)>, DeserializerT::Error>
+    where
+        DeserializerT: Deserializer<'de>,
+    {
+        let entries = Vec::<(String, String)>::deserialize(deserializer)?;
+
+        entries
+            .into_iter()
+            .enumerate()
+            .map(|(index, (key, value))| {
+                // Appended, to retain the error message's prefix
+                let with_index = |error: serde::de::value::Error| {
+                    serde::de::Error::custom(format!("{error} (storage entry at index {index})"))
+                };
+
+                let key = deserialize_storage_key(key.into_deserializer()).map_err(with_index)?;
+                let value =
+                    storage_value::deserialize(value.into_deserializer()).map_err(with_index)?;
+
+                Ok((key, value))
+            })
+            .collect()
+    }
+
+    pub fn serialize<SerializerT>(
+        entries: &[(U256, U256)],
+        serializer: SerializerT,
+    ) -> Result<SerializerT::Ok, SerializerT::Error>
+    where
+        SerializerT: Serializer,
+    {
+        serializer.collect_seq(
+            entries
+                .iter()
+                .map(|(key, value)| (key, format!("0x{value:0>64x}"))),
+        )
+    }
+}
+
 /// Helper module for deserializing the payload of an `eth_signTypedData_v4`
 /// request. The types and the deserializer implementation are a patched version
 /// of [`ethers_core`](https://github.com/gakonst/ethers-rs/blob/5394d899adca736a602e316e6f0c06fdb5aa64b9/ethers-core/src/types/transaction/eip712.rs)
@@ -619,4 +689,47 @@ mod tests {
 
         assert_eq!(parsed.n, n);
     }
+
+    #[test]
+    fn deserialize_storage_entries_reports_invalid_index() {
+        #[derive(Debug, Deserialize)]
+        struct Test {
+            #[serde(with = "storage_entries")]
+            _entries: Vec<(U256, U256)>,
+        }
+
+        let valid_value = format!("0x{:0>64x}", 1);
+        let json = format!(r#"{{ "_entries": [["0x0", "{valid_value}"], ["0x1", "0x01"]] }}"#);
+
+        let error = serde_json::from_str::<Test>(&json).unwrap_err().to_string();
+        assert!(
+            error.starts_with(STORAGE_VALUE_INVALID_LENGTH_ERROR_MESSAGE),
+            "actual: {error}"
+        );
+        assert!(
+            error.contains("(storage entry at index 1)"),
+            "actual: {error}"
+        );
+    }
+
+    #[test]
+    fn deserialize_storage_slots_reports_invalid_index() {
+        #[derive(Debug, Deserialize)]
+        struct Test {
+            #[serde(deserialize_with = "deserialize_storage_slots")]
+            _slots: Vec<U256>,
+        }
+
+        let json = r#"{ "_slots": ["0x0", 1] }"#;
+
+        let error = serde_json::from_str::<Test>(json).unwrap_err().to_string();
+        assert!(
+            error.starts_with("Storage slot argument must be a string, got '1'"),
+            "actual: {error}"
+        );
+        assert!(
+            error.contains("(storage slot at index 1)"),
+            "actual: {error}"
+        );
+    }
 }
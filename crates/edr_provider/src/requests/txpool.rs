@@ -0,0 +1,138 @@
+use core::fmt::Debug;
+use std::collections::BTreeMap;
+
+use edr_eth::{remote, Address, SpecId};
+use edr_evm::ExecutableTransaction;
+
+use crate::{
+    data::{ProviderData, TransactionAndBlock},
+    requests::eth::transaction_to_rpc_result,
+    ProviderError,
+};
+
+/// Transactions grouped by sender and nonce.
+pub type TransactionsBySender = BTreeMap<Address, BTreeMap<u64, remote::eth::Transaction>>;
+
+/// The result of a `txpool_content` request.
+#[derive(Clone, Debug, PartialEq, serde::Deserialize, serde::Serialize)]
+pub struct TxpoolContent {
+    /// Transactions that can be included in the next block
+    pub pending: TransactionsBySender,
+    /// Transactions that have a nonce gap with the sender's account
+    pub queued: TransactionsBySender,
+}
+
+/// The result of a `txpool_status` request.
+#[derive(Clone, Debug, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+pub struct TxpoolStatus {
+    /// Number of transactions that can be included in the next block
+    #[serde(with = "edr_eth::serde::u64")]
+    pub pending: u64,
+    /// Number of transactions that have a nonce gap with the sender's account
+    #[serde(with = "edr_eth::serde::u64")]
+    pub queued: u64,
+}
+
+pub fn handle_txpool_content<LoggerErrorT: Debug>(
+    data: &ProviderData<LoggerErrorT>,
+) -> Result<TxpoolContent, ProviderError<LoggerErrorT>> {
+    let spec_id = data.spec_id();
+
+    Ok(TxpoolContent {
+        pending: group_by_sender(spec_id, data.executable_pending_transactions())?,
+        queued: group_by_sender(spec_id, data.queued_transactions())?,
+    })
+}
+
+pub fn handle_txpool_status<LoggerErrorT: Debug>(
+    data: &ProviderData<LoggerErrorT>,
+) -> Result<TxpoolStatus, ProviderError<LoggerErrorT>> {
+    Ok(TxpoolStatus {
+        pending: data.executable_pending_transactions().count() as u64,
+        queued: data.queued_transactions().count() as u64,
+    })
+}
+
+fn group_by_sender<'transaction, LoggerErrorT: Debug>(
+    spec_id: SpecId,
+    transactions: impl Iterator<Item = &'transaction ExecutableTransaction>,
+) -> Result<TransactionsBySender, ProviderError<LoggerErrorT>> {
+    let mut grouped = TransactionsBySender::new();
+    for transaction in transactions {
+        let rpc_transaction = transaction_to_rpc_result(
+            TransactionAndBlock {
+                transaction: transaction.clone(),
+                block_data: None,
+                is_pending: true,
+            },
+            spec_id,
+        )?;
+
+        grouped
+            .entry(*transaction.caller())
+            .or_default()
+            .insert(transaction.nonce(), rpc_transaction);
+    }
+
+    Ok(grouped)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::test_utils::ProviderTestFixture;
+
+    #[test]
+    fn nonce_gap_is_queued() -> anyhow::Result<()> {
+        let mut fixture = ProviderTestFixture::new_local()?;
+        fixture.provider_data.set_auto_mining(false);
+
+        let sender = fixture.nth_local_account(0)?;
+        for nonce in [0, 1, 3] {
+            let transaction = fixture.signed_dummy_transaction(0, Some(nonce))?;
+            fixture.provider_data.send_transaction(transaction)?;
+        }
+
+        let status = handle_txpool_status(&fixture.provider_data)?;
+        assert_eq!(
+            status,
+            TxpoolStatus {
+                pending: 2,
+                queued: 1
+            }
+        );
+
+        let content = handle_txpool_content(&fixture.provider_data)?;
+
+        let pending = content.pending.get(&sender).expect("sender has pending");
+        assert_eq!(pending.keys().copied().collect::<Vec<_>>(), vec![0, 1]);
+
+        let queued = content.queued.get(&sender).expect("sender has queued");
+        assert_eq!(queued.keys().copied().collect::<Vec<_>>(), vec![3]);
+        assert_eq!(queued[&3].from, sender);
+
+        Ok(())
+    }
+
+    #[test]
+    fn txpool_content_serializes_nonces_as_keys() -> anyhow::Result<()> {
+        let mut fixture = ProviderTestFixture::new_local()?;
+        fixture.provider_data.set_auto_mining(false);
+
+        let sender = fixture.nth_local_account(0)?;
+        let transaction = fixture.signed_dummy_transaction(0, Some(0))?;
+        fixture.provider_data.send_transaction(transaction)?;
+
+        let content = serde_json::to_value(handle_txpool_content(&fixture.provider_data)?)?;
+        let pending = content["pending"]
+            .as_object()
+            .expect("pending is an object");
+        assert_eq!(pending.len(), 1);
+
+        let transactions = pending.values().next().expect("sender exists");
+        assert_eq!(transactions["0"]["from"], serde_json::to_value(sender)?);
+        assert_eq!(content["queued"], serde_json::json!({}));
+
+        Ok(())
+    }
+}
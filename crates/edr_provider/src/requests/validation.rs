@@ -89,6 +89,14 @@ impl<'data> From<&'data SignedTransaction> for SpecValidationData<'data> {
                 blobs: None,
                 blob_hashes: Some(tx.blob_hashes.as_ref()),
             },
+            SignedTransaction::Eip7702(tx) => Self {
+                gas_price: None,
+                max_fee_per_gas: Some(&tx.max_fee_per_gas),
+                max_priority_fee_per_gas: Some(&tx.max_priority_fee_per_gas),
+                access_list: Some(tx.access_list.0.as_ref()),
+                blobs: None,
+                blob_hashes: None,
+            },
         }
     }
 }
@@ -210,6 +218,26 @@ Enable the 'allowUnlimitedContractSize' option to allow init codes of any length
     Ok(())
 }
 
+/// The minimum block gas limit, as defined in the yellow paper.
+pub const MIN_BLOCK_GAS_LIMIT: u64 = 5000;
+
+/// The minimum block gas limit of `hardhat_setBlockGasLimit`, which ensures
+/// that a block can at least fit a plain value transfer.
+pub const MIN_HARDHAT_BLOCK_GAS_LIMIT: u64 = 21_000;
+
+pub fn validate_block_gas_limit<LoggerErrorT: Debug>(
+    gas_limit: u64,
+    min_gas_limit: u64,
+) -> Result<(), ProviderError<LoggerErrorT>> {
+    if gas_limit < min_gas_limit {
+        return Err(ProviderError::InvalidInput(format!(
+            "Block gas limit must be at least {min_gas_limit}, but {gas_limit} was provided"
+        )));
+    }
+
+    Ok(())
+}
+
 pub enum ValidationBlockSpec<'a> {
     PreEip1898(&'a PreEip1898BlockSpec),
     PostEip1898(&'a BlockSpec),
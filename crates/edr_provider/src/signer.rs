@@ -0,0 +1,62 @@
+use core::fmt::Debug;
+
+use edr_eth::{
+    signature::{public_key_to_address, RecoveryMessage, Signature, SignatureError},
+    transaction::{SignedTransaction, TransactionRequest},
+    Address, B256,
+};
+
+/// A backend that signs hashes and transactions on behalf of an account.
+///
+/// Local accounts are signed for using an in-memory secret key, but other
+/// backends - e.g. hardware wallets or remote signers - can be plugged in by
+/// implementing this trait.
+pub trait Signer: Debug + Send + Sync {
+    /// Returns the address of the account.
+    fn address(&self) -> Address;
+
+    /// Signs the provided hash.
+    fn sign_hash(&self, hash: B256) -> Result<Signature, SignatureError>;
+
+    /// Signs the provided transaction request.
+    fn sign_transaction(
+        &self,
+        request: TransactionRequest,
+    ) -> Result<SignedTransaction, SignatureError>;
+}
+
+/// A signer that uses an in-memory secret key.
+#[derive(Debug)]
+pub struct LocalSigner {
+    address: Address,
+    secret_key: k256::SecretKey,
+}
+
+impl LocalSigner {
+    /// Constructs a new instance for the provided secret key.
+    pub fn new(secret_key: k256::SecretKey) -> Self {
+        let address = public_key_to_address(secret_key.public_key());
+
+        Self {
+            address,
+            secret_key,
+        }
+    }
+}
+
+impl Signer for LocalSigner {
+    fn address(&self) -> Address {
+        self.address
+    }
+
+    fn sign_hash(&self, hash: B256) -> Result<Signature, SignatureError> {
+        Signature::new(RecoveryMessage::Hash(hash), &self.secret_key)
+    }
+
+    fn sign_transaction(
+        &self,
+        request: TransactionRequest,
+    ) -> Result<SignedTransaction, SignatureError> {
+        request.sign(&self.secret_key)
+    }
+}
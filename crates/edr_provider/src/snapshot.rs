@@ -1,4 +1,4 @@
-use std::{collections::BTreeMap, time::Instant};
+use std::collections::BTreeMap;
 
 use edr_eth::{Address, U256};
 use edr_evm::{state::IrregularState, MemPool, RandomHashGenerator};
@@ -13,8 +13,10 @@ pub(crate) struct Snapshot {
     pub irregular_state: IrregularState,
     pub mem_pool: MemPool,
     pub next_block_base_fee_per_gas: Option<U256>,
+    pub next_block_difficulty: Option<U256>,
     pub next_block_timestamp: Option<u64>,
     pub parent_beacon_block_root_generator: RandomHashGenerator,
     pub prev_randao_generator: RandomHashGenerator,
-    pub time: Instant,
+    /// UNIX timestamp at which the snapshot was taken
+    pub timestamp: u64,
 }
@@ -2,7 +2,7 @@ use std::{convert::Infallible, time::SystemTime};
 
 use anyhow::anyhow;
 use edr_eth::{
-    block::{miner_reward, BlobGas, BlockOptions},
+    block::{BlobGas, BlockOptions},
     remote::{PreEip1898BlockSpec, RpcClient},
     signature::secret_key_from_str,
     spec::chain_hardfork_activations,
@@ -55,15 +55,21 @@ pub fn create_test_config_with_fork(fork: Option<ForkConfig>) -> ProviderConfig
         ],
         allow_blocks_with_same_timestamp: false,
         allow_unlimited_contract_size: false,
+        allow_zero_gas_price: true,
         bail_on_call_failure: false,
+        bail_on_call_without_code: false,
         bail_on_transaction_failure: false,
-        block_gas_limit: 30_000_000,
+        base_fee_params: None,
+        block_gas_limit: Some(30_000_000),
         chain_id: 123,
         chains: HashMap::new(),
         coinbase: Address::from(U160::from(1)),
         fork,
+        generate_access_lists_for_calls: false,
         genesis_accounts: HashMap::new(),
+        get_logs_block_range_limit: None,
         hardfork: SpecId::LATEST,
+        hardfork_activations: None,
         initial_base_fee_per_gas: Some(U256::from(1000000000)),
         initial_blob_gas: Some(BlobGas {
             gas_used: 0,
@@ -74,6 +80,9 @@ pub fn create_test_config_with_fork(fork: Option<ForkConfig>) -> ProviderConfig
         min_gas_price: U256::ZERO,
         mining: MiningConfig::default(),
         network_id: 123,
+        prev_randao_seed: None,
+        receipt_retention: None,
+        reorg_config: None,
         cache_dir: edr_defaults::CACHE_DIR.into(),
     }
 }
@@ -178,10 +187,7 @@ pub async fn run_full_block(url: String, block_number: u64, chain_id: u64) -> an
         result?;
     }
 
-    let rewards = vec![(
-        replay_header.beneficiary,
-        miner_reward(spec_id).unwrap_or(U256::ZERO),
-    )];
+    let rewards = builder.reward_beneficiary(replay_block.ommer_hashes().len() as u64);
     let mined_block = builder.finalize(&mut state, rewards)?;
 
     let mined_header = mined_block.block.header();
@@ -0,0 +1,179 @@
+use edr_eth::remote::jsonrpc;
+use edr_provider::{error_code, test_utils::create_test_config, NoopLogger, Provider};
+use serde_json::json;
+use tokio::runtime;
+
+const ADDRESS: &str = "0x0000000000000000000000000000000000000042";
+
+#[tokio::test(flavor = "multi_thread")]
+async fn batch_executes_requests_in_order() -> anyhow::Result<()> {
+    let config = create_test_config();
+    let logger = Box::new(NoopLogger);
+    let subscriber = Box::new(|_event| {});
+    let provider = Provider::new(runtime::Handle::current(), logger, subscriber, config)?;
+
+    let batch = json!([
+        {
+            "jsonrpc": "2.0",
+            "id": 1,
+            "method": "eth_getBalance",
+            "params": [ADDRESS, "latest"]
+        },
+        {
+            "jsonrpc": "2.0",
+            "id": "set",
+            "method": "hardhat_setBalance",
+            "params": [ADDRESS, "0x2a"]
+        },
+        {
+            "jsonrpc": "2.0",
+            "id": 3,
+            "method": "eth_getBalance",
+            "params": [ADDRESS, "latest"]
+        },
+        {
+            "jsonrpc": "2.0",
+            "id": 4,
+            "method": "eth_unknownMethod",
+            "params": []
+        },
+        {
+            "jsonrpc": "2.0",
+            "method": "eth_blockNumber",
+            "params": []
+        },
+    ]);
+
+    let response = provider.handle_batch_json_request(&batch.to_string())?;
+    let responses = response.responses;
+
+    // The notification without an id doesn't receive a response
+    assert_eq!(responses.len(), 4);
+
+    let ids: Vec<_> = responses
+        .iter()
+        .map(|response| response.id.clone())
+        .collect();
+    assert_eq!(
+        ids,
+        vec![
+            jsonrpc::Id::Num(1),
+            jsonrpc::Id::Str("set".to_string()),
+            jsonrpc::Id::Num(3),
+            jsonrpc::Id::Num(4),
+        ]
+    );
+
+    let mut responses = responses.into_iter().map(|response| response.data);
+
+    let balance_before = responses.next().expect("response exists").into_result()?;
+    assert_eq!(balance_before, json!("0x0"));
+
+    let set_balance = responses.next().expect("response exists").into_result()?;
+    assert_eq!(set_balance, json!(true));
+
+    let balance_after = responses.next().expect("response exists").into_result()?;
+    assert_eq!(balance_after, json!("0x2a"));
+
+    // A failing request doesn't abort the batch
+    let error = responses
+        .next()
+        .expect("response exists")
+        .into_result()
+        .expect_err("method is not supported");
+    assert_eq!(error.code, error_code::METHOD_NOT_SUPPORTED);
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn batch_responds_to_malformed_entries_with_null_id() -> anyhow::Result<()> {
+    let config = create_test_config();
+    let logger = Box::new(NoopLogger);
+    let subscriber = Box::new(|_event| {});
+    let provider = Provider::new(runtime::Handle::current(), logger, subscriber, config)?;
+
+    let batch = json!([
+        {
+            "jsonrpc": "2.0",
+            "id": 1,
+            "method": "eth_blockNumber",
+            "params": []
+        },
+        1,
+        {
+            "jsonrpc": "2.0",
+            "params": []
+        },
+        {
+            "jsonrpc": "2.0",
+            "id": 2,
+            "method": "eth_chainId",
+            "params": []
+        },
+    ]);
+
+    let response = provider.handle_batch_json_request(&batch.to_string())?;
+    let responses = response.responses;
+
+    assert_eq!(responses.len(), 4);
+
+    let ids: Vec<_> = responses
+        .iter()
+        .map(|response| response.id.clone())
+        .collect();
+    assert_eq!(
+        ids,
+        vec![
+            jsonrpc::Id::Num(1),
+            jsonrpc::Id::Null,
+            jsonrpc::Id::Null,
+            jsonrpc::Id::Num(2),
+        ]
+    );
+
+    let serialized = serde_json::to_value(&responses[1])?;
+    assert_eq!(serialized["id"], serde_json::Value::Null);
+
+    let mut responses = responses.into_iter().map(|response| response.data);
+
+    let block_number = responses.next().expect("response exists").into_result()?;
+    assert_eq!(block_number, json!("0x0"));
+
+    assert!(responses
+        .next()
+        .expect("response exists")
+        .into_result()
+        .is_err());
+    assert!(responses
+        .next()
+        .expect("response exists")
+        .into_result()
+        .is_err());
+
+    let chain_id = responses.next().expect("response exists").into_result()?;
+    assert!(chain_id.is_string());
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn batch_must_be_an_array() -> anyhow::Result<()> {
+    let config = create_test_config();
+    let logger = Box::new(NoopLogger);
+    let subscriber = Box::new(|_event| {});
+    let provider = Provider::new(runtime::Handle::current(), logger, subscriber, config)?;
+
+    let request = json!({
+        "jsonrpc": "2.0",
+        "id": 1,
+        "method": "eth_blockNumber",
+        "params": []
+    });
+
+    assert!(provider
+        .handle_batch_json_request(&request.to_string())
+        .is_err());
+
+    Ok(())
+}
@@ -4,7 +4,7 @@ use edr_eth::{
     remote::{
         eth::CallRequest,
         filter::{LogFilterOptions, LogOutput, OneOrMore},
-        BlockSpec, BlockTag, PreEip1898BlockSpec,
+        BlockSpec, BlockTag, Eip1898BlockSpec, PreEip1898BlockSpec,
     },
     transaction::EthTransactionRequest,
     Address, Bytes, B256, U256, U64,
@@ -63,6 +63,25 @@ fn test_serde_eth_coinbase() {
     help_test_method_invocation_serde(MethodInvocation::Coinbase(()));
 }
 
+#[test]
+fn test_serde_eth_create_access_list() {
+    let tx = CallRequest {
+        from: Some(Address::from(U160::from(1))),
+        to: Some(Address::from(U160::from(2))),
+        data: Some(Bytes::from(&b"whatever"[..])),
+        ..CallRequest::default()
+    };
+    help_test_method_invocation_serde(MethodInvocation::CreateAccessList(
+        tx.clone(),
+        Some(BlockSpec::latest()),
+    ));
+
+    help_test_method_invocation_serde_with_expected(
+        MethodInvocation::CreateAccessList(tx.clone(), None),
+        MethodInvocation::CreateAccessList(tx, Some(BlockSpec::latest())),
+    );
+}
+
 #[test]
 fn test_serde_eth_estimate_gas() {
     let tx = CallRequest {
@@ -139,6 +158,19 @@ fn test_serde_eth_get_block_by_hash() {
     ));
 }
 
+#[test]
+fn test_serde_eth_get_block_receipts() {
+    help_test_method_invocation_serde(MethodInvocation::GetBlockReceipts(BlockSpec::latest()));
+    help_test_method_invocation_serde(MethodInvocation::GetBlockReceipts(BlockSpec::pending()));
+    help_test_method_invocation_serde(MethodInvocation::GetBlockReceipts(BlockSpec::Number(100)));
+    help_test_method_invocation_serde(MethodInvocation::GetBlockReceipts(BlockSpec::Eip1898(
+        Eip1898BlockSpec::Hash {
+            block_hash: B256::from(U256::from(1)),
+            require_canonical: None,
+        },
+    )));
+}
+
 #[test]
 fn test_serde_eth_get_transaction_count() {
     help_test_method_invocation_serde(MethodInvocation::GetTransactionCount(
@@ -223,6 +255,15 @@ fn test_serde_eth_get_logs_by_block_hash() {
     }));
 }
 
+#[test]
+fn test_serde_eth_get_proof() {
+    help_test_method_invocation_serde(MethodInvocation::GetProof(
+        Address::from(U160::from(1)),
+        vec![U256::ZERO, U256::from(1)],
+        BlockSpec::latest(),
+    ));
+}
+
 #[test]
 fn test_serde_eth_get_storage_at() {
     help_test_method_invocation_serde(MethodInvocation::GetStorageAt(
@@ -462,6 +503,13 @@ fn test_evm_set_next_block_timestamp() {
     )));
 }
 
+#[test]
+fn test_evm_set_time() {
+    help_test_method_invocation_serde(MethodInvocation::EvmSetTime(U64OrUsize::U64(U64::from(
+        12345,
+    ))));
+}
+
 #[test]
 fn test_serde_web3_client_version() {
     help_test_method_invocation_serde(MethodInvocation::Web3ClientVersion(()));
@@ -515,3 +563,13 @@ fn test_personal_sign() {
 
     assert_eq!(call, call_deserialized);
 }
+
+#[test]
+fn test_txpool_content() {
+    help_test_method_invocation_serde(MethodInvocation::TxpoolContent(()));
+}
+
+#[test]
+fn test_txpool_status() {
+    help_test_method_invocation_serde(MethodInvocation::TxpoolStatus(()));
+}
@@ -0,0 +1,48 @@
+use edr_eth::{access_list::AccessListItem, remote::eth::CallRequest, Address, Bytes, B256};
+use edr_provider::{
+    test_utils::create_test_config, MethodInvocation, NoopLogger, Provider, ProviderRequest,
+};
+use tokio::runtime;
+
+#[tokio::test(flavor = "multi_thread")]
+async fn call_and_estimate_gas_return_generated_access_list() -> anyhow::Result<()> {
+    // PUSH1 0 SLOAD POP STOP
+    const SLOAD_SLOT_ZERO_CODE: [u8; 5] = [0x60, 0x00, 0x54, 0x50, 0x00];
+
+    let mut config = create_test_config();
+    config.generate_access_lists_for_calls = true;
+
+    let logger = Box::new(NoopLogger);
+    let subscriber = Box::new(|_event| {});
+    let provider = Provider::new(runtime::Handle::current(), logger, subscriber, config)?;
+
+    let contract_address = Address::random();
+    provider.handle_request(ProviderRequest::Single(MethodInvocation::SetCode(
+        contract_address,
+        Bytes::from_static(&SLOAD_SLOT_ZERO_CODE),
+    )))?;
+
+    let call_request = CallRequest {
+        to: Some(contract_address),
+        ..CallRequest::default()
+    };
+
+    let expected_access_list = vec![AccessListItem {
+        address: contract_address,
+        storage_keys: vec![B256::ZERO],
+    }];
+
+    let call = provider.handle_request(ProviderRequest::Single(MethodInvocation::Call(
+        call_request.clone(),
+        None,
+        None,
+    )))?;
+    assert_eq!(call.access_list.as_ref(), Some(&expected_access_list));
+
+    let estimation = provider.handle_request(ProviderRequest::Single(
+        MethodInvocation::EstimateGas(call_request, None),
+    ))?;
+    assert_eq!(estimation.access_list, Some(expected_access_list));
+
+    Ok(())
+}
@@ -1,9 +1,11 @@
 mod common;
 
-use edr_eth::{Address, Bytes, B256, U256};
+use edr_eth::{remote::BlockSpec, Address, Bytes, B256, U256, U64};
 use edr_evm::alloy_primitives::U160;
 use edr_provider::{
-    hardhat_rpc_types::{CompilerInput, CompilerOutput, ForkConfig, ResetProviderConfig},
+    hardhat_rpc_types::{
+        CompilerInput, CompilerOutput, ForkConfig, ResetProviderConfig, StateQuery, StateQueryKind,
+    },
     MethodInvocation,
 };
 
@@ -49,11 +51,56 @@ fn serde_hardhat_drop_transaction() {
     help_test_method_invocation_serde(MethodInvocation::DropTransaction(B256::from(U256::from(1))));
 }
 
+#[test]
+fn serde_hardhat_batch_query_state() {
+    help_test_method_invocation_serde(MethodInvocation::BatchQueryState(vec![
+        StateQuery {
+            kind: StateQueryKind::Balance,
+            address: Address::from(U160::from(1)),
+            slot: None,
+            block: Some(BlockSpec::latest()),
+        },
+        StateQuery {
+            kind: StateQueryKind::Code,
+            address: Address::from(U160::from(2)),
+            slot: None,
+            block: None,
+        },
+        StateQuery {
+            kind: StateQueryKind::Storage,
+            address: Address::from(U160::from(3)),
+            slot: Some(U256::from(4)),
+            block: Some(BlockSpec::Number(5)),
+        },
+    ]));
+}
+
+#[test]
+fn serde_hardhat_gas_fees() {
+    help_test_method_invocation_serde(MethodInvocation::GasFees(()));
+}
+
 #[test]
 fn serde_hardhat_get_automine() {
     help_test_method_invocation_serde(MethodInvocation::GetAutomine(()));
 }
 
+#[test]
+fn serde_hardhat_get_accounts() {
+    help_test_method_invocation_serde(MethodInvocation::GetAccounts(
+        vec![Address::from(U160::from(1)), Address::from(U160::from(2))],
+        Some(BlockSpec::latest()),
+    ));
+}
+
+#[test]
+fn serde_hardhat_get_code_hash() {
+    help_test_method_invocation_serde(MethodInvocation::GetCodeHash(
+        Address::from(U160::from(1)),
+        Some(BlockSpec::latest()),
+    ));
+}
+
 #[test]
 fn serde_hardhat_get_stack_trace_failures_count() {
     help_test_method_invocation_serde(MethodInvocation::GetStackTraceFailuresCount(()));
@@ -100,6 +147,37 @@ fn serde_hardhat_reset() {
     })));
 }
 
+#[test]
+fn serde_hardhat_reset_with_http_headers() {
+    help_test_method_invocation_serde(MethodInvocation::Reset(Some(ResetProviderConfig {
+        forking: Some(ForkConfig {
+            json_rpc_url: String::from("http://whatever.com/whatever"),
+            block_number: Some(123456),
+            http_headers: Some(
+                [
+                    (
+                        String::from("Authorization"),
+                        String::from("Bearer some-token"),
+                    ),
+                    (String::from("x-api-key"), String::from("some-key")),
+                ]
+                .into_iter()
+                .collect(),
+            ),
+        }),
+    })));
+}
+
+#[test]
+fn serde_hardhat_rollback() {
+    help_test_method_invocation_serde(MethodInvocation::Rollback(U64::from(5)));
+}
+
+#[test]
+fn serde_hardhat_safe_block_number() {
+    help_test_method_invocation_serde(MethodInvocation::SafeBlockNumber(()));
+}
+
 #[test]
 fn serde_hardhat_set_balance() {
     help_test_method_invocation_serde(MethodInvocation::SetBalance(
@@ -108,6 +186,11 @@ fn serde_hardhat_set_balance() {
     ));
 }
 
+#[test]
+fn serde_hardhat_set_block_gas_limit() {
+    help_test_method_invocation_serde(MethodInvocation::SetBlockGasLimit(U256::from(30_000_000)));
+}
+
 #[test]
 fn serde_hardhat_set_code() {
     help_test_method_invocation_serde(MethodInvocation::SetCode(
@@ -136,6 +219,11 @@ fn serde_hardhat_set_next_block_base_fee_per_gas() {
     help_test_method_invocation_serde(MethodInvocation::SetNextBlockBaseFeePerGas(U256::from(1)));
 }
 
+#[test]
+fn serde_hardhat_set_next_block_difficulty() {
+    help_test_method_invocation_serde(MethodInvocation::SetNextBlockDifficulty(U256::from(1)));
+}
+
 #[test]
 fn serde_hardhat_set_nonce() {
     help_test_method_invocation_serde(MethodInvocation::SetNonce(Address::random(), 1u64));
@@ -155,6 +243,14 @@ fn serde_hardhat_set_storage_at() {
     ));
 }
 
+#[test]
+fn serde_hardhat_set_storage_bulk() {
+    help_test_method_invocation_serde(MethodInvocation::SetStorageBulk(
+        Address::random(),
+        vec![(U256::ZERO, U256::MAX), (U256::MAX, U256::from(1))],
+    ));
+}
+
 #[test]
 fn serde_hardhat_stop_impersonating_account() {
     help_test_method_invocation_serde(MethodInvocation::StopImpersonatingAccount(
@@ -0,0 +1,72 @@
+use std::{convert::Infallible, time::Duration};
+
+use edr_provider::{test_utils::create_test_config, NoopLogger, Provider, ProviderError};
+use serde_json::json;
+use tokio::runtime;
+
+fn block_number(provider: &Provider<Infallible>) -> anyhow::Result<u64> {
+    let response = provider.handle_request(serde_json::from_value(json!({
+        "method": "eth_blockNumber",
+        "params": []
+    }))?)?;
+
+    let block_number = response
+        .result
+        .as_str()
+        .ok_or_else(|| anyhow::anyhow!("expected a quantity"))?;
+
+    Ok(u64::from_str_radix(
+        block_number.trim_start_matches("0x"),
+        16,
+    )?)
+}
+
+fn set_interval_mining(
+    provider: &Provider<Infallible>,
+    config: serde_json::Value,
+) -> anyhow::Result<Result<serde_json::Value, ProviderError<Infallible>>> {
+    let response = provider.handle_request(serde_json::from_value(json!({
+        "method": "evm_setIntervalMining",
+        "params": [config]
+    }))?);
+
+    Ok(response.map(|response| response.result))
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn interval_mining_can_be_started_and_stopped() -> anyhow::Result<()> {
+    let config = create_test_config();
+    let logger = Box::new(NoopLogger);
+    let subscriber = Box::new(|_event| {});
+    let provider = Provider::new(runtime::Handle::current(), logger, subscriber, config)?;
+
+    assert_eq!(block_number(&provider)?, 0);
+
+    let result = set_interval_mining(&provider, json!([10, 20]))?;
+    assert_eq!(result.expect("valid range"), json!(true));
+
+    tokio::time::sleep(Duration::from_millis(200)).await;
+    assert!(block_number(&provider)? > 0);
+
+    let result = set_interval_mining(&provider, json!(0))?;
+    assert_eq!(result.expect("disables interval mining"), json!(true));
+
+    let stopped_at = block_number(&provider)?;
+    tokio::time::sleep(Duration::from_millis(100)).await;
+    assert_eq!(block_number(&provider)?, stopped_at);
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn interval_mining_rejects_invalid_range() -> anyhow::Result<()> {
+    let config = create_test_config();
+    let logger = Box::new(NoopLogger);
+    let subscriber = Box::new(|_event| {});
+    let provider = Provider::new(runtime::Handle::current(), logger, subscriber, config)?;
+
+    let result = set_interval_mining(&provider, json!([20, 10]))?;
+    assert!(matches!(result, Err(ProviderError::InvalidArgument(_))));
+
+    Ok(())
+}